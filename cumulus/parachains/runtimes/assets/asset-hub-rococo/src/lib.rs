@@ -550,6 +550,10 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -705,6 +709,20 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// The calls budgeted proxies may dispatch: anything but approving transfers of assets, which
+/// would let the approved account spend them without going through the proxy.
+pub struct BudgetedProxyCalls;
+impl frame_support::traits::Contains<RuntimeCall> for BudgetedProxyCalls {
+	fn contains(c: &RuntimeCall) -> bool {
+		!matches!(
+			c,
+			RuntimeCall::Assets(TrustBackedAssetsCall::approve_transfer { .. }) |
+				RuntimeCall::ForeignAssets(pallet_assets::Call::approve_transfer { .. }) |
+				RuntimeCall::PoolAssets(pallet_assets::Call::approve_transfer { .. })
+		)
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -719,6 +737,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type BudgetAssets = pallet_proxy::FungiblesBudget<Assets>;
+	type MaxBudgetAssets = ConstU32<8>;
+	type BudgetCallFilter = BudgetedProxyCalls;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_proxy::AssetKindFromSeed;
 }
 
 parameter_types! {
@@ -35,7 +35,8 @@ use xcm::latest::BodyId;
 mod origins;
 pub use origins::{
 	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin,
-	ReferendumCanceller, ReferendumKiller, Spender, StakingAdmin, Treasurer, WhitelistedCaller,
+	ReferendumCanceller, ReferendumKiller, Spender, SpenderTrack, StakingAdmin, Treasurer,
+	WhitelistedCaller,
 };
 mod tracks;
 pub use tracks::TracksInfo;
@@ -51,6 +52,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegates = ConstU32<16>;
 	type MaxTurnout =
 		frame_support::traits::tokens::currency::ActiveIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
@@ -155,6 +157,10 @@ impl pallet_treasury::Config for Runtime {
 	type BenchmarkHelper = parachains_common::pay::benchmarks::LocalPayArguments<
 		xcm_config::TrustBackedAssetsPalletIndex,
 	>;
+	// The referenda track of the spend origin.
+	type BudgetHolder = u16;
+	type BudgetOrigin = SpenderTrack;
+	type ManageBudgetOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_asset_rate::Config for Runtime {
@@ -159,4 +159,16 @@ pub mod pallet_custom_origins {
 			Treasurer = 10_000 * GRAND,
 		}
 	}
+
+	// The referenda track of each spending origin, holding the treasury budgets of the origin.
+	decl_ensure! {
+		pub type SpenderTrack: EnsureOrigin<Success = u16> {
+			SmallTipper = 30,
+			BigTipper = 31,
+			SmallSpender = 32,
+			MediumSpender = 33,
+			BigSpender = 34,
+			Treasurer = 11,
+		}
+	}
 }
@@ -489,6 +489,11 @@ parameter_types! {
 	pub const MinVestedTransfer: Balance = 100 * CENTS;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const VestingPalletId: PalletId = PalletId(*b"py/vestg");
+	// One storage item; key size 60, value size 436.
+	pub const ReleaseScheduleDeposit: Balance = deposit(1, 496);
+	// Schedules of assets must hold at least this many times the minimum balance of the asset.
+	pub const MinVestedAssetTransferFactor: u32 = 100;
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -500,6 +505,13 @@ impl pallet_vesting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type Assets = pallet_vesting::FungiblesVestingAssets<Assets, MinVestedAssetTransferFactor>;
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = ConstU32<16>;
+	type ReleaseScheduleDeposit = ReleaseScheduleDeposit;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 parameter_types! {
@@ -631,6 +643,10 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -851,6 +867,20 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// The calls budgeted proxies may dispatch: anything but approving transfers of assets, which
+/// would let the approved account spend them without going through the proxy.
+pub struct BudgetedProxyCalls;
+impl frame_support::traits::Contains<RuntimeCall> for BudgetedProxyCalls {
+	fn contains(c: &RuntimeCall) -> bool {
+		!matches!(
+			c,
+			RuntimeCall::Assets(TrustBackedAssetsCall::approve_transfer { .. }) |
+				RuntimeCall::ForeignAssets(pallet_assets::Call::approve_transfer { .. }) |
+				RuntimeCall::PoolAssets(pallet_assets::Call::approve_transfer { .. })
+		)
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -865,6 +895,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type BudgetAssets = pallet_proxy::FungiblesBudget<Assets>;
+	type MaxBudgetAssets = ConstU32<8>;
+	type BudgetCallFilter = BudgetedProxyCalls;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_proxy::AssetKindFromSeed;
 }
 
 parameter_types! {
@@ -1231,6 +1268,8 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ConstU64<{ RELAY_CHAIN_SLOT_DURATION_MILLIS as u64 }>;
 }
 
 parameter_types! {
@@ -1875,6 +1914,28 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		pallet_referenda::TrackIdOf<Runtime, ()>,
+		pallet_referenda::TallyOf<Runtime, ()>,
+		pallet_referenda::BlockNumberFor<Runtime, ()>,
+	> for Runtime {
+		fn referendum_timeline(
+			index: pallet_referenda::ReferendumIndex,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::referendum_timeline(index, step)
+		}
+
+		fn simulate_timeline(
+			track: pallet_referenda::TrackIdOf<Runtime, ()>,
+			tally: pallet_referenda::TallyOf<Runtime, ()>,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::simulate_timeline(track, tally, step)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
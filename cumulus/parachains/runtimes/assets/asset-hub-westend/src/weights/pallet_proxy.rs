@@ -165,33 +165,40 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `161 + p * (37 ±0)`
 		//  Estimated: `4706`
 		// Minimum execution time: 23_865_000 picoseconds.
-		Weight::from_parts(25_111_800, 0)
+		Weight::from_parts(34_451_800, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
 			// Standard Error: 1_501
 			.saturating_add(Weight::from_parts(45_002, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:31 w:31)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `4706 + p * (3027 ±0)`
 		// Minimum execution time: 21_721_000 picoseconds.
 		Weight::from_parts(22_626_669, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
 			// Standard Error: 1_314
-			.saturating_add(Weight::from_parts(27_650, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(6_147_650, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3027).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -210,18 +217,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:30 w:30)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `198 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `4706 + p * (3027 ±0)`
 		// Minimum execution time: 22_817_000 picoseconds.
 		Weight::from_parts(23_755_496, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
 			// Standard Error: 1_854
-			.saturating_add(Weight::from_parts(33_383, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(6_153_383, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3027).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -239,4 +251,55 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 8]`.
+	/// The range of component `p` is `[1, 31]`.
+	fn set_proxy_budget(b: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65 + p * (36 ±0)`
+		//  Estimated: `4706`
+		// Minimum execution time: 19_562_000 picoseconds.
+		Weight::from_parts(25_950_469, 0)
+			.saturating_add(Weight::from_parts(0, 4706))
+			// Standard Error: 53_414
+			.saturating_add(Weight::from_parts(943_822, 0).saturating_mul(b.into()))
+			// Standard Error: 15_058
+			.saturating_add(Weight::from_parts(184_050, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
+	fn remove_proxy_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `4017`
+		// Minimum execution time: 32_215_000 picoseconds.
+		Weight::from_parts(36_317_000, 0)
+			.saturating_add(Weight::from_parts(0, 4017))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:0)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 8]`.
+	fn budgeted_dispatch(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `201 + b * (54 ±0)`
+		//  Estimated: `4017`
+		// Minimum execution time: 16_790_000 picoseconds.
+		Weight::from_parts(31_038_534, 0)
+			.saturating_add(Weight::from_parts(0, 4017))
+			// Standard Error: 100_241
+			.saturating_add(Weight::from_parts(1_936_822, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(b.into()))
+	}
 }
@@ -53,15 +53,19 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: Treasury Approvals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
 	/// Storage: Treasury Proposals (r:0 w:1)
 	/// Proof: Treasury Proposals (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Treasury LastSpendPeriod (r:1 w:0)
+	/// Proof: Treasury LastSpendPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury LocalBudgets (r:1 w:1)
+	/// Proof: Treasury LocalBudgets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	fn spend_local() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `1887`
-		// Minimum execution time: 177_000_000 picoseconds.
-		Weight::from_parts(191_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 1887))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `77`
+		//  Estimated: `4948`
+		// Minimum execution time: 16_156_000 picoseconds.
+		Weight::from_parts(17_427_000, 0)
+			.saturating_add(Weight::from_parts(0, 4948))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Treasury Approvals (r:1 w:1)
 	/// Proof: Treasury Approvals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
@@ -109,15 +113,21 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: Treasury SpendCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Treasury Spends (r:0 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
+	/// Storage: Treasury LastSpendPeriod (r:1 w:0)
+	/// Proof: Treasury LastSpendPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury Budgets (r:1 w:1)
+	/// Proof: Treasury Budgets (max_values: None, max_size: Some(1918), added: 4393, mode: MaxEncodedLen)
+	/// Storage: Treasury SpendBudgets (r:0 w:1)
+	/// Proof: Treasury SpendBudgets (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
 	fn spend() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `114`
-		//  Estimated: `4702`
-		// Minimum execution time: 208_000_000 picoseconds.
-		Weight::from_parts(222_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 4702))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `9594`
+		// Minimum execution time: 16_318_000 picoseconds.
+		Weight::from_parts(17_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 9594))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
@@ -153,25 +163,83 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
 	/// Storage: XcmPallet Queries (r:1 w:1)
 	/// Proof Skipped: XcmPallet Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Treasury LastSpendPeriod (r:1 w:0)
+	/// Proof: Treasury LastSpendPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury Budgets (r:1 w:1)
+	/// Proof: Treasury Budgets (max_values: None, max_size: Some(1918), added: 4393, mode: MaxEncodedLen)
+	/// Storage: Treasury SpendBudgets (r:1 w:1)
+	/// Proof: Treasury SpendBudgets (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
 	fn check_status() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `442`
-		//  Estimated: `5313`
-		// Minimum execution time: 245_000_000 picoseconds.
-		Weight::from_parts(281_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 5313))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `12698`
+		// Minimum execution time: 11_701_000 picoseconds.
+		Weight::from_parts(12_321_000, 0)
+			.saturating_add(Weight::from_parts(0, 12698))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
+	/// Storage: Treasury LastSpendPeriod (r:1 w:0)
+	/// Proof: Treasury LastSpendPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury Budgets (r:1 w:1)
+	/// Proof: Treasury Budgets (max_values: None, max_size: Some(1918), added: 4393, mode: MaxEncodedLen)
+	/// Storage: Treasury SpendBudgets (r:1 w:1)
+	/// Proof: Treasury SpendBudgets (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
 	fn void_spend() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
-		//  Estimated: `5313`
-		// Minimum execution time: 147_000_000 picoseconds.
-		Weight::from_parts(160_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 5313))
+		//  Estimated: `12698`
+		// Minimum execution time: 21_333_000 picoseconds.
+		Weight::from_parts(22_797_000, 0)
+			.saturating_add(Weight::from_parts(0, 12698))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Treasury LastSpendPeriod (r:1 w:0)
+	/// Proof: Treasury LastSpendPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury Budgets (r:1 w:1)
+	/// Proof: Treasury Budgets (max_values: None, max_size: Some(1918), added: 4393, mode: MaxEncodedLen)
+	fn set_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `5381`
+		// Minimum execution time: 14_912_000 picoseconds.
+		Weight::from_parts(15_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 5381))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Treasury LastSpendPeriod (r:1 w:0)
+	/// Proof: Treasury LastSpendPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury LocalBudgets (r:1 w:1)
+	/// Proof: Treasury LocalBudgets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	fn set_local_budget() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(15_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 3550))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Treasury Budgets (r:1 w:1)
+	/// Proof: Treasury Budgets (max_values: None, max_size: Some(1918), added: 4393, mode: MaxEncodedLen)
+	fn remove_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `5381`
+		// Minimum execution time: 13_476_000 picoseconds.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 5381))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Treasury LocalBudgets (r:1 w:1)
+	/// Proof: Treasury LocalBudgets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	fn remove_local_budget() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 3550))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -115,4 +115,12 @@ impl<T: frame_system::Config> pallet_utility::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(9_481_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// The range of component `c` is `[0, 1000]`.
+	fn batch_with_results(c: u32, ) -> Weight {
+		// Not benchmarked: estimated from `batch_all`, which dispatches the calls within a
+		// storage layer in the same way.
+		Weight::from_parts(5_596_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(5_576_310, 0).saturating_mul(c.into()))
+	}
 }
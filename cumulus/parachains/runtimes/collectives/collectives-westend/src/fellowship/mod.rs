@@ -335,4 +335,7 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 		ConstU32<1000>,
 	>;
 	type BlockNumberProvider = crate::System;
+	type BudgetHolder = ();
+	type BudgetOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ManageBudgetOrigin = EnsureRoot<AccountId>;
 }
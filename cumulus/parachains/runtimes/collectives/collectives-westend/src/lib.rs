@@ -280,6 +280,10 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -389,6 +393,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type BudgetAssets = ();
+	type MaxBudgetAssets = ConstU32<0>;
+	type BudgetCallFilter = frame_support::traits::Everything;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -634,6 +645,8 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = EqualOrGreatestRootCmp;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ConstU64<MILLISECS_PER_BLOCK>;
 }
 
 parameter_types! {
@@ -56,15 +56,16 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipTreasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipTreasury::Proposals` (r:0 w:1)
 	/// Proof: `FellowshipTreasury::Proposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `FellowshipTreasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::LocalBudgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::LocalBudgets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	fn spend_local() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `1887`
-		// Minimum execution time: 12_879_000 picoseconds.
-		Weight::from_parts(13_346_000, 0)
-			.saturating_add(Weight::from_parts(0, 1887))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(19_346_000, 0)
+			.saturating_add(Weight::from_parts(0, 4946))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipTreasury::Approvals` (r:1 w:1)
 	/// Proof: `FellowshipTreasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
@@ -105,15 +106,18 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipTreasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipTreasury::Spends` (r:0 w:1)
 	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `FellowshipTreasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::Budgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::SpendBudgets` (r:0 w:1)
+	/// Proof: `FellowshipTreasury::SpendBudgets` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn spend() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `219`
-		//  Estimated: `4703`
-		// Minimum execution time: 24_150_000 picoseconds.
-		Weight::from_parts(24_739_000, 0)
-			.saturating_add(Weight::from_parts(0, 4703))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(31_739_000, 0)
+			.saturating_add(Weight::from_parts(0, 9593))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipTreasury::Spends` (r:1 w:1)
 	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
@@ -147,25 +151,77 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipTreasury::SpendBudgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::SpendBudgets` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `FellowshipTreasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::Budgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
 	fn check_status() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `240`
-		//  Estimated: `5318`
-		// Minimum execution time: 25_460_000 picoseconds.
-		Weight::from_parts(26_237_000, 0)
-			.saturating_add(Weight::from_parts(0, 5318))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(34_237_000, 0)
+			.saturating_add(Weight::from_parts(0, 12699))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipTreasury::Spends` (r:1 w:1)
 	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::SpendBudgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::SpendBudgets` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `FellowshipTreasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::Budgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(23_787_000, 0)
+			.saturating_add(Weight::from_parts(0, 12699))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `FellowshipTreasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `FellowshipTreasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::Budgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
+	fn set_budget() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `212`
-		//  Estimated: `5318`
-		// Minimum execution time: 15_357_000 picoseconds.
-		Weight::from_parts(15_787_000, 0)
-			.saturating_add(Weight::from_parts(0, 5318))
+		//  Measured:  `6`
+		//  Estimated: `5381`
+		// Minimum execution time: 14_912_000 picoseconds.
+		Weight::from_parts(15_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 5381))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FellowshipTreasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `FellowshipTreasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::LocalBudgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::LocalBudgets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn set_local_budget() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(15_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 3550))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FellowshipTreasury::Budgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
+	fn remove_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `5381`
+		// Minimum execution time: 13_476_000 picoseconds.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 5381))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FellowshipTreasury::LocalBudgets` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::LocalBudgets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn remove_local_budget() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 3550))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -114,6 +114,8 @@ parameter_types! {
 	pub const MinimumCreditPurchase: Balance = UNITS / 10;
 	pub RevenueAccumulationAccount: AccountId = BrokerPalletId::get().into_sub_account_truncating(b"burnstash");
 	pub const MinimumEndPrice: Balance = UNITS;
+	pub const ListingDeposit: Balance = deposit(1, 124);
+	pub const BidDeposit: Balance = deposit(1, 132);
 }
 
 /// Type that implements the `CoretimeInterface` for the allocation of Coretime. Meant to operate
@@ -331,4 +333,9 @@ impl pallet_broker::Config for Runtime {
 	type MaxAutoRenewals = ConstU32<20>;
 	type PriceAdapter = pallet_broker::MinimumPrice<Balance, MinimumEndPrice>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
+	type ListingDeposit = ListingDeposit;
+	type BidDeposit = BidDeposit;
+	type MaxListings = ConstU32<1000>;
+	type MaxListingsPerAccount = ConstU32<10>;
+	type MaxBids = ConstU32<20>;
 }
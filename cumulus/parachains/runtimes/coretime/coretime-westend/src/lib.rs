@@ -581,6 +581,10 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -598,6 +602,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type BudgetAssets = ();
+	type MaxBudgetAssets = ConstU32<0>;
+	type BudgetCallFilter = frame_support::traits::Everything;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_utility::Config for Runtime {
@@ -165,33 +165,40 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
 		//  Estimated: `4706`
 		// Minimum execution time: 23_797_000 picoseconds.
-		Weight::from_parts(24_845_997, 0)
+		Weight::from_parts(34_185_997, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
 			// Standard Error: 1_369
 			.saturating_add(Weight::from_parts(49_882, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:31 w:31)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `4706 + p * (2595 ±0)`
 		// Minimum execution time: 21_311_000 picoseconds.
 		Weight::from_parts(22_123_383, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
 			// Standard Error: 1_276
-			.saturating_add(Weight::from_parts(32_718, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(6_152_718, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -210,18 +217,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:30 w:30)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `164 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `4706 + p * (2595 ±0)`
 		// Minimum execution time: 22_087_000 picoseconds.
 		Weight::from_parts(23_104_943, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
 			// Standard Error: 1_686
-			.saturating_add(Weight::from_parts(35_333, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(6_155_333, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -239,4 +251,47 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 0]`.
+	/// The range of component `p` is `[1, 31]`.
+	fn set_proxy_budget(_b: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65 + p * (36 ±0)`
+		//  Estimated: `4706`
+		// Minimum execution time: 19_562_000 picoseconds.
+		Weight::from_parts(25_950_469, 0)
+			.saturating_add(Weight::from_parts(0, 4706))
+			// Standard Error: 15_058
+			.saturating_add(Weight::from_parts(184_050, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	fn remove_proxy_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3585`
+		// Minimum execution time: 32_215_000 picoseconds.
+		Weight::from_parts(36_317_000, 0)
+			.saturating_add(Weight::from_parts(0, 3585))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::ProxyBudgets` (r:1 w:1)
+	/// Proof: `Proxy::ProxyBudgets` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 0]`.
+	fn budgeted_dispatch(_b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `201`
+		//  Estimated: `3585`
+		// Minimum execution time: 16_790_000 picoseconds.
+		Weight::from_parts(31_038_534, 0)
+			.saturating_add(Weight::from_parts(0, 3585))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -64,6 +64,151 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Switch the state pruning mode of an existing database.
+	MigrateStatePruning(sc_cli::MigrateStatePruningCmd),
+
+	/// Export and replay dispute evidence.
+	#[command(subcommand)]
+	DisputeEvidence(DisputeEvidenceCmd),
+
+	/// List the candidates held by the availability store, one per line.
+	HeldCandidates(HeldCandidatesCmd),
+
+	/// Move compiled PVF artifacts between compatible nodes.
+	#[command(subcommand)]
+	PvfArtifacts(PvfArtifactsCmd),
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct HeldCandidatesCmd {
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for HeldCandidatesCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Offline tooling for the persisted PVF artifacts.
+#[derive(Debug, Parser)]
+pub enum PvfArtifactsCmd {
+	/// Export the compiled PVF artifacts of the node into a directory.
+	///
+	/// Only artifacts compatible with this node are exported.
+	Export(PvfArtifactsDirCmd),
+
+	/// Import the compiled PVF artifacts exported by a compatible node.
+	///
+	/// Artifacts are native code; only import them from a trusted source. The node must not be
+	/// running.
+	Import(PvfArtifactsDirCmd),
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct PvfArtifactsDirCmd {
+	/// The directory to export the artifacts to or to import them from.
+	#[arg(long, value_name = "PATH")]
+	pub path: PathBuf,
+
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for PvfArtifactsDirCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Offline tooling for investigating disputes.
+#[derive(Debug, Parser)]
+pub enum DisputeEvidenceCmd {
+	/// Export everything known about a disputed candidate into a portable bundle.
+	///
+	/// Reads the databases of the node, which must not be running.
+	Export(ExportDisputeEvidenceCmd),
+
+	/// Replay the validation of an exported bundle to reproduce the dispute verdict.
+	Replay(ReplayDisputeEvidenceCmd),
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct ExportDisputeEvidenceCmd {
+	/// The session the candidate was disputed in.
+	#[arg(long)]
+	pub session: u32,
+
+	/// The hash of the disputed candidate.
+	#[arg(long, value_name = "HASH")]
+	pub candidate: polkadot_service::Hash,
+
+	/// The file to write the evidence bundle to.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: PathBuf,
+
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ExportDisputeEvidenceCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct ReplayDisputeEvidenceCmd {
+	/// The file to read the evidence bundle from.
+	#[arg(long, short, value_name = "PATH")]
+	pub input: PathBuf,
+
+	/// Path to the directory where auxiliary worker binaries reside.
+	///
+	/// If not specified, the main binary's directory is searched first, then
+	/// `/usr/lib/polkadot` is searched.
+	#[arg(long, value_name = "PATH")]
+	pub workers_path: Option<PathBuf>,
+
+	/// TESTING ONLY: disable the version check between nodes and workers.
+	#[arg(long, hide = true)]
+	pub disable_worker_version_check: bool,
+
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+}
+
+impl sc_cli::CliConfiguration for ReplayDisputeEvidenceCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
 }
 
 #[allow(missing_docs)]
@@ -155,10 +300,23 @@ pub struct RunCmd {
 	pub disable_worker_version_check: bool,
 
 	/// How long finalized data should be kept in the availability store (in hours).
-	/// Only used for testnets. If not specified, set to 1 hour. Always set to 25 hours for live
-	/// networks.
+	/// If not specified, set to 1 hour for testnets and to 25 hours for live networks. Live
+	/// networks can only extend the retention, e.g. for archival nodes serving PoVs.
 	#[arg(long)]
 	pub keep_finalized_for: Option<u32>,
+
+	/// How long data of candidates not included in any block should be kept in the availability
+	/// store (in hours). If not specified, set to 1 hour.
+	#[arg(long)]
+	pub keep_unavailable_for: Option<u32>,
+
+	/// Upper bound on the size of the availability store (in MiB).
+	///
+	/// Once exceeded, finalized data is pruned ahead of time, the least recently finalized first.
+	/// Data which is not finalized yet, or was finalized within the last 25 hours (the period
+	/// needed by approval checking and disputes), is always kept.
+	#[arg(long, value_name = "MiB")]
+	pub availability_store_max_size: Option<u64>,
 }
 
 #[allow(missing_docs)]
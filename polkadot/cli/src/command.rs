@@ -14,15 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::cli::{Cli, Subcommand, NODE_VERSION};
+use crate::cli::{
+	Cli, DisputeEvidenceCmd, ExportDisputeEvidenceCmd, PvfArtifactsCmd, ReplayDisputeEvidenceCmd,
+	Subcommand, NODE_VERSION,
+};
 use frame_benchmarking_cli::{
 	BenchmarkCmd, ExtrinsicFactory, SubstrateRemarkBuilder, SUBSTRATE_REFERENCE_HARDWARE,
 };
 use futures::future::TryFutureExt;
 use log::info;
+use polkadot_node_primitives::ValidationResult;
 use polkadot_service::{
 	self,
 	benchmarking::{benchmark_inherent_data, TransferKeepAliveBuilder},
+	dispute_evidence::{self, DisputeEvidence, Verdict},
 	HeaderBackend, IdentifyVariant,
 };
 #[cfg(feature = "pyroscope")]
@@ -34,6 +39,7 @@ use sp_keyring::Sr25519Keyring;
 pub use crate::error::Error;
 #[cfg(feature = "pyroscope")]
 use std::net::ToSocketAddrs;
+use std::path::PathBuf;
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -235,6 +241,11 @@ where
 				prepare_workers_hard_max_num: cli.run.prepare_workers_hard_max_num,
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
 				keep_finalized_for: cli.run.keep_finalized_for,
+				keep_unavailable_for: cli.run.keep_unavailable_for,
+				availability_store_max_size: cli
+					.run
+					.availability_store_max_size
+					.map(|mib| mib.saturating_mul(1024 * 1024)),
 			},
 		)
 		.map(|full| full.task_manager)?;
@@ -251,6 +262,57 @@ where
 	})
 }
 
+fn export_dispute_evidence(
+	cmd: &ExportDisputeEvidenceCmd,
+	config: &mut sc_service::Configuration,
+) -> Result<()> {
+	let candidate_hash = dispute_evidence::CandidateHash(cmd.candidate);
+	let evidence = dispute_evidence::export(config, cmd.session, candidate_hash)?;
+	std::fs::write(&cmd.output, evidence.to_bundle()).map_err(Error::DisputeEvidenceBundle)?;
+
+	info!(
+		"Exported evidence for candidate {:?} of session {} ({} valid and {} invalid votes, verdict: {:?}, replayable: {}) to {}",
+		candidate_hash,
+		cmd.session,
+		evidence.valid.len(),
+		evidence.invalid.len(),
+		evidence.verdict(),
+		evidence.is_replayable(),
+		cmd.output.display(),
+	);
+	Ok(())
+}
+
+async fn replay_dispute_evidence(
+	cmd: &ReplayDisputeEvidenceCmd,
+	cache_path: PathBuf,
+) -> Result<()> {
+	let bundle = std::fs::read(&cmd.input).map_err(Error::DisputeEvidenceBundle)?;
+	let evidence =
+		DisputeEvidence::from_bundle(&bundle).map_err(polkadot_service::Error::DisputeEvidence)?;
+	let node_version =
+		if cmd.disable_worker_version_check { None } else { Some(NODE_VERSION.to_string()) };
+
+	let result =
+		dispute_evidence::replay(&evidence, cmd.workers_path.clone(), node_version, cache_path)
+			.await?;
+	let replayed = match result {
+		ValidationResult::Valid(..) => Verdict::Valid,
+		ValidationResult::Invalid(err) => {
+			info!("Candidate {:?} is invalid: {:?}", evidence.candidate_hash(), err);
+			Verdict::Invalid
+		},
+	};
+
+	match evidence.verdict() {
+		Some(recorded) if recorded != replayed =>
+			return Err(Error::DisputeVerdictMismatch { recorded, replayed }),
+		Some(recorded) => info!("Replayed verdict {:?} matches the dispute outcome", recorded),
+		None => info!("Replayed verdict {:?}, the dispute has not concluded", replayed),
+	}
+	Ok(())
+}
+
 /// Parses polkadot specific CLI arguments and run the service.
 pub fn run() -> Result<()> {
 	let cli: Cli = Cli::from_args();
@@ -468,6 +530,48 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
 		},
+		Some(Subcommand::MigrateStatePruning(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
+		},
+		Some(Subcommand::HeldCandidates(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.sync_run(|config| {
+				for held in polkadot_service::held_availability_candidates(&config.database)? {
+					println!("{held}");
+				}
+				Ok::<_, Error>(())
+			})?)
+		},
+		Some(Subcommand::PvfArtifacts(PvfArtifactsCmd::Export(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.sync_run(|config| {
+				let exported = polkadot_service::export_pvf_artifacts(&config.database, &cmd.path)?;
+				info!("Exported {} PVF artifacts to {}", exported, cmd.path.display());
+				Ok::<_, Error>(())
+			})?)
+		},
+		Some(Subcommand::PvfArtifacts(PvfArtifactsCmd::Import(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.sync_run(|config| {
+				let imported = polkadot_service::import_pvf_artifacts(&config.database, &cmd.path)?;
+				info!("Imported {} PVF artifacts from {}", imported, cmd.path.display());
+				Ok::<_, Error>(())
+			})?)
+		},
+		Some(Subcommand::DisputeEvidence(DisputeEvidenceCmd::Export(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.sync_run(|mut config| export_dispute_evidence(cmd, &mut config))?)
+		},
+		Some(Subcommand::DisputeEvidence(DisputeEvidenceCmd::Replay(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			Ok(runner.async_run(|config| {
+				let task_manager = sc_service::TaskManager::new(config.tokio_handle.clone(), None)
+					.map_err(sc_service::Error::Prometheus)?;
+				let cache_path = config.base_path.path().join("dispute-evidence-replay");
+				Ok((replay_dispute_evidence(cmd, cache_path), task_manager))
+			})?)
+		},
 	}?;
 
 	#[cfg(feature = "pyroscope")]
@@ -51,6 +51,15 @@ pub enum Error {
 	#[error("Other: {0}")]
 	Other(String),
 
+	#[error("Failed to access the dispute evidence bundle: {0}")]
+	DisputeEvidenceBundle(std::io::Error),
+
+	#[error("Replayed verdict {replayed:?} differs from the dispute outcome {recorded:?}")]
+	DisputeVerdictMismatch {
+		recorded: polkadot_service::dispute_evidence::Verdict,
+		replayed: polkadot_service::dispute_evidence::Verdict,
+	},
+
 	#[error("This subcommand is only available when compiled with `{feature}`")]
 	FeatureNotEnabled { feature: &'static str },
 }
@@ -67,14 +67,12 @@ const CHUNK_PREFIX: &[u8; 5] = b"chunk";
 const META_PREFIX: &[u8; 4] = b"meta";
 const UNFINALIZED_PREFIX: &[u8; 11] = b"unfinalized";
 const PRUNE_BY_TIME_PREFIX: &[u8; 13] = b"prune_by_time";
+const STORED_SIZE_PREFIX: &[u8; 11] = b"stored_size";
 
 // We have some keys we want to map to empty values because existence of the key is enough. We use
 // this because rocksdb doesn't support empty values.
 const TOMBSTONE_VALUE: &[u8] = b" ";
 
-/// Unavailable blocks are kept for 1 hour.
-const KEEP_UNAVAILABLE_FOR: Duration = Duration::from_secs(60 * 60);
-
 /// The pruning interval.
 const PRUNING_INTERVAL: Duration = Duration::from_secs(60 * 5);
 
@@ -186,7 +184,10 @@ fn write_available_data(
 	tx.put_vec(config.col_data, &key[..], available_data.encode());
 }
 
-fn load_available_data(
+/// Load the full available data of a candidate straight from the database.
+///
+/// Exposed for offline tooling inspecting the database of a stopped node.
+pub fn load_available_data(
 	db: &Arc<dyn Database>,
 	config: &Config,
 	hash: &CandidateHash,
@@ -196,6 +197,114 @@ fn load_available_data(
 	query_inner(db, config.col_data, &key)
 }
 
+/// The state of a candidate held by the availability store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeldCandidateState {
+	/// The candidate is not included in any block. Holds the time it was first observed at.
+	Unavailable(Duration),
+	/// The candidate is included in the given unfinalized blocks. Holds the time it was first
+	/// observed at.
+	Unfinalized(Duration, Vec<(BlockNumber, Hash)>),
+	/// The candidate is included in a finalized block. Holds the time of finalization.
+	Finalized(Duration),
+}
+
+/// A candidate held by the availability store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeldCandidate {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The number of the relay parent of the candidate, if the full available data is stored.
+	pub relay_parent_number: Option<BlockNumber>,
+	/// The state of the candidate.
+	pub state: HeldCandidateState,
+	/// The time the candidate is due to be pruned at.
+	pub prune_at: Duration,
+	/// Whether the full available data is stored.
+	pub data_available: bool,
+	/// The number of chunks stored.
+	pub chunks_stored: usize,
+	/// The number of bytes stored, if known.
+	pub stored_size: Option<u64>,
+}
+
+/// Formats the candidate as a single line of `key=value` fields, with the times in seconds
+/// since the UNIX epoch.
+impl std::fmt::Display for HeldCandidate {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:?}", self.candidate_hash.0)?;
+		match self.relay_parent_number {
+			Some(number) => write!(f, " relay_parent=#{number}")?,
+			None => write!(f, " relay_parent=unknown")?,
+		}
+		match &self.state {
+			HeldCandidateState::Unavailable(at) =>
+				write!(f, " state=unavailable since={}", at.as_secs())?,
+			HeldCandidateState::Unfinalized(at, blocks) => {
+				write!(f, " state=unfinalized since={} included_in=", at.as_secs())?;
+				for (i, (number, hash)) in blocks.iter().enumerate() {
+					let separator = if i == 0 { "" } else { "," };
+					write!(f, "{separator}#{number}:{hash:?}")?;
+				}
+			},
+			HeldCandidateState::Finalized(at) =>
+				write!(f, " state=finalized finalized_at={}", at.as_secs())?,
+		}
+		let data = if self.data_available { "stored" } else { "missing" };
+		write!(
+			f,
+			" prune_at={} data={data} chunks={}",
+			self.prune_at.as_secs(),
+			self.chunks_stored
+		)?;
+		match self.stored_size {
+			Some(size) => write!(f, " size={size}"),
+			None => write!(f, " size=unknown"),
+		}
+	}
+}
+
+/// List all candidates held by the availability store, in the order they are due to be pruned.
+///
+/// Exposed for offline tooling inspecting the database of a stopped node.
+pub fn held_candidates(
+	db: &Arc<dyn Database>,
+	config: &Config,
+) -> Result<Vec<HeldCandidate>, Error> {
+	let mut held = Vec::new();
+	for r in db.iter_with_prefix(config.col_meta, PRUNE_BY_TIME_PREFIX) {
+		let (k, _v) = r?;
+		let (prune_at, candidate_hash) = decode_pruning_key(&k[..])?;
+		let Some(meta) = load_meta(db, config, &candidate_hash)? else { continue };
+
+		let state = match meta.state {
+			State::Unavailable(at) => HeldCandidateState::Unavailable(at.into()),
+			State::Unfinalized(at, blocks) => HeldCandidateState::Unfinalized(
+				at.into(),
+				blocks.into_iter().map(|(number, hash)| (number.0, hash)).collect(),
+			),
+			State::Finalized(at) => HeldCandidateState::Finalized(at.into()),
+		};
+		let relay_parent_number = if meta.data_available {
+			load_available_data(db, config, &candidate_hash)?
+				.map(|data| data.validation_data.relay_parent_number)
+		} else {
+			None
+		};
+		held.push(HeldCandidate {
+			candidate_hash,
+			relay_parent_number,
+			state,
+			prune_at,
+			data_available: meta.data_available,
+			chunks_stored: meta.chunks_stored.count_ones(),
+			stored_size: load_stored_size(db, config, &candidate_hash)?,
+		});
+	}
+
+	Ok(held)
+}
+
 fn delete_available_data(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash) {
 	let key = (AVAILABLE_PREFIX, hash).encode();
 
@@ -257,6 +366,31 @@ fn delete_meta(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash) {
 	tx.delete(config.col_meta, &key[..])
 }
 
+// The number of bytes stored for a candidate, across its available data and chunks.
+//
+// Only used for size-bounded pruning. Candidates stored by older versions have no record until
+// size-bounded pruning backfills it.
+fn load_stored_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	hash: &CandidateHash,
+) -> Result<Option<u64>, Error> {
+	let key = (STORED_SIZE_PREFIX, hash).encode();
+
+	query_inner(db, config.col_meta, &key)
+}
+
+fn write_stored_size(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash, size: u64) {
+	let key = (STORED_SIZE_PREFIX, hash).encode();
+
+	tx.put_vec(config.col_meta, &key, size.encode());
+}
+
+fn delete_stored_size(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash) {
+	let key = (STORED_SIZE_PREFIX, hash).encode();
+	tx.delete(config.col_meta, &key[..])
+}
+
 fn delete_unfinalized_height(tx: &mut DBTransaction, config: &Config, block_number: BlockNumber) {
 	let prefix = (UNFINALIZED_PREFIX, BEBlockNumber(block_number)).encode();
 	tx.delete_prefix(config.col_meta, &prefix);
@@ -429,6 +563,19 @@ pub struct Config {
 	pub col_meta: u32,
 	/// How long finalized data should be kept (in hours).
 	pub keep_finalized_for: u32,
+	/// How long data of candidates which are not included in any block is kept (in hours).
+	pub keep_unavailable_for: u32,
+	/// The number of bytes of data to keep at most, if bounded.
+	///
+	/// Once exceeded, finalized data is pruned ahead of time, the least recently finalized first.
+	/// Data of candidates which are not yet finalized, or were finalized less than
+	/// `min_keep_finalized_for` ago, is never pruned early, so the bound may still be exceeded.
+	pub max_size: Option<u64>,
+	/// How long finalized data is kept at least when pruning to stay within `max_size` (in hours).
+	///
+	/// This should cover the dispute window, so that the data remains available to approval
+	/// checking and disputes.
+	pub min_keep_finalized_for: u32,
 }
 
 trait Clock: Send + Sync {
@@ -465,7 +612,7 @@ impl AvailabilityStoreSubsystem {
 		metrics: Metrics,
 	) -> Self {
 		let pruning_config = PruningConfig {
-			keep_unavailable_for: KEEP_UNAVAILABLE_FOR,
+			keep_unavailable_for: Duration::from_secs(config.keep_unavailable_for as u64 * 3600),
 			keep_finalized_for: Duration::from_secs(config.keep_finalized_for as u64 * 3600),
 			pruning_interval: PRUNING_INTERVAL,
 		};
@@ -1257,6 +1404,13 @@ fn store_chunk(
 		Some(false) => {
 			meta.chunks_stored.set(validator_index.0 as usize, true);
 
+			let stored_size = load_stored_size(db, config, &candidate_hash)?.unwrap_or(0);
+			write_stored_size(
+				&mut tx,
+				config,
+				&candidate_hash,
+				stored_size.saturating_add(chunk.encoded_size() as u64),
+			);
 			write_chunk(&mut tx, config, &candidate_hash, validator_index, &chunk);
 			write_meta(&mut tx, config, &candidate_hash, &meta);
 		},
@@ -1329,14 +1483,17 @@ fn store_available_data(
 		})
 		.collect();
 
+	let mut stored_size = available_data.encoded_size() as u64;
 	let chunk_indices = availability_chunk_indices(&node_features, n_validators, core_index)?;
 	for (validator_index, chunk_index) in chunk_indices.into_iter().enumerate() {
+		let chunk = &erasure_chunks[chunk_index.0 as usize];
+		stored_size = stored_size.saturating_add(chunk.encoded_size() as u64);
 		write_chunk(
 			&mut tx,
 			&subsystem.config,
 			&candidate_hash,
 			ValidatorIndex(validator_index as u32),
-			&erasure_chunks[chunk_index.0 as usize],
+			chunk,
 		);
 	}
 
@@ -1344,6 +1501,7 @@ fn store_available_data(
 	meta.chunks_stored = bitvec::bitvec![u8, BitOrderLsb0; 1; n_validators];
 
 	write_meta(&mut tx, &subsystem.config, &candidate_hash, &meta);
+	write_stored_size(&mut tx, &subsystem.config, &candidate_hash, stored_size);
 	write_available_data(&mut tx, &subsystem.config, &candidate_hash, &available_data);
 
 	subsystem.db.write(tx)?;
@@ -1371,37 +1529,147 @@ fn prune_all(db: &Arc<dyn Database>, config: &Config, now: Duration) -> Result<(
 		};
 
 		delete_meta(&mut tx, config, &candidate_hash);
+		delete_stored_size(&mut tx, config, &candidate_hash);
 
 		// Clean up all attached data of the candidate.
 		if let Some(meta) = load_meta(db, config, &candidate_hash)? {
-			// delete available data.
-			if meta.data_available {
-				delete_available_data(&mut tx, config, &candidate_hash)
-			}
+			delete_candidate_data(&mut tx, config, &candidate_hash, meta);
+		}
+	}
 
-			// delete chunks.
-			for (i, b) in meta.chunks_stored.iter().enumerate() {
-				if *b {
-					delete_chunk(&mut tx, config, &candidate_hash, ValidatorIndex(i as _));
-				}
-			}
+	db.write(tx)?;
 
-			// delete unfinalized block references. Pruning references don't need to be
-			// manually taken care of as we are deleting them as we go in the outer loop.
-			if let State::Unfinalized(_, blocks) = meta.state {
-				for (block_number, block_hash) in blocks {
-					delete_unfinalized_inclusion(
-						&mut tx,
-						config,
-						block_number.0,
-						&block_hash,
-						&candidate_hash,
-					);
-				}
+	if let Some(max_size) = config.max_size {
+		prune_to_size(db, config, now, max_size)?;
+	}
+	Ok(())
+}
+
+// Prune finalized data ahead of time until at most `max_size` bytes are stored.
+//
+// Data is pruned in the order it was finalized, the oldest first. Data finalized less than
+// `config.min_keep_finalized_for` ago is kept regardless of the bound.
+fn prune_to_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	now: Duration,
+	max_size: u64,
+) -> Result<(), Error> {
+	let mut tx = DBTransaction::new();
+	let mut total_size = 0u64;
+	let mut backfilled = 0;
+	let mut finalized: Vec<(Duration, Vec<u8>, CandidateHash, u64)> = Vec::new();
+	for r in db.iter_with_prefix(config.col_meta, PRUNE_BY_TIME_PREFIX) {
+		let (k, _v) = r?;
+		let (_, candidate_hash) = match decode_pruning_key(&k[..]) {
+			Ok(m) => m,
+			Err(_) => continue, // sanity
+		};
+		let Some(meta) = load_meta(db, config, &candidate_hash)? else { continue };
+
+		let size = match load_stored_size(db, config, &candidate_hash)? {
+			Some(size) => size,
+			None => {
+				// Candidates stored by older versions have no size record yet.
+				let size = compute_stored_size(db, config, &candidate_hash, &meta)?;
+				write_stored_size(&mut tx, config, &candidate_hash, size);
+				backfilled += 1;
+				size
+			},
+		};
+		total_size = total_size.saturating_add(size);
+
+		if let State::Finalized(at) = meta.state {
+			finalized.push((at.into(), k.to_vec(), candidate_hash, size));
+		}
+	}
+
+	let mut pruned = 0;
+	if total_size > max_size {
+		let keep_since =
+			now.saturating_sub(Duration::from_secs(config.min_keep_finalized_for as u64 * 3600));
+
+		finalized.sort_unstable_by_key(|(finalized_at, ..)| *finalized_at);
+		for (finalized_at, pruning_key, candidate_hash, size) in finalized {
+			if total_size <= max_size || finalized_at >= keep_since {
+				break
 			}
+			let Some(meta) = load_meta(db, config, &candidate_hash)? else { continue };
+
+			tx.delete(config.col_meta, &pruning_key[..]);
+			delete_meta(&mut tx, config, &candidate_hash);
+			delete_stored_size(&mut tx, config, &candidate_hash);
+			delete_candidate_data(&mut tx, config, &candidate_hash, meta);
+
+			total_size = total_size.saturating_sub(size);
+			pruned += 1;
 		}
 	}
 
 	db.write(tx)?;
+
+	if pruned > 0 || backfilled > 0 {
+		gum::debug!(
+			target: LOG_TARGET,
+			pruned,
+			backfilled,
+			total_size,
+			max_size,
+			"Pruned finalized data to stay within the size bound",
+		);
+	}
 	Ok(())
 }
+
+// The number of bytes stored for a candidate, computed from the stored data and chunks.
+fn compute_stored_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+	meta: &CandidateMeta,
+) -> Result<u64, Error> {
+	let stored_len = |key: Vec<u8>| -> Result<u64, Error> {
+		Ok(db.get(config.col_data, &key)?.map_or(0, |value| value.len() as u64))
+	};
+
+	let mut size = 0u64;
+	if meta.data_available {
+		size = size.saturating_add(stored_len((AVAILABLE_PREFIX, candidate_hash).encode())?);
+	}
+	for (i, b) in meta.chunks_stored.iter().enumerate() {
+		if *b {
+			let key = (CHUNK_PREFIX, candidate_hash, ValidatorIndex(i as _)).encode();
+			size = size.saturating_add(stored_len(key)?);
+		}
+	}
+
+	Ok(size)
+}
+
+// Delete the available data, chunks and unfinalized block references of a candidate.
+fn delete_candidate_data(
+	tx: &mut DBTransaction,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+	meta: CandidateMeta,
+) {
+	// delete available data.
+	if meta.data_available {
+		delete_available_data(tx, config, candidate_hash)
+	}
+
+	// delete chunks.
+	for (i, b) in meta.chunks_stored.iter().enumerate() {
+		if *b {
+			delete_chunk(tx, config, candidate_hash, ValidatorIndex(i as _));
+		}
+	}
+
+	// delete unfinalized block references. Pruning references don't need to be
+	// manually taken care of as they are deleted by the callers.
+	if let State::Unfinalized(_, blocks) = meta.state {
+		for (block_number, block_hash) in blocks {
+			delete_unfinalized_inclusion(tx, config, block_number.0, &block_hash, candidate_hash);
+		}
+	}
+}
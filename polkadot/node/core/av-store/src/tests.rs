@@ -43,8 +43,14 @@ mod columns {
 	pub const NUM_COLUMNS: u32 = 2;
 }
 
-const TEST_CONFIG: Config =
-	Config { col_data: columns::DATA, col_meta: columns::META, keep_finalized_for: 1 };
+const TEST_CONFIG: Config = Config {
+	col_data: columns::DATA,
+	col_meta: columns::META,
+	keep_finalized_for: 1,
+	keep_unavailable_for: 1,
+	max_size: None,
+	min_keep_finalized_for: 1,
+};
 
 type VirtualOverseer =
 	polkadot_node_subsystem_test_helpers::TestSubsystemContextHandle<AvailabilityStoreMessage>;
@@ -1384,3 +1390,228 @@ fn query_chunk_size_works() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn stored_data_is_listed_with_its_size() {
+	let store = test_store();
+	let test_state = TestState::default();
+
+	test_harness(test_state.clone(), store.clone(), |mut virtual_overseer| async move {
+		let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+		let n_validators = 10;
+
+		let pov = PoV { block_data: BlockData(vec![4, 5, 6]) };
+		let available_data = AvailableData {
+			pov: Arc::new(pov),
+			validation_data: test_state.persisted_validation_data.clone(),
+		};
+		let chunks =
+			polkadot_erasure_coding::obtain_chunks_v1(n_validators as _, &available_data).unwrap();
+		let branches = polkadot_erasure_coding::branches(chunks.as_ref());
+
+		let (tx, rx) = oneshot::channel();
+		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
+			candidate_hash,
+			n_validators,
+			available_data: available_data.clone(),
+			tx,
+			core_index: CoreIndex(0),
+			expected_erasure_root: branches.root(),
+			node_features: NodeFeatures::EMPTY,
+		};
+
+		overseer_send(&mut virtual_overseer, block_msg).await;
+		assert_eq!(rx.await.unwrap(), Ok(()));
+
+		let held = held_candidates(&store, &TEST_CONFIG).unwrap();
+		assert_eq!(held.len(), 1);
+		assert_eq!(held[0].candidate_hash, candidate_hash);
+		assert_eq!(
+			held[0].relay_parent_number,
+			Some(test_state.persisted_validation_data.relay_parent_number)
+		);
+		assert_eq!(held[0].state, HeldCandidateState::Unavailable(Duration::ZERO));
+		assert_eq!(held[0].prune_at, test_state.pruning_config.keep_unavailable_for);
+		assert!(held[0].data_available);
+		assert_eq!(held[0].chunks_stored, n_validators as usize);
+		assert!(held[0].stored_size.unwrap() > available_data.encoded_size() as u64);
+		virtual_overseer
+	});
+}
+
+#[test]
+fn held_candidate_is_displayed_on_one_line() {
+	let mut held = HeldCandidate {
+		candidate_hash: CandidateHash(Hash::repeat_byte(1)),
+		relay_parent_number: Some(5),
+		state: HeldCandidateState::Unfinalized(
+			Duration::from_secs(10),
+			vec![(6, Hash::repeat_byte(2)), (7, Hash::repeat_byte(3))],
+		),
+		prune_at: Duration::from_secs(100),
+		data_available: true,
+		chunks_stored: 3,
+		stored_size: Some(1024),
+	};
+	assert_eq!(
+		held.to_string(),
+		format!(
+			"{:?} relay_parent=#5 state=unfinalized since=10 included_in=#6:{:?},#7:{:?} \
+			 prune_at=100 data=stored chunks=3 size=1024",
+			Hash::repeat_byte(1),
+			Hash::repeat_byte(2),
+			Hash::repeat_byte(3),
+		)
+	);
+
+	held.relay_parent_number = None;
+	held.state = HeldCandidateState::Finalized(Duration::from_secs(20));
+	held.data_available = false;
+	held.stored_size = None;
+	assert_eq!(
+		held.to_string(),
+		format!(
+			"{:?} relay_parent=unknown state=finalized finalized_at=20 prune_at=100 data=missing \
+			 chunks=3 size=unknown",
+			Hash::repeat_byte(1),
+		)
+	);
+}
+
+fn write_candidate_with_size(
+	store: &Arc<dyn Database>,
+	byte: u8,
+	state: State,
+	prune_at: u64,
+	size: Option<u64>,
+) -> CandidateHash {
+	let available_data = AvailableData {
+		pov: Arc::new(PoV { block_data: BlockData(vec![1, 2, 3]) }),
+		validation_data: TestState::default().persisted_validation_data,
+	};
+	let candidate_hash = CandidateHash(Hash::repeat_byte(byte));
+	with_tx(store, |tx| {
+		let meta = CandidateMeta { state, data_available: true, chunks_stored: BitVec::new() };
+		write_meta(tx, &TEST_CONFIG, &candidate_hash, &meta);
+		write_available_data(tx, &TEST_CONFIG, &candidate_hash, &available_data);
+		write_pruning_key(tx, &TEST_CONFIG, Duration::from_secs(prune_at), &candidate_hash);
+		if let Some(size) = size {
+			write_stored_size(tx, &TEST_CONFIG, &candidate_hash, size);
+		}
+	});
+	candidate_hash
+}
+
+fn held_candidate_hashes(store: &Arc<dyn Database>) -> Vec<CandidateHash> {
+	held_candidates(store, &TEST_CONFIG)
+		.unwrap()
+		.into_iter()
+		.map(|held| held.candidate_hash)
+		.collect()
+}
+
+// Past `TEST_CONFIG.min_keep_finalized_for`, so data finalized around the time zero may be pruned
+// ahead of time. The pruning keys used in the tests lie beyond it.
+const SIZE_PRUNING_NOW: Duration = Duration::from_secs(2 * 3600);
+
+#[test]
+fn size_bounded_pruning_only_prunes_finalized_data() {
+	let store = test_store();
+
+	// The unfinalized candidate is due first, but must not be pruned early.
+	let unfinalized = write_candidate_with_size(
+		&store,
+		1,
+		State::Unfinalized(BETimestamp(0), Vec::new()),
+		10_005,
+		Some(50),
+	);
+	let oldest =
+		write_candidate_with_size(&store, 2, State::Finalized(BETimestamp(0)), 10_010, Some(100));
+	let older =
+		write_candidate_with_size(&store, 3, State::Finalized(BETimestamp(1)), 10_020, Some(100));
+	let newest =
+		write_candidate_with_size(&store, 4, State::Finalized(BETimestamp(2)), 10_030, Some(100));
+
+	// Without a bound nothing is due yet.
+	prune_all(&store, &TEST_CONFIG, SIZE_PRUNING_NOW).unwrap();
+	assert_eq!(held_candidates(&store, &TEST_CONFIG).unwrap().len(), 4);
+
+	let config = Config { max_size: Some(200), ..TEST_CONFIG };
+	prune_all(&store, &config, SIZE_PRUNING_NOW).unwrap();
+
+	assert_eq!(held_candidate_hashes(&store), vec![unfinalized, newest]);
+	assert!(load_available_data(&store, &config, &oldest).unwrap().is_none());
+	assert!(load_available_data(&store, &config, &older).unwrap().is_none());
+	assert!(load_stored_size(&store, &config, &older).unwrap().is_none());
+	assert!(load_available_data(&store, &config, &newest).unwrap().is_some());
+}
+
+#[test]
+fn size_bounded_pruning_prunes_least_recently_finalized_first() {
+	let store = test_store();
+
+	// The pruning keys disagree with the finalization order, e.g. because the retention was
+	// changed in between.
+	let newer =
+		write_candidate_with_size(&store, 1, State::Finalized(BETimestamp(2)), 10_010, Some(100));
+	let oldest =
+		write_candidate_with_size(&store, 2, State::Finalized(BETimestamp(0)), 10_020, Some(100));
+
+	let config = Config { max_size: Some(150), ..TEST_CONFIG };
+	prune_all(&store, &config, SIZE_PRUNING_NOW).unwrap();
+
+	assert_eq!(held_candidate_hashes(&store), vec![newer]);
+	assert!(load_available_data(&store, &config, &oldest).unwrap().is_none());
+}
+
+#[test]
+fn size_bounded_pruning_keeps_recently_finalized_data() {
+	let store = test_store();
+
+	let keep_since = SIZE_PRUNING_NOW.as_secs() - TEST_CONFIG.min_keep_finalized_for as u64 * 3600;
+	let old =
+		write_candidate_with_size(&store, 1, State::Finalized(BETimestamp(0)), 10_010, Some(100));
+	let recent = write_candidate_with_size(
+		&store,
+		2,
+		State::Finalized(BETimestamp(keep_since)),
+		10_020,
+		Some(100),
+	);
+
+	// Only the data finalized before the floor may go, even though the bound is exceeded after.
+	let config = Config { max_size: Some(0), ..TEST_CONFIG };
+	prune_all(&store, &config, SIZE_PRUNING_NOW).unwrap();
+
+	assert_eq!(held_candidate_hashes(&store), vec![recent]);
+	assert!(load_available_data(&store, &config, &old).unwrap().is_none());
+	assert!(load_available_data(&store, &config, &recent).unwrap().is_some());
+}
+
+#[test]
+fn size_bounded_pruning_backfills_missing_sizes() {
+	let store = test_store();
+
+	// Stored by an older version, without a size record.
+	let legacy =
+		write_candidate_with_size(&store, 1, State::Finalized(BETimestamp(0)), 10_010, None);
+	let newer =
+		write_candidate_with_size(&store, 2, State::Finalized(BETimestamp(1)), 10_020, Some(100));
+
+	let available_data_size = load_available_data(&store, &TEST_CONFIG, &legacy)
+		.unwrap()
+		.unwrap()
+		.encoded_size() as u64;
+
+	// Within the bound the size is only recorded.
+	let config = Config { max_size: Some(available_data_size + 100), ..TEST_CONFIG };
+	prune_all(&store, &config, SIZE_PRUNING_NOW).unwrap();
+	assert_eq!(held_candidate_hashes(&store), vec![legacy, newer]);
+	assert_eq!(load_stored_size(&store, &config, &legacy).unwrap(), Some(available_data_size));
+
+	// The recorded size counts towards the bound.
+	let config = Config { max_size: Some(100), ..TEST_CONFIG };
+	prune_all(&store, &config, SIZE_PRUNING_NOW).unwrap();
+	assert_eq!(held_candidate_hashes(&store), vec![newer]);
+}
@@ -25,7 +25,7 @@
 
 use polkadot_node_core_pvf::{
 	InternalValidationError, InvalidCandidate as WasmInvalidCandidate, PossiblyInvalidError,
-	PrepareError, PrepareJobKind, PvfPrepData, ValidationError, ValidationHost,
+	PrepareError, PrepareJobKind, PvfPrepData, ResourceUsage, ValidationError, ValidationHost,
 };
 use polkadot_node_primitives::{InvalidCandidate, PoV, ValidationResult};
 use polkadot_node_subsystem::{
@@ -99,6 +99,8 @@ const TASK_LIMIT: usize = 30;
 pub struct Config {
 	/// The path where candidate validation can store compiled artifacts for PVFs.
 	pub artifacts_cache_path: PathBuf,
+	/// The path where compiled artifacts are persisted across restarts, if any.
+	pub artifacts_store_path: Option<PathBuf>,
 	/// The version of the node. `None` can be passed to skip the version check (only for tests).
 	pub node_version: Option<String>,
 	/// Whether the node is attempting to run as a secure validator.
@@ -310,6 +312,7 @@ async fn run<Context>(
 	pvf_metrics: polkadot_node_core_pvf::Metrics,
 	Config {
 		artifacts_cache_path,
+		artifacts_store_path,
 		node_version,
 		secure_validator_mode,
 		prep_worker_path,
@@ -319,8 +322,9 @@ async fn run<Context>(
 		pvf_prepare_workers_hard_max_num,
 	}: Config,
 ) -> SubsystemResult<()> {
-	let (mut validation_host, task) = polkadot_node_core_pvf::start(
-		polkadot_node_core_pvf::Config::new(
+	let pvf_config = polkadot_node_core_pvf::Config {
+		artifacts_store_path,
+		..polkadot_node_core_pvf::Config::new(
 			artifacts_cache_path,
 			node_version,
 			secure_validator_mode,
@@ -329,10 +333,10 @@ async fn run<Context>(
 			pvf_execute_workers_max_num,
 			pvf_prepare_workers_soft_max_num,
 			pvf_prepare_workers_hard_max_num,
-		),
-		pvf_metrics,
-	)
-	.await?;
+		)
+	};
+	let (mut validation_host, task) =
+		polkadot_node_core_pvf::start(pvf_config, pvf_metrics).await?;
 	ctx.spawn_blocking("pvf-validation-host", task.boxed())?;
 
 	let mut tasks = FuturesUnordered::new();
@@ -848,6 +852,37 @@ where
 	}
 }
 
+/// Validate a candidate the same way dispute participation does, outside of the subsystem.
+///
+/// This is meant for offline tooling replaying exported dispute evidence against a locally started
+/// PVF host, in order to reproduce the verdict reached on-chain. No runtime is consulted: the
+/// caller provides everything that would otherwise be fetched from the relay parent state.
+pub async fn replay_dispute_validation(
+	validation_host: ValidationHost,
+	session_index: SessionIndex,
+	persisted_validation_data: PersistedValidationData,
+	validation_code: ValidationCode,
+	candidate_receipt: CandidateReceipt,
+	pov: Arc<PoV>,
+	executor_params: ExecutorParams,
+	validation_code_bomb_limit: u32,
+) -> Result<ValidationResult, ValidationFailed> {
+	validate_candidate_exhaustive(
+		session_index,
+		validation_host,
+		persisted_validation_data,
+		validation_code,
+		candidate_receipt,
+		pov,
+		executor_params,
+		PvfExecKind::Dispute,
+		&Metrics::default(),
+		None,
+		validation_code_bomb_limit,
+	)
+	.await
+}
+
 async fn validate_candidate_exhaustive(
 	expected_session_index: SessionIndex,
 	mut validation_backend: impl ValidationBackend + Send,
@@ -936,8 +971,21 @@ async fn validate_candidate_exhaustive(
 				.await,
 	};
 
-	if let Err(ref error) = result {
-		gum::info!(target: LOG_TARGET, ?para_id, ?candidate_hash, ?error, "Failed to validate candidate");
+	match result {
+		Ok((_, ref resource_usage)) => {
+			metrics.on_execution_resource_usage(exec_kind, resource_usage);
+			gum::debug!(
+				target: LOG_TARGET,
+				?para_id,
+				?candidate_hash,
+				?resource_usage,
+				max_pov_size = persisted_validation_data.max_pov_size,
+				"Executed candidate",
+			)
+		},
+		Err(ref error) => {
+			gum::info!(target: LOG_TARGET, ?para_id, ?candidate_hash, ?error, "Failed to validate candidate")
+		},
 	}
 
 	match result {
@@ -990,7 +1038,7 @@ async fn validate_candidate_exhaustive(
 			);
 			Err(ValidationFailed(e.to_string()))
 		},
-		Ok(res) =>
+		Ok((res, _)) =>
 			if res.head_data.hash() != candidate_receipt.descriptor.para_head() {
 				gum::info!(target: LOG_TARGET, ?para_id, "Invalid candidate (para_head)");
 				Ok(ValidationResult::Invalid(InvalidCandidate::ParaHeadHashMismatch))
@@ -1084,7 +1132,7 @@ trait ValidationBackend {
 		prepare_priority: polkadot_node_core_pvf::Priority,
 		// The kind for the execution job.
 		exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError>;
+	) -> Result<(WasmValidationResult, ResourceUsage), ValidationError>;
 
 	/// Tries executing a PVF. Will retry once if an error is encountered that may have
 	/// been transient.
@@ -1107,7 +1155,7 @@ trait ValidationBackend {
 		// The kind for the execution job.
 		exec_kind: PvfExecKind,
 		validation_code_bomb_limit: u32,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<(WasmValidationResult, ResourceUsage), ValidationError> {
 		let prep_timeout = pvf_prep_timeout(&executor_params, PvfPrepKind::Prepare);
 		// Construct the PVF a single time, since it is an expensive operation. Cloning it is cheap.
 		let pvf = PvfPrepData::from_code(
@@ -1249,7 +1297,7 @@ impl ValidationBackend for ValidationHost {
 		prepare_priority: polkadot_node_core_pvf::Priority,
 		// The kind for the execution job.
 		exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<(WasmValidationResult, ResourceUsage), ValidationError> {
 		let (tx, rx) = oneshot::channel();
 		if let Err(err) = self
 			.execute_pvf(pvf, exec_timeout, pvd, pov, prepare_priority, exec_kind, tx)
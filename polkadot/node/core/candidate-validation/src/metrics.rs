@@ -15,13 +15,19 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::{ValidationFailed, ValidationResult};
+use polkadot_node_core_pvf::ResourceUsage;
 use polkadot_node_metrics::metrics::{self, prometheus};
+use polkadot_node_subsystem::messages::PvfExecKind;
 
 #[derive(Clone)]
 pub(crate) struct MetricsInner {
 	pub(crate) validation_requests: prometheus::CounterVec<prometheus::U64>,
 	pub(crate) validate_from_exhaustive: prometheus::Histogram,
 	pub(crate) validate_candidate_exhaustive: prometheus::Histogram,
+	pub(crate) execution_cpu_time: prometheus::HistogramVec,
+	pub(crate) execution_max_rss: prometheus::HistogramVec,
+	pub(crate) execution_instructions: prometheus::HistogramVec,
+	pub(crate) execution_pov_size: prometheus::HistogramVec,
 }
 
 /// Candidate validation metrics.
@@ -45,6 +51,30 @@ impl Metrics {
 		}
 	}
 
+	/// Observe the resources used to execute a candidate, by the kind of execution.
+	pub fn on_execution_resource_usage(&self, exec_kind: PvfExecKind, usage: &ResourceUsage) {
+		if let Some(metrics) = &self.0 {
+			let kind = [exec_kind.as_str()];
+			metrics
+				.execution_cpu_time
+				.with_label_values(&kind)
+				.observe(usage.cpu_time.as_secs_f64());
+			if let Some(max_rss) = usage.max_rss {
+				metrics.execution_max_rss.with_label_values(&kind).observe(max_rss as f64);
+			}
+			if let Some(instructions) = usage.instructions {
+				metrics
+					.execution_instructions
+					.with_label_values(&kind)
+					.observe(instructions as f64);
+			}
+			metrics
+				.execution_pov_size
+				.with_label_values(&kind)
+				.observe(usage.pov_size as f64);
+		}
+	}
+
 	/// Provide a timer for `validate_from_exhaustive` which observes on drop.
 	pub fn time_validate_from_exhaustive(
 		&self,
@@ -89,6 +119,62 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			execution_cpu_time: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_candidate_validation_execution_cpu_time",
+						"CPU time spent executing candidates, in seconds",
+					)
+					.buckets(vec![
+						0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0, 10.0,
+						12.0,
+					]),
+					&["kind"],
+				)?,
+				registry,
+			)?,
+			execution_max_rss: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_candidate_validation_execution_max_rss",
+						"ru_maxrss (maximum resident set size) observed for executing candidates (in kilobytes)",
+					)
+					.buckets(
+						prometheus::exponential_buckets(8192.0, 2.0, 10)
+							.expect("arguments are always valid; qed"),
+					),
+					&["kind"],
+				)?,
+				registry,
+			)?,
+			execution_instructions: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_candidate_validation_execution_instructions",
+						"Number of user-space instructions retired executing candidates",
+					)
+					.buckets(
+						prometheus::exponential_buckets(1_000_000.0, 4.0, 12)
+							.expect("arguments are always valid; qed"),
+					),
+					&["kind"],
+				)?,
+				registry,
+			)?,
+			execution_pov_size: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_candidate_validation_execution_pov_size",
+						"Uncompressed size of the PoVs of executed candidates, in bytes",
+					)
+					.buckets(
+						prometheus::exponential_buckets(16384.0, 2.0, 9)
+							.expect("arguments are always valid; qed"),
+					),
+					&["kind"],
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
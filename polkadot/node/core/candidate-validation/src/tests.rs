@@ -23,7 +23,7 @@ use super::*;
 use crate::PvfExecKind;
 use assert_matches::assert_matches;
 use futures::executor;
-use polkadot_node_core_pvf::PrepareError;
+use polkadot_node_core_pvf::{PrepareError, ResourceUsage};
 use polkadot_node_primitives::BlockData;
 use polkadot_node_subsystem::messages::AllMessages;
 use polkadot_node_subsystem_test_helpers::{make_subsystem_context, TestSubsystemContextHandle};
@@ -445,13 +445,13 @@ impl ValidationBackend for MockValidateCandidateBackend {
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<(WasmValidationResult, ResourceUsage), ValidationError> {
 		// This is expected to panic if called more times than expected, indicating an error in the
 		// test.
 		let result = self.result_list[self.num_times_called].clone();
 		self.num_times_called += 1;
 
-		result
+		result.map(|result| (result, ResourceUsage::default()))
 	}
 
 	async fn precheck_pvf(&mut self, _pvf: PvfPrepData) -> Result<(), PrepareError> {
@@ -1032,6 +1032,77 @@ fn candidate_validation_one_ambiguous_error_is_valid() {
 	});
 }
 
+#[test]
+fn candidate_validation_observes_execution_resource_usage() {
+	use polkadot_node_metrics::metrics::{prometheus::Registry, Metrics as _};
+
+	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
+
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let head_data = HeadData(vec![1, 1, 1]);
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = perform_basic_checks_on_valid_candidate(
+		&pov,
+		&validation_code,
+		&validation_data,
+		head_data.hash(),
+	);
+
+	let validation_result = WasmValidationResult {
+		head_data,
+		new_validation_code: None,
+		upward_messages: Default::default(),
+		horizontal_messages: Default::default(),
+		processed_downward_messages: 0,
+		hrmp_watermark: 0,
+	};
+
+	let commitments = CandidateCommitments {
+		head_data: validation_result.head_data.clone(),
+		upward_messages: validation_result.upward_messages.clone(),
+		horizontal_messages: validation_result.horizontal_messages.clone(),
+		new_validation_code: validation_result.new_validation_code.clone(),
+		processed_downward_messages: validation_result.processed_downward_messages,
+		hrmp_watermark: validation_result.hrmp_watermark,
+	};
+
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: commitments.hash() };
+
+	let registry = Registry::new();
+	let metrics = Metrics::try_register(&registry).unwrap();
+
+	let v = executor::block_on(validate_candidate_exhaustive(
+		1,
+		MockValidateCandidateBackend::with_hardcoded_result(Ok(validation_result)),
+		validation_data,
+		validation_code,
+		candidate_receipt,
+		Arc::new(pov),
+		ExecutorParams::default(),
+		PvfExecKind::Approval,
+		&metrics,
+		Default::default(),
+		VALIDATION_CODE_BOMB_LIMIT,
+	))
+	.unwrap();
+
+	assert_matches!(v, ValidationResult::Valid(_, _));
+
+	let families = registry.gather();
+	let observed = |name: &str| {
+		let family = families.iter().find(|family| family.get_name() == name)?;
+		let metric = &family.get_metric()[0];
+		assert_eq!(metric.get_label()[0].get_value(), "approval");
+		Some(metric.get_histogram().get_sample_count())
+	};
+	assert_eq!(observed("polkadot_parachain_candidate_validation_execution_cpu_time"), Some(1));
+	assert_eq!(observed("polkadot_parachain_candidate_validation_execution_pov_size"), Some(1));
+	// The mock backend does not report memory or instruction usage.
+	assert_eq!(observed("polkadot_parachain_candidate_validation_execution_max_rss"), None);
+	assert_eq!(observed("polkadot_parachain_candidate_validation_execution_instructions"), None);
+}
+
 #[test]
 fn candidate_validation_multiple_ambiguous_errors_is_invalid() {
 	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
@@ -1417,7 +1488,7 @@ impl ValidationBackend for MockPreCheckBackend {
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<(WasmValidationResult, ResourceUsage), ValidationError> {
 		unreachable!()
 	}
 
@@ -1576,7 +1647,7 @@ impl ValidationBackend for MockHeadsUp {
 		_pov: Arc<PoV>,
 		_prepare_priority: polkadot_node_core_pvf::Priority,
 		_exec_kind: PvfExecKind,
-	) -> Result<WasmValidationResult, ValidationError> {
+	) -> Result<(WasmValidationResult, ResourceUsage), ValidationError> {
 		unreachable!()
 	}
 
@@ -0,0 +1,331 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offline export of dispute evidence.
+//!
+//! Everything the dispute coordinator knows about a disputed candidate can be exported from the
+//! database of a stopped node into a portable [`DisputeEvidence`] bundle. The bundle is SCALE
+//! encoded and prefixed with [`EVIDENCE_FORMAT_VERSION`].
+//!
+//! The coordinator itself only stores the candidate receipt, the votes and the dispute status.
+//! Session info, executor parameters, validation code (and its bomb limit) and the available data
+//! live elsewhere (in the runtime state and the availability store) and are filled in by the
+//! exporting tool, if it has access to them. With all of them present, the validation can be
+//! replayed locally in order to reproduce the verdict.
+
+use codec::{Decode, Encode};
+
+use polkadot_node_primitives::{AvailableData, DisputeStatus};
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{
+	vstaging::CandidateReceiptV2 as CandidateReceipt, CandidateHash, ExecutorParams,
+	InvalidDisputeStatementKind, SessionIndex, SessionInfo, ValidDisputeStatementKind,
+	ValidationCode, ValidatorIndex, ValidatorSignature,
+};
+
+use crate::{db, Config};
+
+/// Version of the encoding of exported [`DisputeEvidence`] bundles.
+pub const EVIDENCE_FORMAT_VERSION: u8 = 1;
+
+/// Errors while exporting or importing dispute evidence.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Reading the dispute coordinator data failed.
+	#[error("Reading from database failed: {0}")]
+	DbReadFailed(String),
+
+	/// The bundle could not be decoded.
+	#[error(transparent)]
+	Codec(#[from] codec::Error),
+
+	/// The bundle was written in a format this version does not understand.
+	#[error("Unsupported evidence format version {0}, expected {EVIDENCE_FORMAT_VERSION}")]
+	UnsupportedVersion(u8),
+
+	/// The included validation code does not match the candidate descriptor.
+	#[error("Validation code does not match the code hash in the candidate descriptor")]
+	ValidationCodeMismatch,
+
+	/// The included PoV does not match the candidate descriptor.
+	#[error("PoV does not match the PoV hash in the candidate descriptor")]
+	PoVMismatch,
+}
+
+/// The outcome a dispute concluded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+	/// The candidate was found to be valid.
+	Valid,
+	/// The candidate was found to be invalid.
+	Invalid,
+}
+
+/// Everything known about a disputed candidate, in a portable form.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct DisputeEvidence {
+	/// The session the candidate was disputed in.
+	pub session: SessionIndex,
+	/// The receipt of the disputed candidate.
+	///
+	/// The descriptor carries the validation code hash and the PoV hash.
+	pub candidate_receipt: CandidateReceipt,
+	/// The dispute status, if the candidate has been disputed at all.
+	pub status: Option<DisputeStatus>,
+	/// Votes of validity, sorted by validator index.
+	pub valid: Vec<(ValidDisputeStatementKind, ValidatorIndex, ValidatorSignature)>,
+	/// Votes of invalidity, sorted by validator index.
+	pub invalid: Vec<(InvalidDisputeStatementKind, ValidatorIndex, ValidatorSignature)>,
+	/// The session info of `session`, if known.
+	pub session_info: Option<SessionInfo>,
+	/// The executor parameters of `session`, if known.
+	pub executor_params: Option<ExecutorParams>,
+	/// The validation code of the candidate, if known.
+	pub validation_code: Option<ValidationCode>,
+	/// The maximum uncompressed size of the validation code, if known.
+	pub validation_code_bomb_limit: Option<u32>,
+	/// The PoV and persisted validation data of the candidate, if still available.
+	pub available_data: Option<AvailableData>,
+}
+
+impl DisputeEvidence {
+	/// The hash of the disputed candidate.
+	pub fn candidate_hash(&self) -> CandidateHash {
+		self.candidate_receipt.hash()
+	}
+
+	/// The verdict the dispute concluded with, if it concluded.
+	pub fn verdict(&self) -> Option<Verdict> {
+		match self.status? {
+			DisputeStatus::ConcludedFor(_) => Some(Verdict::Valid),
+			DisputeStatus::ConcludedAgainst(_) => Some(Verdict::Invalid),
+			DisputeStatus::Active | DisputeStatus::Confirmed => None,
+		}
+	}
+
+	/// Explicit dispute statements, i.e. the outcome of dispute participation of each validator.
+	///
+	/// Returns `true` for validators which found the candidate valid.
+	pub fn participation(&self) -> impl Iterator<Item = (ValidatorIndex, bool)> + '_ {
+		let valid = self.valid.iter().filter_map(|(kind, index, _)| {
+			matches!(kind, ValidDisputeStatementKind::Explicit).then_some((*index, true))
+		});
+		let invalid = self.invalid.iter().map(|(kind, index, _)| match kind {
+			InvalidDisputeStatementKind::Explicit => (*index, false),
+		});
+		valid.chain(invalid)
+	}
+
+	/// Whether everything needed to replay the validation is present.
+	pub fn is_replayable(&self) -> bool {
+		self.executor_params.is_some() &&
+			self.validation_code.is_some() &&
+			self.available_data.is_some()
+	}
+
+	/// Check the included validation code and PoV against the candidate descriptor.
+	pub fn check(&self) -> Result<(), Error> {
+		let descriptor = &self.candidate_receipt.descriptor;
+		if let Some(code) = &self.validation_code {
+			if code.hash() != descriptor.validation_code_hash() {
+				return Err(Error::ValidationCodeMismatch)
+			}
+		}
+		if let Some(data) = &self.available_data {
+			if data.pov.hash() != descriptor.pov_hash() {
+				return Err(Error::PoVMismatch)
+			}
+		}
+		Ok(())
+	}
+
+	/// Encode the evidence into a versioned bundle.
+	pub fn to_bundle(&self) -> Vec<u8> {
+		(EVIDENCE_FORMAT_VERSION, self).encode()
+	}
+
+	/// Decode evidence from a bundle created by [`DisputeEvidence::to_bundle`].
+	pub fn from_bundle(mut bundle: &[u8]) -> Result<Self, Error> {
+		let version = u8::decode(&mut bundle)?;
+		if version != EVIDENCE_FORMAT_VERSION {
+			return Err(Error::UnsupportedVersion(version))
+		}
+		let evidence = Self::decode(&mut bundle)?;
+		evidence.check()?;
+		Ok(evidence)
+	}
+}
+
+/// Export the evidence the dispute coordinator has stored for a candidate.
+///
+/// Returns `None` if there are no votes for the candidate in the given session. Only the data
+/// kept by the dispute coordinator is filled in, see the [module docs](self).
+pub fn export_evidence(
+	db: &dyn Database,
+	config: &Config,
+	session: SessionIndex,
+	candidate_hash: CandidateHash,
+) -> Result<Option<DisputeEvidence>, Error> {
+	let column_config = config.column_config();
+	let Some(votes) = db::v1::load_candidate_votes(db, &column_config, session, &candidate_hash)
+		.map_err(|e| Error::DbReadFailed(e.to_string()))?
+	else {
+		return Ok(None)
+	};
+	let status = db::v1::load_recent_disputes(db, &column_config)
+		.map_err(|e| Error::DbReadFailed(e.to_string()))?
+		.and_then(|recent| recent.get(&(session, candidate_hash)).copied());
+
+	Ok(Some(DisputeEvidence {
+		session,
+		candidate_receipt: votes.candidate_receipt,
+		status,
+		valid: votes.valid,
+		invalid: votes.invalid,
+		session_info: None,
+		executor_params: None,
+		validation_code: None,
+		validation_code_bomb_limit: None,
+		available_data: None,
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use super::*;
+	use crate::{
+		backend::{Backend, OverlayedBackend},
+		db::v1::{CandidateVotes, ColumnConfiguration, DbBackend, RecentDisputes},
+		metrics::Metrics,
+	};
+	use polkadot_node_primitives::PoV;
+	use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
+	use polkadot_primitives::{vstaging::MutateDescriptorV2, Hash, PersistedValidationData};
+	use polkadot_primitives_test_helpers::{dummy_candidate_receipt_v2, dummy_hash};
+
+	fn make_db() -> (Arc<dyn Database>, DbBackend) {
+		let db = kvdb_memorydb::create(1);
+		let store: Arc<dyn Database> = Arc::new(DbAdapter::new(db, &[0]));
+		let backend = DbBackend::new(
+			store.clone(),
+			ColumnConfiguration { col_dispute_data: 0 },
+			Metrics::default(),
+		);
+		(store, backend)
+	}
+
+	fn signature() -> ValidatorSignature {
+		sp_core::crypto::UncheckedFrom::unchecked_from([1u8; 64])
+	}
+
+	#[test]
+	fn export_includes_votes_and_status() {
+		let (store, mut backend) = make_db();
+		let config = Config { col_dispute_data: 0 };
+		let session = 5;
+		let receipt = dummy_candidate_receipt_v2(dummy_hash());
+		let candidate_hash = receipt.hash();
+
+		assert!(export_evidence(&*store, &config, session, candidate_hash).unwrap().is_none());
+
+		let mut overlay_db = OverlayedBackend::new(&backend);
+		overlay_db.write_candidate_votes(
+			session,
+			candidate_hash,
+			CandidateVotes {
+				candidate_receipt: receipt.clone(),
+				valid: vec![
+					(
+						ValidDisputeStatementKind::BackingSeconded(Hash::zero()),
+						ValidatorIndex(0),
+						signature(),
+					),
+					(ValidDisputeStatementKind::Explicit, ValidatorIndex(1), signature()),
+				],
+				invalid: vec![(
+					InvalidDisputeStatementKind::Explicit,
+					ValidatorIndex(2),
+					signature(),
+				)],
+			},
+		);
+		let mut recent = RecentDisputes::new();
+		recent.insert((session, candidate_hash), DisputeStatus::ConcludedAgainst(0));
+		overlay_db.write_recent_disputes(recent);
+		let write_ops = overlay_db.into_write_ops();
+		backend.write(write_ops).unwrap();
+
+		let evidence = export_evidence(&*store, &config, session, candidate_hash).unwrap().unwrap();
+		assert_eq!(evidence.candidate_hash(), candidate_hash);
+		assert_eq!(evidence.verdict(), Some(Verdict::Invalid));
+		assert_eq!(
+			evidence.participation().collect::<Vec<_>>(),
+			vec![(ValidatorIndex(1), true), (ValidatorIndex(2), false)],
+		);
+		assert!(!evidence.is_replayable());
+
+		// Other sessions are not mixed in.
+		assert!(export_evidence(&*store, &config, session + 1, candidate_hash)
+			.unwrap()
+			.is_none());
+	}
+
+	#[test]
+	fn bundle_roundtrip_checks_version_and_descriptor() {
+		let pov = PoV { block_data: vec![1, 2, 3].into() };
+		let code = ValidationCode(vec![4, 5, 6]);
+		let mut receipt = dummy_candidate_receipt_v2(dummy_hash());
+		receipt.descriptor.set_pov_hash(pov.hash());
+		receipt.descriptor.set_validation_code_hash(code.hash());
+
+		let mut evidence = DisputeEvidence {
+			session: 1,
+			candidate_receipt: receipt,
+			status: Some(DisputeStatus::ConcludedFor(0)),
+			valid: Vec::new(),
+			invalid: Vec::new(),
+			session_info: None,
+			executor_params: Some(ExecutorParams::default()),
+			validation_code: Some(code),
+			validation_code_bomb_limit: Some(1024),
+			available_data: Some(AvailableData {
+				pov: Arc::new(pov),
+				validation_data: PersistedValidationData::default(),
+			}),
+		};
+		assert!(evidence.is_replayable());
+
+		let bundle = evidence.to_bundle();
+		let decoded = DisputeEvidence::from_bundle(&bundle).unwrap();
+		assert_eq!(decoded.encode(), evidence.encode());
+		assert_eq!(decoded.verdict(), Some(Verdict::Valid));
+
+		let mut bad_version = bundle.clone();
+		bad_version[0] = EVIDENCE_FORMAT_VERSION + 1;
+		assert!(matches!(
+			DisputeEvidence::from_bundle(&bad_version),
+			Err(Error::UnsupportedVersion(v)) if v == EVIDENCE_FORMAT_VERSION + 1
+		));
+
+		evidence.validation_code = Some(ValidationCode(vec![7]));
+		assert!(matches!(
+			DisputeEvidence::from_bundle(&evidence.to_bundle()),
+			Err(Error::ValidationCodeMismatch)
+		));
+	}
+}
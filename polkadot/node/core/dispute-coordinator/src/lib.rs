@@ -110,6 +110,9 @@ mod metrics;
 /// Status tracking of disputes (`DisputeStatus`).
 mod status;
 
+/// Offline export of dispute evidence (`DisputeEvidence`).
+pub mod evidence;
+
 use crate::status::Clock;
 
 #[cfg(test)]
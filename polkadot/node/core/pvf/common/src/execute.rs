@@ -47,8 +47,29 @@ pub struct ExecuteRequest {
 pub struct WorkerResponse {
 	/// The response from the execute job process.
 	pub job_response: JobResponse,
+	/// The resources used by the job.
+	pub resource_usage: ResourceUsage,
+}
+
+/// The resources used by a single execution job.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+pub struct ResourceUsage {
 	/// The amount of CPU time taken by the job.
-	pub duration: Duration,
+	pub cpu_time: Duration,
+	/// The wall clock time taken by the job, from spawning the job process until it exited.
+	pub wall_time: Duration,
+	/// `ru_maxrss` (maximum resident set size) of the terminated job processes of the worker, in
+	/// kilobytes, as obtained by the worker with `RUSAGE_CHILDREN`. This is the peak of the
+	/// largest job the worker has run so far, and so an upper bound for this job. `None` if it
+	/// could not be obtained.
+	pub max_rss: Option<i64>,
+	/// The number of user-space instructions retired by the execution thread of the job, read
+	/// from a hardware performance counter. `None` if no such counter is available, e.g. outside
+	/// of Linux or when `perf_event_paranoid` does not allow it.
+	///
+	/// The executor does not meter fuel, so this is the only instruction count available. It is
+	/// reported by the untrusted job process and must only be used for informational purposes.
+	pub instructions: Option<u64>,
 	/// The uncompressed PoV size.
 	pub pov_size: u32,
 }
@@ -89,6 +89,47 @@ pub fn framed_recv_blocking(r: &mut (impl Read + Unpin)) -> io::Result<Vec<u8>>
 	Ok(buf)
 }
 
+/// The version of the format of prepared artifacts.
+///
+/// It must be bumped whenever the same PVF and executor parameters could be compiled into a
+/// different or incompatible artifact, e.g. on a wasmtime upgrade or a change of the executor
+/// semantics. See [`artifact_format_version`].
+pub const ARTIFACT_FORMAT_VERSION: &str = "wasmtime-8.0.1-v1";
+
+/// The version under which prepared artifacts are persisted, which tells whether artifacts
+/// prepared by a node can be reused by another one.
+///
+/// Artifacts are native code, so they are only compatible between nodes compiling them with the
+/// same wasmtime version, compiler configuration and target. The wasmtime version is covered by
+/// [`ARTIFACT_FORMAT_VERSION`], the rest is derived from [`executor_interface::DEFAULT_CONFIG`]
+/// and the target this binary was built for. Parts of the configuration set by executor
+/// parameters are not included, as artifacts are keyed by their hash anyway.
+pub fn artifact_format_version() -> String {
+	let config = &executor_interface::DEFAULT_CONFIG;
+	let semantics = &config.semantics;
+	let compiler_config = format!(
+		"{:?}/{:?}/{:?}/{}/{}/{}/{}/{}/{}/{}/{}/{}",
+		semantics.heap_alloc_strategy,
+		semantics.instantiation_strategy,
+		semantics
+			.deterministic_stack_limit
+			.as_ref()
+			.map(|limit| (limit.logical_max, limit.native_stack_max)),
+		semantics.canonicalize_nans,
+		semantics.parallel_compilation,
+		semantics.wasm_reference_types,
+		semantics.wasm_simd,
+		semantics.wasm_bulk_memory,
+		semantics.wasm_multi_value,
+		config.allow_missing_func_imports,
+		std::env::consts::ARCH,
+		std::env::consts::OS,
+	);
+	let compiler_config_hash = sp_crypto_hashing::twox_64(compiler_config.as_bytes());
+
+	format!("{ARTIFACT_FORMAT_VERSION}-{:016x}", u64::from_le_bytes(compiler_config_hash))
+}
+
 #[derive(Debug, Default, Clone, Copy, Encode, Decode, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct ArtifactChecksum(H256);
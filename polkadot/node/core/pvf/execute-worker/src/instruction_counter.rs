@@ -0,0 +1,114 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Counting the instructions retired by the execute thread.
+//!
+//! The wasmtime executor does not meter fuel, so the instructions are counted with a hardware
+//! performance counter instead. Counting is best-effort: if the counter cannot be opened (no PMU,
+//! restrictive `perf_event_paranoid`, non-Linux platform) no count is reported.
+
+cfg_if::cfg_if! {
+	if #[cfg(target_os = "linux")] {
+		use std::{
+			fs::File,
+			io::Read,
+			os::fd::{FromRawFd, RawFd},
+		};
+
+		const PERF_TYPE_HARDWARE: u32 = 0;
+		const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+		const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+		const EXCLUDE_KERNEL: u64 = 1 << 5;
+		const EXCLUDE_HV: u64 = 1 << 6;
+
+		/// The first published version (`PERF_ATTR_SIZE_VER0`) of `struct perf_event_attr`. The
+		/// kernel accepts it and zero-extends the fields added later.
+		#[repr(C)]
+		#[derive(Default)]
+		struct PerfEventAttr {
+			type_: u32,
+			size: u32,
+			config: u64,
+			sample_period: u64,
+			sample_type: u64,
+			read_format: u64,
+			flags: u64,
+			wakeup_events: u32,
+			bp_type: u32,
+			config1: u64,
+		}
+
+		/// A counter of the user-space instructions retired by the calling thread.
+		pub struct InstructionCounter(File);
+
+		impl InstructionCounter {
+			/// Starts counting the instructions of the calling thread. Returns `None` if the
+			/// counter is not available.
+			pub fn start() -> Option<Self> {
+				let attr = PerfEventAttr {
+					type_: PERF_TYPE_HARDWARE,
+					size: std::mem::size_of::<PerfEventAttr>() as u32,
+					config: PERF_COUNT_HW_INSTRUCTIONS,
+					flags: EXCLUDE_KERNEL | EXCLUDE_HV,
+					..Default::default()
+				};
+
+				// SAFETY: `attr` is a valid `perf_event_attr` of the size it declares, and lives
+				// for the duration of the call. `pid = 0, cpu = -1` counts the calling thread on
+				// any CPU.
+				let fd = unsafe {
+					libc::syscall(
+						libc::SYS_perf_event_open,
+						&attr as *const PerfEventAttr,
+						0 as libc::pid_t,
+						-1 as libc::c_int,
+						-1 as libc::c_int,
+						PERF_FLAG_FD_CLOEXEC,
+					)
+				};
+				if fd < 0 {
+					return None
+				}
+
+				// SAFETY: the file descriptor was just opened and is owned by nobody else.
+				Some(Self(unsafe { File::from_raw_fd(fd as RawFd) }))
+			}
+
+			/// Returns the number of instructions counted so far.
+			pub fn read(mut self) -> Option<u64> {
+				let mut count = [0u8; 8];
+				self.0.read_exact(&mut count).ok()?;
+				Some(u64::from_ne_bytes(count))
+			}
+		}
+	} else {
+		/// A counter of the user-space instructions retired by the calling thread. Not available
+		/// on this platform.
+		pub struct InstructionCounter;
+
+		impl InstructionCounter {
+			/// Always returns `None`, as the counter is not available on this platform.
+			pub fn start() -> Option<Self> {
+				None
+			}
+
+			/// Returns the number of instructions counted so far.
+			pub fn read(self) -> Option<u64> {
+				None
+			}
+		}
+	}
+}
@@ -19,6 +19,8 @@
 #![deny(unused_crate_dependencies)]
 #![warn(missing_docs)]
 
+mod instruction_counter;
+
 pub use polkadot_node_core_pvf_common::{
 	error::ExecuteError, executor_interface::execute_artifact,
 };
@@ -30,6 +32,7 @@ const LOG_TARGET: &str = "parachain::pvf-execute-worker";
 
 use codec::{Decode, Encode};
 use cpu_time::ProcessTime;
+use instruction_counter::InstructionCounter;
 use nix::{
 	errno::Errno,
 	sys::{
@@ -42,7 +45,8 @@ use polkadot_node_core_pvf_common::{
 	compute_checksum,
 	error::InternalValidationError,
 	execute::{
-		ExecuteRequest, Handshake, JobError, JobResponse, JobResult, WorkerError, WorkerResponse,
+		ExecuteRequest, Handshake, JobError, JobResponse, JobResult, ResourceUsage, WorkerError,
+		WorkerResponse,
 	},
 	executor_interface::params_to_wasmtime_semantics,
 	framed_recv_blocking, framed_send_blocking,
@@ -66,7 +70,7 @@ use std::{
 	path::PathBuf,
 	process,
 	sync::{mpsc::channel, Arc},
-	time::Duration,
+	time::{Duration, Instant},
 };
 
 /// The number of threads for the child process:
@@ -188,8 +192,7 @@ pub fn worker_entrypoint(
 						&mut stream,
 						Ok(WorkerResponse {
 							job_response: JobResponse::CorruptedArtifact,
-							duration: Duration::ZERO,
-							pov_size: 0,
+							resource_usage: ResourceUsage::default(),
 						}),
 						worker_info,
 					)?;
@@ -227,8 +230,7 @@ pub fn worker_entrypoint(
 								&mut stream,
 								Ok(WorkerResponse {
 									job_response: JobResponse::PoVDecompressionFailure,
-									duration: Duration::ZERO,
-									pov_size: 0,
+									resource_usage: ResourceUsage::default(),
 								}),
 								worker_info,
 							)?;
@@ -246,6 +248,7 @@ pub fn worker_entrypoint(
 				};
 				let params = Arc::new(params.encode());
 
+				let job_start = Instant::now();
 				cfg_if::cfg_if! {
 					if #[cfg(target_os = "linux")] {
 						let result = if security_status.can_do_secure_clone {
@@ -296,6 +299,11 @@ pub fn worker_entrypoint(
 					}
 				}
 
+				let result = result.map(|mut response| {
+					response.resource_usage.wall_time = job_start.elapsed();
+					response
+				});
+
 				gum::trace!(
 					target: LOG_TARGET,
 					?worker_info,
@@ -448,7 +456,7 @@ fn handle_child_process(
 
 	// Drop the read end so we don't have too many FDs open.
 	if let Err(errno) = nix::unistd::close(pipe_read_fd) {
-		send_child_response(&mut pipe_write, job_error_from_errno("closing pipe", errno));
+		send_child_response(&mut pipe_write, job_error_from_errno("closing pipe", errno), None);
 	}
 
 	// Dropping the stream closes the underlying socket. We want to make sure
@@ -456,7 +464,7 @@ fn handle_child_process(
 	// outside world. The only IPC it should be able to do is sending its
 	// response over the pipe.
 	if let Err(errno) = nix::unistd::close(stream_fd) {
-		send_child_response(&mut pipe_write, job_error_from_errno("closing stream", errno));
+		send_child_response(&mut pipe_write, job_error_from_errno("closing stream", errno), None);
 	}
 
 	gum::debug!(
@@ -478,41 +486,60 @@ fn handle_child_process(
 		WaitOutcome::TimedOut,
 	)
 	.unwrap_or_else(|err| {
-		send_child_response(&mut pipe_write, Err(JobError::CouldNotSpawnThread(err.to_string())))
+		send_child_response(
+			&mut pipe_write,
+			Err(JobError::CouldNotSpawnThread(err.to_string())),
+			None,
+		)
 	});
 
 	let execute_thread = thread::spawn_worker_thread_with_stack_size(
 		"execute thread",
-		move || validate_using_artifact(&compiled_artifact_blob, &executor_params, &params),
+		move || {
+			let instruction_counter = InstructionCounter::start();
+			let response =
+				validate_using_artifact(&compiled_artifact_blob, &executor_params, &params);
+			(response, instruction_counter.and_then(InstructionCounter::read))
+		},
 		Arc::clone(&condvar),
 		WaitOutcome::Finished,
 		execute_thread_stack_size,
 	)
 	.unwrap_or_else(|err| {
-		send_child_response(&mut pipe_write, Err(JobError::CouldNotSpawnThread(err.to_string())))
+		send_child_response(
+			&mut pipe_write,
+			Err(JobError::CouldNotSpawnThread(err.to_string())),
+			None,
+		)
 	});
 
 	let outcome = thread::wait_for_threads(condvar);
 
-	let response = match outcome {
+	let (response, instructions) = match outcome {
 		WaitOutcome::Finished => {
 			let _ = cpu_time_monitor_tx.send(());
-			execute_thread.join().map_err(|e| JobError::Panic(stringify_panic_payload(e)))
+			match execute_thread.join() {
+				Ok((response, instructions)) => (Ok(response), instructions),
+				Err(e) => (Err(JobError::Panic(stringify_panic_payload(e))), None),
+			}
 		},
 		// If the CPU thread is not selected, we signal it to end, the join handle is
 		// dropped and the thread will finish in the background.
 		WaitOutcome::TimedOut => match cpu_time_monitor_thread.join() {
-			Ok(Some(_cpu_time_elapsed)) => Err(JobError::TimedOut),
-			Ok(None) => Err(JobError::CpuTimeMonitorThread(
-				"error communicating over finished channel".into(),
-			)),
-			Err(e) => Err(JobError::CpuTimeMonitorThread(stringify_panic_payload(e))),
+			Ok(Some(_cpu_time_elapsed)) => (Err(JobError::TimedOut), None),
+			Ok(None) => (
+				Err(JobError::CpuTimeMonitorThread(
+					"error communicating over finished channel".into(),
+				)),
+				None,
+			),
+			Err(e) => (Err(JobError::CpuTimeMonitorThread(stringify_panic_payload(e))), None),
 		},
 		WaitOutcome::Pending =>
 			unreachable!("we run wait_while until the outcome is no longer pending; qed"),
 	};
 
-	send_child_response(&mut pipe_write, response);
+	send_child_response(&mut pipe_write, response, instructions);
 }
 
 /// Returns stack size based on the number of threads.
@@ -622,7 +649,8 @@ fn handle_parent_process(
 	match status {
 		Ok(WaitStatus::Exited(_, exit_status)) => {
 			let mut reader = io::BufReader::new(received_data.as_slice());
-			let result = recv_child_response(&mut reader, "execute")?;
+			let (result, instructions): (JobResult, Option<u64>) =
+				recv_child_response(&mut reader, "execute")?;
 
 			match result {
 				Ok(job_response) => {
@@ -633,7 +661,18 @@ fn handle_parent_process(
 						))));
 					}
 
-					Ok(Ok(WorkerResponse { job_response, pov_size, duration: cpu_tv }))
+					// The wall clock time is filled in by the caller, which knows when the job
+					// was started.
+					let resource_usage = ResourceUsage {
+						cpu_time: cpu_tv,
+						wall_time: Duration::ZERO,
+						// `c_long` is either `i32` or `i64` depending on architecture.
+						// `i64::from` always works.
+						max_rss: Some(i64::from(usage_after.max_rss())),
+						pov_size,
+						instructions,
+					};
+					Ok(Ok(WorkerResponse { job_response, resource_usage }))
 				},
 				Err(job_error) => {
 					gum::warn!(
@@ -677,8 +716,14 @@ fn handle_parent_process(
 /// - `pipe_write`: A `PipeFd` structure, the writing end of a pipe.
 ///
 /// - `response`: Child process response
-fn send_child_response(pipe_write: &mut PipeFd, response: JobResult) -> ! {
-	framed_send_blocking(pipe_write, response.encode().as_slice())
+///
+/// - `instructions`: The instructions retired by the execute thread, if they could be counted.
+fn send_child_response(
+	pipe_write: &mut PipeFd,
+	response: JobResult,
+	instructions: Option<u64>,
+) -> ! {
+	framed_send_blocking(pipe_write, (&response, instructions).encode().as_slice())
 		.unwrap_or_else(|_| process::exit(libc::EXIT_FAILURE));
 
 	if response.is_ok() {
@@ -18,7 +18,9 @@
 //!
 //! # Lifecycle of an artifact
 //!
-//! 1. During node start-up, we prune all the cached artifacts, if any.
+//! 1. During node start-up, we prune all the cached artifacts, if any. If an [`ArtifactStore`] is
+//!    configured, the compatible artifacts persisted in it are then restored into the cache after
+//!    an integrity check, so that they don't need to be prepared again.
 //!
 //! 2. In order to be executed, a PVF should be prepared first. This means that artifacts should
 //!    have an [`ArtifactState::Prepared`] entry for that artifact in the table. If not, the
@@ -53,15 +55,32 @@
 //! 7. There is a separate process for pruning the prepared artifacts whose `last_time_needed` is
 //!    older by a predefined parameter. This process is run very rarely (say, once a day). Once the
 //!    artifact is expired it is removed from disk eagerly atomically.
+//!
+//! # Artifact store
+//!
+//! Prepared artifacts can additionally be persisted in an [`ArtifactStore`], which survives node
+//! restarts and upgrades. The store is content-addressed: every entry is named after the code hash,
+//! the preparation-related executor parameters hash and the checksum of the artifact, and entries
+//! live in a directory named after the [`artifact_format_version`], which covers the wasmtime
+//! version and the compiler configuration. Entries that were written under a
+//! different format version are never loaded. An entry is removed from the store whenever the
+//! corresponding artifact is pruned from the cache.
+//!
+//! Note that artifacts are native code that is executed without further validation. The store and
+//! any artifacts imported into it must therefore be trusted as much as the node binary itself.
 
-use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX};
+use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX, LOG_TARGET};
 use always_assert::always;
-use polkadot_node_core_pvf_common::{error::PrepareError, pvf::PvfPrepData, ArtifactChecksum};
+use codec::{Decode, Encode};
+use polkadot_node_core_pvf_common::{
+	artifact_format_version, compute_checksum, error::PrepareError, pvf::PvfPrepData,
+	ArtifactChecksum,
+};
 use polkadot_parachain_primitives::primitives::ValidationCodeHash;
 use polkadot_primitives::ExecutorParamsPrepHash;
 use std::{
 	collections::HashMap,
-	fs,
+	fs, io,
 	path::{Path, PathBuf},
 	time::{Duration, SystemTime},
 };
@@ -170,6 +189,7 @@ pub enum ArtifactState {
 /// A container of all known artifact ids and their states.
 pub struct Artifacts {
 	inner: HashMap<ArtifactId, ArtifactState>,
+	store: Option<ArtifactStore>,
 }
 
 /// Parameters we use to cleanup artifacts
@@ -202,7 +222,7 @@ impl ArtifactsCleanupConfig {
 impl Artifacts {
 	#[cfg(test)]
 	pub(crate) fn empty() -> Self {
-		Self { inner: HashMap::new() }
+		Self { inner: HashMap::new(), store: None }
 	}
 
 	#[cfg(test)]
@@ -250,7 +270,44 @@ impl Artifacts {
 			}
 		}
 
-		Self { inner: HashMap::new() }
+		Self { inner: HashMap::new(), store: None }
+	}
+
+	/// Open the persistent artifact store rooted at `store_path` and restore all valid artifacts
+	/// from it into the cache as prepared.
+	///
+	/// Restoring reads every artifact in the store, so it is done on a blocking thread.
+	///
+	/// Must be called right after [`Artifacts::new`], before any artifact is known to the table.
+	pub async fn attach_store(&mut self, store_path: &Path, cache_path: &Path) -> io::Result<()> {
+		let (store_path, cache_path) = (store_path.to_owned(), cache_path.to_owned());
+		let (store, restored) = tokio::task::spawn_blocking(move || {
+			let store = ArtifactStore::open(&store_path)?;
+			let restored = store.restore(&cache_path);
+			Ok::<_, io::Error>((store, restored))
+		})
+		.await
+		.map_err(io::Error::other)??;
+
+		let now = SystemTime::now();
+		for (artifact_id, path, checksum, size) in restored {
+			self.insert_prepared(artifact_id, path, checksum, now, size);
+		}
+		self.store = Some(store);
+		Ok(())
+	}
+
+	/// Persist a freshly prepared artifact in the artifact store, if there is one.
+	pub fn persist(&self, artifact_id: &ArtifactId, path: &Path, checksum: ArtifactChecksum) {
+		let Some(ref store) = self.store else { return };
+		if let Err(err) = store.insert(artifact_id, path, checksum) {
+			gum::warn!(
+				target: LOG_TARGET,
+				?artifact_id,
+				?err,
+				"failed to persist the artifact in the artifact store",
+			);
+		}
 	}
 
 	/// Returns the state of the given artifact by its ID.
@@ -278,7 +335,6 @@ impl Artifacts {
 	///
 	/// This function should only be used to build the artifact table at startup with valid
 	/// artifact caches.
-	#[cfg(test)]
 	pub(crate) fn insert_prepared(
 		&mut self,
 		artifact_id: ArtifactId,
@@ -296,10 +352,20 @@ impl Artifacts {
 
 	/// Remove artifact by its id.
 	pub fn remove(&mut self, artifact_id: ArtifactId) -> Option<(ArtifactId, PathBuf)> {
-		self.inner.remove(&artifact_id).and_then(|state| match state {
+		let removed = self.inner.remove(&artifact_id).and_then(|state| match state {
 			ArtifactState::Prepared { path, .. } => Some((artifact_id, path)),
 			_ => None,
-		})
+		});
+		if let Some((ref artifact_id, _)) = removed {
+			self.remove_from_store(artifact_id);
+		}
+		removed
+	}
+
+	fn remove_from_store(&self, artifact_id: &ArtifactId) {
+		if let Some(ref store) = self.store {
+			store.remove(artifact_id);
+		}
 	}
 
 	/// Remove artifacts older than the given TTL when the total artifact size reaches the limit
@@ -334,6 +400,7 @@ impl Artifacts {
 			}
 
 			self.inner.remove(&artifact_id);
+			self.remove_from_store(&artifact_id);
 			to_remove.push((artifact_id, path));
 			total_size -= size;
 		}
@@ -342,6 +409,217 @@ impl Artifacts {
 	}
 }
 
+/// The extension of temporary files written into the artifact store.
+const STORE_TMP_EXTENSION: &str = "tmp";
+
+/// A persistent, content-addressed store of prepared artifacts.
+///
+/// See the [module-level documentation][self] for details.
+#[derive(Debug)]
+pub struct ArtifactStore {
+	/// The directory holding the entries of the current format version.
+	path: PathBuf,
+	/// The [`artifact_format_version`] of this node.
+	format_version: String,
+}
+
+impl ArtifactStore {
+	/// Open the store rooted at the given directory, creating it if it doesn't exist.
+	///
+	/// Entries persisted under any other artifact format version, as well as leftovers of
+	/// interrupted writes, are removed.
+	pub fn open(root: &Path) -> io::Result<Self> {
+		let format_version = artifact_format_version();
+		let path = root.join(&format_version);
+		fs::create_dir_all(&path)?;
+
+		for entry in fs::read_dir(root)?.flatten() {
+			let entry_path = entry.path();
+			if entry_path != path && entry_path.is_dir() {
+				gum::debug!(
+					target: LOG_TARGET,
+					"removing incompatible artifacts from the store: {}",
+					entry_path.display(),
+				);
+				let _ = fs::remove_dir_all(entry_path);
+			}
+		}
+		for entry in fs::read_dir(&path)?.flatten() {
+			let entry_path = entry.path();
+			if entry_path.extension().map_or(false, |ext| ext == STORE_TMP_EXTENSION) {
+				let _ = fs::remove_file(entry_path);
+			}
+		}
+
+		Ok(Self { path, format_version })
+	}
+
+	/// Export all the valid entries of the store into `destination`.
+	///
+	/// The entries are written into a sub-directory named after the artifact format version, so
+	/// that the export can only ever be imported by compatible nodes. Returns the number of
+	/// exported entries.
+	pub fn export(&self, destination: &Path) -> io::Result<usize> {
+		let destination = destination.join(&self.format_version);
+		fs::create_dir_all(&destination)?;
+		copy_valid_entries(&self.path, &destination)
+	}
+
+	/// Import all the valid entries exported into `source` by a node with a compatible artifact
+	/// format version. Returns the number of imported entries.
+	///
+	/// Artifacts are native code; only import artifacts from a trusted source.
+	pub fn import(&self, source: &Path) -> io::Result<usize> {
+		let source = source.join(&self.format_version);
+		if !source.is_dir() {
+			return Ok(0)
+		}
+		copy_valid_entries(&source, &self.path)
+	}
+
+	/// Persist the artifact at the given path, replacing any other entry for the same artifact ID.
+	pub(crate) fn insert(
+		&self,
+		artifact_id: &ArtifactId,
+		artifact_path: &Path,
+		checksum: ArtifactChecksum,
+	) -> io::Result<()> {
+		self.remove(artifact_id);
+		link_or_copy(artifact_path, &self.path.join(entry_file_name(artifact_id, &checksum)))
+	}
+
+	/// Remove all the entries for the given artifact ID.
+	pub(crate) fn remove(&self, artifact_id: &ArtifactId) {
+		for (entry_id, _, path) in read_entries(&self.path) {
+			if entry_id == *artifact_id {
+				let _ = fs::remove_file(path);
+			}
+		}
+	}
+
+	/// Restore all the valid entries into the cache. Entries failing the integrity check are
+	/// removed from the store.
+	///
+	/// Returns the ID, cache path, checksum and size of every restored artifact.
+	fn restore(&self, cache_path: &Path) -> Vec<(ArtifactId, PathBuf, ArtifactChecksum, u64)> {
+		let mut restored = Vec::new();
+		for (artifact_id, checksum, path) in read_entries(&self.path) {
+			let size = match verify_entry(&path, &checksum) {
+				Ok(size) => size,
+				Err(err) => {
+					gum::warn!(
+						target: LOG_TARGET,
+						?artifact_id,
+						?err,
+						"removing an invalid artifact from the store",
+					);
+					let _ = fs::remove_file(path);
+					continue
+				},
+			};
+
+			let artifact_path = generate_artifact_path(cache_path);
+			if let Err(err) = link_or_copy(&path, &artifact_path) {
+				gum::warn!(
+					target: LOG_TARGET,
+					?artifact_id,
+					?err,
+					"failed to restore the artifact from the store",
+				);
+				continue
+			}
+			restored.push((artifact_id, artifact_path, checksum, size));
+		}
+
+		gum::debug!(
+			target: LOG_TARGET,
+			"restored {} artifacts from the store",
+			restored.len(),
+		);
+		restored
+	}
+}
+
+/// The file name of the store entry: `<code hash>_<executor params prep hash>_<checksum>.pvf`.
+fn entry_file_name(artifact_id: &ArtifactId, checksum: &ArtifactChecksum) -> String {
+	use array_bytes::Hex;
+	format!(
+		"{}_{}_{}.{}",
+		artifact_id.code_hash.encode().hex(""),
+		artifact_id.executor_params_prep_hash.encode().hex(""),
+		checksum.encode().hex(""),
+		ARTIFACT_EXTENSION,
+	)
+}
+
+/// Parse the file name of a store entry, see [`entry_file_name`].
+fn parse_entry_file_name(file_name: &str) -> Option<(ArtifactId, ArtifactChecksum)> {
+	let stem = file_name.strip_suffix(ARTIFACT_EXTENSION)?.strip_suffix('.')?;
+	let mut parts = stem.split('_');
+	let mut next_hash = || array_bytes::hex2array::<_, 32>(parts.next()?).ok();
+	let code_hash = ValidationCodeHash::decode(&mut &next_hash()?[..]).ok()?;
+	let executor_params_prep_hash = ExecutorParamsPrepHash::decode(&mut &next_hash()?[..]).ok()?;
+	let checksum = ArtifactChecksum::decode(&mut &next_hash()?[..]).ok()?;
+	if parts.next().is_some() {
+		return None
+	}
+	Some((ArtifactId::new(code_hash, executor_params_prep_hash), checksum))
+}
+
+/// List the well-formed entries in the given store directory.
+fn read_entries(path: &Path) -> Vec<(ArtifactId, ArtifactChecksum, PathBuf)> {
+	fs::read_dir(path)
+		.into_iter()
+		.flatten()
+		.flatten()
+		.filter_map(|entry| {
+			let path = entry.path();
+			let (artifact_id, checksum) = parse_entry_file_name(path.file_name()?.to_str()?)?;
+			Some((artifact_id, checksum, path))
+		})
+		.collect()
+}
+
+/// Check that the contents of the entry match its checksum. Returns the size of the entry.
+fn verify_entry(path: &Path, checksum: &ArtifactChecksum) -> io::Result<u64> {
+	let data = fs::read(path)?;
+	if compute_checksum(&data) != *checksum {
+		return Err(io::Error::new(io::ErrorKind::InvalidData, "checksum mismatch"))
+	}
+	Ok(data.len() as u64)
+}
+
+/// Copy all the entries of `source` passing the integrity check into `destination`.
+fn copy_valid_entries(source: &Path, destination: &Path) -> io::Result<usize> {
+	let mut copied = 0;
+	for (artifact_id, checksum, path) in read_entries(source) {
+		if let Err(err) = verify_entry(&path, &checksum) {
+			gum::warn!(target: LOG_TARGET, ?artifact_id, ?err, "skipping an invalid artifact");
+			continue
+		}
+		copy_atomically(&path, &destination.join(entry_file_name(&artifact_id, &checksum)))?;
+		copied += 1;
+	}
+	Ok(copied)
+}
+
+/// Make the file at `source` available at `destination`. A hard link is used when possible, so
+/// that the artifact store and the cache don't take up space twice.
+fn link_or_copy(source: &Path, destination: &Path) -> io::Result<()> {
+	if fs::hard_link(source, destination).is_ok() {
+		return Ok(())
+	}
+	copy_atomically(source, destination)
+}
+
+/// Copy the file at `source` to `destination` through a temporary file, so that an interrupted
+/// copy never leaves a truncated artifact behind.
+fn copy_atomically(source: &Path, destination: &Path) -> io::Result<()> {
+	let tmp_path = destination.with_extension(STORE_TMP_EXTENSION);
+	fs::copy(source, &tmp_path)?;
+	fs::rename(tmp_path, destination)
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::testing::artifact_id;
@@ -471,4 +749,105 @@ mod tests {
 		assert!(!artifacts.artifact_ids().contains(&artifact_id3));
 		assert!(pruned.contains(&(artifact_id3, path3)));
 	}
+
+	#[tokio::test]
+	async fn artifacts_restored_from_store() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path().join("cache");
+		let store_path = tempdir.path().join("store");
+
+		let artifact_id = artifact_id(1);
+		let checksum = compute_checksum(b"artifact");
+
+		// Persist a freshly prepared artifact.
+		let mut artifacts = Artifacts::new(&cache_path).await;
+		artifacts.attach_store(&store_path, &cache_path).await.unwrap();
+		assert_eq!(artifacts.len(), 0);
+		let path = generate_artifact_path(&cache_path);
+		fs::write(&path, b"artifact").unwrap();
+		artifacts.persist(&artifact_id, &path, checksum);
+
+		// After a restart the cache is cleared, but the artifact is restored from the store.
+		let mut artifacts = Artifacts::new(&cache_path).await;
+		assert!(!path.exists());
+		artifacts.attach_store(&store_path, &cache_path).await.unwrap();
+
+		assert_eq!(artifacts.artifact_ids(), vec![artifact_id.clone()]);
+		let Some(ArtifactState::Prepared { checksum: restored_checksum, path, size, .. }) =
+			artifacts.artifact_state_mut(&artifact_id)
+		else {
+			panic!("the artifact must be restored as prepared")
+		};
+		assert_eq!(*restored_checksum, checksum);
+		assert_eq!(*size, 8);
+		assert_eq!(fs::read(path).unwrap(), b"artifact");
+
+		// Removing the artifact from the cache removes it from the store as well.
+		assert!(artifacts.remove(artifact_id).is_some());
+		let artifacts_in_store = fs::read_dir(store_path.join(artifact_format_version())).unwrap();
+		assert_eq!(artifacts_in_store.count(), 0);
+	}
+
+	#[tokio::test]
+	async fn invalid_and_incompatible_store_entries_are_removed() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path().join("cache");
+		let store_path = tempdir.path().join("store");
+
+		let store = ArtifactStore::open(&store_path).unwrap();
+		let valid = artifact_id(1);
+		let corrupted = artifact_id(2);
+		let artifact_path = tempdir.path().join("artifact");
+		fs::write(&artifact_path, b"artifact").unwrap();
+		store.insert(&valid, &artifact_path, compute_checksum(b"artifact")).unwrap();
+		store.insert(&corrupted, &artifact_path, compute_checksum(b"other")).unwrap();
+
+		// An artifact written under another format version.
+		let outdated_path = store_path.join("outdated");
+		fs::create_dir(&outdated_path).unwrap();
+		fs::write(outdated_path.join("artifact.pvf"), b"artifact").unwrap();
+
+		let mut artifacts = Artifacts::new(&cache_path).await;
+		artifacts.attach_store(&store_path, &cache_path).await.unwrap();
+
+		assert_eq!(artifacts.artifact_ids(), vec![valid.clone()]);
+		assert!(!outdated_path.exists());
+		let entries: Vec<_> = read_entries(&store.path)
+			.into_iter()
+			.map(|(artifact_id, _, _)| artifact_id)
+			.collect();
+		assert_eq!(entries, vec![valid]);
+	}
+
+	#[test]
+	fn store_export_and_import() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let export_path = tempdir.path().join("export");
+
+		let store = ArtifactStore::open(&tempdir.path().join("store")).unwrap();
+		let artifact_path = tempdir.path().join("artifact");
+		fs::write(&artifact_path, b"artifact").unwrap();
+		store
+			.insert(&artifact_id(1), &artifact_path, compute_checksum(b"artifact"))
+			.unwrap();
+		store
+			.insert(&artifact_id(2), &artifact_path, compute_checksum(b"other"))
+			.unwrap();
+
+		// Only the entry passing the integrity check is exported.
+		assert_eq!(store.export(&export_path).unwrap(), 1);
+
+		let other_store = ArtifactStore::open(&tempdir.path().join("other-store")).unwrap();
+		assert_eq!(other_store.import(&export_path).unwrap(), 1);
+		let entries: Vec<_> = read_entries(&other_store.path)
+			.into_iter()
+			.map(|(artifact_id, checksum, _)| (artifact_id, checksum))
+			.collect();
+		assert_eq!(entries, vec![(artifact_id(1), compute_checksum(b"artifact"))]);
+
+		// Nothing is imported from an export of an incompatible node.
+		let incompatible_path = tempdir.path().join("incompatible");
+		fs::create_dir_all(incompatible_path.join("outdated")).unwrap();
+		assert_eq!(other_store.import(&incompatible_path).unwrap(), 0);
+	}
 }
@@ -443,28 +443,29 @@ async fn handle_job_finish(
 	artifact_id: ArtifactId,
 	result_tx: ResultSender,
 ) {
-	let (idle_worker, result, duration, sync_channel, pov_size) = match worker_result {
+	let (idle_worker, result, resource_usage, sync_channel) = match worker_result {
 		Ok(WorkerInterfaceResponse {
 			worker_response:
-				WorkerResponse {
-					job_response: JobResponse::Ok { result_descriptor },
-					duration,
-					pov_size,
-				},
+				WorkerResponse { job_response: JobResponse::Ok { result_descriptor }, resource_usage },
 			idle_worker,
 		}) => {
 			// TODO: propagate the soft timeout
 
-			(Some(idle_worker), Ok(result_descriptor), Some(duration), None, Some(pov_size))
+			(
+				Some(idle_worker),
+				Ok((result_descriptor, resource_usage.clone())),
+				Some(resource_usage),
+				None,
+			)
 		},
 		Ok(WorkerInterfaceResponse {
-			worker_response: WorkerResponse { job_response: JobResponse::InvalidCandidate(err), .. },
+			worker_response:
+				WorkerResponse { job_response: JobResponse::InvalidCandidate(err), resource_usage },
 			idle_worker,
 		}) => (
 			Some(idle_worker),
 			Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err))),
-			None,
-			None,
+			Some(resource_usage),
 			None,
 		),
 		Ok(WorkerInterfaceResponse {
@@ -476,7 +477,6 @@ async fn handle_job_finish(
 			Err(ValidationError::Invalid(InvalidCandidate::PoVDecompressionFailure)),
 			None,
 			None,
-			None,
 		),
 		Ok(WorkerInterfaceResponse {
 			worker_response:
@@ -500,7 +500,6 @@ async fn handle_job_finish(
 				))),
 				None,
 				Some(result_rx),
-				None,
 			)
 		},
 		Ok(WorkerInterfaceResponse {
@@ -520,45 +519,41 @@ async fn handle_job_finish(
 				Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::CorruptedArtifact)),
 				None,
 				Some(rx),
-				None,
 			)
 		},
 
 		Err(WorkerInterfaceError::InternalError(err)) |
 		Err(WorkerInterfaceError::WorkerError(WorkerError::InternalError(err))) =>
-			(None, Err(ValidationError::Internal(err)), None, None, None),
+			(None, Err(ValidationError::Internal(err)), None, None),
 		// Either the worker or the job timed out. Kill the worker in either case. Treated as
 		// definitely-invalid, because if we timed out, there's no time left for a retry.
 		Err(WorkerInterfaceError::HardTimeout) |
 		Err(WorkerInterfaceError::WorkerError(WorkerError::JobTimedOut)) =>
-			(None, Err(ValidationError::Invalid(InvalidCandidate::HardTimeout)), None, None, None),
+			(None, Err(ValidationError::Invalid(InvalidCandidate::HardTimeout)), None, None),
 		// "Maybe invalid" errors (will retry).
 		Err(WorkerInterfaceError::CommunicationErr(_err)) => (
 			None,
 			Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousWorkerDeath)),
 			None,
 			None,
-			None,
 		),
 		Err(WorkerInterfaceError::WorkerError(WorkerError::JobDied { err, .. })) => (
 			None,
 			Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::AmbiguousJobDeath(err))),
 			None,
 			None,
-			None,
 		),
 		Err(WorkerInterfaceError::WorkerError(WorkerError::JobError(err))) => (
 			None,
 			Err(ValidationError::PossiblyInvalid(PossiblyInvalidError::JobError(err.to_string()))),
 			None,
 			None,
-			None,
 		),
 	};
 
 	queue.metrics.execute_finished();
-	if let Some(pov_size) = pov_size {
-		queue.metrics.observe_pov_size(pov_size as usize, false)
+	if let Some(ref resource_usage) = resource_usage {
+		queue.metrics.observe_execution_resource_usage(resource_usage);
 	}
 	if let Err(ref err) = result {
 		gum::warn!(
@@ -575,7 +570,7 @@ async fn handle_job_finish(
 			?artifact_id,
 			?worker,
 			worker_rip = idle_worker.is_none(),
-			?duration,
+			?resource_usage,
 			"execute worker concluded successfully",
 		);
 	}
@@ -212,7 +212,8 @@ async fn handle_result(
 	worker_pid: u32,
 	execution_timeout: Duration,
 ) -> Result<WorkerResponse, WorkerError> {
-	if let Ok(WorkerResponse { duration, .. }) = worker_result {
+	if let Ok(WorkerResponse { ref resource_usage, .. }) = worker_result {
+		let duration = resource_usage.cpu_time;
 		if duration > execution_timeout {
 			// The job didn't complete within the timeout.
 			gum::warn!(
@@ -35,6 +35,7 @@ use futures::{
 use polkadot_node_core_pvf_common::ArtifactChecksum;
 use polkadot_node_core_pvf_common::{
 	error::{PrecheckResult, PrepareError},
+	execute::ResourceUsage,
 	prepare::PrepareSuccess,
 	pvf::PvfPrepData,
 };
@@ -71,7 +72,10 @@ pub const EXECUTE_BINARY_NAME: &str = "polkadot-execute-worker";
 pub const HOST_MESSAGE_QUEUE_SIZE: usize = 10;
 
 /// An alias to not spell the type for the oneshot sender for the PVF execution result.
-pub(crate) type ResultSender = oneshot::Sender<Result<ValidationResult, ValidationError>>;
+///
+/// On success, the resources used by the execution are reported along with the result.
+pub(crate) type ResultSender =
+	oneshot::Sender<Result<(ValidationResult, ResourceUsage), ValidationError>>;
 
 /// Transmission end used for sending the PVF preparation result.
 pub(crate) type PrecheckResultSender = oneshot::Sender<PrecheckResult>;
@@ -213,6 +217,11 @@ struct ExecutePvfInputs {
 pub struct Config {
 	/// The root directory where the prepared artifacts can be stored.
 	pub cache_path: PathBuf,
+	/// The root directory of the persistent artifact store, if any.
+	///
+	/// Prepared artifacts are persisted there and restored into the cache on start-up, so that
+	/// compatible PVFs don't need to be prepared again after a restart.
+	pub artifacts_store_path: Option<PathBuf>,
 	/// The version of the node. `None` can be passed to skip the version check (only for tests).
 	pub node_version: Option<String>,
 	/// Whether the node is attempting to run as a secure validator.
@@ -250,6 +259,7 @@ impl Config {
 	) -> Self {
 		Self {
 			cache_path,
+			artifacts_store_path: None,
 			node_version,
 			secure_validator_mode,
 
@@ -280,7 +290,21 @@ pub async fn start(
 	gum::debug!(target: LOG_TARGET, ?config, "starting PVF validation host");
 
 	// Make sure the cache is initialized before doing anything else.
-	let artifacts = Artifacts::new(&config.cache_path).await;
+	let mut artifacts = Artifacts::new(&config.cache_path).await;
+
+	// Restore the artifacts persisted by previous runs. The store is best-effort, so the host is
+	// still started if it can't be opened.
+	if let Some(ref artifacts_store_path) = config.artifacts_store_path {
+		match artifacts.attach_store(artifacts_store_path, &config.cache_path).await {
+			Ok(()) => {},
+			Err(err) => gum::warn!(
+				target: LOG_TARGET,
+				?err,
+				"could not open the artifact store at {}",
+				artifacts_store_path.display(),
+			),
+		}
+	}
 
 	// Run checks for supported security features once per host startup. If some checks fail, warn
 	// if Secure Validator Mode is disabled and return an error otherwise.
@@ -886,6 +910,8 @@ async fn handle_prepare_done(
 		.await?;
 	}
 
+	let prepared = result.as_ref().ok().map(|success| (success.path.clone(), success.checksum));
+
 	*state = match result {
 		Ok(PrepareSuccess { checksum, path, size, .. }) =>
 			ArtifactState::Prepared { checksum, path, last_time_needed: SystemTime::now(), size },
@@ -905,6 +931,10 @@ async fn handle_prepare_done(
 		},
 	};
 
+	if let Some((path, checksum)) = prepared {
+		artifacts.persist(&artifact_id, &path, checksum);
+	}
+
 	Ok(())
 }
 
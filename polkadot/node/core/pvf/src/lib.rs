@@ -104,6 +104,7 @@ mod worker_interface;
 #[cfg(feature = "test-utils")]
 pub mod testing;
 
+pub use artifacts::ArtifactStore;
 pub use error::{InvalidCandidate, PossiblyInvalidError, ValidationError};
 pub use host::{
 	start, Config, ValidationHost, EXECUTE_BINARY_NAME, HOST_MESSAGE_QUEUE_SIZE,
@@ -116,6 +117,7 @@ pub use worker_interface::{framed_recv, framed_send, JOB_TIMEOUT_WALL_CLOCK_FACT
 // Re-export some common types.
 pub use polkadot_node_core_pvf_common::{
 	error::{InternalValidationError, PrepareError},
+	execute::ResourceUsage,
 	prepare::{PrepareJobKind, PrepareStats},
 	pvf::PvfPrepData,
 	SecurityStatus,
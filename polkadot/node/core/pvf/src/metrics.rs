@@ -16,7 +16,7 @@
 
 //! Prometheus metrics related to the validation host.
 
-use polkadot_node_core_pvf_common::prepare::MemoryStats;
+use polkadot_node_core_pvf_common::{execute::ResourceUsage, prepare::MemoryStats};
 use polkadot_node_metrics::metrics::{self, prometheus};
 use polkadot_node_subsystem::messages::PvfExecKind;
 
@@ -107,6 +107,23 @@ impl Metrics {
 		}
 	}
 
+	/// Observe the resources used by an execution.
+	pub(crate) fn observe_execution_resource_usage(&self, resource_usage: &ResourceUsage) {
+		if let Some(metrics) = &self.0 {
+			metrics.execution_cpu_time.observe(resource_usage.cpu_time.as_secs_f64());
+			if let Some(max_rss) = resource_usage.max_rss {
+				metrics.execution_max_rss.observe(max_rss as f64);
+			}
+			if let Some(instructions) = resource_usage.instructions {
+				metrics.execution_instructions.observe(instructions as f64);
+			}
+			metrics
+				.pov_size
+				.with_label_values(&["false"])
+				.observe(resource_usage.pov_size as f64);
+		}
+	}
+
 	pub(crate) fn observe_code_size(&self, code_size: usize) {
 		if let Some(metrics) = &self.0 {
 			metrics.code_size.observe(code_size as f64);
@@ -142,6 +159,9 @@ struct MetricsInner {
 	preparation_time: prometheus::Histogram,
 	execution_time: prometheus::Histogram,
 	execution_queued_time: prometheus::Histogram,
+	execution_cpu_time: prometheus::Histogram,
+	execution_max_rss: prometheus::Histogram,
+	execution_instructions: prometheus::Histogram,
 	#[cfg(target_os = "linux")]
 	preparation_max_rss: prometheus::Histogram,
 	// Max. allocated memory, tracked by Jemallocator, polling-based
@@ -298,6 +318,56 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			execution_cpu_time: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_pvf_execution_cpu_time",
+						"CPU time spent by the execution jobs of PVFs",
+					).buckets(vec![
+						// This is synchronized with the `polkadot_pvf_execution_time` buckets.
+						0.01,
+						0.025,
+						0.05,
+						0.1,
+						0.25,
+						0.5,
+						1.0,
+						2.0,
+						3.0,
+						4.0,
+						5.0,
+						6.0,
+						8.0,
+						10.0,
+						12.0,
+					]),
+				)?,
+				registry,
+			)?,
+			execution_max_rss: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_pvf_execution_max_rss",
+						"ru_maxrss (maximum resident set size) observed for execution (in kilobytes)",
+					).buckets(
+						prometheus::exponential_buckets(8192.0, 2.0, 10)
+							.expect("arguments are always valid; qed"),
+					),
+				)?,
+				registry,
+			)?,
+			execution_instructions: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_pvf_execution_instructions",
+						"Number of user-space instructions retired by the execution jobs of PVFs",
+					).buckets(
+						prometheus::exponential_buckets(1_000_000.0, 4.0, 12)
+							.expect("arguments are always valid; qed"),
+					),
+				)?,
+				registry,
+			)?,
 			#[cfg(target_os = "linux")]
 			preparation_max_rss: prometheus::register(
 				prometheus::Histogram::with_opts(
@@ -58,6 +58,36 @@ async fn execute_good_block_on_parent() {
 	assert_eq!(new_head.post_state, hash_state(512));
 }
 
+#[tokio::test]
+async fn execution_reports_resource_usage() {
+	let parent_head = HeadData { number: 0, parent_hash: [0; 32], post_state: hash_state(0) };
+	let block_data = BlockData { state: 0, add: 512 };
+	let pvd = PersistedValidationData {
+		parent_head: GenericHeadData(parent_head.encode()),
+		relay_parent_number: 1u32,
+		relay_parent_storage_root: H256::default(),
+		max_pov_size: 4096 * 1024,
+	};
+	let pov = PoV { block_data: GenericBlockData(block_data.encode()) };
+
+	let host = TestHost::new().await;
+
+	let (_, resource_usage) = host
+		.validate_candidate_with_resource_usage(
+			test_parachain_adder::wasm_binary_unwrap(),
+			pvd,
+			pov,
+			Default::default(),
+			H256::default(),
+		)
+		.await
+		.unwrap();
+
+	assert_eq!(resource_usage.pov_size, block_data.encode().len() as u32);
+	assert!(!resource_usage.wall_time.is_zero());
+	assert!(resource_usage.max_rss.is_some_and(|max_rss| max_rss > 0));
+}
+
 #[tokio::test]
 async fn execute_good_chain_on_parent() {
 	let mut parent_hash = [0; 32];
@@ -21,8 +21,8 @@ use assert_matches::assert_matches;
 use polkadot_node_core_pvf::SecurityStatus;
 use polkadot_node_core_pvf::{
 	start, testing::build_workers_and_get_paths, Config, InvalidCandidate, Metrics,
-	PossiblyInvalidError, PrepareError, PrepareJobKind, PvfPrepData, ValidationError,
-	ValidationHost, JOB_TIMEOUT_WALL_CLOCK_FACTOR,
+	PossiblyInvalidError, PrepareError, PrepareJobKind, PvfPrepData, ResourceUsage,
+	ValidationError, ValidationHost, JOB_TIMEOUT_WALL_CLOCK_FACTOR,
 };
 use polkadot_node_core_pvf_common::{compute_checksum, ArtifactChecksum};
 use polkadot_node_primitives::{PoV, POV_BOMB_LIMIT};
@@ -114,6 +114,19 @@ impl TestHost {
 		executor_params: ExecutorParams,
 		relay_parent: Hash,
 	) -> Result<ValidationResult, ValidationError> {
+		self.validate_candidate_with_resource_usage(code, pvd, pov, executor_params, relay_parent)
+			.await
+			.map(|(result, _)| result)
+	}
+
+	async fn validate_candidate_with_resource_usage(
+		&self,
+		code: &[u8],
+		pvd: PersistedValidationData,
+		pov: PoV,
+		executor_params: ExecutorParams,
+		relay_parent: Hash,
+	) -> Result<(ValidationResult, ResourceUsage), ValidationError> {
 		let (result_tx, result_rx) = futures::channel::oneshot::channel();
 
 		self.host
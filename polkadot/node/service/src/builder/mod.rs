@@ -85,6 +85,11 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub prepare_workers_hard_max_num: Option<usize>,
 	/// How long finalized data should be kept in the availability store (in hours)
 	pub keep_finalized_for: Option<u32>,
+	/// How long data of candidates not included in any block should be kept in the availability
+	/// store (in hours)
+	pub keep_unavailable_for: Option<u32>,
+	/// An optional upper bound on the size of the availability store (in bytes)
+	pub availability_store_max_size: Option<u64>,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
 	#[allow(dead_code)]
@@ -199,6 +204,8 @@ where
 					prepare_workers_soft_max_num,
 					prepare_workers_hard_max_num,
 					keep_finalized_for,
+					keep_unavailable_for,
+					availability_store_max_size,
 				},
 			overseer_connector,
 			partial_components:
@@ -374,12 +381,10 @@ where
 				log::info!("🚀 Using prepare-worker binary at: {:?}", prep_worker_path);
 				log::info!("🚀 Using execute-worker binary at: {:?}", exec_worker_path);
 
+				let database_path = config.database.path().ok_or(Error::DatabasePathRequired)?;
 				Some(CandidateValidationConfig {
-					artifacts_cache_path: config
-						.database
-						.path()
-						.ok_or(Error::DatabasePathRequired)?
-						.join("pvf-artifacts"),
+					artifacts_cache_path: database_path.join("pvf-artifacts"),
+					artifacts_store_path: Some(crate::pvf_artifacts_store_path(database_path)),
 					node_version,
 					secure_validator_mode,
 					prep_worker_path,
@@ -422,12 +427,27 @@ where
 				keep_finalized_for: if matches!(config.chain_spec.identify_chain(), Chain::Rococo) {
 					keep_finalized_for.unwrap_or(1)
 				} else {
-					KEEP_FINALIZED_FOR_LIVE_NETWORKS
+					// Live networks may keep data for longer, e.g. on archival nodes, but never for
+					// less than needed by approval checking and disputes.
+					keep_finalized_for.map_or(KEEP_FINALIZED_FOR_LIVE_NETWORKS, |hours| {
+						hours.max(KEEP_FINALIZED_FOR_LIVE_NETWORKS)
+					})
 				},
+				keep_unavailable_for: keep_unavailable_for.unwrap_or(1),
+				max_size: availability_store_max_size,
+				// Never prune data ahead of time which approval checking and disputes may still
+				// need.
+				min_keep_finalized_for: KEEP_FINALIZED_FOR_LIVE_NETWORKS,
 			};
 
 			Some(ExtendedOverseerGenArgs {
-				keystore: keystore_container.local_keystore(),
+				// Approval voting signs assignments with the secret keys, which remote signers
+				// don't reveal.
+				keystore: if role.is_authority() {
+					keystore_container.require_local_keystore()?
+				} else {
+					keystore_container.local_keystore()
+				},
 				parachains_db,
 				candidate_validation_config,
 				availability_config,
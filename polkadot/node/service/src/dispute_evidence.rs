@@ -0,0 +1,151 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offline export and replay of dispute evidence, as used by the `dispute-evidence` command.
+//!
+//! Exporting reads the databases of the node, so the node must not be running at the same time.
+
+use super::{
+	new_chain_ops, open_database, parachains_db, workers, Configuration, Error,
+	KEEP_FINALIZED_FOR_LIVE_NETWORKS,
+};
+use futures::future::{self, Either};
+use polkadot_node_core_av_store::Config as AvailabilityConfig;
+use polkadot_node_core_dispute_coordinator::{evidence, Config as DisputeCoordinatorConfig};
+use polkadot_node_primitives::ValidationResult;
+use polkadot_primitives::{runtime_api::ParachainHost, SessionIndex};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use std::path::PathBuf;
+
+pub use evidence::{DisputeEvidence, Verdict};
+pub use polkadot_primitives::CandidateHash;
+
+/// Export the evidence for a candidate disputed in `session`.
+///
+/// The data kept by the dispute coordinator is complemented with the available data from the
+/// availability store and with the session data and validation code from the runtime state at the
+/// best block, as far as they are still present.
+pub fn export(
+	config: &mut Configuration,
+	session: SessionIndex,
+	candidate_hash: CandidateHash,
+) -> Result<DisputeEvidence, Error> {
+	let parachains_db = open_database(&config.database)?;
+	let dispute_coordinator_config = DisputeCoordinatorConfig {
+		col_dispute_data: parachains_db::REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+	let mut evidence = evidence::export_evidence(
+		&*parachains_db,
+		&dispute_coordinator_config,
+		session,
+		candidate_hash,
+	)?
+	.ok_or(Error::DisputeEvidenceNotFound { session, candidate_hash })?;
+
+	let availability_config = AvailabilityConfig {
+		col_data: parachains_db::REAL_COLUMNS.col_availability_data,
+		col_meta: parachains_db::REAL_COLUMNS.col_availability_meta,
+		keep_finalized_for: KEEP_FINALIZED_FOR_LIVE_NETWORKS,
+		keep_unavailable_for: 1,
+		max_size: None,
+		min_keep_finalized_for: KEEP_FINALIZED_FOR_LIVE_NETWORKS,
+	};
+	evidence.available_data = polkadot_node_core_av_store::load_available_data(
+		&parachains_db,
+		&availability_config,
+		&candidate_hash,
+	)?;
+
+	let (client, _, _, _task_manager) = new_chain_ops(config)?;
+	let at = client.info().best_hash;
+	let api = client.runtime_api();
+	let validation_code_hash = evidence.candidate_receipt.descriptor.validation_code_hash();
+
+	evidence.session_info = or_warn("session info", api.session_info(at, session));
+	evidence.executor_params = or_warn("executor params", api.session_executor_params(at, session));
+	evidence.validation_code =
+		or_warn("validation code", api.validation_code_by_hash(at, validation_code_hash));
+	evidence.validation_code_bomb_limit =
+		or_warn("validation code bomb limit", api.validation_code_bomb_limit(at).map(Some));
+
+	Ok(evidence)
+}
+
+/// Replay the validation of exported evidence against a freshly started PVF host.
+///
+/// Compiled artifacts are kept in `cache_path`. Returns the result of the validation, which can be
+/// compared against [`DisputeEvidence::verdict`].
+pub async fn replay(
+	evidence: &DisputeEvidence,
+	workers_path: Option<PathBuf>,
+	node_version: Option<String>,
+	cache_path: PathBuf,
+) -> Result<ValidationResult, Error> {
+	let executor_params = evidence
+		.executor_params
+		.clone()
+		.ok_or(Error::DisputeEvidenceIncomplete("executor params"))?;
+	let validation_code = evidence
+		.validation_code
+		.clone()
+		.ok_or(Error::DisputeEvidenceIncomplete("validation code"))?;
+	let available_data = evidence
+		.available_data
+		.clone()
+		.ok_or(Error::DisputeEvidenceIncomplete("available data"))?;
+	// Same fallback as used for runtimes not exposing the limit yet.
+	#[allow(deprecated)]
+	let default_bomb_limit = polkadot_node_primitives::VALIDATION_CODE_BOMB_LIMIT as u32;
+
+	let (prep_worker_path, exec_worker_path) =
+		workers::determine_workers_paths(workers_path, None, node_version.clone())?;
+	let pvf_config = polkadot_node_core_pvf::Config::new(
+		cache_path,
+		node_version,
+		false,
+		prep_worker_path,
+		exec_worker_path,
+		1,
+		1,
+		1,
+	);
+	let (validation_host, host_task) =
+		polkadot_node_core_pvf::start(pvf_config, Default::default()).await?;
+
+	let validation = polkadot_node_core_candidate_validation::replay_dispute_validation(
+		validation_host,
+		evidence.session,
+		available_data.validation_data,
+		validation_code,
+		evidence.candidate_receipt.clone(),
+		available_data.pov,
+		executor_params,
+		evidence.validation_code_bomb_limit.unwrap_or(default_bomb_limit),
+	);
+	match future::select(Box::pin(validation), Box::pin(host_task)).await {
+		Either::Left((result, _)) => result.map_err(|err| Error::DisputeReplayFailed(err.0)),
+		Either::Right(((), _)) =>
+			Err(Error::DisputeReplayFailed("PVF validation host exited".into())),
+	}
+}
+
+fn or_warn<T>(what: &str, result: Result<Option<T>, sp_api::ApiError>) -> Option<T> {
+	result.unwrap_or_else(|err| {
+		log::warn!("Could not fetch the {} from the runtime: {}", what, err);
+		None
+	})
+}
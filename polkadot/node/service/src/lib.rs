@@ -28,6 +28,8 @@ mod relay_chain_selection;
 #[cfg(feature = "full-node")]
 pub mod builder;
 #[cfg(feature = "full-node")]
+pub mod dispute_evidence;
+#[cfg(feature = "full-node")]
 pub mod overseer;
 #[cfg(feature = "full-node")]
 pub mod workers;
@@ -232,6 +234,25 @@ pub enum Error {
 		workers_names: Option<(String, String)>,
 	},
 
+	#[cfg(feature = "full-node")]
+	#[error(transparent)]
+	DisputeEvidence(#[from] polkadot_node_core_dispute_coordinator::evidence::Error),
+
+	#[cfg(feature = "full-node")]
+	#[error("No dispute evidence found for candidate {candidate_hash:?} in session {session}")]
+	DisputeEvidenceNotFound {
+		session: polkadot_primitives::SessionIndex,
+		candidate_hash: polkadot_primitives::CandidateHash,
+	},
+
+	#[cfg(feature = "full-node")]
+	#[error("Dispute evidence lacks the {0} needed to replay the validation")]
+	DisputeEvidenceIncomplete(&'static str),
+
+	#[cfg(feature = "full-node")]
+	#[error("Replaying the dispute validation failed: {0}")]
+	DisputeReplayFailed(String),
+
 	#[cfg(feature = "full-node")]
 	#[error("Version of worker binary ({worker_version}) is different from node version ({node_version}), worker_path: {worker_path}. If you ran with `cargo run`, please run `cargo build` first, otherwise try to `cargo clean`. TESTING ONLY: this check can be disabled with --disable-worker-version-check")]
 	WorkerBinaryVersionMismatch {
@@ -345,6 +366,61 @@ pub fn open_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Er
 	Ok(parachains_db)
 }
 
+/// List the candidates held by the availability store, in the order they are due to be pruned.
+///
+/// Reads the database of the node, so the node must not be running at the same time.
+#[cfg(feature = "full-node")]
+pub fn held_availability_candidates(
+	db_source: &DatabaseSource,
+) -> Result<Vec<polkadot_node_core_av_store::HeldCandidate>, Error> {
+	let parachains_db = open_database(db_source)?;
+	let availability_config = polkadot_node_core_av_store::Config {
+		col_data: parachains_db::REAL_COLUMNS.col_availability_data,
+		col_meta: parachains_db::REAL_COLUMNS.col_availability_meta,
+		keep_finalized_for: KEEP_FINALIZED_FOR_LIVE_NETWORKS,
+		keep_unavailable_for: 1,
+		max_size: None,
+		min_keep_finalized_for: KEEP_FINALIZED_FOR_LIVE_NETWORKS,
+	};
+	Ok(polkadot_node_core_av_store::held_candidates(&parachains_db, &availability_config)?)
+}
+
+/// The root directory of the persistent PVF artifact store within the given database directory.
+#[cfg(feature = "full-node")]
+pub fn pvf_artifacts_store_path(database_path: &std::path::Path) -> std::path::PathBuf {
+	database_path.join("pvf-artifacts-store")
+}
+
+/// Export the compiled PVF artifacts persisted by the node into `destination`.
+///
+/// Only artifacts compatible with this node are exported. Returns the number of exported
+/// artifacts.
+#[cfg(feature = "full-node")]
+pub fn export_pvf_artifacts(
+	db_source: &DatabaseSource,
+	destination: &std::path::Path,
+) -> Result<usize, Error> {
+	let database_path = db_source.path().ok_or(Error::DatabasePathRequired)?;
+	let store =
+		polkadot_node_core_pvf::ArtifactStore::open(&pvf_artifacts_store_path(database_path))?;
+	Ok(store.export(destination)?)
+}
+
+/// Import the compiled PVF artifacts exported into `source` by a compatible node.
+///
+/// The artifacts are native code, so they must only be imported from a trusted source. The node
+/// must not be running at the same time. Returns the number of imported artifacts.
+#[cfg(feature = "full-node")]
+pub fn import_pvf_artifacts(
+	db_source: &DatabaseSource,
+	source: &std::path::Path,
+) -> Result<usize, Error> {
+	let database_path = db_source.path().ok_or(Error::DatabasePathRequired)?;
+	let store =
+		polkadot_node_core_pvf::ArtifactStore::open(&pvf_artifacts_store_path(database_path))?;
+	Ok(store.import(source)?)
+}
+
 /// Is this node running as in-process node for a parachain node?
 #[cfg(feature = "full-node")]
 #[derive(Clone)]
@@ -103,6 +103,8 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					prepare_workers_hard_max_num: None,
 					prepare_workers_soft_max_num: None,
 					keep_finalized_for: None,
+					keep_unavailable_for: None,
+					availability_store_max_size: None,
 				},
 			),
 		sc_network::config::NetworkBackendType::Litep2p =>
@@ -125,6 +127,8 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					prepare_workers_hard_max_num: None,
 					prepare_workers_soft_max_num: None,
 					keep_finalized_for: None,
+					keep_unavailable_for: None,
+					availability_store_max_size: None,
 				},
 			),
 	}
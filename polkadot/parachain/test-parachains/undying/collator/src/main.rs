@@ -110,6 +110,8 @@ fn main() -> Result<()> {
 						prepare_workers_hard_max_num: None,
 						prepare_workers_soft_max_num: None,
 						keep_finalized_for: None,
+						keep_unavailable_for: None,
+						availability_store_max_size: None,
 					},
 				)
 				.map_err(|e| e.to_string())?;
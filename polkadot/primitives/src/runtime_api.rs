@@ -319,5 +319,18 @@ sp_api::decl_runtime_apis! {
 		#[api_version(14)]
 		fn para_ids() -> Vec<ppp::Id>;
 
+		/***** Added in v15 *****/
+		/// Predict the prices of on-demand coretime orders for `order_count` orders placed back to
+		/// back and for single orders placed in each of the next `blocks` blocks.
+		///
+		/// The current queue size is assumed, unless `queue_size` is given. Returns `None` if
+		/// on-demand coretime is not available.
+		#[api_version(15)]
+		fn on_demand_price_prediction(
+			order_count: u32,
+			blocks: u32,
+			queue_size: Option<u32>,
+		) -> Option<vstaging::OnDemandPricePrediction>;
+
 	}
 }
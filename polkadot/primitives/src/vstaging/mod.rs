@@ -22,9 +22,9 @@ use crate::{slashing::DisputesTimeSlot, ValidatorId, ValidatorIndex, ValidityAtt
 // Put any primitives used by staging APIs functions here
 use super::{
 	async_backing::{InboundHrmpLimitations, OutboundHrmpChannelLimitations},
-	BlakeTwo256, BlockNumber, CandidateCommitments, CandidateDescriptor, CandidateHash, CollatorId,
-	CollatorSignature, CoreIndex, GroupIndex, Hash, HashT, HeadData, Header, Id, Id as ParaId,
-	MultiDisputeStatementSet, ScheduledCore, UncheckedSignedAvailabilityBitfields,
+	Balance, BlakeTwo256, BlockNumber, CandidateCommitments, CandidateDescriptor, CandidateHash,
+	CollatorId, CollatorSignature, CoreIndex, GroupIndex, Hash, HashT, HeadData, Header, Id,
+	Id as ParaId, MultiDisputeStatementSet, ScheduledCore, UncheckedSignedAvailabilityBitfields,
 	UpgradeRestriction, ValidationCodeHash,
 };
 use alloc::{
@@ -1649,3 +1649,17 @@ impl TryFrom<DisputeProof> for super::v8::slashing::DisputeProof {
 		})
 	}
 }
+
+/// Predicted prices of on-demand coretime orders.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct OnDemandPricePrediction {
+	/// The spot price of a single order placed now.
+	pub spot_price: Balance,
+	/// The prices of orders placed back to back now, as charged for a batched order.
+	///
+	/// Shorter than requested if the queue would be full before.
+	pub batch_prices: Vec<Balance>,
+	/// The spot prices of single orders placed in each of the following blocks, assuming the
+	/// queue keeps its size.
+	pub future_spot_prices: Vec<Balance>,
+}
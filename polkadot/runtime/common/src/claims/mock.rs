@@ -58,6 +58,7 @@ parameter_types! {
 	pub const MinVestedTransfer: u64 = 1;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const VestingPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/vestg");
 }
 
 impl pallet_vesting::Config for Test {
@@ -69,6 +70,13 @@ impl pallet_vesting::Config for Test {
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
+	type Assets = ();
+	type RevokeOrigin = frame_system::EnsureRoot<u64>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = frame_support::traits::ConstU32<16>;
+	type ReleaseScheduleDeposit = frame_support::traits::ConstU64<0>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 parameter_types! {
@@ -398,6 +398,9 @@ mod tests {
 		type BlockNumberProvider = System;
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
+		type BudgetHolder = ();
+		type BudgetOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+		type ManageBudgetOrigin = frame_system::EnsureRoot<AccountId>;
 	}
 
 	pub struct OneAuthor;
@@ -412,15 +412,27 @@ parameter_types! {
 	// defined Timeslice for Coretime.
 	pub const MaxHistoricalRevenue: BlockNumber = 2 * 5;
 	pub const OnDemandPalletId: PalletId = PalletId(*b"py/ondmd");
+	pub const MaxStandingOrders: u32 = 10;
+	pub const MaxStandingOrdersPerBlock: u32 = 2;
+	pub const MaxStandingOrdersPerAccount: u32 = 3;
+	pub const MaxStandingOrderLifetime: BlockNumber = 100;
+	pub const StandingOrderDeposit: Balance = 10;
 }
 
 impl on_demand::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
 	type WeightInfo = crate::on_demand::TestWeightInfo;
 	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type PalletId = OnDemandPalletId;
+	type MaxStandingOrders = MaxStandingOrders;
+	type MaxStandingOrdersPerBlock = MaxStandingOrdersPerBlock;
+	type MaxStandingOrdersPerAccount = MaxStandingOrdersPerAccount;
+	type MaxStandingOrderLifetime = MaxStandingOrderLifetime;
+	type StandingOrderDeposit = StandingOrderDeposit;
 }
 
 impl assigner_coretime::Config for Test {}
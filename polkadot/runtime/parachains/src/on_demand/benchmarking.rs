@@ -27,8 +27,10 @@ use crate::{
 
 use alloc::vec;
 use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::InspectHold;
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded;
+use types::StandingOrder;
 
 use polkadot_primitives::{
 	HeadData, Id as ParaId, SessionIndex, ValidationCode, ON_DEMAND_DEFAULT_QUEUE_MAX_SIZE,
@@ -36,6 +38,7 @@ use polkadot_primitives::{
 
 // Constants for the benchmarking
 const SESSION_INDEX: SessionIndex = 1;
+const SEED: u32 = 0;
 
 // Initialize a parathread for benchmarking.
 pub fn init_parathread<T>(para_id: ParaId)
@@ -58,6 +61,19 @@ where
 	);
 }
 
+// Fill the standing orders with `o` orders of other accounts, which are all waiting.
+fn populate_standing_orders<T: Config>(para_id: ParaId, o: u32) {
+	let orders = (0..o).map(|i| StandingOrder {
+		ordered_by: account("orderer", i, SEED),
+		para_id,
+		max_amount: 1u32.into(),
+		remaining: 1,
+		expires_at: 1u32.into(),
+		deposit: T::StandingOrderDeposit::get(),
+	});
+	StandingOrders::<T>::put(BoundedVec::truncate_from(orders.collect()));
+}
+
 #[benchmarks]
 mod benchmarks {
 	/// We want to fill the queue to the maximum, so exactly one more item fits.
@@ -105,6 +121,83 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id)
 	}
 
+	#[benchmark]
+	fn place_batch_order_with_credits(
+		s: Linear<1, MAX_FILL_BENCH>,
+		o: Linear<0, { T::MaxStandingOrders::get() - 1 }>,
+	) {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		Credits::<T>::insert(&caller, BalanceOf::<T>::max_value());
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		Pallet::<T>::populate_queue(para_id, s);
+		populate_standing_orders::<T>(para_id, o);
+
+		// The first order is placed right away, the second one is kept waiting.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id, 2);
+
+		assert_eq!(StandingOrders::<T>::get().len() as u32, o + 1);
+	}
+
+	#[benchmark]
+	fn place_standing_order_with_credits(
+		s: Linear<1, MAX_FILL_BENCH>,
+		o: Linear<0, { T::MaxStandingOrders::get() - 1 }>,
+	) {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		Credits::<T>::insert(&caller, BalanceOf::<T>::max_value());
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		Pallet::<T>::populate_queue(para_id, s);
+		populate_standing_orders::<T>(para_id, o);
+
+		// The spot price is higher than the price ceiling, so the order has to wait.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone().into()), 1u32.into(), para_id, 1u32.into());
+
+		assert_eq!(StandingOrders::<T>::get().len() as u32, o + 1);
+	}
+
+	#[benchmark]
+	fn cancel_standing_order(
+		o: Linear<1, { T::MaxStandingOrders::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		let deposit = T::StandingOrderDeposit::get();
+		let deposits = deposit.saturating_mul(o.into());
+		T::Currency::make_free_balance_be(
+			&caller,
+			T::Currency::minimum_balance().saturating_add(deposits),
+		);
+		T::DepositCurrency::hold(&HoldReason::StandingOrderDeposit.into(), &caller, deposits)?;
+
+		let orders = (0..o).map(|_| StandingOrder {
+			ordered_by: caller.clone(),
+			para_id,
+			max_amount: 1u32.into(),
+			remaining: 1,
+			expires_at: 1u32.into(),
+			deposit,
+		});
+		StandingOrders::<T>::put(BoundedVec::truncate_from(orders.collect()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone().into()), para_id);
+
+		assert!(StandingOrders::<T>::get().is_empty());
+		assert!(T::DepositCurrency::total_balance_on_hold(&caller).is_zero());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(
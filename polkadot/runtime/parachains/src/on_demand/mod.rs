@@ -25,6 +25,11 @@
 //! a specific `ParaId`, orders for blockspace for that `ParaId` will only be assigned to
 //! that `CoreIndex`.
 //!
+//! Besides single orders at the current spot price, orders paid with credits can be placed in
+//! batches, one order per block for a number of blocks with a maximum total price, or as standing
+//! orders which wait for the spot price to drop to their price ceiling.
+//! [`Pallet::price_prediction`] helps with planning the costs.
+//!
 //! NOTE: Once we have elastic scaling implemented we might want to extend this module to support
 //! ignoring core affinity up to a certain extend. This should be opt-in though as the parachain
 //! needs to support multiple cores in the same block. If we want to enable a single parachain
@@ -43,12 +48,15 @@ extern crate alloc;
 mod tests;
 
 use crate::{configuration, paras, scheduler::common::Assignment};
-use alloc::collections::BinaryHeap;
+use alloc::{collections::BinaryHeap, vec::Vec};
 use core::mem::take;
 use frame_support::{
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		defensive_prelude::*,
+		fungible::{self, MutateHold},
+		tokens::Precision,
 		Currency,
 		ExistenceRequirement::{self, AllowDeath, KeepAlive},
 		WithdrawReasons,
@@ -56,24 +64,30 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::{pallet_prelude::*, Pallet as System};
-use polkadot_primitives::{CoreIndex, Id as ParaId};
+use polkadot_primitives::{vstaging::OnDemandPricePrediction, CoreIndex, Id as ParaId};
 use sp_runtime::{
 	traits::{AccountIdConversion, One, SaturatedConversion},
 	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Saturating,
 };
 use types::{
 	BalanceOf, CoreAffinityCount, EnqueuedOrder, QueuePushDirection, QueueStatusType,
-	SpotTrafficCalculationErr,
+	SpotTrafficCalculationErr, StandingOrder, StandingOrderOf,
 };
 
 const LOG_TARGET: &str = "runtime::parachains::on-demand";
 
+/// The maximum number of blocks spot prices are predicted for, about an hour with 6 second blocks.
+const MAX_PREDICTED_BLOCKS: u32 = 600;
+
 pub use pallet::*;
 
 pub trait WeightInfo {
 	fn place_order_allow_death(s: u32) -> Weight;
 	fn place_order_keep_alive(s: u32) -> Weight;
 	fn place_order_with_credits(s: u32) -> Weight;
+	fn place_batch_order_with_credits(s: u32, o: u32) -> Weight;
+	fn place_standing_order_with_credits(s: u32, o: u32) -> Weight;
+	fn cancel_standing_order(o: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -91,6 +105,18 @@ impl WeightInfo for TestWeightInfo {
 	fn place_order_with_credits(_: u32) -> Weight {
 		Weight::MAX
 	}
+
+	fn place_batch_order_with_credits(_: u32, _: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn place_standing_order_with_credits(_: u32, _: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn cancel_standing_order(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 /// Defines how the account wants to pay for on-demand.
@@ -123,6 +149,13 @@ pub mod pallet {
 		/// The runtime's definition of a Currency.
 		type Currency: Currency<Self::AccountId>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The native currency, holding the deposits of standing orders.
+		type DepositCurrency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ fungible::Inspect<Self::AccountId, Balance = BalanceOf<Self>>;
+
 		/// Something that provides the weight of this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -138,6 +171,35 @@ pub mod pallet {
 		/// Identifier for the internal revenue balance.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+
+		/// The maximum number of standing orders waiting to be placed.
+		#[pallet::constant]
+		type MaxStandingOrders: Get<u32>;
+
+		/// The maximum number of standing orders placed or expired in a single block.
+		///
+		/// Bounds the weight of processing the standing orders in `on_initialize`.
+		#[pallet::constant]
+		type MaxStandingOrdersPerBlock: Get<u32>;
+
+		/// The maximum number of standing orders a single account may have waiting.
+		#[pallet::constant]
+		type MaxStandingOrdersPerAccount: Get<u32>;
+
+		/// The maximum number of blocks a standing order stays valid for.
+		#[pallet::constant]
+		type MaxStandingOrderLifetime: Get<BlockNumberFor<Self>>;
+
+		/// The deposit held from the orderer for every waiting standing order.
+		#[pallet::constant]
+		type StandingOrderDeposit: Get<BalanceOf<Self>>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as deposit for a standing order.
+		StandingOrderDeposit,
 	}
 
 	/// Creates an empty queue status for an empty queue with initial traffic value.
@@ -189,6 +251,11 @@ pub mod pallet {
 	pub type Credits<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// Orders waiting to be placed in a later block, in the order they are processed in.
+	#[pallet::storage]
+	pub type StandingOrders<T: Config> =
+		StorageValue<_, BoundedVec<StandingOrderOf<T>, T::MaxStandingOrders>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -198,6 +265,21 @@ pub mod pallet {
 		SpotPriceSet { spot_price: BalanceOf<T> },
 		/// An account was given credits.
 		AccountCredited { who: T::AccountId, amount: BalanceOf<T> },
+		/// A standing order was placed, waiting to place `count` orders for at most `max_amount`
+		/// in total.
+		StandingOrderPlaced {
+			para_id: ParaId,
+			max_amount: BalanceOf<T>,
+			count: u32,
+			expires_at: BlockNumberFor<T>,
+			ordered_by: T::AccountId,
+		},
+		/// A standing order expired before all of its orders could be placed.
+		StandingOrderExpired { para_id: ParaId, ordered_by: T::AccountId },
+		/// A standing order was cancelled by the orderer.
+		StandingOrderCancelled { para_id: ParaId, ordered_by: T::AccountId },
+		/// A standing order was dropped as the orderer could not pay for it.
+		StandingOrderDropped { para_id: ParaId, ordered_by: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -209,11 +291,24 @@ pub mod pallet {
 		SpotPriceHigherThanMaxAmount,
 		/// The account doesn't have enough credits to purchase on-demand coretime.
 		InsufficientCredits,
+		/// A batched order must contain at least one order.
+		EmptyBatch,
+		/// A standing order must stay valid for at least one block.
+		ZeroLifetime,
+		/// A standing or batched order can't stay valid for more than `MaxStandingOrderLifetime`
+		/// blocks.
+		LifetimeTooLong,
+		/// The maximum number of standing orders has been reached.
+		TooManyStandingOrders,
+		/// The maximum number of standing orders of the account has been reached.
+		TooManyStandingOrdersForAccount,
+		/// The account has no standing order for the para.
+		NoStandingOrder,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
 			// Update revenue information storage.
 			Revenue::<T>::mutate(|revenue| {
 				if let Some(overdue) =
@@ -236,7 +331,9 @@ pub mod pallet {
 
 			// Reads: `Revenue`, `ActiveConfig`, `QueueStatus`
 			// Writes: `Revenue`, `QueueStatus`
-			T::DbWeight::get().reads_writes(3, 2)
+			T::DbWeight::get()
+				.reads_writes(3, 2)
+				.saturating_add(Self::process_standing_orders(now))
 		}
 	}
 
@@ -274,6 +371,7 @@ pub mod pallet {
 				AllowDeath,
 				PaymentType::Balance,
 			)
+			.map(drop)
 		}
 
 		/// Same as the [`place_order_allow_death`](Self::place_order_allow_death) call , but with a
@@ -308,6 +406,7 @@ pub mod pallet {
 				KeepAlive,
 				PaymentType::Balance,
 			)
+			.map(drop)
 		}
 
 		/// Create a single on demand core order with credits.
@@ -342,6 +441,171 @@ pub mod pallet {
 				KeepAlive,
 				PaymentType::Credits,
 			)
+			.map(drop)
+		}
+
+		/// Create on demand core orders with credits for the next `count` blocks, one order per
+		/// block, for at most `max_total_amount` together.
+		/// The first order is placed right away. The others are kept as a standing order, which
+		/// places one order in each of the following `count - 1` blocks whose spot price does not
+		/// exceed the rest of `max_total_amount` and whose queue is not full. While they are
+		/// waiting, `StandingOrderDeposit` is held from the origin.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, on-demand credits will be withdrawn from this
+		///   account.
+		/// - `max_total_amount`: The maximum number of credits to spend on all orders together.
+		/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
+		/// - `count`: The number of blocks to place an order in, at most
+		///   `MaxStandingOrderLifetime`.
+		///
+		/// Errors:
+		/// - `EmptyBatch`
+		/// - `LifetimeTooLong`
+		/// - `InsufficientCredits`
+		/// - `QueueFull`
+		/// - `SpotPriceHigherThanMaxAmount`
+		/// - `TooManyStandingOrders`
+		/// - `TooManyStandingOrdersForAccount`
+		/// - `FundsUnavailable`: from the fungible implementation, if the deposit can't be held.
+		///
+		/// Events:
+		/// - `OnDemandOrderPlaced`: for the first order.
+		/// - `StandingOrderPlaced`: for the following ones.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_batch_order_with_credits(
+			QueueStatus::<T>::get().size(),
+			T::MaxStandingOrders::get(),
+		))]
+		pub fn place_batch_order_with_credits(
+			origin: OriginFor<T>,
+			max_total_amount: BalanceOf<T>,
+			para_id: ParaId,
+			count: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(count > 0, Error::<T>::EmptyBatch);
+			ensure!(
+				BlockNumberFor::<T>::from(count) <= T::MaxStandingOrderLifetime::get(),
+				Error::<T>::LifetimeTooLong
+			);
+
+			let spot_price = Pallet::<T>::do_place_order(
+				sender.clone(),
+				max_total_amount,
+				para_id,
+				KeepAlive,
+				PaymentType::Credits,
+			)?;
+			if count > 1 {
+				Pallet::<T>::add_standing_order(
+					sender,
+					para_id,
+					max_total_amount.saturating_sub(spot_price),
+					count - 1,
+					BlockNumberFor::<T>::from(count - 1),
+				)?;
+			}
+			Ok(())
+		}
+
+		/// Create an on demand core order with credits which waits for the spot price to drop to
+		/// `max_amount`.
+		/// The order is placed right away if possible. Otherwise it is placed in the first block
+		/// of the next `lifetime` blocks in which the spot price does not exceed `max_amount`
+		/// and the queue is not full. While the order is waiting, `StandingOrderDeposit` is
+		/// held from the origin. The order is dropped if the origin can't pay for it once it
+		/// could be placed.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, on-demand credits will be withdrawn from this
+		///   account once the order is placed.
+		/// - `max_amount`: The price ceiling, i.e. the maximum number of credits to spend on the
+		///   order.
+		/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
+		/// - `lifetime`: The number of blocks the order stays valid for, at most
+		///   `MaxStandingOrderLifetime`.
+		///
+		/// Errors:
+		/// - `ZeroLifetime`
+		/// - `LifetimeTooLong`
+		/// - `InsufficientCredits`: if the order could be placed right away.
+		/// - `TooManyStandingOrders`
+		/// - `TooManyStandingOrdersForAccount`
+		/// - `FundsUnavailable`: from the fungible implementation, if the deposit can't be held.
+		///
+		/// Events:
+		/// - `OnDemandOrderPlaced`: if the order could be placed right away.
+		/// - `StandingOrderPlaced`: otherwise.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order_with_credits(
+			QueueStatus::<T>::get().size()
+		).max(<T as Config>::WeightInfo::place_standing_order_with_credits(
+			QueueStatus::<T>::get().size(),
+			T::MaxStandingOrders::get(),
+		)))]
+		pub fn place_standing_order_with_credits(
+			origin: OriginFor<T>,
+			max_amount: BalanceOf<T>,
+			para_id: ParaId,
+			lifetime: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!lifetime.is_zero(), Error::<T>::ZeroLifetime);
+			ensure!(lifetime <= T::MaxStandingOrderLifetime::get(), Error::<T>::LifetimeTooLong);
+
+			match with_storage_layer(|| {
+				Pallet::<T>::do_place_order(
+					sender.clone(),
+					max_amount,
+					para_id,
+					KeepAlive,
+					PaymentType::Credits,
+				)
+			}) {
+				Ok(_) => Ok(()),
+				Err(err) if Pallet::<T>::order_can_wait(&err) =>
+					Pallet::<T>::add_standing_order(sender, para_id, max_amount, 1, lifetime),
+				Err(err) => Err(err),
+			}
+		}
+
+		/// Cancel the standing orders of the origin for `para_id` and release their deposits.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, which placed the standing orders.
+		/// - `para_id`: The `ParaId` the standing orders were placed for.
+		///
+		/// Errors:
+		/// - `NoStandingOrder`
+		///
+		/// Events:
+		/// - `StandingOrderCancelled`
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_standing_order(
+			T::MaxStandingOrders::get()
+		))]
+		pub fn cancel_standing_order(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let deposit = StandingOrders::<T>::try_mutate(|orders| {
+				let len = orders.len();
+				let mut deposit = BalanceOf::<T>::zero();
+				orders.retain(|order| {
+					let cancelled = order.ordered_by == sender && order.para_id == para_id;
+					if cancelled {
+						deposit.saturating_accrue(order.deposit);
+					}
+					!cancelled
+				});
+				ensure!(orders.len() < len, Error::<T>::NoStandingOrder);
+				Ok::<_, DispatchError>(deposit)
+			})?;
+			Pallet::<T>::release_deposit(&sender, deposit);
+			Pallet::<T>::deposit_event(Event::<T>::StandingOrderCancelled {
+				para_id,
+				ordered_by: sender,
+			});
+			Ok(())
 		}
 	}
 }
@@ -446,6 +710,8 @@ where
 	/// - `existence_requirement`: Whether or not to ensure that the account will not be reaped.
 	/// - `payment_type`: Defines how the user wants to pay for on-demand.
 	///
+	/// Returns the spot price charged for the order.
+	///
 	/// Errors:
 	/// - `InsufficientBalance`: from the Currency implementation
 	/// - `QueueFull`
@@ -459,7 +725,7 @@ where
 		para_id: ParaId,
 		existence_requirement: ExistenceRequirement,
 		payment_type: PaymentType,
-	) -> DispatchResult {
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let config = configuration::ActiveConfig::<T>::get();
 
 		QueueStatus::<T>::mutate(|queue_status| {
@@ -532,7 +798,254 @@ where
 				ordered_by: sender,
 			});
 
-			Ok(())
+			Ok(spot_price)
+		})
+	}
+
+	/// Keep an order for `para_id` waiting to place `count` orders, one per block, for at most
+	/// `max_amount` in total during the next `lifetime` blocks.
+	///
+	/// Errors:
+	/// - `TooManyStandingOrders`
+	/// - `TooManyStandingOrdersForAccount`
+	/// - `FundsUnavailable`: from the fungible implementation, if the deposit can't be held.
+	///
+	/// Events:
+	/// - `StandingOrderPlaced`
+	fn add_standing_order(
+		sender: T::AccountId,
+		para_id: ParaId,
+		max_amount: BalanceOf<T>,
+		count: u32,
+		lifetime: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let expires_at = System::<T>::block_number().saturating_add(lifetime);
+		StandingOrders::<T>::try_mutate(|orders| {
+			ensure!(
+				orders.len() < T::MaxStandingOrders::get() as usize,
+				Error::<T>::TooManyStandingOrders
+			);
+			let waiting = orders.iter().filter(|order| order.ordered_by == sender).count();
+			ensure!(
+				waiting < T::MaxStandingOrdersPerAccount::get() as usize,
+				Error::<T>::TooManyStandingOrdersForAccount
+			);
+
+			let deposit = T::StandingOrderDeposit::get();
+			T::DepositCurrency::hold(&HoldReason::StandingOrderDeposit.into(), &sender, deposit)?;
+			orders
+				.try_push(StandingOrder {
+					ordered_by: sender.clone(),
+					para_id,
+					max_amount,
+					remaining: count,
+					expires_at,
+					deposit,
+				})
+				.defensive_map_err(|_| Error::<T>::TooManyStandingOrders)?;
+			Ok::<_, DispatchError>(())
+		})?;
+		Pallet::<T>::deposit_event(Event::<T>::StandingOrderPlaced {
+			para_id,
+			max_amount,
+			count,
+			expires_at,
+			ordered_by: sender,
+		});
+		Ok(())
+	}
+
+	/// Release the deposit held for standing orders of `who`.
+	fn release_deposit(who: &T::AccountId, deposit: BalanceOf<T>) {
+		T::DepositCurrency::release(
+			&HoldReason::StandingOrderDeposit.into(),
+			who,
+			deposit,
+			Precision::BestEffort,
+		)
+		.defensive_ok();
+	}
+
+	/// Whether a standing order which failed to be placed with `err` may wait for a later block.
+	///
+	/// Only the spot price and the queue size change on their own; any other failure, e.g. a lack
+	/// of credits, is up to the orderer.
+	fn order_can_wait(err: &DispatchError) -> bool {
+		*err == Error::<T>::SpotPriceHigherThanMaxAmount.into() ||
+			*err == Error::<T>::QueueFull.into()
+	}
+
+	/// The spot price the next order would be charged, without updating the spot traffic.
+	fn next_spot_price(
+		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
+		queue_status: &QueueStatusType,
+	) -> BalanceOf<T> {
+		// Same as `update_spot_traffic`, which `do_place_order` calls before charging the order.
+		let traffic = Self::calculate_spot_traffic(
+			queue_status.traffic,
+			config.scheduler_params.on_demand_queue_max_size,
+			queue_status.size(),
+			config.scheduler_params.on_demand_target_queue_utilization,
+			config.scheduler_params.on_demand_fee_variability,
+		)
+		.unwrap_or(queue_status.traffic);
+		traffic.saturating_mul_int(
+			config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
+		)
+	}
+
+	/// Place one order of each standing order whose price ceiling is met and drop the expired
+	/// standing orders, as well as those the orderer can't pay for.
+	///
+	/// At most `MaxStandingOrdersPerBlock` orders are placed or expired per block. Orders whose
+	/// price ceiling is below the spot price, or which would not fit into the queue, are skipped
+	/// without being charged against that budget. Orders which stay after being processed are
+	/// moved to the back, so that all of them get their turn.
+	///
+	/// Returns the weight consumed.
+	fn process_standing_orders(now: BlockNumberFor<T>) -> Weight {
+		let orders = StandingOrders::<T>::get();
+		if orders.is_empty() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		// Reads: `StandingOrders`, `ActiveConfig`, `QueueStatus`
+		// Writes: `StandingOrders`
+		let mut weight = T::DbWeight::get().reads_writes(3, 1);
+		// Releasing a deposit reads and writes the holds and the account of the orderer.
+		let release_weight = T::DbWeight::get().reads_writes(2, 2);
+
+		let config = configuration::ActiveConfig::<T>::get();
+		let mut queue_status = QueueStatus::<T>::get();
+		let mut budget = T::MaxStandingOrdersPerBlock::get();
+		let mut waiting = Vec::with_capacity(orders.len());
+		let mut processed = Vec::new();
+		for mut order in orders {
+			if budget == 0 {
+				waiting.push(order);
+				continue
+			}
+
+			if order.expires_at < now {
+				budget -= 1;
+				weight.saturating_accrue(release_weight);
+				Pallet::<T>::release_deposit(&order.ordered_by, order.deposit);
+				Pallet::<T>::deposit_event(Event::<T>::StandingOrderExpired {
+					para_id: order.para_id,
+					ordered_by: order.ordered_by,
+				});
+				continue
+			}
+
+			if order.max_amount < Self::next_spot_price(&config, &queue_status) ||
+				queue_status.size() >= config.scheduler_params.on_demand_queue_max_size
+			{
+				waiting.push(order);
+				continue
+			}
+
+			budget -= 1;
+			weight.saturating_accrue(
+				<T as Config>::WeightInfo::place_order_with_credits(queue_status.size())
+					.saturating_add(release_weight),
+			);
+			// Orders which can't be placed yet must not leave any trace, in particular they must
+			// not affect the spot traffic.
+			let result = with_storage_layer(|| {
+				Pallet::<T>::do_place_order(
+					order.ordered_by.clone(),
+					order.max_amount,
+					order.para_id,
+					KeepAlive,
+					PaymentType::Credits,
+				)
+			});
+			match result {
+				Ok(spot_price) => {
+					queue_status = QueueStatus::<T>::get();
+					order.max_amount.saturating_reduce(spot_price);
+					order.remaining.saturating_dec();
+					if order.remaining > 0 {
+						processed.push(order);
+						continue
+					}
+				},
+				Err(err) if Pallet::<T>::order_can_wait(&err) => {
+					processed.push(order);
+					continue
+				},
+				Err(_) => Pallet::<T>::deposit_event(Event::<T>::StandingOrderDropped {
+					para_id: order.para_id,
+					ordered_by: order.ordered_by.clone(),
+				}),
+			}
+			Pallet::<T>::release_deposit(&order.ordered_by, order.deposit);
+		}
+		waiting.append(&mut processed);
+		// Orders are only ever removed, so they still fit.
+		StandingOrders::<T>::put(BoundedVec::truncate_from(waiting));
+
+		weight
+	}
+
+	/// Predict the prices of on-demand orders.
+	///
+	/// Parameters:
+	/// - `order_count`: The number of orders placed back to back in the current block.
+	/// - `blocks`: The number of following blocks to predict the spot price for, at most
+	///   `MAX_PREDICTED_BLOCKS`.
+	/// - `queue_size`: The queue size to assume instead of the current one.
+	///
+	/// Returns `None` if the spot traffic can't be calculated, e.g. because on-demand coretime
+	/// is not available.
+	pub fn price_prediction(
+		order_count: u32,
+		blocks: u32,
+		queue_size: Option<u32>,
+	) -> Option<OnDemandPricePrediction> {
+		let config = configuration::ActiveConfig::<T>::get();
+		let params = &config.scheduler_params;
+		let queue_status = QueueStatus::<T>::get();
+		let queue_size = queue_size
+			.unwrap_or_else(|| queue_status.size())
+			.min(params.on_demand_queue_max_size);
+
+		// Same as `update_spot_traffic`, which is called on every block and before every order.
+		let next_traffic = |traffic, queue_size| {
+			Self::calculate_spot_traffic(
+				traffic,
+				params.on_demand_queue_max_size,
+				queue_size,
+				params.on_demand_target_queue_utilization,
+				params.on_demand_fee_variability,
+			)
+			.ok()
+		};
+		let spot_price = |traffic: FixedU128| traffic.saturating_mul_int(params.on_demand_base_fee);
+
+		let spot_price_now = spot_price(next_traffic(queue_status.traffic, queue_size)?);
+
+		let mut batch_prices = Vec::new();
+		let mut traffic = queue_status.traffic;
+		for size in queue_size..queue_size.saturating_add(order_count) {
+			if size >= params.on_demand_queue_max_size {
+				break
+			}
+			traffic = next_traffic(traffic, size)?;
+			batch_prices.push(spot_price(traffic));
+		}
+
+		let mut future_spot_prices = Vec::new();
+		let mut traffic = queue_status.traffic;
+		for _ in 0..blocks.min(MAX_PREDICTED_BLOCKS) {
+			traffic = next_traffic(traffic, queue_size)?;
+			future_spot_prices.push(spot_price(next_traffic(traffic, queue_size)?));
+		}
+
+		Some(OnDemandPricePrediction {
+			spot_price: spot_price_now,
+			batch_prices,
+			future_spot_prices,
 		})
 	}
 
@@ -19,8 +19,8 @@ use super::*;
 use crate::{
 	initializer::SessionChangeNotification,
 	mock::{
-		new_test_ext, Balances, OnDemand, Paras, ParasShared, RuntimeOrigin, Scheduler, System,
-		Test,
+		new_test_ext, Balances, OnDemand, Paras, ParasShared, RuntimeCall, RuntimeEvent,
+		RuntimeOrigin, Scheduler, System, Test,
 	},
 	on_demand::{
 		self,
@@ -31,12 +31,15 @@ use crate::{
 	paras::{ParaGenesisArgs, ParaKind},
 };
 use core::cmp::{Ord, Ordering};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
 use pallet_balances::Error as BalancesError;
 use polkadot_primitives::{
 	BlockNumber, SessionIndex, ValidationCode, ON_DEMAND_MAX_QUEUE_MAX_SIZE,
 };
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{
+	traits::{BadOrigin, Dispatchable},
+	TokenError,
+};
 
 fn schedule_blank_para(id: ParaId, parakind: ParaKind) {
 	let validation_code: ValidationCode = vec![1, 2, 3].into();
@@ -400,6 +403,355 @@ fn place_order_with_credits() {
 	});
 }
 
+#[test]
+fn place_batch_order_with_credits() {
+	let alice = 1u64;
+	let initial_credit = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	let genesis = GenesisConfigBuilder { on_demand_max_queue_size: 10, ..Default::default() };
+	new_test_ext(genesis.build()).execute_with(|| {
+		let deposit = <Test as on_demand::Config>::StandingOrderDeposit::get();
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		OnDemand::credit_account(alice, initial_credit);
+		Balances::make_free_balance_be(&alice, 1_000);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+
+		let batch = |max_total_amount, count| {
+			RuntimeCall::OnDemand(on_demand::Call::place_batch_order_with_credits {
+				max_total_amount,
+				para_id,
+				count,
+			})
+			.dispatch(RuntimeOrigin::signed(alice))
+			.map_err(|err| err.error)
+		};
+		assert_eq!(batch(initial_credit, 0), Err(Error::<Test>::EmptyBatch.into()));
+		assert_eq!(
+			batch(initial_credit, <Test as on_demand::Config>::MaxStandingOrderLifetime::get() + 1),
+			Err(Error::<Test>::LifetimeTooLong.into())
+		);
+		assert_eq!(batch(1, 3), Err(Error::<Test>::SpotPriceHigherThanMaxAmount.into()));
+		assert_eq!(Credits::<Test>::get(alice), initial_credit);
+		assert_eq!(QueueStatus::<Test>::get().size(), 0);
+
+		// Only the first order is placed right away, the others wait for the following blocks.
+		assert_ok!(batch(initial_credit, 3));
+		let credits = Credits::<Test>::get(alice);
+		let spot_price = initial_credit - credits;
+		System::assert_last_event(RuntimeEvent::OnDemand(Event::StandingOrderPlaced {
+			para_id,
+			max_amount: initial_credit - spot_price,
+			count: 2,
+			expires_at: 102,
+			ordered_by: alice,
+		}));
+		assert_eq!(QueueStatus::<Test>::get().size(), 1);
+		assert_eq!(Balances::total_balance_on_hold(&alice), deposit);
+
+		// One order per block.
+		run_to_block(101, |_| None);
+		assert_eq!(QueueStatus::<Test>::get().size(), 2);
+		assert!(Credits::<Test>::get(alice) < credits);
+		let orders = StandingOrders::<Test>::get();
+		assert_eq!(orders.len(), 1);
+		assert_eq!(orders[0].remaining, 1);
+		assert_eq!(orders[0].max_amount, Credits::<Test>::get(alice));
+
+		run_to_block(102, |_| None);
+		assert_eq!(QueueStatus::<Test>::get().size(), 3);
+		assert!(StandingOrders::<Test>::get().is_empty());
+		assert_eq!(Balances::total_balance_on_hold(&alice), 0);
+
+		run_to_block(103, |_| None);
+		assert_eq!(QueueStatus::<Test>::get().size(), 3);
+	});
+}
+
+#[test]
+fn price_prediction_works() {
+	let genesis = GenesisConfigBuilder { on_demand_max_queue_size: 10, ..Default::default() };
+	new_test_ext(genesis.build()).execute_with(|| {
+		let config = configuration::ActiveConfig::<Test>::get();
+		let base_fee = config.scheduler_params.on_demand_base_fee;
+		run_to_block(10, |_| None);
+
+		// Batches are limited by the queue size.
+		let prediction = OnDemand::price_prediction(20, 0, Some(7)).unwrap();
+		assert_eq!(prediction.batch_prices.len(), 3);
+		assert!(prediction.future_spot_prices.is_empty());
+
+		// A full queue keeps driving the prices up, an empty one brings them down to the base fee.
+		QueueStatus::<Test>::mutate(|status| status.traffic = FixedU128::from_u32(5));
+		let full = OnDemand::price_prediction(0, 5, Some(10)).unwrap().future_spot_prices;
+		assert_eq!(full.len(), 5);
+		assert!(full.windows(2).all(|prices| prices[0] < prices[1]));
+		let empty = OnDemand::price_prediction(0, 5, Some(0)).unwrap().future_spot_prices;
+		assert!(empty[0] < 5 * base_fee);
+		assert_eq!(empty[4], base_fee);
+
+		// The number of predicted blocks is bounded.
+		let prediction = OnDemand::price_prediction(0, u32::MAX, None).unwrap();
+		assert_eq!(prediction.future_spot_prices.len(), MAX_PREDICTED_BLOCKS as usize);
+
+		// No on-demand coretime, no prices.
+		configuration::ActiveConfig::<Test>::mutate(|config| {
+			config.scheduler_params.on_demand_queue_max_size = 0
+		});
+		assert_eq!(OnDemand::price_prediction(1, 1, None), None);
+	});
+}
+
+#[test]
+fn standing_order_is_placed_once_price_drops() {
+	let alice = 1u64;
+	let initial_credit = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let config = configuration::ActiveConfig::<Test>::get();
+		let base_fee = config.scheduler_params.on_demand_base_fee;
+		let deposit = <Test as on_demand::Config>::StandingOrderDeposit::get();
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		OnDemand::credit_account(alice, initial_credit);
+		Balances::make_free_balance_be(&alice, 1_000);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+
+		// An order which can be placed right away isn't kept.
+		assert_ok!(OnDemand::place_standing_order_with_credits(
+			RuntimeOrigin::signed(alice),
+			initial_credit,
+			para_id,
+			10,
+		));
+		assert!(StandingOrders::<Test>::get().is_empty());
+		assert_eq!(Balances::total_balance_on_hold(&alice), 0);
+		assert_eq!(QueueStatus::<Test>::get().size(), 1);
+
+		assert_noop!(
+			OnDemand::place_standing_order_with_credits(
+				RuntimeOrigin::signed(alice),
+				base_fee,
+				para_id,
+				0,
+			),
+			Error::<Test>::ZeroLifetime
+		);
+		assert_noop!(
+			OnDemand::place_standing_order_with_credits(
+				RuntimeOrigin::signed(alice),
+				base_fee,
+				para_id,
+				<Test as on_demand::Config>::MaxStandingOrderLifetime::get() + 1,
+			),
+			Error::<Test>::LifetimeTooLong
+		);
+
+		// Too expensive for now.
+		QueueStatus::<Test>::mutate(|status| status.traffic = FixedU128::from_u32(100));
+		let credits = Credits::<Test>::get(alice);
+		assert_ok!(OnDemand::place_standing_order_with_credits(
+			RuntimeOrigin::signed(alice),
+			2 * base_fee,
+			para_id,
+			10,
+		));
+		System::assert_last_event(RuntimeEvent::OnDemand(Event::StandingOrderPlaced {
+			para_id,
+			max_amount: 2 * base_fee,
+			count: 1,
+			expires_at: 110,
+			ordered_by: alice,
+		}));
+		assert_eq!(StandingOrders::<Test>::get().len(), 1);
+		assert_eq!(Balances::total_balance_on_hold(&alice), deposit);
+
+		// Not placing the order in the meantime leaves the traffic untouched.
+		run_to_block(101, |_| None);
+		let traffic = QueueStatus::<Test>::get().traffic;
+		assert!(traffic > FixedU128::from_u32(2));
+		assert_eq!(StandingOrders::<Test>::get().len(), 1);
+		assert_eq!(Credits::<Test>::get(alice), credits);
+		assert_eq!(QueueStatus::<Test>::get().size(), 1);
+
+		run_to_block(105, |_| None);
+		assert!(StandingOrders::<Test>::get().is_empty());
+		assert_eq!(Balances::total_balance_on_hold(&alice), 0);
+		assert!(Credits::<Test>::get(alice) >= credits - 2 * base_fee);
+		assert!(Credits::<Test>::get(alice) < credits);
+		assert_eq!(QueueStatus::<Test>::get().size(), 2);
+	});
+}
+
+#[test]
+fn standing_order_expires() {
+	let alice = 1u64;
+	let initial_credit = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		OnDemand::credit_account(alice, initial_credit);
+		Balances::make_free_balance_be(&alice, 1_000);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+
+		// The spot price never drops below the base fee.
+		assert_ok!(OnDemand::place_standing_order_with_credits(
+			RuntimeOrigin::signed(alice),
+			1,
+			para_id,
+			3,
+		));
+
+		run_to_block(103, |_| None);
+		assert_eq!(StandingOrders::<Test>::get().len(), 1);
+
+		run_to_block(104, |_| None);
+		assert!(StandingOrders::<Test>::get().is_empty());
+		System::assert_has_event(RuntimeEvent::OnDemand(Event::StandingOrderExpired {
+			para_id,
+			ordered_by: alice,
+		}));
+		assert_eq!(Balances::total_balance_on_hold(&alice), 0);
+		assert_eq!(Credits::<Test>::get(alice), initial_credit);
+		assert_eq!(QueueStatus::<Test>::get().size(), 0);
+	});
+}
+
+#[test]
+fn standing_order_is_dropped_if_it_cant_be_paid_for() {
+	let alice = 1u64;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let config = configuration::ActiveConfig::<Test>::get();
+		let base_fee = config.scheduler_params.on_demand_base_fee;
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		Balances::make_free_balance_be(&alice, 1_000);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+
+		// Too expensive for now, so the lack of credits doesn't matter yet.
+		QueueStatus::<Test>::mutate(|status| status.traffic = FixedU128::from_u32(100));
+		assert_ok!(OnDemand::place_standing_order_with_credits(
+			RuntimeOrigin::signed(alice),
+			2 * base_fee,
+			para_id,
+			10,
+		));
+		assert_eq!(StandingOrders::<Test>::get().len(), 1);
+
+		// Once the price drops the order can't be paid for, which won't change by waiting.
+		run_to_block(105, |_| None);
+		assert!(StandingOrders::<Test>::get().is_empty());
+		System::assert_has_event(RuntimeEvent::OnDemand(Event::StandingOrderDropped {
+			para_id,
+			ordered_by: alice,
+		}));
+		assert_eq!(Balances::total_balance_on_hold(&alice), 0);
+		assert_eq!(QueueStatus::<Test>::get().size(), 0);
+	});
+}
+
+#[test]
+fn standing_orders_are_bounded_and_can_be_cancelled() {
+	let para_a = ParaId::from(111);
+	let para_b = ParaId::from(222);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let deposit = <Test as on_demand::Config>::StandingOrderDeposit::get();
+		let max_orders = <Test as on_demand::Config>::MaxStandingOrders::get() as u64;
+		let max_per_account =
+			<Test as on_demand::Config>::MaxStandingOrdersPerAccount::get() as u64;
+		run_to_block(10, |_| None);
+
+		let place = |who: u64, para_id| {
+			OnDemand::place_standing_order_with_credits(RuntimeOrigin::signed(who), 1, para_id, 100)
+		};
+
+		// The deposit must be paid.
+		assert_noop!(place(1, para_a), TokenError::FundsUnavailable);
+
+		for who in 1..=max_orders {
+			Balances::make_free_balance_be(&who, 1_000);
+		}
+		for _ in 0..max_per_account {
+			assert_ok!(place(1, para_a));
+		}
+		assert_noop!(place(1, para_b), Error::<Test>::TooManyStandingOrdersForAccount);
+		assert_eq!(Balances::total_balance_on_hold(&1), deposit * max_per_account as u128);
+
+		for who in 2..=(max_orders - max_per_account + 1) {
+			assert_ok!(place(who, para_a));
+		}
+		assert_eq!(StandingOrders::<Test>::get().len() as u64, max_orders);
+		assert_noop!(place(max_orders, para_a), Error::<Test>::TooManyStandingOrders);
+
+		assert_noop!(
+			OnDemand::cancel_standing_order(RuntimeOrigin::signed(max_orders), para_a),
+			Error::<Test>::NoStandingOrder
+		);
+		assert_noop!(
+			OnDemand::cancel_standing_order(RuntimeOrigin::signed(1), para_b),
+			Error::<Test>::NoStandingOrder
+		);
+		assert_ok!(OnDemand::cancel_standing_order(RuntimeOrigin::signed(1), para_a));
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
+		assert_eq!(StandingOrders::<Test>::get().len() as u64, max_orders - max_per_account);
+		assert!(StandingOrders::<Test>::get().iter().all(|order| order.ordered_by != 1));
+	});
+}
+
+#[test]
+fn standing_orders_are_processed_within_budget() {
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let config = configuration::ActiveConfig::<Test>::get();
+		let base_fee = config.scheduler_params.on_demand_base_fee;
+		assert_eq!(<Test as on_demand::Config>::MaxStandingOrdersPerBlock::get(), 2);
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		for who in 1..=4u64 {
+			OnDemand::credit_account(who, 10_000_000);
+			Balances::make_free_balance_be(&who, 1_000);
+		}
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+
+		// The first order is priced too low to ever be placed, the others are just too
+		// expensive for now.
+		QueueStatus::<Test>::mutate(|status| status.traffic = FixedU128::from_u32(100));
+		assert_ok!(OnDemand::place_standing_order_with_credits(
+			RuntimeOrigin::signed(1),
+			1,
+			para_id,
+			10,
+		));
+		for who in 2..=4u64 {
+			assert_ok!(OnDemand::place_standing_order_with_credits(
+				RuntimeOrigin::signed(who),
+				2 * base_fee,
+				para_id,
+				10,
+			));
+		}
+		QueueStatus::<Test>::mutate(|status| status.traffic = FixedU128::from_u32(1));
+
+		// Orders priced too low don't use up the budget, so two orders are placed.
+		run_to_block(101, |_| None);
+		assert_eq!(QueueStatus::<Test>::get().size(), 2);
+		let waiting = || {
+			StandingOrders::<Test>::get()
+				.iter()
+				.map(|order| order.ordered_by)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(waiting(), vec![1, 4]);
+
+		run_to_block(102, |_| None);
+		assert_eq!(QueueStatus::<Test>::get().size(), 3);
+		assert_eq!(waiting(), vec![1]);
+	});
+}
+
 #[test]
 fn pop_assignment_for_core_works() {
 	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
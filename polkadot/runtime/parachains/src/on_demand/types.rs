@@ -23,6 +23,7 @@ use frame_support::{
 	pallet_prelude::{Decode, Encode, RuntimeDebug, TypeInfo},
 	traits::Currency,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use polkadot_primitives::{CoreIndex, Id as ParaId, ON_DEMAND_MAX_QUEUE_MAX_SIZE};
 use sp_runtime::FixedU128;
 
@@ -30,6 +31,10 @@ use sp_runtime::FixedU128;
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Shorthand for the standing order type the runtime is using.
+pub type StandingOrderOf<T> =
+	StandingOrder<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
 /// Meta data for full queue.
 ///
 /// This includes elements with affinity and free entries.
@@ -210,6 +215,24 @@ impl Ord for EnqueuedOrder {
 	}
 }
 
+/// Orders waiting to be placed, at most one per block, while the spot price does not exceed
+/// their price ceiling.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Clone, Eq)]
+pub struct StandingOrder<AccountId, Balance, BlockNumber> {
+	/// The account paying for the orders with its credits.
+	pub ordered_by: AccountId,
+	/// The para the orders provide blockspace for.
+	pub para_id: ParaId,
+	/// The maximum amount to spend on the remaining orders together.
+	pub max_amount: Balance,
+	/// The number of orders still to be placed.
+	pub remaining: u32,
+	/// The last block in which an order can still be placed.
+	pub expires_at: BlockNumber,
+	/// The deposit held from `ordered_by` while the orders are waiting.
+	pub deposit: Balance,
+}
+
 /// Keeps track of how many assignments a scheduler currently has at a specific `CoreIndex` for a
 /// specific `ParaId`.
 #[derive(Encode, Decode, Default, Clone, Copy, TypeInfo)]
@@ -178,4 +178,52 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3260), added: 5735, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_managed_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104 + s * (3 ±0)`
+		//  Estimated: `6725`
+		// Minimum execution time: 16_075_000 picoseconds.
+		Weight::from_parts(17_600_617, 0)
+			.saturating_add(Weight::from_parts(0, 6725))
+			// Standard Error: 2_422
+			.saturating_add(Weight::from_parts(156_842, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3260), added: 5735, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_managed_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3694`
+		//  Estimated: `6725`
+		// Minimum execution time: 17_852_000 picoseconds.
+		Weight::from_parts(36_306_900, 0)
+			.saturating_add(Weight::from_parts(0, 6725))
+			// Standard Error: 17_374
+			.saturating_add(Weight::from_parts(85_419, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3260), added: 5735, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn dissolve_managed_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3678`
+		//  Estimated: `6725`
+		// Minimum execution time: 30_772_000 picoseconds.
+		Weight::from_parts(35_868_000, 0)
+			.saturating_add(Weight::from_parts(0, 6725))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -57,15 +57,16 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `Treasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::Proposals` (r:0 w:1)
 	/// Proof: `Treasury::Proposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `Treasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::LocalBudgets` (r:1 w:1)
+	/// Proof: `Treasury::LocalBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
 	fn spend_local() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `142`
-		//  Estimated: `1887`
-		// Minimum execution time: 12_748_000 picoseconds.
-		Weight::from_parts(13_519_000, 0)
-			.saturating_add(Weight::from_parts(0, 1887))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(19_519_000, 0)
+			.saturating_add(Weight::from_parts(0, 4948))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Treasury::Approvals` (r:1 w:1)
 	/// Proof: `Treasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
@@ -102,15 +103,18 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::Spends` (r:0 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `Treasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Budgets` (r:1 w:1)
+	/// Proof: `Treasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendBudgets` (r:0 w:1)
+	/// Proof: `Treasury::SpendBudgets` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
 	fn spend() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `214`
-		//  Estimated: `4703`
-		// Minimum execution time: 20_171_000 picoseconds.
-		Weight::from_parts(21_114_000, 0)
-			.saturating_add(Weight::from_parts(0, 4703))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(28_114_000, 0)
+			.saturating_add(Weight::from_parts(0, 9593))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
@@ -142,25 +146,77 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
 	/// Storage: `XcmPallet::Queries` (r:1 w:1)
 	/// Proof: `XcmPallet::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Treasury::SpendBudgets` (r:1 w:1)
+	/// Proof: `Treasury::SpendBudgets` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `Treasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Budgets` (r:1 w:1)
+	/// Proof: `Treasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
 	fn check_status() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `305`
-		//  Estimated: `5318`
-		// Minimum execution time: 25_238_000 picoseconds.
-		Weight::from_parts(25_654_000, 0)
-			.saturating_add(Weight::from_parts(0, 5318))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(33_654_000, 0)
+			.saturating_add(Weight::from_parts(0, 12701))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendBudgets` (r:1 w:1)
+	/// Proof: `Treasury::SpendBudgets` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `Treasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Budgets` (r:1 w:1)
+	/// Proof: `Treasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(23_441_000, 0)
+			.saturating_add(Weight::from_parts(0, 12701))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Treasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `Treasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Budgets` (r:1 w:1)
+	/// Proof: `Treasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
+	fn set_budget() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
-		//  Estimated: `5318`
-		// Minimum execution time: 14_929_000 picoseconds.
-		Weight::from_parts(15_441_000, 0)
-			.saturating_add(Weight::from_parts(0, 5318))
+		//  Measured:  `6`
+		//  Estimated: `5381`
+		// Minimum execution time: 14_912_000 picoseconds.
+		Weight::from_parts(15_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 5381))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Treasury::LastSpendPeriod` (r:1 w:0)
+	/// Proof: `Treasury::LastSpendPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::LocalBudgets` (r:1 w:1)
+	/// Proof: `Treasury::LocalBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	fn set_local_budget() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(15_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 3552))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Treasury::Budgets` (r:1 w:1)
+	/// Proof: `Treasury::Budgets` (`max_values`: None, `max_size`: Some(1916), added: 4391, mode: `MaxEncodedLen`)
+	fn remove_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `5381`
+		// Minimum execution time: 13_476_000 picoseconds.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 5381))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Treasury::LocalBudgets` (r:1 w:1)
+	/// Proof: `Treasury::LocalBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	fn remove_local_budget() -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 3552))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
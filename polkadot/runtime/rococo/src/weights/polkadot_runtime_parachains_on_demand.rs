@@ -123,4 +123,48 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::on_demand::WeightInfo
 			.saturating_add(T::DbWeight::get().writes(4))
 			.saturating_add(Weight::from_parts(0, 8).saturating_mul(s.into()))
 	}
+	/// Storage: `OnDemandAssignmentProvider::StandingOrders` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::StandingOrders` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 9999]`.
+	/// The range of component `o` is `[0, 99]`.
+	fn place_batch_order_with_credits(s: u32, o: u32, ) -> Weight {
+		// Not benchmarked: the first order is placed right away and the others are kept waiting,
+		// which is the worst case `place_standing_order_with_credits` is estimated for.
+		Self::place_standing_order_with_credits(s, o)
+	}
+	/// Storage: `OnDemandAssignmentProvider::StandingOrders` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::StandingOrders` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 9999]`.
+	/// The range of component `o` is `[0, 99]`.
+	fn place_standing_order_with_credits(s: u32, o: u32, ) -> Weight {
+		// Not benchmarked: estimated from the attempt to place the order right away, which is
+		// bounded by `place_order_with_credits`, and the storage accessed to keep it waiting.
+		Self::place_order_with_credits(s)
+			.saturating_add(Weight::from_parts(35_000_000, 6670))
+			.saturating_add(Weight::from_parts(150_000, 80).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `OnDemandAssignmentProvider::StandingOrders` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::StandingOrders` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `o` is `[1, 100]`.
+	fn cancel_standing_order(o: u32, ) -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(35_000_000, 6670)
+			.saturating_add(Weight::from_parts(150_000, 80).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -524,8 +524,10 @@ impl claims::Config for Runtime {
 
 parameter_types! {
 	pub storage MinVestedTransfer: Balance = 100 * DOLLARS;
+	pub storage ReleaseScheduleDeposit: Balance = DOLLARS;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const VestingPalletId: PalletId = PalletId(*b"py/vestg");
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -537,6 +539,13 @@ impl pallet_vesting::Config for Runtime {
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
+	type Assets = ();
+	type RevokeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = ConstU32<16>;
+	type ReleaseScheduleDeposit = ReleaseScheduleDeposit;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -634,6 +643,11 @@ parameter_types! {
 	// Keep 2 timeslices worth of revenue information.
 	pub const MaxHistoricalRevenue: BlockNumber = 2 * 5;
 	pub const OnDemandPalletId: PalletId = PalletId(*b"py/ondmd");
+	pub const MaxStandingOrders: u32 = 10;
+	pub const MaxStandingOrdersPerBlock: u32 = 3;
+	pub const MaxStandingOrdersPerAccount: u32 = 3;
+	pub const MaxStandingOrderLifetime: BlockNumber = HOURS;
+	pub const StandingOrderDeposit: Balance = DOLLARS;
 }
 
 impl parachains_dmp::Config for Runtime {}
@@ -658,10 +672,17 @@ impl parachains_hrmp::Config for Runtime {
 impl parachains_on_demand::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
 	type WeightInfo = parachains_on_demand::TestWeightInfo;
 	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type PalletId = OnDemandPalletId;
+	type MaxStandingOrders = MaxStandingOrders;
+	type MaxStandingOrdersPerBlock = MaxStandingOrdersPerBlock;
+	type MaxStandingOrdersPerAccount = MaxStandingOrdersPerAccount;
+	type MaxStandingOrderLifetime = MaxStandingOrderLifetime;
+	type StandingOrderDeposit = StandingOrderDeposit;
 }
 
 impl parachains_assigner_coretime::Config for Runtime {}
@@ -150,8 +150,8 @@ use impls::ToParachainIdentityReaper;
 // Governance and configurations.
 pub mod governance;
 use governance::{
-	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin, StakingAdmin,
-	Treasurer, TreasurySpender,
+	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin, SpenderTrack,
+	StakingAdmin, Treasurer, TreasurySpender,
 };
 
 #[cfg(test)]
@@ -250,6 +250,8 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = System;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ExpectedBlockTime;
 }
 
 parameter_types! {
@@ -966,6 +968,10 @@ impl pallet_treasury::Config for Runtime {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
+	// The referenda track of the spend origin.
+	type BudgetHolder = u16;
+	type BudgetOrigin = SpenderTrack;
+	type ManageBudgetOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_offences::Config for Runtime {
@@ -1176,6 +1182,7 @@ parameter_types! {
 	pub const FriendDepositFactor: Balance = 50 * CENTS;
 	pub const MaxFriends: u16 = 9;
 	pub const RecoveryDeposit: Balance = 500 * CENTS;
+	pub const MinInactivityPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_recovery::Config for Runtime {
@@ -1188,12 +1195,18 @@ impl pallet_recovery::Config for Runtime {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
+	type Delegated =
+		(pallet_proxy::HasProxies<Runtime>, pallet_multisig::HasMultisigOperations<Runtime>);
 }
 
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 100 * CENTS;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const VestingPalletId: PalletId = PalletId(*b"py/vestg");
+	// One storage item; key size 60, value size 436.
+	pub const ReleaseScheduleDeposit: Balance = deposit(1, 496);
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -1205,6 +1218,13 @@ impl pallet_vesting::Config for Runtime {
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
+	type Assets = ();
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = ConstU32<16>;
+	type ReleaseScheduleDeposit = ReleaseScheduleDeposit;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1221,6 +1241,10 @@ parameter_types! {
 	pub const MaxProxies: u16 = 32;
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -1382,6 +1406,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type BudgetAssets = ();
+	type MaxBudgetAssets = ConstU32<0>;
+	type BudgetCallFilter = frame_support::traits::Everything;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1544,15 +1575,27 @@ parameter_types! {
 	// Keep 2 timeslices worth of revenue information.
 	pub const MaxHistoricalRevenue: BlockNumber = 2 * TIMESLICE_PERIOD;
 	pub const OnDemandPalletId: PalletId = PalletId(*b"py/ondmd");
+	pub const MaxStandingOrders: u32 = 100;
+	pub const MaxStandingOrdersPerBlock: u32 = 10;
+	pub const MaxStandingOrdersPerAccount: u32 = 10;
+	pub const MaxStandingOrderLifetime: BlockNumber = HOURS;
+	pub const StandingOrderDeposit: Balance = deposit(1, 72);
 }
 
 impl parachains_on_demand::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
 	type WeightInfo = weights::polkadot_runtime_parachains_on_demand::WeightInfo<Runtime>;
 	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type PalletId = OnDemandPalletId;
+	type MaxStandingOrders = MaxStandingOrders;
+	type MaxStandingOrdersPerBlock = MaxStandingOrdersPerBlock;
+	type MaxStandingOrdersPerAccount = MaxStandingOrdersPerAccount;
+	type MaxStandingOrderLifetime = MaxStandingOrderLifetime;
+	type StandingOrderDeposit = StandingOrderDeposit;
 }
 
 impl parachains_assigner_coretime::Config for Runtime {}
@@ -2237,7 +2280,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(14)]
+	#[api_version(15)]
 	impl polkadot_primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -2419,9 +2462,17 @@ sp_api::impl_runtime_apis! {
 		fn para_ids() -> Vec<ParaId> {
 			parachains_staging_runtime_api_impl::para_ids::<Runtime>()
 		}
+
+		fn on_demand_price_prediction(
+			order_count: u32,
+			blocks: u32,
+			queue_size: Option<u32>,
+		) -> Option<polkadot_primitives::vstaging::OnDemandPricePrediction> {
+			parachains_on_demand::Pallet::<Runtime>::price_prediction(order_count, blocks, queue_size)
+		}
 	}
 
-	#[api_version(5)]
+	#[api_version(6)]
 	impl sp_consensus_beefy::BeefyApi<Block, BeefyId> for Runtime {
 		fn beefy_genesis() -> Option<BlockNumber> {
 			pallet_beefy::GenesisBlock::<Runtime>::get()
@@ -2431,6 +2482,10 @@ sp_api::impl_runtime_apis! {
 			Beefy::validator_set()
 		}
 
+		fn proofs_of_possession(authorities: Vec<BeefyId>) -> Option<Vec<Vec<u8>>> {
+			Beefy::proofs_of_possession(authorities)
+		}
+
 		fn submit_report_double_voting_unsigned_extrinsic(
 			equivocation_proof: sp_consensus_beefy::DoubleVotingProof<
 				BlockNumber,
@@ -2660,6 +2715,28 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		pallet_referenda::TrackIdOf<Runtime, ()>,
+		pallet_referenda::TallyOf<Runtime, ()>,
+		pallet_referenda::BlockNumberFor<Runtime, ()>,
+	> for Runtime {
+		fn referendum_timeline(
+			index: pallet_referenda::ReferendumIndex,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::referendum_timeline(index, step)
+		}
+
+		fn simulate_timeline(
+			track: pallet_referenda::TrackIdOf<Runtime, ()>,
+			tally: pallet_referenda::TallyOf<Runtime, ()>,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::simulate_timeline(track, tally, step)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
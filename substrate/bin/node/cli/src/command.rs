@@ -234,5 +234,42 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run::<Block>(&config))
 		},
+		Some(Subcommand::MigrateStatePruning(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run::<Block>(&config))
+		},
+		Some(Subcommand::ExportSnapshot(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run::<Block>(&config))
+		},
+		Some(Subcommand::ExportFinalityProof(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, backend, .. } =
+					new_partial(&config, None)?;
+				let prove = Box::new(|client: Arc<FullClient>, backend, set_id, block| {
+					use sp_api::ProvideRuntimeApi;
+					use sp_consensus_grandpa::GrandpaApi;
+
+					let genesis_authorities = || {
+						let genesis_hash = client.chain_info().genesis_hash;
+						Ok(client.runtime_api().grandpa_authorities(genesis_hash)?)
+					};
+					let bundle = sc_consensus_grandpa::prove_finality_bundle::<Block, _>(
+						&*backend,
+						set_id,
+						genesis_authorities,
+						block,
+					)
+					.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+					Ok(codec::Encode::encode(&bundle))
+				});
+				Ok((cmd.run(client, backend, prove), task_manager))
+			})
+		},
+		Some(Subcommand::ImportSnapshot(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run::<Block>(&config))
+		},
 	}
 }
@@ -295,11 +295,19 @@ pub fn new_partial(
 
 	let import_setup = (block_import, grandpa_link, babe_link, beefy_voter_links);
 
+	// The statement store needs the secret keys to decrypt statements, which remote signers don't
+	// reveal.
+	let local_keystore = if config.role.is_authority() {
+		keystore_container.require_local_keystore()?
+	} else {
+		keystore_container.local_keystore()
+	};
+
 	let statement_store = sc_statement_store::Store::new_shared(
 		&config.data_path,
 		Default::default(),
 		client.clone(),
-		keystore_container.local_keystore(),
+		local_keystore,
 		config.prometheus_registry(),
 		&task_manager.spawn_handle(),
 	)
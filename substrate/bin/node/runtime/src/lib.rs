@@ -407,6 +407,10 @@ parameter_types! {
 	pub const ProxyDepositFactor: Balance = deposit(0, 33);
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -473,6 +477,19 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// The calls budgeted proxies may dispatch: anything but approving transfers of assets, which
+/// would let the approved account spend them without going through the proxy.
+pub struct BudgetedProxyCalls;
+impl Contains<RuntimeCall> for BudgetedProxyCalls {
+	fn contains(c: &RuntimeCall) -> bool {
+		!matches!(
+			c,
+			RuntimeCall::Assets(pallet_assets::Call::approve_transfer { .. }) |
+				RuntimeCall::PoolAssets(pallet_assets::Call::approve_transfer { .. })
+		)
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -487,6 +504,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type BudgetAssets = pallet_proxy::FungiblesBudget<Assets>;
+	type MaxBudgetAssets = ConstU32<8>;
+	type BudgetCallFilter = BudgetedProxyCalls;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_proxy::AssetKindFromSeed;
 }
 
 parameter_types! {
@@ -509,6 +533,8 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ExpectedBlockTime;
 }
 
 impl pallet_glutton::Config for Runtime {
@@ -1016,6 +1042,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegates = ConstU32<16>;
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
 	type BlockNumberProvider = System;
@@ -1331,6 +1358,9 @@ impl pallet_treasury::Config for Runtime {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PalletTreasuryArguments;
+	type BudgetHolder = ();
+	type BudgetOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ManageBudgetOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_asset_rate::Config for Runtime {
@@ -1696,6 +1726,7 @@ parameter_types! {
 	pub const FriendDepositFactor: Balance = 50 * CENTS;
 	pub const MaxFriends: u16 = 9;
 	pub const RecoveryDeposit: Balance = 5 * DOLLARS;
+	pub const MinInactivityPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_recovery::Config for Runtime {
@@ -1708,6 +1739,9 @@ impl pallet_recovery::Config for Runtime {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
+	type Delegated =
+		(pallet_proxy::HasProxies<Runtime>, pallet_multisig::HasMultisigOperations<Runtime>);
 }
 
 parameter_types! {
@@ -1745,6 +1779,11 @@ parameter_types! {
 	pub const MinVestedTransfer: Balance = 100 * DOLLARS;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const VestingPalletId: PalletId = PalletId(*b"py/vestg");
+	// One storage item; key size 60, value size 436.
+	pub const ReleaseScheduleDeposit: Balance = deposit(1, 496);
+	// Schedules of assets must hold at least this many times the minimum balance of the asset.
+	pub const MinVestedAssetTransferFactor: u32 = 100;
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -1758,6 +1797,13 @@ impl pallet_vesting::Config for Runtime {
 	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
 	// highest number of schedules that encodes less than 2^10.
 	const MAX_VESTING_SCHEDULES: u32 = 28;
+	type Assets = pallet_vesting::FungiblesVestingAssets<Assets, MinVestedAssetTransferFactor>;
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = ConstU32<16>;
+	type ReleaseScheduleDeposit = ReleaseScheduleDeposit;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 impl pallet_mmr::Config for Runtime {
@@ -2319,6 +2365,8 @@ impl pallet_migrations::Config for Runtime {
 parameter_types! {
 	pub const BrokerPalletId: PalletId = PalletId(*b"py/broke");
 	pub const MinimumCreditPurchase: Balance =  100 * MILLICENTS;
+	pub const BrokerListingDeposit: Balance = deposit(1, 124);
+	pub const BrokerBidDeposit: Balance = deposit(1, 132);
 }
 
 pub struct IntoAuthor;
@@ -2372,6 +2420,11 @@ impl pallet_broker::Config for Runtime {
 	type MaxAutoRenewals = ConstU32<10>;
 	type PriceAdapter = pallet_broker::CenterTargetPrice<Balance>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
+	type ListingDeposit = BrokerListingDeposit;
+	type BidDeposit = BrokerBidDeposit;
+	type MaxListings = ConstU32<1000>;
+	type MaxListingsPerAccount = ConstU32<10>;
+	type MaxBids = ConstU32<20>;
 }
 
 parameter_types! {
@@ -3377,6 +3430,28 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		pallet_referenda::TrackIdOf<Runtime, ()>,
+		pallet_referenda::TallyOf<Runtime, ()>,
+		pallet_referenda::BlockNumberFor<Runtime, ()>,
+	> for Runtime {
+		fn referendum_timeline(
+			index: pallet_referenda::ReferendumIndex,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::referendum_timeline(index, step)
+		}
+
+		fn simulate_timeline(
+			track: pallet_referenda::TrackIdOf<Runtime, ()>,
+			tally: pallet_referenda::TallyOf<Runtime, ()>,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::simulate_timeline(track, tally, step)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -3475,7 +3550,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
-	#[api_version(5)]
+	#[api_version(6)]
 	impl sp_consensus_beefy::BeefyApi<Block, BeefyId> for Runtime {
 		fn beefy_genesis() -> Option<BlockNumber> {
 			pallet_beefy::GenesisBlock::<Runtime>::get()
@@ -3485,6 +3560,10 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 			Beefy::validator_set()
 		}
 
+		fn proofs_of_possession(authorities: Vec<BeefyId>) -> Option<Vec<Vec<u8>>> {
+			Beefy::proofs_of_possession(authorities)
+		}
+
 		fn submit_report_double_voting_unsigned_extrinsic(
 			equivocation_proof: sp_consensus_beefy::DoubleVotingProof<
 				BlockNumber,
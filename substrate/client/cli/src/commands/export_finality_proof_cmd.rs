@@ -0,0 +1,118 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{backend::Backend as BackendT, blockchain::HeaderBackend};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
+use std::{fmt::Debug, fs, io::Write, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `export-finality-proof` command used to export a self-contained proof of the finality of
+/// a block, which can be verified without a node.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportFinalityProofCmd {
+	/// Output file name or stdout if unspecified.
+	#[arg()]
+	pub output: Option<PathBuf>,
+
+	/// Finalized block to prove. Default is the last finalized block.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// Id of the trusted authority set the proof starts at. Default is the current set.
+	#[arg(long, value_name = "SET_ID")]
+	pub from_set_id: Option<u64>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+/// Generator of the encoded finality proof of a block, starting at the given authority set id or
+/// at the current authority set if `None`.
+type FinalityProver<C, BA, B> =
+	Box<dyn FnOnce(Arc<C>, Arc<BA>, Option<u64>, NumberFor<B>) -> error::Result<Vec<u8>>>;
+
+impl ExportFinalityProofCmd {
+	/// Run the `export-finality-proof` command
+	pub async fn run<B, BA, C>(
+		&self,
+		client: Arc<C>,
+		backend: Arc<BA>,
+		prove: FinalityProver<C, BA, B>,
+	) -> error::Result<()>
+	where
+		B: BlockT,
+		BA: BackendT<B>,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let blockchain = backend.blockchain();
+		let number = match &self.at {
+			Some(block) => blockchain.expect_block_number_from_id(&block.parse()?)?,
+			None => blockchain.info().finalized_number,
+		};
+
+		let proof = prove(client, backend.clone(), self.from_set_id, number)?;
+		match &self.output {
+			Some(filename) => fs::write(filename, &proof)?,
+			None => std::io::stdout().write_all(&proof)?,
+		}
+		match self.from_set_id {
+			Some(set_id) => info!(
+				"Exported finality proof of block #{} from authority set {} ({} bytes)",
+				number,
+				set_id,
+				proof.len(),
+			),
+			None => info!(
+				"Exported finality proof of block #{} from the current authority set ({} bytes)",
+				number,
+				proof.len(),
+			),
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportFinalityProofCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
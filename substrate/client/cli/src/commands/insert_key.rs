@@ -26,6 +26,7 @@ use sc_keystore::LocalKeystore;
 use sc_service::config::{BasePath, KeystoreConfig};
 use sp_core::crypto::{KeyTypeId, SecretString};
 use sp_keystore::KeystorePtr;
+use std::sync::Arc;
 
 /// The `insert` command
 #[derive(Debug, Clone, Parser)]
@@ -72,7 +73,13 @@ impl InsertKeyCmd {
 				let keystore: KeystorePtr = LocalKeystore::open(path, password)?.into();
 				(keystore, public)
 			},
-			_ => unreachable!("keystore_config always returns path and password; qed"),
+			#[cfg(target_family = "unix")]
+			KeystoreConfig::Remote { socket } => {
+				let public = with_crypto_scheme!(self.scheme, to_vec(&suri, None))?;
+				let keystore: KeystorePtr = Arc::new(sc_keystore::RemoteKeystore::open(socket)?);
+				(keystore, public)
+			},
+			_ => unreachable!("keystore_config always returns path and password or remote; qed"),
 		};
 
 		let key_type =
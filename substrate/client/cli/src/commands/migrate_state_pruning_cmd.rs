@@ -0,0 +1,88 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error, CliConfiguration, DatabaseParams, PruningParams, Result as CliResult, SharedParams,
+};
+use sp_runtime::traits::Block as BlockT;
+
+/// The `migrate-state-pruning` subcommand used to switch the state pruning mode of an existing
+/// database.
+///
+/// Switching from an archive mode to `NUMBER` prunes the state of the old blocks in the
+/// background of the node, in batches on each finalized block. Switching to an archive mode
+/// keeps the state of the blocks that are still present in the database.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct MigrateStatePruningCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl MigrateStatePruningCmd {
+	/// Run the `migrate-state-pruning` subcommand
+	pub fn run<B>(&self, config: &sc_service::Configuration) -> CliResult<()>
+	where
+		B: BlockT,
+	{
+		if self.pruning_params.state_pruning.is_none() {
+			return Err(error::Error::Input("`--state-pruning` is required".into()))
+		}
+
+		let db_config = sc_client_db::DatabaseSettings {
+			trie_cache_maximum_size: config.trie_cache_maximum_size,
+			state_pruning: config.state_pruning.clone(),
+			source: config.database.clone(),
+			blocks_pruning: config.blocks_pruning,
+			metrics_registry: None,
+		};
+		let backend = sc_service::new_db_backend_with_state_pruning_migration::<B>(db_config)?;
+		match backend.legacy_state_pruning() {
+			Some(legacy_pruning) => log::info!(
+				"State pruning mode switched. The state of blocks #{}..#{} is pruned once the node \
+				 is started",
+				legacy_pruning.next,
+				legacy_pruning.end,
+			),
+			None => log::info!("State pruning mode switched"),
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for MigrateStatePruningCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -35,6 +35,18 @@ pub struct KeystoreParams {
 	#[arg(long, value_name = "PATH")]
 	pub keystore_path: Option<PathBuf>,
 
+	/// Use a remote signer listening at the given unix socket instead of a local keystore.
+	///
+	/// All keys are kept by the signer and no keys are stored in the node's filesystem.
+	/// Remote signers never reveal the secret keys, so they can't be used by authorities which
+	/// need them, e.g. parachain validators.
+	#[arg(
+		long,
+		value_name = "PATH",
+		conflicts_with_all = &["keystore_path", "password_interactive", "password", "password_filename"]
+	)]
+	pub keystore_remote: Option<PathBuf>,
+
 	/// Use interactive shell for entering the password used by the keystore.
 	#[arg(long, conflicts_with_all = &["password", "password_filename"])]
 	pub password_interactive: bool,
@@ -66,6 +78,10 @@ pub fn secret_string_from_str(s: &str) -> std::result::Result<SecretString, Stri
 impl KeystoreParams {
 	/// Get the keystore configuration for the parameters
 	pub fn keystore_config(&self, config_dir: &Path) -> Result<KeystoreConfig> {
+		if let Some(socket) = self.keystore_remote.clone() {
+			return Ok(KeystoreConfig::Remote { socket })
+		}
+
 		let password = if self.password_interactive {
 			Some(SecretString::new(input_keystore_password()?))
 		} else if let Some(ref file) = self.password_filename {
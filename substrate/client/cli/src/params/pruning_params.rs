@@ -29,9 +29,10 @@ pub struct PruningParams {
 	/// should be pruned (ie, removed) from the database.
 	/// This setting can only be set on the first creation of the database. Every subsequent run
 	/// will load the pruning mode from the database and will error if the stored mode doesn't
-	/// match this CLI value. It is fine to drop this CLI flag for subsequent runs. The only
-	/// exception is that `NUMBER` can change between subsequent runs (increasing it will not
-	/// lead to restoring pruned state).
+	/// match this CLI value. The stored mode can be switched with the `migrate-state-pruning`
+	/// subcommand. It is fine to drop this CLI flag for subsequent runs. The only exception is
+	/// that `NUMBER` can change between subsequent runs (increasing it will not lead to restoring
+	/// pruned state).
 	///
 	/// Possible values:
 	///
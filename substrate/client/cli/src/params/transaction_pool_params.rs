@@ -17,7 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use clap::{Args, ValueEnum};
-use sc_transaction_pool::TransactionPoolOptions;
+use sc_transaction_pool::{PoolLimit, ReservedLanes, TagQuotas, TransactionPoolOptions};
+use std::path::PathBuf;
 
 /// Type of transaction pool to be used
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -59,6 +60,82 @@ pub struct TransactionPoolParams {
 	/// The type of transaction pool to be instantiated.
 	#[arg(long, value_enum, default_value_t = TransactionPoolType::ForkAware)]
 	pub pool_type: TransactionPoolType,
+
+	/// Persist the pending transactions of the fork-aware transaction pool in the given file.
+	///
+	/// The transactions are restored from this file and revalidated when the node restarts.
+	#[arg(long, value_name = "PATH")]
+	pub pool_journal: Option<PathBuf>,
+
+	/// Maximum number of transactions of a single sender in the transaction pool.
+	///
+	/// The sender is identified by the nonce tag provided by its transactions, made of the account
+	/// id followed by the nonce, see `--pool-sender-tag-len` and `--pool-nonce-tag-len`.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_limit: Option<usize>,
+
+	/// The length of the account id starting the nonce tag of a transaction.
+	///
+	/// Defaults to the length of a 32 bytes long account id, as used by the `CheckNonce`
+	/// extension of the FRAME based runtimes.
+	#[arg(long, value_name = "BYTES", default_value_t = 32)]
+	pub pool_sender_tag_len: usize,
+
+	/// The length of the nonce ending the nonce tag of a transaction.
+	///
+	/// Defaults to the length of a `u32` nonce.
+	#[arg(long, value_name = "BYTES", default_value_t = 4)]
+	pub pool_nonce_tag_len: usize,
+
+	/// Maximum number of transactions providing a tag with the given prefix.
+	///
+	/// Given as the hex encoded prefix and the count, separated by `=`, e.g. `0x0102=100`.
+	/// Can be specified multiple times.
+	#[arg(long, value_name = "PREFIX=COUNT", value_parser = parse_tag_limit)]
+	pub pool_tag_limit: Vec<(Vec<u8>, usize)>,
+
+	/// Number of transactions in the fork-aware transaction pool reserved for unsigned
+	/// transactions.
+	///
+	/// The unsigned transactions are kept apart and do not compete for space with the other
+	/// ones, which are bounded by `--pool-limit` and `--pool-kbytes`.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_unsigned_limit: Option<usize>,
+
+	/// Number of kilobytes in the fork-aware transaction pool reserved for unsigned transactions.
+	///
+	/// Only used together with `--pool-unsigned-limit`.
+	#[arg(long, value_name = "COUNT", default_value_t = 1024)]
+	pub pool_unsigned_kbytes: usize,
+
+	/// Number of transactions in the fork-aware transaction pool reserved for operational
+	/// transactions.
+	///
+	/// The transactions with at least `--pool-operational-priority` are kept apart and do not
+	/// compete for space with the other ones, which are bounded by `--pool-limit` and
+	/// `--pool-kbytes`.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_operational_limit: Option<usize>,
+
+	/// Number of kilobytes in the fork-aware transaction pool reserved for operational
+	/// transactions.
+	///
+	/// Only used together with `--pool-operational-limit`.
+	#[arg(long, value_name = "COUNT", default_value_t = 1024)]
+	pub pool_operational_kbytes: usize,
+
+	/// The minimal priority of the transactions kept in the operational lane.
+	///
+	/// Only used together with `--pool-operational-limit`.
+	#[arg(long, value_name = "PRIORITY", default_value_t = u64::MAX / 2)]
+	pub pool_operational_priority: u64,
+}
+
+fn parse_tag_limit(s: &str) -> Result<(Vec<u8>, usize), String> {
+	let (prefix, count) = s.split_once('=').ok_or("expected `PREFIX=COUNT`")?;
+	let prefix = array_bytes::hex2bytes(prefix).map_err(|e| format!("invalid prefix: {e:?}"))?;
+	let count = count.parse().map_err(|e| format!("invalid count: {e}"))?;
+	Ok((prefix, count))
 }
 
 impl TransactionPoolParams {
@@ -71,5 +148,34 @@ impl TransactionPoolParams {
 			self.pool_type.into(),
 			is_dev,
 		)
+		.with_mempool_journal(self.pool_journal.clone())
+		.with_quotas(self.quotas())
+		.with_reserved_lanes(self.reserved_lanes())
+	}
+
+	/// Returns the quotas of the transactions given by the cli parameters.
+	fn quotas(&self) -> TagQuotas {
+		let mut quotas = TagQuotas::default()
+			.with_nonce_tag_format(self.pool_sender_tag_len, self.pool_nonce_tag_len);
+		if let Some(limit) = self.pool_sender_limit {
+			quotas = quotas.with_sender_limit(limit);
+		}
+		self.pool_tag_limit.iter().fold(quotas, |quotas, (prefix, limit)| {
+			quotas.with_prefix_limit(prefix.clone(), *limit)
+		})
+	}
+
+	/// Returns the reserved lanes of the mempool given by the cli parameters.
+	fn reserved_lanes(&self) -> ReservedLanes {
+		let mut lanes = ReservedLanes::default();
+		if let Some(count) = self.pool_unsigned_limit {
+			lanes = lanes
+				.with_unsigned(PoolLimit { count, total_bytes: self.pool_unsigned_kbytes * 1024 });
+		}
+		if let Some(count) = self.pool_operational_limit {
+			let limit = PoolLimit { count, total_bytes: self.pool_operational_kbytes * 1024 };
+			lanes = lanes.with_operational(self.pool_operational_priority, limit);
+		}
+		lanes
 	}
 }
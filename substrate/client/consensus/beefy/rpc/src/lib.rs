@@ -21,7 +21,7 @@
 #![warn(missing_docs)]
 
 use parking_lot::RwLock;
-use sp_consensus_beefy::AuthorityIdBound;
+use sp_consensus_beefy::{AuthorityIdBound, VersionedFinalityProof};
 use std::sync::Arc;
 
 use sc_rpc::{
@@ -31,7 +31,7 @@ use sc_rpc::{
 use sp_application_crypto::RuntimeAppPublic;
 use sp_runtime::traits::Block as BlockT;
 
-use futures::{task::SpawnError, FutureExt, StreamExt};
+use futures::{future, task::SpawnError, FutureExt, StreamExt};
 use jsonrpsee::{
 	core::async_trait,
 	proc_macros::rpc,
@@ -40,8 +40,9 @@ use jsonrpsee::{
 };
 use log::warn;
 
-use sc_consensus_beefy::communication::notification::{
-	BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
+use sc_consensus_beefy::{
+	communication::notification::{BeefyBestBlockStream, BeefyVersionedFinalityProofStream},
+	justification::aggregate_finality_proof,
 };
 
 mod notification;
@@ -55,6 +56,9 @@ pub enum Error {
 	/// The BEEFY RPC background task failed to spawn.
 	#[error("BEEFY RPC background task failed to spawn")]
 	RpcTaskFailure(#[from] SpawnError),
+	/// Aggregated justifications are not enabled on this node.
+	#[error("BEEFY aggregated justifications are not enabled")]
+	AggregationDisabled,
 }
 
 /// The error codes returned by jsonrpc.
@@ -63,6 +67,8 @@ pub enum ErrorCode {
 	NotReady = 1,
 	/// Returned on BEEFY RPC background task failure.
 	TaskFailure = 2,
+	/// Returned when aggregated justifications are not enabled.
+	AggregationDisabled = 3,
 }
 
 impl From<Error> for ErrorCode {
@@ -70,6 +76,7 @@ impl From<Error> for ErrorCode {
 		match error {
 			Error::EndpointNotReady => ErrorCode::NotReady,
 			Error::RpcTaskFailure(_) => ErrorCode::TaskFailure,
+			Error::AggregationDisabled => ErrorCode::AggregationDisabled,
 		}
 	}
 }
@@ -93,6 +100,21 @@ pub trait BeefyApi<Notification, Hash> {
 	)]
 	fn subscribe_justifications(&self);
 
+	/// Returns the block most recently finalized by BEEFY, alongside its justification in the
+	/// aggregated signature form.
+	///
+	/// The BEEFY gadget produces aggregated justifications once the proofs of possession of all
+	/// the validator keys are registered on chain. Other justifications are aggregated on demand,
+	/// which must be enabled with [`Beefy::with_aggregated_justifications`]. Justifications that
+	/// cannot be aggregated, because the BEEFY authority crypto does not support signature
+	/// aggregation, are skipped.
+	#[subscription(
+		name = "beefy_subscribeAggregatedJustifications" => "beefy_aggregatedJustifications",
+		unsubscribe = "beefy_unsubscribeAggregatedJustifications",
+		item = Notification,
+	)]
+	fn subscribe_aggregated_justifications(&self);
+
 	/// Returns hash of the latest BEEFY finalized block as seen by this client.
 	///
 	/// The latest BEEFY block might not be available if the BEEFY gadget is not running
@@ -107,6 +129,7 @@ pub struct Beefy<Block: BlockT, AuthorityId: AuthorityIdBound> {
 	finality_proof_stream: BeefyVersionedFinalityProofStream<Block, AuthorityId>,
 	beefy_best_block: Arc<RwLock<Option<Block::Hash>>>,
 	executor: SubscriptionTaskExecutor,
+	aggregated_justifications: bool,
 }
 
 impl<Block, AuthorityId> Beefy<Block, AuthorityId>
@@ -130,7 +153,20 @@ where
 		});
 
 		executor.spawn("substrate-rpc-subscription", Some("rpc"), future.map(drop).boxed());
-		Ok(Self { finality_proof_stream, beefy_best_block, executor })
+		Ok(Self {
+			finality_proof_stream,
+			beefy_best_block,
+			executor,
+			aggregated_justifications: false,
+		})
+	}
+
+	/// Serve `beefy_subscribeAggregatedJustifications`.
+	///
+	/// Each justification is aggregated for every subscriber, so this is disabled by default.
+	pub fn with_aggregated_justifications(mut self) -> Self {
+		self.aggregated_justifications = true;
+		self
 	}
 }
 
@@ -154,6 +190,28 @@ where
 		);
 	}
 
+	fn subscribe_aggregated_justifications(&self, pending: PendingSubscriptionSink) {
+		if !self.aggregated_justifications {
+			sc_rpc::utils::spawn_subscription_task(
+				&self.executor,
+				pending.reject(Error::AggregationDisabled),
+			);
+			return
+		}
+
+		let stream = self.finality_proof_stream.subscribe(100_000).filter_map(|vfp| {
+			let vfp = aggregate_finality_proof::<Block, AuthorityId>(vfp);
+			future::ready(matches!(vfp, VersionedFinalityProof::V2(_)).then(|| {
+				notification::EncodedVersionedFinalityProof::new::<Block, AuthorityId>(vfp)
+			}))
+		});
+
+		sc_rpc::utils::spawn_subscription_task(
+			&self.executor,
+			PendingSubscription::from(pending).pipe_from_stream(stream, BoundedVecDeque::default()),
+		);
+	}
+
 	async fn latest_finalized(&self) -> Result<Block::Hash, Error> {
 		self.beefy_best_block.read().as_ref().cloned().ok_or(Error::EndpointNotReady)
 	}
@@ -169,7 +227,9 @@ mod tests {
 		communication::notification::BeefyVersionedFinalityProofSender,
 		justification::BeefyVersionedFinalityProof,
 	};
-	use sp_consensus_beefy::{ecdsa_crypto, known_payloads, Payload, SignedCommitment};
+	use sp_consensus_beefy::{
+		ecdsa_crypto, known_payloads, AggregatedSignedCommitment, Payload, SignedCommitment,
+	};
 	use sp_runtime::traits::{BlakeTwo256, Hash};
 	use substrate_test_runtime_client::runtime::Block;
 
@@ -192,7 +252,8 @@ mod tests {
 
 		let handler =
 			Beefy::new(finality_proof_stream, best_block_stream, sc_rpc::testing::test_executor())
-				.expect("Setting up the BEEFY RPC handler works");
+				.expect("Setting up the BEEFY RPC handler works")
+				.with_aggregated_justifications();
 
 		(handler.into_rpc(), finality_proof_sender)
 	}
@@ -302,4 +363,63 @@ mod tests {
 		assert_eq!(&recv_sub_id, sub.subscription_id());
 		assert_eq!(recv_finality_proof, finality_proof);
 	}
+
+	#[tokio::test]
+	async fn aggregated_justifications_skip_unaggregatable_proofs() {
+		let (rpc, finality_proof_sender) = setup_io_handler();
+
+		// Subscribe
+		let mut sub = rpc
+			.subscribe_unbounded("beefy_subscribeAggregatedJustifications", EmptyParams::new())
+			.await
+			.unwrap();
+
+		// ECDSA signatures can't be aggregated, so this one is skipped.
+		let finality_proof = create_finality_proof();
+		let r: Result<(), ()> = finality_proof_sender.notify(|| Ok(finality_proof.clone()));
+		r.unwrap();
+
+		// An already aggregated proof is forwarded as is.
+		let aggregated_proof = BeefyVersionedFinalityProof::<Block, ecdsa_crypto::AuthorityId>::V2(
+			AggregatedSignedCommitment {
+				commitment: finality_proof.commitment().clone(),
+				signed_by: vec![true, false],
+				signature: vec![1; 48],
+			},
+		);
+		let r: Result<(), ()> = finality_proof_sender.notify(|| Ok(aggregated_proof.clone()));
+		r.unwrap();
+
+		// Inspect what we received
+		let (bytes, recv_sub_id) = sub.next::<sp_core::Bytes>().await.unwrap().unwrap();
+		let recv_finality_proof: BeefyVersionedFinalityProof<Block, ecdsa_crypto::AuthorityId> =
+			Decode::decode(&mut &bytes[..]).unwrap();
+		assert_eq!(&recv_sub_id, sub.subscription_id());
+		assert_eq!(recv_finality_proof, aggregated_proof);
+	}
+
+	#[tokio::test]
+	async fn aggregated_justifications_are_disabled_by_default() {
+		let (_, best_block_stream) = BeefyBestBlockStream::<Block>::channel();
+		let (_, finality_proof_stream) =
+			BeefyVersionedFinalityProofStream::<Block, ecdsa_crypto::AuthorityId>::channel();
+		let rpc = Beefy::<Block, ecdsa_crypto::AuthorityId>::new(
+			finality_proof_stream,
+			best_block_stream,
+			sc_rpc::testing::test_executor(),
+		)
+		.unwrap()
+		.into_rpc();
+
+		let (response, _) = rpc
+			.raw_json_request(
+				r#"{"jsonrpc":"2.0","method":"beefy_subscribeAggregatedJustifications","params":[],"id":1}"#,
+				1,
+			)
+			.await
+			.unwrap();
+		let expected = r#"{"jsonrpc":"2.0","id":1,"error":{"code":3,"message":"BEEFY aggregated justifications are not enabled"}}"#;
+
+		assert_eq!(response, expected);
+	}
 }
@@ -119,6 +119,7 @@ pub(crate) struct GossipFilterCfg<'a, B: Block, AuthorityId: AuthorityIdBound> {
 	pub start: NumberFor<B>,
 	pub end: NumberFor<B>,
 	pub validator_set: &'a ValidatorSet<AuthorityId>,
+	pub proofs_of_possession: Option<&'a [Vec<u8>]>,
 }
 
 #[derive(Clone, Debug)]
@@ -126,6 +127,7 @@ struct FilterInner<B: Block, AuthorityId: AuthorityIdBound> {
 	pub start: NumberFor<B>,
 	pub end: NumberFor<B>,
 	pub validator_set: ValidatorSet<AuthorityId>,
+	pub proofs_of_possession: Option<Vec<Vec<u8>>>,
 }
 
 struct Filter<B: Block, AuthorityId: AuthorityIdBound> {
@@ -155,6 +157,7 @@ impl<B: Block, AuthorityId: AuthorityIdBound> Filter<B, AuthorityId> {
 					start: cfg.start,
 					end: cfg.end,
 					validator_set: cfg.validator_set.clone(),
+					proofs_of_possession: cfg.proofs_of_possession.map(<[_]>::to_vec),
 				}),
 		}
 	}
@@ -208,8 +211,10 @@ impl<B: Block, AuthorityId: AuthorityIdBound> Filter<B, AuthorityId> {
 		self.rounds_with_valid_proofs.contains(&round)
 	}
 
-	fn validator_set(&self) -> Option<&ValidatorSet<AuthorityId>> {
-		self.inner.as_ref().map(|f| &f.validator_set)
+	fn validator_set(&self) -> Option<(&ValidatorSet<AuthorityId>, Option<&[Vec<u8>]>)> {
+		self.inner
+			.as_ref()
+			.map(|f| (&f.validator_set, f.proofs_of_possession.as_deref()))
 	}
 }
 
@@ -298,7 +303,7 @@ where
 			// ensure authority is part of the set.
 			if !filter
 				.validator_set()
-				.map(|set| set.validators().contains(&vote.id))
+				.map(|(set, _)| set.validators().contains(&vote.id))
 				.unwrap_or(false)
 			{
 				debug!(target: LOG_TARGET, "Message from voter not in validator set: {}", vote.id);
@@ -345,10 +350,13 @@ where
 			// Verify justification signatures.
 			guard
 				.validator_set()
-				.map(|validator_set| {
-					if let Err((_, signatures_checked)) =
-						verify_with_validator_set::<B, AuthorityId>(round, validator_set, &proof)
-					{
+				.map(|(validator_set, proofs_of_possession)| {
+					if let Err((_, signatures_checked)) = verify_with_validator_set::<B, AuthorityId>(
+						round,
+						validator_set,
+						proofs_of_possession,
+						&proof,
+					) {
 						debug!(
 							target: LOG_TARGET,
 							"🥩 Bad signatures on message: {:?}, from: {:?}", proof, sender
@@ -699,7 +707,12 @@ pub(crate) mod tests {
 		// nothing reported
 		assert!(report_stream.try_next().is_err());
 
-		gv.update_filter(GossipFilterCfg { start: 0, end: 10, validator_set: &validator_set });
+		gv.update_filter(GossipFilterCfg {
+			start: 0,
+			end: 10,
+			validator_set: &validator_set,
+			proofs_of_possession: None,
+		});
 		// nothing in cache first time
 		let res = gv.validate(&mut context, &sender, &encoded);
 		assert!(matches!(res, ValidationResult::ProcessAndKeep(_)));
@@ -716,7 +729,12 @@ pub(crate) mod tests {
 		assert_eq!(report_stream.try_next().unwrap().unwrap(), expected_report);
 
 		// reject if the round is not GRANDPA finalized
-		gv.update_filter(GossipFilterCfg { start: 1, end: 2, validator_set: &validator_set });
+		gv.update_filter(GossipFilterCfg {
+			start: 1,
+			end: 2,
+			validator_set: &validator_set,
+			proofs_of_possession: None,
+		});
 		let number = vote.commitment.block_number;
 		let set_id = vote.commitment.validator_set_id;
 		assert_eq!(gv.gossip_filter.read().consider_vote(number, set_id), Consider::RejectFuture);
@@ -726,7 +744,12 @@ pub(crate) mod tests {
 		assert_eq!(report_stream.try_next().unwrap().unwrap(), expected_report);
 
 		// reject if the round is not live anymore
-		gv.update_filter(GossipFilterCfg { start: 7, end: 10, validator_set: &validator_set });
+		gv.update_filter(GossipFilterCfg {
+			start: 7,
+			end: 10,
+			validator_set: &validator_set,
+			proofs_of_possession: None,
+		});
 		let number = vote.commitment.block_number;
 		let set_id = vote.commitment.validator_set_id;
 		assert_eq!(gv.gossip_filter.read().consider_vote(number, set_id), Consider::RejectPast);
@@ -796,13 +819,23 @@ pub(crate) mod tests {
 			Arc::new(Mutex::new(KnownPeers::new())),
 			Arc::new(TestNetwork::new().0),
 		);
-		gv.update_filter(GossipFilterCfg { start: 0, end: 10, validator_set: &validator_set });
+		gv.update_filter(GossipFilterCfg {
+			start: 0,
+			end: 10,
+			validator_set: &validator_set,
+			proofs_of_possession: None,
+		});
 		let sender = sc_network_types::PeerId::random();
 		let topic = Default::default();
 		let intent = MessageIntent::Broadcast;
 
 		// conclude 2
-		gv.update_filter(GossipFilterCfg { start: 2, end: 10, validator_set: &validator_set });
+		gv.update_filter(GossipFilterCfg {
+			start: 2,
+			end: 10,
+			validator_set: &validator_set,
+			proofs_of_possession: None,
+		});
 		let mut allowed = gv.message_allowed();
 		let mut expired = gv.message_expired();
 
@@ -889,7 +922,12 @@ pub(crate) mod tests {
 			Arc::new(Mutex::new(KnownPeers::new())),
 			Arc::new(TestNetwork::new().0),
 		);
-		gv.update_filter(GossipFilterCfg { start: 0, end: 10, validator_set: &validator_set });
+		gv.update_filter(GossipFilterCfg {
+			start: 0,
+			end: 10,
+			validator_set: &validator_set,
+			proofs_of_possession: None,
+		});
 		let sender = sc_network_types::PeerId::random();
 		let topic = Default::default();
 
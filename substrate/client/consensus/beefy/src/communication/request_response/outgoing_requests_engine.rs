@@ -52,6 +52,7 @@ type ResponseReceiver = oneshot::Receiver<Response>;
 struct RequestInfo<B: Block, AuthorityId: AuthorityIdBound> {
 	block: NumberFor<B>,
 	active_set: ValidatorSet<AuthorityId>,
+	proofs_of_possession: Option<Vec<Vec<u8>>>,
 }
 
 enum State<B: Block, AuthorityId: AuthorityIdBound> {
@@ -136,8 +137,14 @@ impl<B: Block, AuthorityId: AuthorityIdBound> OnDemandJustificationsEngine<B, Au
 
 	/// Start new justification request for `block`, if no other request is in progress.
 	///
-	/// `active_set` will be used to verify validity of potential responses.
-	pub fn request(&mut self, block: NumberFor<B>, active_set: ValidatorSet<AuthorityId>) {
+	/// `active_set` and the `proofs_of_possession` of its keys will be used to verify validity of
+	/// potential responses.
+	pub fn request(
+		&mut self,
+		block: NumberFor<B>,
+		active_set: ValidatorSet<AuthorityId>,
+		proofs_of_possession: Option<Vec<Vec<u8>>>,
+	) {
 		// ignore new requests while there's already one pending
 		if matches!(self.state, State::AwaitingResponse(_, _, _)) {
 			return;
@@ -147,7 +154,7 @@ impl<B: Block, AuthorityId: AuthorityIdBound> OnDemandJustificationsEngine<B, Au
 		// Start the requests engine - each unsuccessful received response will automatically
 		// trigger a new request to the next peer in the `peers_cache` until there are none left.
 		if let Some(peer) = self.try_next_peer() {
-			self.request_from_peer(peer, RequestInfo { block, active_set });
+			self.request_from_peer(peer, RequestInfo { block, active_set, proofs_of_possession });
 		} else {
 			metric_inc!(self.metrics, beefy_on_demand_justification_no_peer_to_request_from);
 			debug!(
@@ -211,6 +218,7 @@ impl<B: Block, AuthorityId: AuthorityIdBound> OnDemandJustificationsEngine<B, Au
 					&encoded[..],
 					req_info.block,
 					&req_info.active_set,
+					req_info.proofs_of_possession.as_deref(),
 				)
 				.map_err(|(err, signatures_checked)| {
 					metric_inc!(self.metrics, beefy_on_demand_justification_invalid_proof);
@@ -113,8 +113,16 @@ where
 			.map_err(|e| ImportError(e.to_string()))?
 			.ok_or_else(|| ImportError("Unknown validator set".to_string()))?;
 
-		decode_and_verify_finality_proof::<Block, AuthorityId>(&encoded[..], number, &validator_set)
-			.map_err(|(err, _)| err)
+		let proofs_of_possession =
+			crate::proofs_of_possession(&*self.runtime, hash, &validator_set);
+
+		decode_and_verify_finality_proof::<Block, AuthorityId>(
+			&encoded[..],
+			number,
+			&validator_set,
+			proofs_of_possession.as_deref(),
+		)
+		.map_err(|(err, _)| err)
 	}
 }
 
@@ -20,8 +20,7 @@ use codec::DecodeAll;
 use sp_application_crypto::RuntimeAppPublic;
 use sp_consensus::Error as ConsensusError;
 use sp_consensus_beefy::{
-	AuthorityIdBound, BeefySignatureHasher, KnownSignature, ValidatorSet, ValidatorSetId,
-	VersionedFinalityProof,
+	AuthorityIdBound, BeefySignatureHasher, ValidatorSet, ValidatorSetId, VersionedFinalityProof,
 };
 use sp_runtime::traits::{Block as BlockT, NumberFor};
 
@@ -32,10 +31,8 @@ pub type BeefyVersionedFinalityProof<Block, AuthorityId> =
 pub(crate) fn proof_block_num_and_set_id<Block: BlockT, AuthorityId: AuthorityIdBound>(
 	proof: &BeefyVersionedFinalityProof<Block, AuthorityId>,
 ) -> (NumberFor<Block>, ValidatorSetId) {
-	match proof {
-		VersionedFinalityProof::V1(sc) =>
-			(sc.commitment.block_number, sc.commitment.validator_set_id),
-	}
+	let commitment = proof.commitment();
+	(commitment.block_number, commitment.validator_set_id)
 }
 
 /// Decode and verify a Beefy FinalityProof.
@@ -43,39 +40,73 @@ pub(crate) fn decode_and_verify_finality_proof<Block: BlockT, AuthorityId: Autho
 	encoded: &[u8],
 	target_number: NumberFor<Block>,
 	validator_set: &ValidatorSet<AuthorityId>,
+	proofs_of_possession: Option<&[Vec<u8>]>,
 ) -> Result<BeefyVersionedFinalityProof<Block, AuthorityId>, (ConsensusError, u32)> {
 	let proof = <BeefyVersionedFinalityProof<Block, AuthorityId>>::decode_all(&mut &*encoded)
 		.map_err(|_| (ConsensusError::InvalidJustification, 0))?;
-	verify_with_validator_set::<Block, AuthorityId>(target_number, validator_set, &proof)?;
+	verify_with_validator_set::<Block, AuthorityId>(
+		target_number,
+		validator_set,
+		proofs_of_possession,
+		&proof,
+	)?;
 	Ok(proof)
 }
 
 /// Verify the Beefy finality proof against the validator set at the block it was generated.
+///
+/// Aggregated proofs are verified against the `proofs_of_possession` of the validator keys,
+/// in validator set order, and are rejected if these are not known.
+///
+/// Returns the authorities whose signatures are part of the proof.
 pub(crate) fn verify_with_validator_set<'a, Block: BlockT, AuthorityId: AuthorityIdBound>(
 	target_number: NumberFor<Block>,
 	validator_set: &'a ValidatorSet<AuthorityId>,
+	proofs_of_possession: Option<&[Vec<u8>]>,
 	proof: &'a BeefyVersionedFinalityProof<Block, AuthorityId>,
-) -> Result<
-	Vec<KnownSignature<&'a AuthorityId, &'a <AuthorityId as RuntimeAppPublic>::Signature>>,
-	(ConsensusError, u32),
-> {
-	match proof {
+) -> Result<Vec<&'a AuthorityId>, (ConsensusError, u32)> {
+	let (signatories, signature_count) = match proof {
 		VersionedFinalityProof::V1(signed_commitment) => {
 			let signatories = signed_commitment
 				.verify_signatures::<_, BeefySignatureHasher>(target_number, validator_set)
 				.map_err(|checked_signatures| {
 					(ConsensusError::InvalidJustification, checked_signatures)
 				})?;
-
-			if signatories.len() >= crate::round::threshold(validator_set.len()) {
-				Ok(signatories)
-			} else {
-				Err((
-					ConsensusError::InvalidJustification,
-					signed_commitment.signature_count() as u32,
-				))
-			}
+			let signatories: Vec<_> = signatories.into_iter().map(|s| s.validator_id).collect();
+			(signatories, signed_commitment.signature_count())
 		},
+		VersionedFinalityProof::V2(aggregated_commitment) => {
+			let proofs_of_possession =
+				proofs_of_possession.ok_or((ConsensusError::InvalidJustification, 0))?;
+			let signatories = aggregated_commitment
+				.verify_signature(target_number, validator_set, proofs_of_possession)
+				.map_err(|checked_signatures| {
+					(ConsensusError::InvalidJustification, checked_signatures)
+				})?;
+			(signatories, aggregated_commitment.signature_count())
+		},
+	};
+
+	if signatories.len() >= crate::round::threshold(validator_set.len()) {
+		Ok(signatories)
+	} else {
+		Err((ConsensusError::InvalidJustification, signature_count as u32))
+	}
+}
+
+/// Convert a finality proof into its aggregated form.
+///
+/// Returns the proof unchanged if it is already aggregated, or if `AuthorityId` does not
+/// support signature aggregation.
+pub fn aggregate_finality_proof<Block: BlockT, AuthorityId: AuthorityIdBound>(
+	proof: BeefyVersionedFinalityProof<Block, AuthorityId>,
+) -> BeefyVersionedFinalityProof<Block, AuthorityId> {
+	match proof {
+		VersionedFinalityProof::V1(ref signed_commitment) => signed_commitment
+			.aggregate::<AuthorityId>()
+			.map(VersionedFinalityProof::V2)
+			.unwrap_or(proof),
+		VersionedFinalityProof::V2(_) => proof,
 	}
 }
 
@@ -83,8 +114,8 @@ pub(crate) fn verify_with_validator_set<'a, Block: BlockT, AuthorityId: Authorit
 pub(crate) mod tests {
 	use codec::Encode;
 	use sp_consensus_beefy::{
-		ecdsa_crypto, known_payloads, test_utils::Keyring, Commitment, Payload, SignedCommitment,
-		VersionedFinalityProof,
+		ecdsa_crypto, known_payloads, test_utils::Keyring, AggregatedSignedCommitment, Commitment,
+		Payload, SignedCommitment, VersionedFinalityProof,
 	};
 	use substrate_test_runtime_client::runtime::Block;
 
@@ -120,6 +151,7 @@ pub(crate) mod tests {
 		verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
 			block_num,
 			&validator_set,
+			None,
 			&good_proof,
 		)
 		.unwrap();
@@ -129,6 +161,7 @@ pub(crate) mod tests {
 		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
 			block_num + 1,
 			&validator_set,
+			None,
 			&good_proof,
 		) {
 			Err((ConsensusError::InvalidJustification, 0)) => (),
@@ -141,6 +174,7 @@ pub(crate) mod tests {
 		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
 			block_num,
 			&other,
+			None,
 			&good_proof,
 		) {
 			Err((ConsensusError::InvalidJustification, 0)) => (),
@@ -152,11 +186,13 @@ pub(crate) mod tests {
 		// change length of signatures
 		let bad_signed_commitment = match bad_proof {
 			VersionedFinalityProof::V1(ref mut sc) => sc,
+			VersionedFinalityProof::V2(_) => unreachable!(),
 		};
 		bad_signed_commitment.signatures.pop().flatten().unwrap();
 		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
 			block_num + 1,
 			&validator_set,
+			None,
 			&bad_proof.into(),
 		) {
 			Err((ConsensusError::InvalidJustification, 0)) => (),
@@ -167,12 +203,14 @@ pub(crate) mod tests {
 		let mut bad_proof = proof.clone();
 		let bad_signed_commitment = match bad_proof {
 			VersionedFinalityProof::V1(ref mut sc) => sc,
+			VersionedFinalityProof::V2(_) => unreachable!(),
 		};
 		// remove a signature (but same length)
 		*bad_signed_commitment.signatures.first_mut().unwrap() = None;
 		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
 			block_num,
 			&validator_set,
+			None,
 			&bad_proof.into(),
 		) {
 			Err((ConsensusError::InvalidJustification, 2)) => (),
@@ -183,6 +221,7 @@ pub(crate) mod tests {
 		let mut bad_proof = proof.clone();
 		let bad_signed_commitment = match bad_proof {
 			VersionedFinalityProof::V1(ref mut sc) => sc,
+			VersionedFinalityProof::V2(_) => unreachable!(),
 		};
 		// change a signature to a different key
 		*bad_signed_commitment.signatures.first_mut().unwrap() = Some(
@@ -192,11 +231,93 @@ pub(crate) mod tests {
 		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
 			block_num,
 			&validator_set,
+			None,
 			&bad_proof.into(),
 		) {
 			Err((ConsensusError::InvalidJustification, 3)) => (),
 			e => assert!(false, "Got unexpected {:?}", e),
 		};
+
+		// aggregated proof without proofs of possession -> should be rejected
+		let aggregated_proof = VersionedFinalityProof::V2(AggregatedSignedCommitment {
+			commitment: proof.commitment().clone(),
+			signed_by: vec![true; keys.len()],
+			signature: vec![1; 48],
+		});
+		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
+			block_num,
+			&validator_set,
+			None,
+			&aggregated_proof,
+		) {
+			Err((ConsensusError::InvalidJustification, 0)) => (),
+			e => assert!(false, "Got unexpected {:?}", e),
+		};
+
+		// aggregated proof with ECDSA keys -> should be rejected
+		let proofs_of_possession = vec![vec![1; 112]; keys.len()];
+		match verify_with_validator_set::<Block, ecdsa_crypto::AuthorityId>(
+			block_num,
+			&validator_set,
+			Some(&proofs_of_possession),
+			&aggregated_proof,
+		) {
+			Err((ConsensusError::InvalidJustification, _)) => (),
+			e => assert!(false, "Got unexpected {:?}", e),
+		};
+	}
+
+	#[test]
+	#[cfg(feature = "bls-experimental")]
+	fn should_verify_aggregated_proof_with_proofs_of_possession() {
+		use sp_consensus_beefy::ecdsa_bls_crypto;
+		use sp_core::{crypto::ByteArray, proof_of_possession::ProofOfPossessionGenerator, Pair};
+
+		let keys = &[Keyring::Alice, Keyring::Bob, Keyring::Charlie];
+		let validator_set = ValidatorSet::<ecdsa_bls_crypto::AuthorityId>::new(
+			keys.iter().map(|key| key.public()),
+			0,
+		)
+		.unwrap();
+		let commitment = Commitment {
+			payload: Payload::from_single_entry(known_payloads::MMR_ROOT_ID, vec![]),
+			block_number: 42,
+			validator_set_id: 0,
+		};
+		let message = commitment.encode();
+		let signatures = keys.iter().map(|key| Some(key.sign(&message))).collect();
+		let proof: BeefyVersionedFinalityProof<Block, ecdsa_bls_crypto::AuthorityId> =
+			aggregate_finality_proof::<Block, ecdsa_bls_crypto::AuthorityId>(
+				VersionedFinalityProof::V1(SignedCommitment { commitment, signatures }),
+			);
+		assert!(matches!(proof, VersionedFinalityProof::V2(_)));
+
+		let proofs_of_possession: Vec<Vec<u8>> = keys
+			.iter()
+			.map(|key| {
+				let mut pair = sp_core::bls381::Pair::from_string(&key.to_seed(), None).unwrap();
+				pair.generate_proof_of_possession().to_raw_vec()
+			})
+			.collect();
+
+		// should verify successfully
+		let signatories = verify_with_validator_set::<Block, ecdsa_bls_crypto::AuthorityId>(
+			42,
+			&validator_set,
+			Some(&proofs_of_possession),
+			&proof,
+		)
+		.unwrap();
+		assert_eq!(signatories.len(), keys.len());
+
+		// unknown proofs of possession -> should be rejected
+		assert!(verify_with_validator_set::<Block, ecdsa_bls_crypto::AuthorityId>(
+			42,
+			&validator_set,
+			None,
+			&proof,
+		)
+		.is_err());
 	}
 
 	#[test]
@@ -216,6 +337,7 @@ pub(crate) mod tests {
 			&encoded,
 			block_num,
 			&validator_set,
+			None,
 		)
 		.unwrap();
 		assert_eq!(verified, versioned_proof);
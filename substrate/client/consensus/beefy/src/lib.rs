@@ -40,7 +40,7 @@ use sc_consensus::BlockImport;
 use sc_network::{NetworkRequest, NotificationService, ProtocolName};
 use sc_network_gossip::{GossipEngine, Network as GossipNetwork, Syncing as GossipSyncing};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::{Backend as BlockchainBackend, HeaderBackend};
 use sp_consensus::{Error as ConsensusError, SyncOracle};
 use sp_consensus_beefy::{
@@ -375,7 +375,9 @@ where
 				if sessions.is_empty() {
 					let active_set =
 						expect_validator_set(runtime.as_ref(), backend.as_ref(), &header).await?;
-					let mut rounds = Rounds::new(best_beefy, active_set);
+					let proofs = proofs_of_possession(runtime.as_ref(), header.hash(), &active_set);
+					let mut rounds =
+						Rounds::new(best_beefy, active_set).with_proofs_of_possession(proofs);
 					// Mark the round as already finalized.
 					rounds.conclude(best_beefy);
 					sessions.push_front(rounds);
@@ -403,7 +405,10 @@ where
 					genesis_set,
 				);
 
-				sessions.push_front(Rounds::new(beefy_genesis, genesis_set));
+				let proofs = proofs_of_possession(runtime.as_ref(), header.hash(), &genesis_set);
+				sessions.push_front(
+					Rounds::new(beefy_genesis, genesis_set).with_proofs_of_possession(proofs),
+				);
 				break PersistedState::checked_new(
 					best_grandpa,
 					Zero::zero(),
@@ -420,7 +425,10 @@ where
 					"🥩 Marking block {:?} as BEEFY Mandatory.",
 					*header.number()
 				);
-				sessions.push_front(Rounds::new(*header.number(), active));
+				let proofs = proofs_of_possession(runtime.as_ref(), header.hash(), &active);
+				sessions.push_front(
+					Rounds::new(*header.number(), active).with_proofs_of_possession(proofs),
+				);
 			}
 
 			// Move up the chain.
@@ -460,7 +468,8 @@ where
 			while *header.number() > state.best_beefy() {
 				if state.voting_oracle().can_add_session(*header.number()) {
 					if let Some(active) = find_authorities_change::<B, AuthorityId>(&header) {
-						new_sessions.push((active, *header.number()));
+						let proofs = proofs_of_possession(runtime.as_ref(), header.hash(), &active);
+						new_sessions.push((active, proofs, *header.number()));
 					}
 				}
 				header =
@@ -468,7 +477,7 @@ where
 			}
 
 			// Make sure we didn't miss any sessions during node restart.
-			for (validator_set, new_session_start) in new_sessions.drain(..).rev() {
+			for (validator_set, proofs, new_session_start) in new_sessions.drain(..).rev() {
 				debug!(
 					target: LOG_TARGET,
 					"🥩 Handling missed BEEFY session after node restart: {:?}.",
@@ -477,6 +486,7 @@ where
 				state.init_session_at(
 					new_session_start,
 					validator_set,
+					proofs,
 					key_store,
 					metrics,
 					is_authority,
@@ -795,6 +805,32 @@ where
 	}
 }
 
+/// Provides the proofs of possession of the keys of `validator_set`, as registered in the state
+/// at block `at`, in validator set order.
+///
+/// Returns `None` if the runtime doesn't provide them, or if some of them are missing.
+pub(crate) fn proofs_of_possession<B, R, AuthorityId: AuthorityIdBound>(
+	runtime: &R,
+	at: B::Hash,
+	validator_set: &ValidatorSet<AuthorityId>,
+) -> Option<Vec<Vec<u8>>>
+where
+	B: Block,
+	R: ProvideRuntimeApi<B>,
+	R::Api: BeefyApi<B, AuthorityId>,
+{
+	let runtime_api = runtime.runtime_api();
+	let version = runtime_api.api_version::<dyn BeefyApi<B, AuthorityId>>(at).ok().flatten()?;
+	if version < 6 {
+		return None
+	}
+	runtime_api
+		.proofs_of_possession(at, validator_set.validators().to_vec())
+		.ok()
+		.flatten()
+		.filter(|proofs| proofs.len() == validator_set.len())
+}
+
 /// Scan the `header` digest log for a BEEFY validator set change. Return either the new
 /// validator set or `None` in case no validator set change has been signaled.
 pub(crate) fn find_authorities_change<B, AuthorityId>(
@@ -91,6 +91,7 @@ pub(crate) struct Rounds<B: Block, AuthorityId: AuthorityIdBound> {
 	>,
 	session_start: NumberFor<B>,
 	validator_set: ValidatorSet<AuthorityId>,
+	proofs_of_possession: Option<Vec<Vec<u8>>>,
 	mandatory_done: bool,
 	best_done: Option<NumberFor<B>>,
 }
@@ -109,15 +110,31 @@ where
 			previous_votes: BTreeMap::new(),
 			session_start,
 			validator_set,
+			proofs_of_possession: None,
 			mandatory_done: false,
 			best_done: None,
 		}
 	}
 
+	/// Set the proofs of possession of the validator keys, in validator set order.
+	///
+	/// Concluded rounds are aggregated only if these are known.
+	pub(crate) fn with_proofs_of_possession(
+		mut self,
+		proofs_of_possession: Option<Vec<Vec<u8>>>,
+	) -> Self {
+		self.proofs_of_possession = proofs_of_possession;
+		self
+	}
+
 	pub(crate) fn validator_set(&self) -> &ValidatorSet<AuthorityId> {
 		&self.validator_set
 	}
 
+	pub(crate) fn proofs_of_possession(&self) -> Option<&[Vec<u8>]> {
+		self.proofs_of_possession.as_deref()
+	}
+
 	pub(crate) fn validator_set_id(&self) -> ValidatorSetId {
 		self.validator_set.id()
 	}
@@ -539,10 +539,7 @@ async fn wait_for_beefy_signed_commitments(
 		wait_for.push(Box::pin(stream.take(len).for_each(move |versioned_finality_proof| {
 			let expected = expected.next();
 			async move {
-				let signed_commitment = match versioned_finality_proof {
-					sp_consensus_beefy::VersionedFinalityProof::V1(sc) => sc,
-				};
-				let commitment_block_num = signed_commitment.commitment.block_number;
+				let commitment_block_num = versioned_finality_proof.commitment().block_number;
 				assert_eq!(expected, Some(commitment_block_num).as_ref());
 				// TODO: also verify commitment payload, validator set id, and signatures.
 			}
@@ -1501,6 +1498,7 @@ async fn gossipped_finality_proofs() {
 		start: 1,
 		end: 10,
 		validator_set: &validator_set,
+		proofs_of_possession: None,
 	});
 	let mut charlie_gossip_engine = sc_network_gossip::GossipEngine::new(
 		charlie.network_service().clone(),
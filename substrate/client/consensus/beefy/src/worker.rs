@@ -24,7 +24,7 @@ use crate::{
 	error::Error,
 	find_authorities_change,
 	fisherman::Fisherman,
-	justification::BeefyVersionedFinalityProof,
+	justification::{aggregate_finality_proof, BeefyVersionedFinalityProof},
 	keystore::BeefyKeystore,
 	metric_inc, metric_set,
 	metrics::VoterMetrics,
@@ -172,6 +172,10 @@ where
 		self.active_rounds().map(|r| r.validator_set())
 	}
 
+	fn current_proofs_of_possession(&self) -> Result<Option<&[Vec<u8>]>, Error> {
+		self.active_rounds().map(|r| r.proofs_of_possession())
+	}
+
 	// Prune the sessions queue to keep the Oracle in one of the expected three states.
 	//
 	// To be called on each BEEFY finality and on each new rounds/session addition.
@@ -205,13 +209,20 @@ where
 		Ok(())
 	}
 
-	/// Return current pending mandatory block, if any, plus its active validator set.
-	pub fn mandatory_pending(&self) -> Option<(NumberFor<B>, ValidatorSet<AuthorityId>)> {
+	/// Return current pending mandatory block, if any, plus its active validator set and the
+	/// proofs of possession of the validator keys, if known.
+	pub fn mandatory_pending(
+		&self,
+	) -> Option<(NumberFor<B>, ValidatorSet<AuthorityId>, Option<Vec<Vec<u8>>>)> {
 		self.sessions.front().and_then(|round| {
 			if round.mandatory_done() {
 				None
 			} else {
-				Some((round.session_start(), round.validator_set().clone()))
+				Some((
+					round.session_start(),
+					round.validator_set().clone(),
+					round.proofs_of_possession().map(<[_]>::to_vec),
+				))
 			}
 		})
 	}
@@ -328,7 +339,8 @@ impl<B: Block, AuthorityId: AuthorityIdBound> PersistedState<B, AuthorityId> {
 	pub(crate) fn gossip_filter_config(&self) -> Result<GossipFilterCfg<B, AuthorityId>, Error> {
 		let (start, end) = self.voting_oracle.accepted_interval()?;
 		let validator_set = self.voting_oracle.current_validator_set()?;
-		Ok(GossipFilterCfg { start, end, validator_set })
+		let proofs_of_possession = self.voting_oracle.current_proofs_of_possession()?;
+		Ok(GossipFilterCfg { start, end, validator_set, proofs_of_possession })
 	}
 
 	/// Handle session changes by starting new voting round for mandatory blocks.
@@ -336,6 +348,7 @@ impl<B: Block, AuthorityId: AuthorityIdBound> PersistedState<B, AuthorityId> {
 		&mut self,
 		new_session_start: NumberFor<B>,
 		validator_set: ValidatorSet<AuthorityId>,
+		proofs_of_possession: Option<Vec<Vec<u8>>>,
 		key_store: &BeefyKeystore<AuthorityId>,
 		metrics: &Option<VoterMetrics>,
 		is_authority: bool,
@@ -368,7 +381,10 @@ impl<B: Block, AuthorityId: AuthorityIdBound> PersistedState<B, AuthorityId> {
 		}
 
 		let id = validator_set.id();
-		self.voting_oracle.add_session(Rounds::new(new_session_start, validator_set));
+		self.voting_oracle.add_session(
+			Rounds::new(new_session_start, validator_set)
+				.with_proofs_of_possession(proofs_of_possession),
+		);
 		metric_set!(metrics, beefy_validator_set_id, id);
 		info!(
 			target: LOG_TARGET,
@@ -434,11 +450,13 @@ where
 	fn init_session_at(
 		&mut self,
 		validator_set: ValidatorSet<AuthorityId>,
+		proofs_of_possession: Option<Vec<Vec<u8>>>,
 		new_session_start: NumberFor<B>,
 	) {
 		self.persisted_state.init_session_at(
 			new_session_start,
 			validator_set,
+			proofs_of_possession,
 			&self.key_store,
 			&self.metrics,
 			self.is_authority,
@@ -491,7 +509,12 @@ where
 			{
 				if let Some(new_validator_set) = find_authorities_change::<B, AuthorityId>(&header)
 				{
-					self.init_session_at(new_validator_set, *header.number());
+					let proofs = crate::proofs_of_possession(
+						&*self.runtime,
+						header.hash(),
+						&new_validator_set,
+					);
+					self.init_session_at(new_validator_set, proofs, *header.number());
 					new_session_added = true;
 				}
 			}
@@ -548,10 +571,7 @@ where
 		&mut self,
 		justification: BeefyVersionedFinalityProof<B, AuthorityId>,
 	) -> Result<(), Error> {
-		let signed_commitment = match justification {
-			VersionedFinalityProof::V1(ref sc) => sc,
-		};
-		let block_num = signed_commitment.commitment.block_number;
+		let block_num = justification.commitment().block_number;
 		match self.voting_oracle().triage_round(block_num)? {
 			RoundAction::Process => {
 				debug!(target: LOG_TARGET, "🥩 Process justification for round: {:?}.", block_num);
@@ -585,7 +605,12 @@ where
 		let block_number = vote.commitment.block_number;
 		match rounds.add_vote(vote) {
 			VoteImportResult::RoundConcluded(signed_commitment) => {
-				let finality_proof = VersionedFinalityProof::V1(signed_commitment);
+				let mut finality_proof = VersionedFinalityProof::V1(signed_commitment);
+				// Aggregated proofs can be verified by everyone who knows the proofs of
+				// possession of the validator keys.
+				if rounds.proofs_of_possession().is_some() {
+					finality_proof = aggregate_finality_proof::<B, AuthorityId>(finality_proof);
+				}
 				debug!(
 					target: LOG_TARGET,
 					"🥩 Round #{} concluded, finality_proof: {:?}.", block_number, finality_proof
@@ -601,7 +626,7 @@ where
 				if self
 					.voting_oracle()
 					.mandatory_pending()
-					.map(|(mandatory_num, _)| mandatory_num == block_number)
+					.map(|(mandatory_num, _, _)| mandatory_num == block_number)
 					.unwrap_or(false)
 				{
 					crate::aux_schema::write_voter_state(&*self.backend, &self.persisted_state)
@@ -630,9 +655,7 @@ where
 		&mut self,
 		finality_proof: BeefyVersionedFinalityProof<B, AuthorityId>,
 	) -> Result<(), Error> {
-		let block_num = match finality_proof {
-			VersionedFinalityProof::V1(ref sc) => sc.commitment.block_number,
-		};
+		let block_num = finality_proof.commitment().block_number;
 
 		if block_num <= self.persisted_state.voting_oracle.best_beefy_block {
 			// we've already finalized this round before, short-circuit.
@@ -834,9 +857,9 @@ where
 			}
 			// If the current target is a mandatory block,
 			// make sure there's also an on-demand justification request out for it.
-			if let Some((block, active)) = self.voting_oracle().mandatory_pending() {
+			if let Some((block, active, proofs)) = self.voting_oracle().mandatory_pending() {
 				// This only starts new request if there isn't already an active one.
-				self.comms.on_demand_justifications.request(block, active);
+				self.comms.on_demand_justifications.request(block, active, proofs);
 			}
 		}
 	}
@@ -1549,7 +1572,7 @@ pub(crate) mod tests {
 		let keys = &[Keyring::Bob];
 		let new_validator_set = ValidatorSet::new(make_beefy_ids(keys), 1).unwrap();
 
-		worker.init_session_at(new_validator_set.clone(), 11);
+		worker.init_session_at(new_validator_set.clone(), None, 11);
 		// Since mandatory is not done for old rounds, we still get those.
 		let rounds = worker.persisted_state.voting_oracle.active_rounds_mut().unwrap();
 		assert_eq!(rounds.validator_set_id(), validator_set.id());
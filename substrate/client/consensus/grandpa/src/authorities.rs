@@ -756,6 +756,12 @@ impl<N: Ord + Clone> AuthoritySetChanges<N> {
 		self.0.insert(idx, (set_id, block_number));
 	}
 
+	/// Returns an iterator over all historical authority set changes. The iterator yields a tuple
+	/// representing the set id and the block number of the last block in that set.
+	pub(crate) fn iter(&self) -> impl Iterator<Item = &(u64, N)> {
+		self.0.iter()
+	}
+
 	/// Returns an iterator over all historical authority set changes starting at the given block
 	/// number (excluded). The iterator yields a tuple representing the set id and the block number
 	/// of the last block in that set.
@@ -46,6 +46,7 @@ const SET_STATE_KEY: &[u8] = b"grandpa_completed_round";
 const CONCLUDED_ROUNDS: &[u8] = b"grandpa_concluded_rounds";
 const AUTHORITY_SET_KEY: &[u8] = b"grandpa_voters";
 const BEST_JUSTIFICATION: &[u8] = b"grandpa_best_justification";
+const AUTHORITY_SET_HISTORY: &[u8] = b"grandpa_authority_set_history";
 
 const CURRENT_VERSION: u32 = 3;
 
@@ -404,6 +405,10 @@ where
 		&[
 			(AUTHORITY_SET_KEY, genesis_set.encode().as_slice()),
 			(SET_STATE_KEY, genesis_state.encode().as_slice()),
+			(
+				authority_set_history_key(0).as_slice(),
+				genesis_set.current_authorities.encode().as_slice(),
+			),
 		],
 		&[],
 	)?;
@@ -415,14 +420,15 @@ where
 ///
 /// If there has just been a handoff, pass a `new_set` parameter that describes the
 /// handoff. `set` in all cases should reflect the current authority set, with all
-/// changes and handoffs applied.
+/// changes and handoffs applied. The authorities of the new set are also kept in the history
+/// of authority sets, see [`load_historical_authorities`].
 pub(crate) fn update_authority_set<Block: BlockT, F, R>(
 	set: &AuthoritySet<Block::Hash, NumberFor<Block>>,
 	new_set: Option<&NewAuthoritySet<Block::Hash, NumberFor<Block>>>,
 	write_aux: F,
 ) -> R
 where
-	F: FnOnce(&[(&'static [u8], &[u8])]) -> R,
+	F: FnOnce(&[(&[u8], &[u8])]) -> R,
 {
 	// write new authority set state to disk.
 	let encoded_set = set.encode();
@@ -437,8 +443,14 @@ where
 			(new_set.canon_hash, new_set.canon_number),
 		);
 		let encoded = set_state.encode();
-
-		write_aux(&[(AUTHORITY_SET_KEY, &encoded_set[..]), (SET_STATE_KEY, &encoded[..])])
+		let history_key = authority_set_history_key(new_set.set_id);
+		let encoded_authorities = new_set.authorities.encode();
+
+		write_aux(&[
+			(AUTHORITY_SET_KEY, &encoded_set[..]),
+			(SET_STATE_KEY, &encoded[..]),
+			(&history_key[..], &encoded_authorities[..]),
+		])
 	} else {
 		write_aux(&[(AUTHORITY_SET_KEY, &encoded_set[..])])
 	}
@@ -491,11 +503,35 @@ pub(crate) fn write_concluded_round<Block: BlockT, B: AuxStore>(
 	backend.insert_aux(&[(&key[..], round_data.encode().as_slice())], &[])
 }
 
+/// The key of the authorities of the given set in the history of authority sets.
+fn authority_set_history_key(set_id: SetId) -> Vec<u8> {
+	let mut key = AUTHORITY_SET_HISTORY.to_vec();
+	set_id.using_encoded(|id| key.extend(id));
+	key
+}
+
+/// Load the authorities of a past authority set.
+///
+/// Returns `None` if the set was enacted before the history of authority sets was kept.
+pub(crate) fn load_historical_authorities<B: AuxStore>(
+	backend: &B,
+	set_id: SetId,
+) -> ClientResult<Option<AuthorityList>> {
+	load_decode(backend, &authority_set_history_key(set_id))
+}
+
+/// Load the persisted authority set, if any.
+pub(crate) fn load_authority_set<B: AuxStore, H: Decode, N: Decode + Clone + Ord>(
+	backend: &B,
+) -> ClientResult<Option<AuthoritySet<H, N>>> {
+	load_decode::<_, AuthoritySet<H, N>>(backend, AUTHORITY_SET_KEY)
+}
+
 #[cfg(test)]
 pub(crate) fn load_authorities<B: AuxStore, H: Decode, N: Decode + Clone + Ord>(
 	backend: &B,
 ) -> Option<AuthoritySet<H, N>> {
-	load_decode::<_, AuthoritySet<H, N>>(backend, AUTHORITY_SET_KEY).expect("backend error")
+	load_authority_set(backend).expect("backend error")
 }
 
 #[cfg(test)]
@@ -788,4 +824,35 @@ mod test {
 			Some(completed_round),
 		);
 	}
+
+	#[test]
+	fn authority_set_handoff_is_kept_in_history() {
+		let client = substrate_test_runtime_client::new();
+		let authorities = vec![(dummy_id(), 100)];
+		let set = AuthoritySet::<H256, u64>::new(
+			authorities.clone(),
+			4,
+			ForkTree::new(),
+			Vec::new(),
+			AuthoritySetChanges::empty(),
+		)
+		.unwrap();
+		let new_set = NewAuthoritySet {
+			canon_number: 10,
+			canon_hash: H256::random(),
+			set_id: 4,
+			authorities: authorities.clone(),
+		};
+
+		update_authority_set::<Block, _, _>(&set, None, |values| client.insert_aux(values, &[]))
+			.unwrap();
+		assert_eq!(load_historical_authorities(&client, 4).unwrap(), None);
+
+		update_authority_set::<Block, _, _>(&set, Some(&new_set), |values| {
+			client.insert_aux(values, &[])
+		})
+		.unwrap();
+		assert_eq!(load_historical_authorities(&client, 4).unwrap(), Some(authorities));
+		assert_eq!(load_historical_authorities(&client, 3).unwrap(), None);
+	}
 }
@@ -39,18 +39,23 @@
 use log::{trace, warn};
 use std::sync::Arc;
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeAll, Encode};
 use sc_client_api::backend::Backend;
-use sp_blockchain::{Backend as BlockchainBackend, HeaderBackend};
-use sp_consensus_grandpa::GRANDPA_ENGINE_ID;
+use sp_blockchain::{
+	Backend as BlockchainBackend, Error as ClientError, HeaderBackend, Result as ClientResult,
+};
+use sp_consensus_grandpa::{
+	finality_proof::{FinalityProofBundle, JustifiedHeader},
+	AuthorityList, SetId, GRANDPA_ENGINE_ID,
+};
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, Header as HeaderT, NumberFor, One},
 };
 
 use crate::{
-	authorities::{AuthoritySetChangeId, AuthoritySetChanges},
-	best_justification,
+	authorities::{AuthoritySet, AuthoritySetChangeId, AuthoritySetChanges},
+	aux_schema, best_justification, find_scheduled_change,
 	justification::GrandpaJustification,
 	SharedAuthoritySet, LOG_TARGET,
 };
@@ -155,6 +160,16 @@ pub enum FinalityProofError {
 	/// in the latest authority set, and the subscription API is more appropriate.
 	#[error("Block not covered by authority set changes")]
 	BlockNotInAuthoritySetChanges,
+	/// The requested block was finalized before the authority set the proof should start at.
+	#[error("Block finalized before the start authority set")]
+	BlockBeforeStartAuthoritySet,
+	/// The proof would have to cross an authority set change which was forced. Forced changes
+	/// aren't signaled by the previous authority set, so they can't be proven.
+	#[error("Authority set {0} was enacted by a forced change")]
+	ForcedAuthoritySetChange(SetId),
+	/// Headers, justifications or authority set data required for the proof are missing.
+	#[error("Missing data to prove finality: {0}")]
+	MissingData(String),
 	/// Errors originating from the client.
 	#[error(transparent)]
 	Client(#[from] sp_blockchain::Error),
@@ -256,6 +271,151 @@ where
 	}))
 }
 
+/// Prove the finality of the given block with a self-contained [`FinalityProofBundle`] which
+/// starts at the authority set `from_set_id`, or at the current authority set if `None`.
+///
+/// The authorities of past sets are read from the history of authority sets kept by GRANDPA.
+/// Sets enacted before the history was kept fall back to `genesis_authorities` for the genesis
+/// set, and to the authority set change signaled in the last block of the previous set for later
+/// sets. The chain of authority set changes can't be proven across forced changes, so
+/// [`FinalityProofError::ForcedAuthoritySetChange`] is returned for such ranges.
+pub fn prove_finality_bundle<Block, B>(
+	backend: &B,
+	from_set_id: Option<SetId>,
+	genesis_authorities: impl FnOnce() -> ClientResult<AuthorityList>,
+	block: NumberFor<Block>,
+) -> Result<FinalityProofBundle<Block::Header>, FinalityProofError>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+{
+	let authority_set: AuthoritySet<Block::Hash, NumberFor<Block>> =
+		aux_schema::load_authority_set(backend)?
+			.ok_or_else(|| FinalityProofError::MissingData("no GRANDPA authority set".into()))?;
+	let from_set_id = from_set_id.unwrap_or(authority_set.set_id);
+
+	let authorities = if from_set_id == authority_set.set_id {
+		authority_set.current_authorities.clone()
+	} else if let Some(authorities) = aux_schema::load_historical_authorities(backend, from_set_id)?
+	{
+		authorities
+	} else if from_set_id == 0 {
+		genesis_authorities()?
+	} else {
+		let last_block = authority_set
+			.authority_set_changes
+			.iter()
+			.find(|(set_id, _)| *set_id == from_set_id - 1)
+			.map(|(_, last_block)| *last_block)
+			.ok_or_else(|| {
+				FinalityProofError::MissingData(format!("unknown authority set {}", from_set_id))
+			})?;
+		let hash = backend.blockchain().expect_block_hash_from_id(&BlockId::Number(last_block))?;
+		let header = backend.blockchain().expect_header(hash)?;
+		find_scheduled_change::<Block>(&header)
+			.ok_or(FinalityProofError::ForcedAuthoritySetChange(from_set_id))?
+			.next_authorities
+	};
+
+	build_finality_bundle(
+		backend,
+		&authority_set.authority_set_changes,
+		from_set_id,
+		authorities,
+		block,
+	)
+}
+
+/// Build the [`FinalityProofBundle`] of the given block, starting at the given authority set.
+fn build_finality_bundle<Block, B>(
+	backend: &B,
+	authority_set_changes: &AuthoritySetChanges<NumberFor<Block>>,
+	set_id: SetId,
+	authorities: AuthorityList,
+	block: NumberFor<Block>,
+) -> Result<FinalityProofBundle<Block::Header>, FinalityProofError>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+{
+	let blockchain = backend.blockchain();
+	if blockchain.info().finalized_number < block {
+		return Err(FinalityProofError::BlockNotYetFinalized)
+	}
+
+	let justified_header = |number: NumberFor<Block>| {
+		let hash = blockchain.expect_block_hash_from_id(&BlockId::Number(number))?;
+		let justification = blockchain
+			.justifications(hash)?
+			.and_then(|justifications| justifications.into_justification(GRANDPA_ENGINE_ID))
+			.ok_or_else(|| {
+				FinalityProofError::MissingData(format!("no justification for block #{}", number))
+			})?;
+		let justification = GrandpaJustification::<Block>::decode_all(&mut &justification[..])
+			.map_err(|_| ClientError::JustificationDecode)?;
+		Ok::<_, FinalityProofError>(JustifiedHeader {
+			header: blockchain.expect_header(hash)?,
+			justification: justification.into(),
+		})
+	};
+
+	let (target_set_id, justification) = match authority_set_changes.get_set_id(block) {
+		AuthoritySetChangeId::Latest => {
+			let justification = best_justification(backend)?
+				.filter(|justification: &GrandpaJustification<Block>| {
+					justification.target().0 >= block
+				})
+				.ok_or_else(|| {
+					FinalityProofError::MissingData(format!(
+						"no justification for a descendant of block #{}",
+						block,
+					))
+				})?;
+			let latest_set_id =
+				authority_set_changes.iter().last().map(|(set_id, _)| set_id + 1).unwrap_or(0);
+			let header = blockchain.expect_header(justification.target().1)?;
+			(latest_set_id, JustifiedHeader { header, justification: justification.into() })
+		},
+		AuthoritySetChangeId::Set(target_set_id, last_block_for_set) =>
+			(target_set_id, justified_header(last_block_for_set)?),
+		AuthoritySetChangeId::Unknown =>
+			return Err(FinalityProofError::BlockNotInAuthoritySetChanges),
+	};
+	if target_set_id < set_id {
+		return Err(FinalityProofError::BlockBeforeStartAuthoritySet)
+	}
+
+	let set_changes = (set_id..target_set_id)
+		.map(|set_id| {
+			let last_block = authority_set_changes
+				.iter()
+				.find(|(id, _)| *id == set_id)
+				.map(|(_, last_block)| *last_block)
+				.ok_or_else(|| {
+					FinalityProofError::MissingData(format!("unknown authority set {}", set_id))
+				})?;
+			// the next set must have been signaled by this one, i.e. not forced.
+			let hash = blockchain.expect_block_hash_from_id(&BlockId::Number(last_block))?;
+			if find_scheduled_change::<Block>(&blockchain.expect_header(hash)?).is_none() {
+				return Err(FinalityProofError::ForcedAuthoritySetChange(set_id + 1))
+			}
+			justified_header(last_block)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let hash = blockchain.expect_block_hash_from_id(&BlockId::Number(block))?;
+	let target = blockchain.expect_header(hash)?;
+	let mut headers = Vec::new();
+	let mut current = block + One::one();
+	while current < *justification.header.number() {
+		let hash = blockchain.expect_block_hash_from_id(&BlockId::Number(current))?;
+		headers.push(blockchain.expect_header(hash)?);
+		current += One::one();
+	}
+
+	Ok(FinalityProofBundle { set_id, authorities, set_changes, target, headers, justification })
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -555,6 +715,48 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn finality_proof_bundle_in_last_set_is_verified() {
+		let (client, backend, blocks) = test_blockchain(8, &[4, 5, 8]);
+		let block8 = &blocks[7];
+
+		let round = 8;
+		let commit = create_commit(block8.clone(), round, 1, &[Ed25519Keyring::Alice]);
+		let grandpa_just8 = GrandpaJustification::from_commit(&client, round, commit).unwrap();
+		store_best_justification(&client, &grandpa_just8);
+
+		let mut authority_set_changes = AuthoritySetChanges::empty();
+		authority_set_changes.append(0, 5);
+		let authorities = vec![(Ed25519Keyring::Alice.public().into(), 1u64)];
+
+		let bundle =
+			build_finality_bundle(&*backend, &authority_set_changes, 1, authorities.clone(), 6)
+				.unwrap();
+		assert_eq!(bundle.target, blocks[5].header().clone());
+		assert_eq!(bundle.headers, vec![blocks[6].header().clone()]);
+		assert_eq!(bundle.verify(1, &authorities).unwrap(), (1, authorities.clone()));
+
+		// the proof must start at the trusted authority set.
+		let other_authorities = vec![(Ed25519Keyring::Bob.public().into(), 1u64)];
+		assert!(bundle.verify(1, &other_authorities).is_err());
+
+		// the headers must link the target to the justified block.
+		let mut invalid_bundle = bundle.clone();
+		invalid_bundle.headers.clear();
+		assert!(invalid_bundle.verify(1, &authorities).is_err());
+
+		assert!(matches!(
+			build_finality_bundle(&*backend, &authority_set_changes, 2, authorities.clone(), 6),
+			Err(FinalityProofError::BlockBeforeStartAuthoritySet)
+		));
+
+		// block 5 doesn't signal the change to set 1, so it was forced and can't be proven.
+		assert!(matches!(
+			build_finality_bundle(&*backend, &authority_set_changes, 0, authorities, 6),
+			Err(FinalityProofError::ForcedAuthoritySetChange(1))
+		));
+	}
+
 	#[test]
 	fn finality_proof_in_last_set_fails_without_latest() {
 		let (_, backend, _) = test_blockchain(8, &[4, 5, 8]);
@@ -0,0 +1,226 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Time travel support for the manual seal engine.
+//!
+//! The [`Clock`] is adjusted over rpc and applied on top of the inherent data created by the
+//! user supplied inherent data providers, overriding the timestamp and the Aura/BABE slot of the
+//! next sealed blocks.
+
+use parking_lot::Mutex;
+use sp_consensus_aura::inherents::AuraInherentData;
+use sp_consensus_babe::inherents::BabeInherentData;
+use sp_consensus_slots::{Slot, SlotDuration};
+use sp_inherents::{
+	CreateInherentDataProviders, Error, InherentData, InherentDataProvider, InherentIdentifier,
+};
+use sp_runtime::traits::Block as BlockT;
+use sp_timestamp::{Timestamp, TimestampInherentData};
+use std::sync::Arc;
+
+/// Clock shared between the authorship task and the inherent data providers it creates.
+pub(crate) type SharedClock = Arc<Mutex<Clock>>;
+
+/// Time adjustments applied to the blocks sealed by the manual seal engine.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Clock {
+	/// Milliseconds added to the timestamp of every sealed block.
+	offset: u64,
+	/// Timestamp of the next sealed block.
+	next_timestamp: Option<u64>,
+	/// Slot of the next sealed block.
+	next_slot: Option<Slot>,
+}
+
+impl Clock {
+	/// Sets the timestamp of the next sealed block.
+	pub(crate) fn set_next_timestamp(&mut self, timestamp: u64) {
+		self.next_timestamp = Some(timestamp);
+	}
+
+	/// Sets the slot of the next sealed block.
+	pub(crate) fn set_next_slot(&mut self, slot: Slot) {
+		self.next_slot = Some(slot);
+	}
+
+	/// Moves the clock forward by the given number of milliseconds.
+	///
+	/// Returns the total offset of the clock.
+	pub(crate) fn increase(&mut self, millis: u64) -> u64 {
+		self.offset = self.offset.saturating_add(millis);
+		self.offset
+	}
+
+	/// Applies the clock to the inherent data of the next block.
+	///
+	/// A pending timestamp (or a timestamp derived from a pending slot) replaces the provided
+	/// timestamp, and the clock offset is updated so that the following blocks continue from it.
+	/// The slot inherent data of Aura and BABE is only replaced if it is present.
+	fn apply(&mut self, inherent_data: &mut InherentData, slot_duration: Option<SlotDuration>) {
+		let next_slot = self.next_slot.take();
+		let target = self.next_timestamp.take().or_else(|| {
+			next_slot.zip(slot_duration).and_then(|(slot, duration)| {
+				slot.timestamp(duration).map(|timestamp| timestamp.as_millis())
+			})
+		});
+		let provided = inherent_data.timestamp_inherent_data().ok().flatten();
+
+		let timestamp = match (target, provided) {
+			(Some(target), Some(provided)) => {
+				self.offset = target.saturating_sub(*provided);
+				Some(Timestamp::new(target))
+			},
+			(Some(target), None) => Some(Timestamp::new(target)),
+			(None, Some(provided)) if self.offset != 0 =>
+				Some(Timestamp::new(provided.saturating_add(self.offset))),
+			(None, _) => None,
+		};
+		if let Some(timestamp) = timestamp {
+			inherent_data.replace_data(sp_timestamp::INHERENT_IDENTIFIER, &timestamp);
+		}
+
+		let slot = next_slot
+			.or_else(|| timestamp.zip(slot_duration).map(|(t, d)| Slot::from_timestamp(t, d)));
+		if let Some(slot) = slot {
+			if let Ok(Some(_)) = inherent_data.babe_inherent_data() {
+				inherent_data.babe_replace_inherent_data(slot);
+			}
+			if let Ok(Some(_)) = inherent_data.aura_inherent_data() {
+				inherent_data.aura_replace_inherent_data(slot);
+			}
+		}
+	}
+}
+
+/// Wraps something that can create inherent data providers, applying the [`Clock`] to the
+/// inherent data they provide.
+pub(crate) struct ClockInherentDataProviders<CIDP> {
+	inner: CIDP,
+	clock: SharedClock,
+	slot_duration: Option<SlotDuration>,
+}
+
+impl<CIDP> ClockInherentDataProviders<CIDP> {
+	/// Create a new instance.
+	pub(crate) fn new(
+		inner: CIDP,
+		clock: SharedClock,
+		slot_duration: Option<SlotDuration>,
+	) -> Self {
+		Self { inner, clock, slot_duration }
+	}
+}
+
+#[async_trait::async_trait]
+impl<B, CIDP> CreateInherentDataProviders<B, ()> for ClockInherentDataProviders<CIDP>
+where
+	B: BlockT,
+	CIDP: CreateInherentDataProviders<B, ()>,
+{
+	type InherentDataProviders = (CIDP::InherentDataProviders, ClockInherentDataProvider);
+
+	async fn create_inherent_data_providers(
+		&self,
+		parent: B::Hash,
+		extra_args: (),
+	) -> Result<Self::InherentDataProviders, Box<dyn std::error::Error + Send + Sync>> {
+		let inner = self.inner.create_inherent_data_providers(parent, extra_args).await?;
+		let clock = ClockInherentDataProvider {
+			clock: self.clock.clone(),
+			slot_duration: self.slot_duration,
+		};
+		// Inherent data providers of a tuple are called in order, so the clock is applied last.
+		Ok((inner, clock))
+	}
+}
+
+/// Inherent data provider applying the [`Clock`] to the data provided before it.
+pub(crate) struct ClockInherentDataProvider {
+	clock: SharedClock,
+	slot_duration: Option<SlotDuration>,
+}
+
+#[async_trait::async_trait]
+impl InherentDataProvider for ClockInherentDataProvider {
+	async fn provide_inherent_data(&self, inherent_data: &mut InherentData) -> Result<(), Error> {
+		self.clock.lock().apply(inherent_data, self.slot_duration);
+		Ok(())
+	}
+
+	async fn try_handle_error(
+		&self,
+		_: &InherentIdentifier,
+		_: &[u8],
+	) -> Option<Result<(), Error>> {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SLOT_DURATION: SlotDuration = SlotDuration::from_millis(6000);
+
+	fn inherent_data(timestamp: u64, slot: Option<u64>) -> InherentData {
+		let mut inherent_data = InherentData::new();
+		inherent_data
+			.put_data(sp_timestamp::INHERENT_IDENTIFIER, &Timestamp::new(timestamp))
+			.unwrap();
+		if let Some(slot) = slot {
+			inherent_data.babe_replace_inherent_data(slot.into());
+		}
+		inherent_data
+	}
+
+	#[test]
+	fn clock_moves_timestamp_and_slot_forward() {
+		let mut clock = Clock::default();
+		let mut data = inherent_data(12_000, Some(2));
+		clock.apply(&mut data, Some(SLOT_DURATION));
+		assert_eq!(data.timestamp_inherent_data().unwrap(), Some(Timestamp::new(12_000)));
+		assert_eq!(data.babe_inherent_data().unwrap(), Some(2.into()));
+
+		assert_eq!(clock.increase(60_000), 60_000);
+		let mut data = inherent_data(12_000, Some(2));
+		clock.apply(&mut data, Some(SLOT_DURATION));
+		assert_eq!(data.timestamp_inherent_data().unwrap(), Some(Timestamp::new(72_000)));
+		assert_eq!(data.babe_inherent_data().unwrap(), Some(12.into()));
+		assert_eq!(data.aura_inherent_data().unwrap(), None);
+	}
+
+	#[test]
+	fn clock_applies_next_timestamp_and_slot_once() {
+		let mut clock = Clock::default();
+		clock.set_next_timestamp(120_000);
+		let mut data = inherent_data(12_000, None);
+		clock.apply(&mut data, None);
+		assert_eq!(data.timestamp_inherent_data().unwrap(), Some(Timestamp::new(120_000)));
+
+		// following blocks continue from the timestamp set above.
+		let mut data = inherent_data(18_000, None);
+		clock.apply(&mut data, None);
+		assert_eq!(data.timestamp_inherent_data().unwrap(), Some(Timestamp::new(126_000)));
+
+		clock.set_next_slot(100.into());
+		let mut data = inherent_data(24_000, Some(4));
+		clock.apply(&mut data, Some(SLOT_DURATION));
+		assert_eq!(data.timestamp_inherent_data().unwrap(), Some(Timestamp::new(600_000)));
+		assert_eq!(data.babe_inherent_data().unwrap(), Some(100.into()));
+	}
+}
@@ -20,6 +20,7 @@
 use super::Error;
 
 use sc_consensus::BlockImportParams;
+use sp_consensus_slots::SlotDuration;
 use sp_inherents::InherentData;
 use sp_runtime::{traits::Block as BlockT, Digest};
 
@@ -44,4 +45,11 @@ pub trait ConsensusDataProvider<B: BlockT>: Send + Sync {
 		inherents: &InherentData,
 		proof: Self::Proof,
 	) -> Result<(), Error>;
+
+	/// The slot duration of the consensus engine, if it is slot based.
+	///
+	/// Used to derive the slot of the blocks from their (adjusted) timestamp.
+	fn slot_duration(&self) -> Option<SlotDuration> {
+		None
+	}
 }
@@ -97,4 +97,8 @@ where
 	) -> Result<(), Error> {
 		Ok(())
 	}
+
+	fn slot_duration(&self) -> Option<SlotDuration> {
+		Some(self.slot_duration)
+	}
 }
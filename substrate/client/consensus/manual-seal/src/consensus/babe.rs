@@ -40,7 +40,7 @@ use sp_consensus_babe::{
 	inherents::BabeInherentData,
 	AuthorityId, BabeApi, BabeAuthorityWeight, BabeConfiguration, ConsensusLog, BABE_ENGINE_ID,
 };
-use sp_consensus_slots::Slot;
+use sp_consensus_slots::{Slot, SlotDuration};
 use sp_inherents::InherentData;
 use sp_runtime::{
 	generic::Digest,
@@ -315,4 +315,8 @@ where
 
 		Ok(())
 	}
+
+	fn slot_duration(&self) -> Option<SlotDuration> {
+		Some(self.config.slot_duration())
+	}
 }
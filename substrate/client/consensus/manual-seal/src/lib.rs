@@ -30,13 +30,17 @@ use sc_consensus::{
 	block_import::{BlockImport, BlockImportParams, ForkChoiceStrategy},
 	import_queue::{BasicQueue, BoxBlockImport, Verifier},
 };
-use sp_blockchain::HeaderBackend;
+use sp_blockchain::{Backend as _, HeaderBackend};
 use sp_consensus::{Environment, Proposer, SelectChain};
 use sp_core::traits::SpawnNamed;
 use sp_inherents::CreateInherentDataProviders;
-use sp_runtime::{traits::Block as BlockT, ConsensusEngineId};
-use std::{marker::PhantomData, sync::Arc, time::Duration};
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT, Saturating},
+	ConsensusEngineId, SaturatedConversion,
+};
+use std::{collections::HashMap, marker::PhantomData, sync::Arc, time::Duration};
 
+mod clock;
 mod error;
 mod finalize_block;
 mod seal_block;
@@ -51,7 +55,8 @@ pub use self::{
 	rpc::{CreatedBlock, EngineCommand},
 	seal_block::{seal_block, SealBlockParams, MAX_PROPOSAL_DURATION},
 };
-use sc_transaction_pool_api::TransactionPool;
+use clock::{ClockInherentDataProviders, SharedClock};
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
 use sp_api::ProvideRuntimeApi;
 
 const LOG_TARGET: &str = "manual-seal";
@@ -151,7 +156,48 @@ pub struct DelayedFinalizeParams<C, S> {
 }
 
 /// Creates the background authorship task for the manually seal engine.
+///
+/// [`EngineCommand::Revert`] is not supported, see [`run_manual_seal_with_backend`].
 pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
+	params: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+) where
+	B: BlockT + 'static,
+	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
+	C: HeaderBackend<B> + Finalizer<B, CB> + ProvideRuntimeApi<B> + 'static,
+	CB: ClientBackend<B> + 'static,
+	E: Environment<B> + 'static,
+	E::Proposer: Proposer<B, Proof = P>,
+	CS: Stream<Item = EngineCommand<<B as BlockT>::Hash>> + Unpin + 'static,
+	SC: SelectChain<B> + 'static,
+	TP: TransactionPool<Block = B>,
+	CIDP: CreateInherentDataProviders<B, ()>,
+	P: codec::Encode + Send + Sync + 'static,
+{
+	manual_seal_task(params, None::<Arc<CB>>).await
+}
+
+/// Creates the background authorship task for the manually seal engine, using the client
+/// backend to revert the chain with [`EngineCommand::Revert`].
+pub async fn run_manual_seal_with_backend<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
+	params: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+	backend: Arc<CB>,
+) where
+	B: BlockT + 'static,
+	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
+	C: HeaderBackend<B> + Finalizer<B, CB> + ProvideRuntimeApi<B> + 'static,
+	CB: ClientBackend<B> + 'static,
+	E: Environment<B> + 'static,
+	E::Proposer: Proposer<B, Proof = P>,
+	CS: Stream<Item = EngineCommand<<B as BlockT>::Hash>> + Unpin + 'static,
+	SC: SelectChain<B> + 'static,
+	TP: TransactionPool<Block = B>,
+	CIDP: CreateInherentDataProviders<B, ()>,
+	P: codec::Encode + Send + Sync + 'static,
+{
+	manual_seal_task(params, Some(backend)).await
+}
+
+async fn manual_seal_task<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 	ManualSealParams {
 		mut block_import,
 		mut env,
@@ -162,6 +208,7 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 		consensus_data_provider,
 		create_inherent_data_providers,
 	}: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+	backend: Option<Arc<CB>>,
 ) where
 	B: BlockT + 'static,
 	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
@@ -175,6 +222,15 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 	CIDP: CreateInherentDataProviders<B, ()>,
 	P: codec::Encode + Send + Sync + 'static,
 {
+	let clock = SharedClock::default();
+	let create_inherent_data_providers = ClockInherentDataProviders::new(
+		create_inherent_data_providers,
+		clock.clone(),
+		consensus_data_provider.as_ref().and_then(|provider| provider.slot_duration()),
+	);
+	// the clock at the blocks recorded by `EngineCommand::Snapshot`.
+	let mut snapshots = HashMap::new();
+
 	while let Some(command) = commands_stream.next().await {
 		match command {
 			EngineCommand::SealNewBlock { create_empty, finalize, parent_hash, sender } => {
@@ -204,10 +260,111 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 				})
 				.await
 			},
+			EngineCommand::SetNextBlockTimestamp { timestamp, mut sender } => {
+				clock.lock().set_next_timestamp(timestamp);
+				rpc::send_result(&mut sender, Ok(()))
+			},
+			EngineCommand::SetNextBlockSlot { slot, mut sender } => {
+				clock.lock().set_next_slot(slot.into());
+				rpc::send_result(&mut sender, Ok(()))
+			},
+			EngineCommand::IncreaseTime { millis, mut sender } => {
+				let offset = clock.lock().increase(millis);
+				rpc::send_result(&mut sender, Ok(offset))
+			},
+			EngineCommand::Snapshot { mut sender } => {
+				let hash = client.info().best_hash;
+				snapshots.insert(hash, clock.lock().clone());
+				rpc::send_result(&mut sender, Ok(hash))
+			},
+			EngineCommand::Revert { hash, mut sender } => {
+				let result = match revert_to(&*client, backend.as_deref(), hash) {
+					Ok(extrinsics) => {
+						if let Some(snapshot) = snapshots.get(&hash) {
+							*clock.lock() = snapshot.clone();
+						}
+						resubmit(&*pool, hash, extrinsics).await;
+						Ok(())
+					},
+					Err(e) => Err(e),
+				};
+				rpc::send_result(&mut sender, result)
+			},
 		}
 	}
 }
 
+/// Reverts the best chain to the block with the given hash.
+///
+/// Finalized blocks are never reverted. Returns the extrinsics of the reverted blocks.
+fn revert_to<B, C, CB>(
+	client: &C,
+	backend: Option<&CB>,
+	hash: B::Hash,
+) -> Result<Vec<B::Extrinsic>, Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B>,
+	CB: ClientBackend<B>,
+{
+	let backend = backend
+		.ok_or_else(|| Error::StringError("Reverting requires the client backend".into()))?;
+	let number = client.number(hash)?.ok_or_else(|| Error::BlockNotFound(format!("{}", hash)))?;
+	if client.hash(number)? != Some(hash) {
+		return Err(Error::StringError(format!("Block {} is not on the best chain", hash)))
+	}
+	let info = client.info();
+	if number < info.finalized_number {
+		return Err(Error::StringError(format!(
+			"Cannot revert past the finalized block #{}",
+			info.finalized_number,
+		)))
+	}
+
+	// collect the extrinsics before their blocks are reverted.
+	let mut extrinsics = Vec::new();
+	let mut current = info.best_hash;
+	while current != hash {
+		let blockchain = backend.blockchain();
+		extrinsics.extend(blockchain.body(current)?.unwrap_or_default());
+		current = *blockchain
+			.header(current)?
+			.ok_or_else(|| Error::BlockNotFound(format!("{}", current)))?
+			.parent_hash();
+	}
+
+	let blocks = info.best_number.saturating_sub(number);
+	let (reverted, _) = backend.revert(blocks, false)?;
+	if reverted != blocks {
+		return Err(Error::StringError(format!(
+			"Reverted {} of {} blocks",
+			reverted.saturated_into::<u64>(),
+			blocks.saturated_into::<u64>(),
+		)))
+	}
+	Ok(extrinsics)
+}
+
+/// Resubmits the extrinsics of reverted blocks to the transaction pool.
+///
+/// Extrinsics that are no longer valid, such as inherents, are dropped.
+async fn resubmit<TP: TransactionPool>(
+	pool: &TP,
+	at: <TP::Block as BlockT>::Hash,
+	extrinsics: Vec<<TP::Block as BlockT>::Extrinsic>,
+) {
+	if extrinsics.is_empty() {
+		return
+	}
+	match pool.submit_at(at, TransactionSource::External, extrinsics).await {
+		Ok(results) => {
+			let dropped = results.iter().filter(|result| result.is_err()).count();
+			log::debug!("Resubmitted reverted extrinsics, {} of them dropped", dropped);
+		},
+		Err(e) => log::warn!("Failed to resubmit reverted extrinsics: {}", e),
+	}
+}
+
 /// runs the background authorship task for the instant seal engine.
 /// instant-seal creates a new block for every transaction imported into
 /// the transaction pool.
@@ -346,12 +503,15 @@ pub async fn run_delayed_finalize<B, CB, C, S>(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::rpc::{ManualSeal, ManualSealApiServer};
+	use codec::Encode;
 	use sc_basic_authorship::ProposerFactory;
 	use sc_consensus::ImportedAux;
 	use sc_transaction_pool::{BasicPool, FullChainApi, Options, RevalidationType};
 	use sc_transaction_pool_api::{MaintainedTransactionPool, TransactionPool, TransactionSource};
 	use sp_inherents::InherentData;
 	use sp_runtime::generic::{Digest, DigestItem};
+	use sp_timestamp::TimestampInherentData;
 	use substrate_test_runtime_client::{
 		DefaultTestClientBuilderExt, Sr25519Keyring::*, TestClientBuilder, TestClientBuilderExt,
 	};
@@ -393,6 +553,32 @@ mod tests {
 		}
 	}
 
+	/// Records the timestamp of the block in a post digest.
+	struct TimestampDigestProvider;
+	impl<B: BlockT> ConsensusDataProvider<B> for TimestampDigestProvider {
+		type Proof = ();
+
+		fn create_digest(
+			&self,
+			_parent: &B::Header,
+			_inherents: &InherentData,
+		) -> Result<Digest, Error> {
+			Ok(Digest { logs: vec![] })
+		}
+
+		fn append_block_import(
+			&self,
+			_parent: &B::Header,
+			params: &mut BlockImportParams<B>,
+			inherents: &InherentData,
+			_proof: Self::Proof,
+		) -> Result<(), Error> {
+			let timestamp = inherents.timestamp_inherent_data()?.expect("provided by the test");
+			params.post_digests.push(DigestItem::Other(timestamp.encode()));
+			Ok(())
+		}
+	}
+
 	#[tokio::test]
 	async fn instant_seal() {
 		let builder = TestClientBuilder::new();
@@ -800,4 +986,83 @@ mod tests {
 		let header = client.header(created_block.hash).unwrap().unwrap();
 		assert_eq!(header.number, 1);
 	}
+
+	#[tokio::test]
+	async fn manual_seal_time_travel_and_revert() {
+		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			api(),
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+		let (sink, commands_stream) = futures::channel::mpsc::channel(1024);
+
+		// spawn the background authorship task
+		tokio::spawn(run_manual_seal_with_backend(
+			ManualSealParams {
+				block_import: client.clone(),
+				env,
+				client: client.clone(),
+				pool: pool.clone(),
+				commands_stream,
+				select_chain,
+				consensus_data_provider: Some(Box::new(TimestampDigestProvider)),
+				create_inherent_data_providers: |_, _| async {
+					Ok(sp_timestamp::InherentDataProvider::new(1_000.into()))
+				},
+			},
+			backend,
+		));
+
+		let rpc = ManualSeal::new(sink);
+		let timestamp = |hash| {
+			let header = client.header(hash).unwrap().unwrap();
+			header.digest.logs.last().cloned()
+		};
+		let digest = |timestamp: u64| Some(DigestItem::Other(timestamp.encode()));
+
+		let first = rpc.create_block(true, false, None).await.unwrap();
+		assert_eq!(timestamp(first.hash), digest(1_000));
+		let snapshot = rpc.snapshot().await.unwrap();
+		assert_eq!(snapshot, first.hash);
+
+		// move the time forward and seal several blocks at once.
+		assert_eq!(rpc.increase_time(60_000).await.unwrap(), 60_000);
+		let blocks = rpc.create_blocks(2, false).await.unwrap();
+		assert_eq!(blocks.len(), 2);
+		assert!(blocks.iter().all(|block| timestamp(block.hash) == digest(61_000)));
+		assert!(rpc.create_blocks(rpc::MAX_CREATE_BLOCKS + 1, false).await.is_err());
+
+		assert!(rpc.set_next_block_timestamp(200_000).await.unwrap());
+		let block = rpc.create_block(true, false, None).await.unwrap();
+		assert_eq!(timestamp(block.hash), digest(200_000));
+		assert_eq!(client.info().best_number, 4);
+
+		// revert to the snapshot, the time adjustments are reverted too.
+		assert!(rpc.revert(snapshot).await.unwrap());
+		assert_eq!(client.info().best_hash, snapshot);
+		let block = rpc.create_block(true, false, None).await.unwrap();
+		assert_eq!(client.header(block.hash).unwrap().unwrap().number, 2);
+		assert_eq!(timestamp(block.hash), digest(1_000));
+
+		// the reverted blocks are not on the best chain anymore.
+		assert!(rpc.revert(blocks[0].hash).await.is_err());
+
+		// finalized blocks are never reverted.
+		assert!(rpc.finalize_block(block.hash, None).await.unwrap());
+		assert!(rpc.revert(snapshot).await.is_err());
+		assert_eq!(client.info().best_hash, block.hash);
+	}
 }
@@ -28,10 +28,14 @@ use sc_consensus::ImportedAux;
 use serde::{Deserialize, Serialize};
 use sp_runtime::EncodedJustification;
 
+/// Maximum number of blocks that can be created with a single `engine_createBlocks` call.
+pub const MAX_CREATE_BLOCKS: u32 = 1024;
+
 /// Sender passed to the authorship task to report errors or successes.
 pub type Sender<T> = Option<oneshot::Sender<std::result::Result<T, Error>>>;
 
 /// Message sent to the background authorship task, usually by RPC.
+#[non_exhaustive]
 pub enum EngineCommand<Hash> {
 	/// Tells the engine to propose a new block
 	///
@@ -59,6 +63,48 @@ pub enum EngineCommand<Hash> {
 		/// finalization justification
 		justification: Option<EncodedJustification>,
 	},
+	/// Tells the engine to use the supplied timestamp for the next block.
+	///
+	/// The following blocks continue from this timestamp.
+	SetNextBlockTimestamp {
+		/// timestamp of the next block, in milliseconds.
+		timestamp: u64,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
+	/// Tells the engine to use the supplied slot for the next block.
+	///
+	/// The timestamp of the block is derived from the slot if the consensus data provider is
+	/// slot based.
+	SetNextBlockSlot {
+		/// slot of the next block.
+		slot: u64,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
+	/// Tells the engine to move the timestamp of the following blocks forward.
+	IncreaseTime {
+		/// duration to move forward, in milliseconds.
+		millis: u64,
+		/// sender to report the total time offset, in milliseconds, to the rpc.
+		sender: Sender<u64>,
+	},
+	/// Tells the engine to record the current best block, so it can be reverted to later.
+	Snapshot {
+		/// sender to report the hash of the recorded block to the rpc.
+		sender: Sender<Hash>,
+	},
+	/// Tells the engine to revert the chain to the block with the supplied hash.
+	///
+	/// The block must be on the best chain. If it was recorded by [`EngineCommand::Snapshot`],
+	/// the time adjustments made since then are reverted as well. Finalized blocks are never
+	/// reverted, the extrinsics of the reverted blocks are resubmitted to the transaction pool.
+	Revert {
+		/// hash of the block
+		hash: Hash,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
 }
 
 /// RPC trait that provides methods for interacting with the manual-seal authorship task over rpc.
@@ -80,6 +126,36 @@ pub trait ManualSealApi<Hash> {
 		hash: Hash,
 		justification: Option<EncodedJustification>,
 	) -> Result<bool, Error>;
+
+	/// Instructs the manual-seal authorship task to create several blocks in a row
+	///
+	/// At most [`MAX_CREATE_BLOCKS`] blocks can be created at once.
+	#[method(name = "engine_createBlocks")]
+	async fn create_blocks(
+		&self,
+		count: u32,
+		finalize: bool,
+	) -> Result<Vec<CreatedBlock<Hash>>, Error>;
+
+	/// Sets the timestamp, in milliseconds, of the next block
+	#[method(name = "engine_setNextBlockTimestamp")]
+	async fn set_next_block_timestamp(&self, timestamp: u64) -> Result<bool, Error>;
+
+	/// Sets the slot of the next block
+	#[method(name = "engine_setNextBlockSlot")]
+	async fn set_next_block_slot(&self, slot: u64) -> Result<bool, Error>;
+
+	/// Moves the timestamp of the next blocks forward, returns the total offset in milliseconds
+	#[method(name = "engine_increaseTime")]
+	async fn increase_time(&self, millis: u64) -> Result<u64, Error>;
+
+	/// Records the current best block, returns its hash
+	#[method(name = "engine_snapshot")]
+	async fn snapshot(&self) -> Result<Hash, Error>;
+
+	/// Reverts the chain to the block with the given hash
+	#[method(name = "engine_revert")]
+	async fn revert(&self, hash: Hash) -> Result<bool, Error>;
 }
 
 /// A struct that implements the [`ManualSealApiServer`].
@@ -103,6 +179,17 @@ impl<Hash> ManualSeal<Hash> {
 	pub fn new(import_block_channel: mpsc::Sender<EngineCommand<Hash>>) -> Self {
 		Self { import_block_channel }
 	}
+
+	/// Sends the command built with the given sender and waits for its result.
+	async fn send_command<T>(
+		&self,
+		command: impl FnOnce(Sender<T>) -> EngineCommand<Hash> + Send,
+	) -> Result<T, Error> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		sink.send(command(Some(sender))).await?;
+		receiver.await?
+	}
 }
 
 #[async_trait]
@@ -143,6 +230,57 @@ impl<Hash: Send + 'static> ManualSealApiServer<Hash> for ManualSeal<Hash> {
 		sink.send(command).await?;
 		receiver.await.map(|_| true).map_err(Into::into)
 	}
+
+	async fn create_blocks(
+		&self,
+		count: u32,
+		finalize: bool,
+	) -> Result<Vec<CreatedBlock<Hash>>, Error> {
+		if count > MAX_CREATE_BLOCKS {
+			return Err(Error::StringError(format!(
+				"Cannot create more than {MAX_CREATE_BLOCKS} blocks at once, requested {count}"
+			)))
+		}
+		let mut blocks = Vec::with_capacity(count as usize);
+		for _ in 0..count {
+			let block = self
+				.send_command(|sender| EngineCommand::SealNewBlock {
+					create_empty: true,
+					finalize,
+					parent_hash: None,
+					sender,
+				})
+				.await?;
+			blocks.push(block);
+		}
+		Ok(blocks)
+	}
+
+	async fn set_next_block_timestamp(&self, timestamp: u64) -> Result<bool, Error> {
+		self.send_command(|sender| EngineCommand::SetNextBlockTimestamp { timestamp, sender })
+			.await
+			.map(|_| true)
+	}
+
+	async fn set_next_block_slot(&self, slot: u64) -> Result<bool, Error> {
+		self.send_command(|sender| EngineCommand::SetNextBlockSlot { slot, sender })
+			.await
+			.map(|_| true)
+	}
+
+	async fn increase_time(&self, millis: u64) -> Result<u64, Error> {
+		self.send_command(|sender| EngineCommand::IncreaseTime { millis, sender }).await
+	}
+
+	async fn snapshot(&self) -> Result<Hash, Error> {
+		self.send_command(|sender| EngineCommand::Snapshot { sender }).await
+	}
+
+	async fn revert(&self, hash: Hash) -> Result<bool, Error> {
+		self.send_command(|sender| EngineCommand::Revert { hash, sender })
+			.await
+			.map(|_| true)
+	}
 }
 
 /// report any errors or successes encountered by the authorship task back
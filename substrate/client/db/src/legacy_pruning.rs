@@ -0,0 +1,353 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Computation of the trie nodes that died between two blocks.
+//!
+//! Archive pruning modes don't journal the nodes removed by each block. When such a database is
+//! switched to a constrained pruning mode, the nodes of the blocks imported before the switch are
+//! found by walking the state tries of two consecutive blocks side by side. Subtrees with the same
+//! root hash are skipped, so the cost is proportional to the size of the change.
+//!
+//! Databases without reference counting store every node under its position in the trie. A node
+//! that died in a legacy block may have been inserted again at the same position by a later block,
+//! so the dead nodes that are still part of the last canonicalized state are kept.
+
+use crate::{
+	columns,
+	trie_diff::{NodeKey, TrieDiff},
+	DbHash,
+};
+use hash_db::{Hasher, Prefix};
+use sp_database::Database;
+use std::{
+	collections::{HashMap, HashSet},
+	sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Nodes of the legacy state that died in a batch of blocks.
+pub(crate) struct LegacyStateBatch {
+	/// The first block of the batch.
+	pub start: u64,
+	/// The block after the last block of the batch.
+	pub next: u64,
+	/// Keys of the state column to delete.
+	pub deleted: Vec<Vec<u8>>,
+}
+
+/// Computes the nodes that died in the blocks `start..start + roots.len() - 1`, given the state
+/// roots of the blocks `start..start + roots.len()`. Blocks without state are skipped.
+///
+/// Databases with prefixed keys pass the state root of the last canonicalized block as
+/// `canonical_root`: the dead nodes that are part of that state are kept.
+///
+/// Fails without a result as soon as `interrupted` is set.
+pub(crate) fn batch_dead_nodes<H: Hasher>(
+	db: &dyn Database<DbHash>,
+	start: u64,
+	roots: Vec<Option<H::Out>>,
+	canonical_root: Option<H::Out>,
+	interrupted: &AtomicBool,
+) -> Result<LegacyStateBatch, String> {
+	let mut deleted = Vec::new();
+	let mut prefixed = HashSet::new();
+	for (number, pair) in (start..).zip(roots.windows(2)) {
+		if interrupted.load(Ordering::Relaxed) {
+			return Err("Interrupted".into())
+		}
+		let [Some(old_root), Some(new_root)] = pair else { continue };
+		let result = match canonical_root {
+			Some(_) => dead_prefixed_nodes::<H>(db, old_root.as_ref(), new_root.as_ref())
+				.map(|dead| prefixed.extend(dead)),
+			None => dead_nodes::<H>(db, old_root.as_ref(), new_root.as_ref())
+				.map(|dead| deleted.extend(dead)),
+		};
+		if let Err(e) = result {
+			log::warn!("Failed to prune the legacy state of block #{}: {}", number, e);
+		}
+	}
+	if interrupted.load(Ordering::Relaxed) {
+		return Err("Interrupted".into())
+	}
+	if let Some(canonical_root) = canonical_root {
+		deleted = prefixed_keys_outside::<H>(db, canonical_root.as_ref(), prefixed)?;
+	}
+	Ok(LegacyStateBatch { start, next: start + roots.len().saturating_sub(1) as u64, deleted })
+}
+
+/// Returns the keys of the state column whose reference count drops when going from the state
+/// with root `old_root` to the state with root `new_root`.
+///
+/// A key is returned once per dropped reference. Only works for databases that don't prefix the
+/// trie node keys.
+pub(crate) fn dead_nodes<H: Hasher>(
+	db: &dyn Database<DbHash>,
+	old_root: &[u8],
+	new_root: &[u8],
+) -> Result<Vec<Vec<u8>>, String> {
+	let get = |hash: &[u8], _: Prefix| Ok(db.get(columns::STATE, hash));
+	let mut references = HashMap::<Vec<u8>, i64>::new();
+	let mut visit = |key: &NodeKey, _: &[u8], delta: i64| {
+		*references.entry(key.hash.clone()).or_default() += delta;
+		Ok(())
+	};
+	TrieDiff::<H>::new(&get, &mut visit).diff_roots(Some(old_root), Some(new_root))?;
+
+	let mut dead = Vec::new();
+	for (key, references) in references {
+		for _ in references..0 {
+			dead.push(key.clone());
+		}
+	}
+	Ok(dead)
+}
+
+/// Returns the nodes of a database with prefixed keys that are part of the state with root
+/// `old_root`, but not of the state with root `new_root`.
+///
+/// Nodes that were already deleted from the database are skipped, together with their subtrees.
+pub(crate) fn dead_prefixed_nodes<H: Hasher>(
+	db: &dyn Database<DbHash>,
+	old_root: &[u8],
+	new_root: &[u8],
+) -> Result<HashSet<NodeKey>, String> {
+	let get = |hash: &[u8], prefix: Prefix| Ok(db.get(columns::STATE, &prefixed_key(hash, prefix)));
+	let mut references = HashMap::<NodeKey, i64>::new();
+	let mut visit = |key: &NodeKey, _: &[u8], delta: i64| {
+		*references.entry(key.clone()).or_default() += delta;
+		Ok(())
+	};
+	TrieDiff::<H>::new(&get, &mut visit)
+		.skip_missing()
+		.diff_roots(Some(old_root), Some(new_root))?;
+
+	Ok(references
+		.into_iter()
+		.filter(|(_, references)| *references < 0)
+		.map(|(key, _)| key)
+		.collect())
+}
+
+/// Returns the prefixed keys of the `dead` nodes that are not part of the state with root `root`.
+pub(crate) fn prefixed_keys_outside<H: Hasher>(
+	db: &dyn Database<DbHash>,
+	root: &[u8],
+	mut dead: HashSet<NodeKey>,
+) -> Result<Vec<Vec<u8>>, String> {
+	let get = |hash: &[u8], prefix: Prefix| Ok(db.get(columns::STATE, &prefixed_key(hash, prefix)));
+	let mut visit = |_: &NodeKey, _: &[u8], _| Ok(());
+	TrieDiff::<H>::new(&get, &mut visit).remove_present(root, &mut dead)?;
+	Ok(dead.iter().map(|key| prefixed_key(&key.hash, key.as_prefix())).collect())
+}
+
+/// Key of a node in a database with prefixed keys, see `sp_trie::prefixed_key`.
+fn prefixed_key(hash: &[u8], prefix: Prefix) -> Vec<u8> {
+	let mut key = Vec::with_capacity(prefix.0.len() + 1 + hash.len());
+	key.extend_from_slice(prefix.0);
+	key.extend(prefix.1);
+	key.extend_from_slice(hash);
+	key
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{Blake2Hasher, H256};
+	use sp_trie::{trie_types::TrieDBMutBuilderV1, HashDBT, MemoryDB, PrefixedMemoryDB, TrieMut};
+	use std::collections::{BTreeMap, HashSet};
+
+	/// Reference counted state column of an archive node: nodes are never released.
+	#[derive(Default)]
+	struct ArchiveDb(parking_lot::RwLock<BTreeMap<Vec<u8>, (Vec<u8>, i64)>>);
+
+	impl Database<DbHash> for ArchiveDb {
+		fn commit(&self, _: sp_database::Transaction<DbHash>) -> sp_database::error::Result<()> {
+			unimplemented!()
+		}
+
+		fn get(&self, col: sp_database::ColumnId, key: &[u8]) -> Option<Vec<u8>> {
+			assert_eq!(col, columns::STATE);
+			self.0.read().get(key).map(|(value, _)| value.clone())
+		}
+	}
+
+	impl ArchiveDb {
+		fn commit(&self, root: Option<H256>, changes: &[(Vec<u8>, Option<Vec<u8>>)]) -> H256 {
+			let mut mdb = MemoryDB::<Blake2Hasher>::default();
+			let existing: HashSet<_> = self.0.read().keys().cloned().collect();
+			for (key, (value, _)) in self.0.read().iter() {
+				mdb.emplace(H256::from_slice(key), hash_db::EMPTY_PREFIX, value.clone());
+			}
+			let mut root = root.unwrap_or_default();
+			{
+				let mut trie = if root == H256::default() {
+					TrieDBMutBuilderV1::<Blake2Hasher>::new(&mut mdb, &mut root).build()
+				} else {
+					TrieDBMutBuilderV1::<Blake2Hasher>::from_existing(&mut mdb, &mut root).build()
+				};
+				for (key, value) in changes {
+					match value {
+						Some(value) => trie.insert(key, value).unwrap(),
+						None => trie.remove(key).unwrap(),
+					};
+				}
+			}
+			let mut data = self.0.write();
+			for (key, (value, rc)) in mdb.drain() {
+				let key = key.as_ref().to_vec();
+				let delta = rc as i64 - existing.contains(&key) as i64;
+				if delta > 0 {
+					data.entry(key).or_insert_with(|| (value, 0)).1 += delta;
+				}
+			}
+			root
+		}
+
+		fn release(&self, keys: Vec<Vec<u8>>) {
+			let mut data = self.0.write();
+			for key in keys {
+				let references = &mut data.get_mut(&key).unwrap().1;
+				*references -= 1;
+				if *references == 0 {
+					data.remove(&key);
+				}
+			}
+		}
+
+		fn keys(&self) -> Vec<Vec<u8>> {
+			self.0.read().keys().cloned().collect()
+		}
+	}
+
+	fn state(entries: impl Iterator<Item = (Vec<u8>, Vec<u8>)>) -> Vec<(Vec<u8>, Option<Vec<u8>>)> {
+		entries.map(|(key, value)| (key, Some(value))).collect()
+	}
+
+	#[test]
+	fn dead_nodes_leave_only_the_new_state() {
+		let long_value = vec![7u8; 64];
+		let initial = state(
+			(0u32..200)
+				.map(|i| (i.to_le_bytes().to_vec(), long_value.clone()))
+				.chain([(b"short".to_vec(), b"value".to_vec())]),
+		);
+		let changes = vec![
+			(1u32.to_le_bytes().to_vec(), None),
+			(7u32.to_le_bytes().to_vec(), Some(vec![1, 2, 3])),
+			(b"new".to_vec(), Some(b"v".to_vec())),
+		];
+
+		let db = ArchiveDb::default();
+		let old_root = db.commit(None, &initial);
+		let new_root = db.commit(Some(old_root), &changes);
+
+		let fresh = ArchiveDb::default();
+		let fresh_root = fresh.commit(None, &initial);
+		let fresh_root = fresh.commit(Some(fresh_root), &changes);
+		assert_eq!(fresh_root, new_root);
+		let expected = ArchiveDb::default();
+		expected.commit(
+			None,
+			&state(
+				(0u32..200)
+					.filter(|i| *i != 1 && *i != 7)
+					.map(|i| (i.to_le_bytes().to_vec(), long_value.clone()))
+					.chain([
+						(7u32.to_le_bytes().to_vec(), vec![1, 2, 3]),
+						(b"new".to_vec(), b"v".to_vec()),
+						(b"short".to_vec(), b"value".to_vec()),
+					]),
+			),
+		);
+
+		let dead = dead_nodes::<Blake2Hasher>(&db, old_root.as_ref(), new_root.as_ref()).unwrap();
+		assert!(!dead.is_empty());
+		db.release(dead);
+		assert_eq!(db.keys(), expected.keys());
+	}
+
+	#[test]
+	fn unchanged_state_has_no_dead_nodes() {
+		let db = ArchiveDb::default();
+		let root = db.commit(None, &state((0u32..10).map(|i| (vec![i as u8], vec![i as u8; 40]))));
+		assert!(dead_nodes::<Blake2Hasher>(&db, root.as_ref(), root.as_ref())
+			.unwrap()
+			.is_empty());
+	}
+
+	/// State column of an archive node with prefixed keys.
+	struct PrefixedDb(parking_lot::RwLock<BTreeMap<Vec<u8>, Vec<u8>>>);
+
+	impl Database<DbHash> for PrefixedDb {
+		fn commit(&self, _: sp_database::Transaction<DbHash>) -> sp_database::error::Result<()> {
+			unimplemented!()
+		}
+
+		fn get(&self, col: sp_database::ColumnId, key: &[u8]) -> Option<Vec<u8>> {
+			assert_eq!(col, columns::STATE);
+			self.0.read().get(key).cloned()
+		}
+	}
+
+	#[test]
+	fn prefixed_dead_nodes_exclude_live_nodes() {
+		let mut mdb = PrefixedMemoryDB::<Blake2Hasher>::default();
+		let mut old_root = H256::default();
+		{
+			let mut trie = TrieDBMutBuilderV1::<Blake2Hasher>::new(&mut mdb, &mut old_root).build();
+			for i in 0u32..200 {
+				trie.insert(&i.to_le_bytes(), &[7u8; 64]).unwrap();
+			}
+		}
+		let mut old_mdb = mdb.clone();
+		let mut new_root = old_root;
+		{
+			let mut trie =
+				TrieDBMutBuilderV1::<Blake2Hasher>::from_existing(&mut mdb, &mut new_root).build();
+			trie.remove(&1u32.to_le_bytes()).unwrap();
+			trie.insert(&7u32.to_le_bytes(), &[1, 2, 3]).unwrap();
+		}
+		let db = PrefixedDb(parking_lot::RwLock::new(
+			old_mdb
+				.drain()
+				.into_iter()
+				.chain(mdb.drain())
+				.filter(|(_, (_, rc))| *rc > 0)
+				.map(|(key, (value, _))| (key, value))
+				.collect(),
+		));
+
+		let dead =
+			dead_prefixed_nodes::<Blake2Hasher>(&db, old_root.as_ref(), new_root.as_ref()).unwrap();
+		assert!(!dead.is_empty());
+		// All dead nodes are part of the old state, none of the new one.
+		assert!(prefixed_keys_outside::<Blake2Hasher>(&db, old_root.as_ref(), dead.clone())
+			.unwrap()
+			.is_empty());
+		let keys =
+			prefixed_keys_outside::<Blake2Hasher>(&db, new_root.as_ref(), dead.clone()).unwrap();
+		assert_eq!(keys.len(), dead.len());
+
+		// Nodes deleted by the pruning window are skipped.
+		for key in &keys {
+			db.0.write().remove(key);
+		}
+		assert!(dead_prefixed_nodes::<Blake2Hasher>(&db, old_root.as_ref(), new_root.as_ref())
+			.unwrap()
+			.is_empty());
+	}
+}
@@ -31,25 +31,31 @@
 pub mod offchain;
 
 pub mod bench;
+pub mod snapshot;
 
 mod children;
+mod legacy_pruning;
 mod parity_db;
 mod pinned_blocks_cache;
 mod record_stats_state;
 mod stats;
+mod trie_diff;
 #[cfg(any(feature = "rocksdb", test))]
 mod upgrade;
 mod utils;
 
 use linked_hash_map::LinkedHashMap;
-use log::{debug, trace, warn};
+use log::{debug, info, trace, warn};
 use parking_lot::{Mutex, RwLock};
 use prometheus_endpoint::Registry;
 use std::{
 	collections::{HashMap, HashSet},
 	io,
 	path::{Path, PathBuf},
-	sync::Arc,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
 };
 
 use crate::{
@@ -103,6 +109,10 @@ pub use bench::BenchmarkingState;
 
 const CACHE_HEADERS: usize = 8;
 
+/// Number of blocks whose legacy state is pruned at once after switching from an archive state
+/// pruning mode.
+const LEGACY_STATE_PRUNING_BATCH: u64 = 8;
+
 /// DB-backed patricia trie state, transaction type is an overlay of changes to commit.
 pub type DbState<H> = sp_state_machine::TrieBackend<Arc<dyn sp_state_machine::Storage<H>>, H>;
 
@@ -1119,6 +1129,19 @@ pub struct Backend<Block: BlockT> {
 	state_usage: Arc<StateUsageStats>,
 	genesis_state: RwLock<Option<Arc<DbGenesisStorage<Block>>>>,
 	shared_trie_cache: Option<sp_trie::cache::SharedTrieCache<HashingFor<Block>>>,
+	legacy_state_pruner:
+		Mutex<Option<std::thread::JoinHandle<Result<legacy_pruning::LegacyStateBatch, String>>>>,
+	legacy_state_pruner_interrupted: Arc<AtomicBool>,
+}
+
+impl<Block: BlockT> Drop for Backend<Block> {
+	fn drop(&mut self) {
+		// Stop the legacy state pruning thread, so that it doesn't outlive the database.
+		self.legacy_state_pruner_interrupted.store(true, Ordering::Relaxed);
+		if let Some(handle) = self.legacy_state_pruner.get_mut().take() {
+			let _ = handle.join();
+		}
+	}
 }
 
 impl<Block: BlockT> Backend<Block> {
@@ -1126,6 +1149,27 @@ impl<Block: BlockT> Backend<Block> {
 	///
 	/// The pruning window is how old a block must be before the state is pruned.
 	pub fn new(db_config: DatabaseSettings, canonicalization_delay: u64) -> ClientResult<Self> {
+		Self::open(db_config, canonicalization_delay, false)
+	}
+
+	/// Create a new instance of database backend, switching the state pruning mode of an existing
+	/// database to the requested one.
+	///
+	/// [`Backend::new`] fails to open a database whose stored state pruning mode differs from the
+	/// requested one. The state that is no longer needed after the switch is pruned in batches in
+	/// the background, see [`Backend::legacy_state_pruning`].
+	pub fn new_with_state_pruning_migration(
+		db_config: DatabaseSettings,
+		canonicalization_delay: u64,
+	) -> ClientResult<Self> {
+		Self::open(db_config, canonicalization_delay, true)
+	}
+
+	fn open(
+		db_config: DatabaseSettings,
+		canonicalization_delay: u64,
+		state_pruning_migration: bool,
+	) -> ClientResult<Self> {
 		use utils::OpenDbError;
 
 		let db_source = &db_config.source;
@@ -1141,7 +1185,13 @@ impl<Block: BlockT> Backend<Block> {
 				Err(as_is) => return Err(as_is.into()),
 			};
 
-		Self::from_database(db as Arc<_>, canonicalization_delay, &db_config, needs_init)
+		Self::from_database(
+			db as Arc<_>,
+			canonicalization_delay,
+			&db_config,
+			needs_init,
+			state_pruning_migration,
+		)
 	}
 
 	/// Reset the shared trie cache.
@@ -1213,6 +1263,7 @@ impl<Block: BlockT> Backend<Block> {
 		canonicalization_delay: u64,
 		config: &DatabaseSettings,
 		should_init: bool,
+		state_pruning_migration: bool,
 	) -> ClientResult<Self> {
 		let mut db_init_transaction = Transaction::new();
 
@@ -1220,19 +1271,44 @@ impl<Block: BlockT> Backend<Block> {
 		let state_meta_db = StateMetaDb(db.clone());
 		let map_e = sp_blockchain::Error::from_state_db;
 
-		let (state_db_init_commit_set, state_db) = StateDb::open(
-			state_meta_db,
-			requested_state_pruning,
-			!db.supports_ref_counting(),
-			should_init,
-		)
-		.map_err(map_e)?;
+		let open_state_db = |requested_state_pruning| {
+			StateDb::open(
+				state_meta_db.clone(),
+				requested_state_pruning,
+				!db.supports_ref_counting(),
+				should_init,
+			)
+		};
+		let (state_db_init_commit_set, state_db, migrate_to) =
+			match open_state_db(requested_state_pruning.clone()) {
+				Err(sc_state_db::Error::StateDb(
+					sc_state_db::StateDbError::IncompatiblePruningModes { .. },
+				)) if state_pruning_migration => {
+					let (commit_set, state_db) = open_state_db(None).map_err(map_e)?;
+					(commit_set, state_db, requested_state_pruning)
+				},
+				result => {
+					let (commit_set, state_db) = result.map_err(map_e)?;
+					(commit_set, state_db, None)
+				},
+			};
 
 		apply_state_commit(&mut db_init_transaction, state_db_init_commit_set);
 
+		let blockchain = BlockchainDb::new(db.clone())?;
+
+		if let Some(mode) = migrate_to {
+			Self::migrate_state_pruning(
+				&state_meta_db,
+				&blockchain,
+				&state_db,
+				mode,
+				&mut db_init_transaction,
+			)?;
+		}
+
 		let state_pruning_used = state_db.pruning_mode();
 		let is_archive_pruning = state_pruning_used.is_archive();
-		let blockchain = BlockchainDb::new(db.clone())?;
 
 		let storage_db =
 			StorageDb { db: db.clone(), state_db, prefix_keys: !db.supports_ref_counting() };
@@ -1267,6 +1343,8 @@ impl<Block: BlockT> Backend<Block> {
 			blocks_pruning: config.blocks_pruning,
 			genesis_state: RwLock::new(None),
 			shared_trie_cache,
+			legacy_state_pruner: Mutex::new(None),
+			legacy_state_pruner_interrupted: Arc::new(AtomicBool::new(false)),
 		};
 
 		// Older DB versions have no last state key. Check if the state is available and set it.
@@ -1292,6 +1370,166 @@ impl<Block: BlockT> Backend<Block> {
 		Ok(backend)
 	}
 
+	/// Switch the state pruning mode of an existing database to `mode`.
+	///
+	/// [`PruningMode::ArchiveAll`] doesn't track the blocks above the last finalized one, so they
+	/// are re-inserted without changes when leaving it. Their state is kept in the database.
+	fn migrate_state_pruning(
+		state_meta_db: &StateMetaDb,
+		blockchain: &BlockchainDb<Block>,
+		state_db: &StateDb<Block::Hash, Vec<u8>, StateMetaDb>,
+		mode: PruningMode,
+		transaction: &mut Transaction<DbHash>,
+	) -> ClientResult<()> {
+		let map_e = sp_blockchain::Error::from_state_db;
+		let info = blockchain.info();
+		let stored_mode = state_db.pruning_mode();
+		info!("🗃️  Migrating the state pruning mode from {:?} to {:?}", stored_mode, mode);
+
+		let finalized_number = info.finalized_number.saturated_into::<u64>();
+		let commit = state_db
+			.migrate_pruning_mode(
+				state_meta_db.clone(),
+				mode,
+				Some((info.finalized_hash, finalized_number)),
+			)
+			.map_err(map_e)?;
+		apply_state_commit(transaction, commit);
+
+		if stored_mode == PruningMode::ArchiveAll {
+			let mut inserted = HashSet::new();
+			for leaf in blockchain.leaves()? {
+				let mut route = Vec::new();
+				let mut hash = leaf;
+				let mut header = blockchain.expect_header(hash)?;
+				while *header.number() > info.finalized_number && !inserted.contains(&hash) {
+					let parent_hash = *header.parent_hash();
+					route.push((hash, *header.number(), parent_hash));
+					hash = parent_hash;
+					header = blockchain.expect_header(hash)?;
+				}
+				// Skip the forks that don't build on the last finalized block.
+				if *header.number() == info.finalized_number && hash != info.finalized_hash {
+					continue
+				}
+				for (hash, number, parent_hash) in route.into_iter().rev() {
+					let commit = state_db
+						.insert_block(
+							&hash,
+							number.saturated_into::<u64>(),
+							&parent_hash,
+							Default::default(),
+						)
+						.map_err(map_e)?;
+					apply_state_commit(transaction, commit);
+					inserted.insert(hash);
+				}
+			}
+		}
+
+		if let Some(legacy_pruning) = state_db.legacy_pruning() {
+			info!(
+				"🗃️  The state of blocks #{}..#{} will be pruned in the background",
+				legacy_pruning.next, legacy_pruning.end,
+			);
+		}
+		Ok(())
+	}
+
+	/// Returns the range of blocks whose state is still waiting to be pruned after switching from
+	/// an archive state pruning mode.
+	pub fn legacy_state_pruning(&self) -> Option<sc_state_db::LegacyPruning> {
+		self.storage.state_db.legacy_pruning()
+	}
+
+	/// Prune the state of the blocks that were imported before switching from an archive state
+	/// pruning mode.
+	///
+	/// The dead nodes of each batch of blocks are computed on a background thread, so that
+	/// finalization is not blocked by walking the legacy states. Applies the batch computed since
+	/// the last call, if any, and starts computing the next one.
+	fn prune_legacy_state(&self, transaction: &mut Transaction<DbHash>) -> ClientResult<()> {
+		let mut pruner = self.legacy_state_pruner.lock();
+		if pruner.as_ref().is_some_and(|handle| !handle.is_finished()) {
+			return Ok(())
+		}
+		if let Some(handle) = pruner.take() {
+			match handle.join() {
+				Ok(Ok(batch)) => self.apply_legacy_state_batch(batch, transaction),
+				Ok(Err(e)) => warn!("Failed to prune the legacy state: {}", e),
+				Err(_) => warn!("Failed to prune the legacy state: the pruning thread panicked"),
+			}
+		}
+
+		let Some((legacy_pruning, canonical)) = self.storage.state_db.start_legacy_pruning() else {
+			return Ok(())
+		};
+		let canonical_root = match canonical {
+			// The last canonicalized block may be committed with this transaction.
+			Some(hash) => match self.blockchain.header(hash)? {
+				Some(header) => Some(*header.state_root()),
+				None => return Ok(()),
+			},
+			None => None,
+		};
+		let next =
+			std::cmp::min(legacy_pruning.next + LEGACY_STATE_PRUNING_BATCH, legacy_pruning.end);
+		let roots = (legacy_pruning.next..=next)
+			.map(|number| self.legacy_state_root(number))
+			.collect::<ClientResult<Vec<_>>>()?;
+
+		let db = self.storage.db.clone();
+		let interrupted = self.legacy_state_pruner_interrupted.clone();
+		let handle = std::thread::Builder::new()
+			.name("legacy-state-pruning".into())
+			.spawn(move || {
+				legacy_pruning::batch_dead_nodes::<HashingFor<Block>>(
+					&*db,
+					legacy_pruning.next,
+					roots,
+					canonical_root,
+					&interrupted,
+				)
+			})
+			.map_err(|e| {
+				sp_blockchain::Error::Backend(format!("Failed to spawn legacy state pruning: {e}"))
+			})?;
+		*pruner = Some(handle);
+		Ok(())
+	}
+
+	fn apply_legacy_state_batch(
+		&self,
+		batch: legacy_pruning::LegacyStateBatch,
+		transaction: &mut Transaction<DbHash>,
+	) {
+		// The pruning mode may have been changed while the batch was computed.
+		let Some(legacy_pruning) = self.storage.state_db.legacy_pruning() else { return };
+		if legacy_pruning.next != batch.start {
+			return
+		}
+
+		let commit = self.storage.state_db.note_legacy_pruned(batch.next, batch.deleted);
+		apply_state_commit(transaction, commit);
+
+		match self.storage.state_db.legacy_pruning() {
+			None => info!("🗃️  Finished pruning the legacy state up to #{}", legacy_pruning.end),
+			Some(progress)
+				if progress.next == batch.next && batch.next / 1000 != batch.start / 1000 =>
+				info!("🗃️  Pruned the state of blocks up to #{} of #{}", batch.next, progress.end),
+			_ => {},
+		}
+	}
+
+	/// Returns the state root of the canonical block `number`, if its state is in the database.
+	fn legacy_state_root(&self, number: u64) -> ClientResult<Option<Block::Hash>> {
+		let Some(hash) = self.blockchain.hash(number.saturated_into())? else { return Ok(None) };
+		let state_root = *self.blockchain.expect_header(hash)?.state_root();
+		let have_state = state_root == EmptyStorage::<Block>::new().0 ||
+			self.storage.db.contains(columns::STATE, state_root.as_ref());
+		Ok(have_state.then_some(state_root))
+	}
+
 	/// Handle setting head within a transaction. `route_to` should be the last
 	/// block that existed in the database. `best_to` should be the best block
 	/// to be set.
@@ -1897,6 +2135,8 @@ impl<Block: BlockT> Backend<Block> {
 			apply_state_commit(transaction, commit);
 		}
 
+		self.prune_legacy_state(transaction)?;
+
 		if remove_displaced {
 			let new_displaced = self.blockchain.displaced_leaves_after_finalizing(f_hash, f_num)?;
 
@@ -2824,6 +3064,54 @@ pub(crate) mod tests {
 		}
 	}
 
+	#[test]
+	fn state_pruning_migration_from_archive() {
+		let backing = sp_database::as_database(kvdb_memorydb::create(crate::utils::NUM_COLUMNS));
+		let settings = |state_pruning, require_create_flag| DatabaseSettings {
+			trie_cache_maximum_size: Some(16 * 1024 * 1024),
+			state_pruning: Some(state_pruning),
+			source: DatabaseSource::Custom { db: backing.clone(), require_create_flag },
+			blocks_pruning: BlocksPruning::KeepAll,
+			metrics_registry: None,
+		};
+
+		let mut blocks = Vec::new();
+		{
+			let backend =
+				Backend::<Block>::new(settings(PruningMode::ArchiveAll, true), 0).unwrap();
+			let mut prev_hash = Default::default();
+			for i in 0..5 {
+				prev_hash = insert_header(&backend, i, prev_hash, None, Default::default());
+				blocks.push(prev_hash);
+			}
+			backend.finalize_block(blocks[1], None).unwrap();
+			backend.finalize_block(blocks[2], None).unwrap();
+		}
+
+		assert!(Backend::<Block>::new(settings(PruningMode::blocks_pruning(1), false), 0).is_err());
+
+		let backend = Backend::<Block>::new_with_state_pruning_migration(
+			settings(PruningMode::blocks_pruning(1), false),
+			0,
+		)
+		.unwrap();
+		assert_eq!(backend.storage.state_db.pruning_mode(), PruningMode::blocks_pruning(1));
+		assert!(!backend.have_state_at(blocks[1], 1));
+
+		// The blocks above the last finalized one are tracked again.
+		let hash = insert_header(&backend, 5, blocks[4], None, Default::default());
+		{
+			let mut op = backend.begin_operation().unwrap();
+			backend.begin_state_operation(&mut op, hash).unwrap();
+			op.mark_finalized(blocks[3], None).unwrap();
+			op.mark_finalized(blocks[4], None).unwrap();
+			op.mark_finalized(hash, None).unwrap();
+			backend.commit_operation(op).unwrap();
+		}
+		assert!(backend.have_state_at(hash, 5));
+		assert!(!backend.have_state_at(blocks[3], 3));
+	}
+
 	#[test]
 	fn set_state_data() {
 		set_state_data_inner(StateVersion::V0);
@@ -0,0 +1,688 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Binary snapshots of the state of a finalized block.
+//!
+//! A snapshot bootstraps a node without syncing from the network. It contains the header and
+//! the justifications of a finalized block, followed by the trie nodes of its state.
+//!
+//! A full snapshot also contains the genesis header and the canonical block index up to the
+//! snapshot block. It is imported into an empty database, which ends up in the same state as
+//! after a warp sync: the blocks below the snapshot block are left as a block gap.
+//!
+//! A delta snapshot contains the headers from a base block up to the snapshot block and the
+//! nodes that were added and removed between the two states. It is imported into a database
+//! whose last finalized block is the base block, for example one that was bootstrapped from an
+//! earlier snapshot.
+//!
+//! The imported nodes are checked against the state root of the snapshot block before the block
+//! is imported.
+//!
+//! The encoding is `MAGIC`, the format version, a `SnapshotHeader`, the hashes of the canonical
+//! blocks of a full snapshot and a sequence of `SnapshotEntry` that ends with
+//! `SnapshotEntry::End`.
+
+use crate::{
+	apply_state_commit, columns,
+	trie_diff::{NodeKey, TrieDiff},
+	utils, Backend, StateMetaDb,
+};
+use codec::{Decode, Encode, IoReader};
+use hash_db::{HashDB, Prefix};
+use log::info;
+use sc_client_api::backend::{Backend as _, BlockImportOperation as _, NewBlockState};
+use sp_blockchain::{Backend as _, Error as ClientError, HeaderBackend, Result as ClientResult};
+use sp_database::Transaction;
+use sp_runtime::{
+	traits::{
+		Block as BlockT, Hash, HashingFor, Header as HeaderT, NumberFor, One, SaturatedConversion,
+		Zero,
+	},
+	Justifications,
+};
+use sp_trie::prefixed_key;
+use std::{
+	collections::HashMap,
+	io::{Read, Write},
+};
+
+/// Bytes every snapshot starts with.
+const MAGIC: [u8; 8] = *b"substate";
+/// Version of the snapshot format.
+const VERSION: u32 = 1;
+/// Number of entries written to the database in one transaction during an import.
+const IMPORT_BATCH: u64 = 16 * 1024;
+
+#[derive(Encode, Decode)]
+struct SnapshotHeader<Block: BlockT> {
+	genesis_hash: Block::Hash,
+	kind: SnapshotKind<Block>,
+	/// Header of the snapshot block.
+	header: Block::Header,
+	justifications: Option<Justifications>,
+}
+
+#[derive(Encode, Decode)]
+enum SnapshotKind<Block: BlockT> {
+	/// Contains all nodes of the state. Followed by the hashes of the canonical blocks
+	/// `1..number`.
+	Full { genesis: Block::Header },
+	/// Contains the changes since the state of `base`.
+	Delta {
+		base: Block::Hash,
+		/// Headers of the canonical blocks between `base` and the snapshot block.
+		headers: Vec<Block::Header>,
+	},
+}
+
+#[derive(Encode, Decode)]
+enum SnapshotEntry {
+	/// A node of the state, stored under the given prefix.
+	Node { prefix: (Vec<u8>, Option<u8>), data: Vec<u8> },
+	/// A node of the base state that is no longer referenced.
+	Removed { prefix: (Vec<u8>, Option<u8>), hash: Vec<u8> },
+	/// End of the snapshot, with the number of preceding entries.
+	End { entries: u64 },
+}
+
+/// Summary of an exported or imported snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotInfo<Block: BlockT> {
+	/// Hash of the snapshot block.
+	pub hash: Block::Hash,
+	/// Number of the snapshot block.
+	pub number: NumberFor<Block>,
+	/// Base block of a delta snapshot.
+	pub base: Option<Block::Hash>,
+	/// Number of node entries.
+	pub entries: u64,
+}
+
+fn snapshot_error(message: impl std::fmt::Display) -> ClientError {
+	ClientError::Backend(format!("Snapshot: {message}"))
+}
+
+fn write_entry(output: &mut impl Write, entry: &SnapshotEntry) -> Result<(), String> {
+	output.write_all(&entry.encode()).map_err(|e| e.to_string())
+}
+
+impl<Block: BlockT> Backend<Block> {
+	/// Write a snapshot of the state of the finalized block `hash` to `output`.
+	///
+	/// With a `base`, only the changes since the state of the finalized block `base` are
+	/// written. Both blocks need to have their state in the database.
+	pub fn export_snapshot(
+		&self,
+		hash: Block::Hash,
+		base: Option<Block::Hash>,
+		output: &mut impl Write,
+	) -> ClientResult<SnapshotInfo<Block>> {
+		let header = self.blockchain.expect_header(hash)?;
+		let number = *header.number();
+		self.ensure_snapshot_block(hash, number)?;
+
+		let kind = match base {
+			None => SnapshotKind::Full {
+				genesis: self.blockchain.expect_header(self.blockchain.info().genesis_hash)?,
+			},
+			Some(base) => {
+				let base_number = *self.blockchain.expect_header(base)?.number();
+				if base_number >= number {
+					return Err(snapshot_error(format!(
+						"base #{base_number} is not below the snapshot block #{number}"
+					)))
+				}
+				self.ensure_snapshot_block(base, base_number)?;
+				let mut headers = Vec::new();
+				let mut current = base_number + One::one();
+				while current < number {
+					let header = self
+						.blockchain
+						.hash(current)?
+						.map(|hash| self.blockchain.expect_header(hash))
+						.transpose()?
+						.ok_or_else(|| {
+							snapshot_error(format!("missing header of canonical block #{current}"))
+						})?;
+					headers.push(header);
+					current += One::one();
+				}
+				SnapshotKind::Delta { base, headers }
+			},
+		};
+
+		let snapshot = SnapshotHeader::<Block> {
+			genesis_hash: self.blockchain.info().genesis_hash,
+			kind,
+			header,
+			justifications: self.blockchain.justifications(hash)?,
+		};
+		output.write_all(&MAGIC).map_err(snapshot_error)?;
+		output.write_all(&VERSION.encode()).map_err(snapshot_error)?;
+		output.write_all(&snapshot.encode()).map_err(snapshot_error)?;
+
+		if let SnapshotKind::Full { .. } = snapshot.kind {
+			let mut current = NumberFor::<Block>::one();
+			while current < number {
+				output
+					.write_all(self.canonical_hash(current)?.as_ref())
+					.map_err(snapshot_error)?;
+				current += One::one();
+			}
+		}
+
+		let state_root = *snapshot.header.state_root();
+		let get = |hash: &[u8], prefix: Prefix| self.snapshot_node(hash, prefix);
+		let mut entries = 0;
+		match base {
+			None => {
+				let mut visit = |key: &NodeKey, data: &[u8], _| {
+					entries += 1;
+					let entry =
+						SnapshotEntry::Node { prefix: key.prefix.clone(), data: data.to_vec() };
+					write_entry(output, &entry)
+				};
+				TrieDiff::<HashingFor<Block>>::new(&get, &mut visit)
+					.diff_roots(None, Some(state_root.as_ref()))
+					.map_err(snapshot_error)?;
+			},
+			Some(base) => {
+				let base_root = *self.blockchain.expect_header(base)?.state_root();
+				let mut changes = HashMap::<NodeKey, (i64, Option<Vec<u8>>)>::new();
+				let mut visit = |key: &NodeKey, data: &[u8], delta: i64| {
+					let change = changes.entry(key.clone()).or_default();
+					change.0 += delta;
+					if delta > 0 && change.1.is_none() {
+						change.1 = Some(data.to_vec());
+					}
+					Ok(())
+				};
+				TrieDiff::<HashingFor<Block>>::new(&get, &mut visit)
+					.diff_roots(Some(base_root.as_ref()), Some(state_root.as_ref()))
+					.map_err(snapshot_error)?;
+
+				for (key, (references, data)) in changes {
+					let entry = match data {
+						Some(data) if references > 0 =>
+							SnapshotEntry::Node { prefix: key.prefix, data },
+						_ => SnapshotEntry::Removed { prefix: key.prefix, hash: key.hash },
+					};
+					for _ in 0..references.abs() {
+						entries += 1;
+						write_entry(output, &entry).map_err(snapshot_error)?;
+					}
+				}
+			},
+		}
+		write_entry(output, &SnapshotEntry::End { entries }).map_err(snapshot_error)?;
+		output.flush().map_err(snapshot_error)?;
+
+		Ok(SnapshotInfo { hash, number, base, entries })
+	}
+
+	/// Import a snapshot written by [`Self::export_snapshot`].
+	///
+	/// A full snapshot can only be imported into an empty database. A delta snapshot can only be
+	/// imported into a database whose state is at the base block of the snapshot. An interrupted
+	/// import of a delta snapshot can be resumed by importing the same snapshot again.
+	pub fn import_snapshot(&self, input: &mut impl Read) -> ClientResult<SnapshotInfo<Block>> {
+		let mut input = IoReader(input);
+		let mut magic = [0u8; 8];
+		input.0.read_exact(&mut magic).map_err(snapshot_error)?;
+		if magic != MAGIC {
+			return Err(snapshot_error("not a snapshot"))
+		}
+		let version = u32::decode(&mut input).map_err(snapshot_error)?;
+		if version != VERSION {
+			return Err(snapshot_error(format!("unsupported version {version}")))
+		}
+		let snapshot = SnapshotHeader::<Block>::decode(&mut input).map_err(snapshot_error)?;
+		let hash = snapshot.header.hash();
+		let number = *snapshot.header.number();
+		let parent_hash = *snapshot.header.parent_hash();
+		let info = self.blockchain.info();
+
+		let base = match &snapshot.kind {
+			SnapshotKind::Full { genesis } => {
+				if genesis.hash() != snapshot.genesis_hash || number.is_zero() {
+					return Err(snapshot_error("invalid genesis block"))
+				}
+				if info.genesis_hash != Default::default() {
+					return Err(snapshot_error(
+						"full snapshots can only be imported into an empty database",
+					))
+				}
+				let mut transaction = Transaction::new();
+				let mut current = NumberFor::<Block>::one();
+				let mut last = snapshot.genesis_hash;
+				while current < number {
+					last = Block::Hash::decode(&mut input).map_err(snapshot_error)?;
+					utils::insert_number_to_key_mapping(
+						&mut transaction,
+						columns::KEY_LOOKUP,
+						current,
+						last,
+					)?;
+					current += One::one();
+				}
+				if last != parent_hash {
+					return Err(snapshot_error(
+						"canonical index does not lead to the snapshot block",
+					))
+				}
+				self.storage.db.commit(transaction)?;
+				None
+			},
+			SnapshotKind::Delta { base, headers } => {
+				self.prepare_delta_snapshot(&snapshot, *base, headers)?;
+				Some(*base)
+			},
+		};
+
+		let mut transaction = Transaction::new();
+		let mut removed = Vec::new();
+		let mut entries = 0;
+		loop {
+			match SnapshotEntry::decode(&mut input).map_err(snapshot_error)? {
+				SnapshotEntry::Node { prefix, data } => {
+					let node_hash = HashingFor::<Block>::hash(&data);
+					let mut key =
+						prefixed_key::<HashingFor<Block>>(&node_hash, (&prefix.0, prefix.1));
+					self.storage.db.sanitize_key(&mut key);
+					transaction.set_from_vec(columns::STATE, &key, data);
+				},
+				SnapshotEntry::Removed { prefix, hash } if base.is_some() => {
+					let hash = Block::Hash::decode(&mut &hash[..]).map_err(snapshot_error)?;
+					removed.push((prefix, hash));
+				},
+				SnapshotEntry::Removed { .. } =>
+					return Err(snapshot_error("full snapshots can't remove nodes")),
+				SnapshotEntry::End { entries: expected } if expected == entries => break,
+				SnapshotEntry::End { .. } => return Err(snapshot_error("wrong number of entries")),
+			}
+			entries += 1;
+			if entries % IMPORT_BATCH == 0 {
+				self.storage.db.commit(std::mem::take(&mut transaction))?;
+			}
+			if entries % (1024 * IMPORT_BATCH) == 0 {
+				info!("📦 Imported {entries} state entries");
+			}
+		}
+		self.storage.db.commit(transaction)?;
+
+		// Every node of the new state that is not shared with the base state has to be present.
+		let get = |hash: &[u8], prefix: Prefix| self.snapshot_node(hash, prefix);
+		let mut visit = |_: &NodeKey, _: &[u8], _| Ok(());
+		let base_root = base.map(|base| self.blockchain.expect_header(base)).transpose()?;
+		TrieDiff::<HashingFor<Block>>::new(&get, &mut visit)
+			.diff_roots(
+				base_root.as_ref().map(|header| header.state_root().as_ref()),
+				Some(snapshot.header.state_root().as_ref()),
+			)
+			.map_err(|e| snapshot_error(format!("incomplete state: {e}")))?;
+
+		if let SnapshotKind::Full { genesis } = snapshot.kind {
+			let mut op = self.begin_operation()?;
+			op.set_block_data(genesis, Some(Vec::new()), None, None, NewBlockState::Final)?;
+			self.commit_operation(op)?;
+		}
+
+		let mut op = self.begin_operation()?;
+		for (prefix, hash) in removed {
+			op.db_updates.remove(&hash, (&prefix.0, prefix.1));
+		}
+		op.commit_state = true;
+		op.set_block_data(
+			snapshot.header,
+			None,
+			None,
+			snapshot.justifications,
+			NewBlockState::Final,
+		)?;
+		self.commit_operation(op)?;
+
+		Ok(SnapshotInfo { hash, number, base, entries })
+	}
+
+	/// Imports the headers of a delta snapshot and prepares the state database for the state of
+	/// the snapshot block.
+	fn prepare_delta_snapshot(
+		&self,
+		snapshot: &SnapshotHeader<Block>,
+		base: Block::Hash,
+		headers: &[Block::Header],
+	) -> ClientResult<()> {
+		let info = self.blockchain.info();
+		if info.genesis_hash != snapshot.genesis_hash {
+			return Err(snapshot_error("the snapshot is for a different chain"))
+		}
+		if info.finalized_state.map(|(hash, _)| hash) != Some(base) {
+			return Err(snapshot_error(format!(
+				"the state of the base block {base:?} is not the last finalized state"
+			)))
+		}
+		if info.best_hash != info.finalized_hash {
+			return Err(snapshot_error("the best block is not finalized"))
+		}
+
+		let mut parent = base;
+		for header in headers.iter().chain(std::iter::once(&snapshot.header)) {
+			if *header.parent_hash() != parent {
+				return Err(snapshot_error(format!(
+					"header #{} is not a child of {parent:?}",
+					header.number()
+				)))
+			}
+			parent = header.hash();
+		}
+		// Resume after the headers that were imported by an interrupted import.
+		let mut importing = info.finalized_hash == base;
+		for header in headers {
+			if importing {
+				let mut op = self.begin_operation()?;
+				op.set_block_data(header.clone(), None, None, None, NewBlockState::Final)?;
+				self.commit_operation(op)?;
+			}
+			importing |= header.hash() == info.finalized_hash;
+		}
+		if !importing {
+			return Err(snapshot_error("the last finalized block is not part of the snapshot"))
+		}
+
+		let parent_number = *snapshot.header.number() - One::one();
+		let commit = self
+			.storage
+			.state_db
+			.reset_canonical_chain(
+				StateMetaDb(self.storage.db.clone()),
+				snapshot.header.parent_hash(),
+				parent_number.saturated_into(),
+			)
+			.map_err(sp_blockchain::Error::from_state_db)?;
+		let mut transaction = Transaction::new();
+		apply_state_commit(&mut transaction, commit);
+		self.storage.db.commit(transaction)?;
+		Ok(())
+	}
+
+	fn ensure_snapshot_block(
+		&self,
+		hash: Block::Hash,
+		number: NumberFor<Block>,
+	) -> ClientResult<()> {
+		if number > self.blockchain.info().finalized_number ||
+			self.blockchain.hash(number)? != Some(hash)
+		{
+			return Err(snapshot_error(format!("block {hash:?} is not finalized")))
+		}
+		if !self.have_state_at(hash, number) {
+			return Err(snapshot_error(format!("the state of block {hash:?} is not available")))
+		}
+		Ok(())
+	}
+
+	/// Returns the hash of the canonical block `number` from the number index, which is also
+	/// available for blocks in a block gap.
+	fn canonical_hash(&self, number: NumberFor<Block>) -> ClientResult<Block::Hash> {
+		let lookup_key = self
+			.storage
+			.db
+			.get(columns::KEY_LOOKUP, utils::number_index_key(number)?.as_ref())
+			.ok_or_else(|| snapshot_error(format!("canonical block #{number} is unknown")))?;
+		Block::Hash::decode(&mut &lookup_key[4..]).map_err(snapshot_error)
+	}
+
+	fn snapshot_node(&self, hash: &[u8], prefix: Prefix) -> Result<Option<Vec<u8>>, String> {
+		let hash = Block::Hash::decode(&mut &hash[..]).map_err(|e| e.to_string())?;
+		sp_state_machine::Storage::get(&*self.storage, &hash, prefix)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::Block;
+	use sc_client_api::TrieCacheContext;
+	use sp_core::{storage::ChildInfo, H256};
+	use sp_runtime::{testing::Header, StateVersion};
+	use sp_state_machine::Backend as _;
+
+	type Changes = Vec<(Vec<u8>, Option<Vec<u8>>)>;
+
+	fn insert_state_block(
+		backend: &Backend<Block>,
+		number: u64,
+		parent_hash: H256,
+		changes: Changes,
+		child_changes: Changes,
+	) -> H256 {
+		let child_info = ChildInfo::new_default(b"child");
+		let mut op = backend.begin_operation().unwrap();
+		backend.begin_state_operation(&mut op, parent_hash).unwrap();
+		let (root, overlay) = op.old_state.full_storage_root(
+			changes.iter().map(|(k, v)| (&k[..], v.as_deref())),
+			std::iter::once((
+				&child_info,
+				child_changes.iter().map(|(k, v)| (&k[..], v.as_deref())),
+			)),
+			StateVersion::V1,
+		);
+		op.update_db_storage(overlay).unwrap();
+		let header = Header {
+			number,
+			parent_hash,
+			state_root: root,
+			digest: Default::default(),
+			extrinsics_root: Default::default(),
+		};
+		op.set_block_data(header.clone(), Some(Vec::new()), None, None, NewBlockState::Final)
+			.unwrap();
+		backend.commit_operation(op).unwrap();
+		header.hash()
+	}
+
+	fn value(i: u32) -> Option<Vec<u8>> {
+		Some(vec![i as u8; 40])
+	}
+
+	/// Blocks `0..=5`, changing and removing some top and child trie values in each block.
+	fn source_chain() -> (Backend<Block>, Vec<H256>) {
+		let backend = Backend::<Block>::new_test(1000, 0);
+		let mut blocks = Vec::new();
+		let mut parent = Default::default();
+		for number in 0u32..=5 {
+			let (changes, child_changes) = if number == 0 {
+				(
+					(0u32..100).map(|i| (i.to_be_bytes().to_vec(), value(i))).collect(),
+					(0u32..20).map(|i| (i.to_be_bytes().to_vec(), value(i))).collect(),
+				)
+			} else {
+				(
+					vec![
+						(number.to_be_bytes().to_vec(), value(number + 100)),
+						((number + 50).to_be_bytes().to_vec(), None),
+					],
+					vec![(number.to_be_bytes().to_vec(), None)],
+				)
+			};
+			parent = insert_state_block(&backend, number as u64, parent, changes, child_changes);
+			blocks.push(parent);
+		}
+		(backend, blocks)
+	}
+
+	fn storage(backend: &Backend<Block>, hash: H256, key: u32) -> Option<Vec<u8>> {
+		backend
+			.state_at(hash, TrieCacheContext::Untrusted)
+			.unwrap()
+			.storage(&key.to_be_bytes())
+			.unwrap()
+	}
+
+	fn child_storage(backend: &Backend<Block>, hash: H256, key: u32) -> Option<Vec<u8>> {
+		backend
+			.state_at(hash, TrieCacheContext::Untrusted)
+			.unwrap()
+			.child_storage(&ChildInfo::new_default(b"child"), &key.to_be_bytes())
+			.unwrap()
+	}
+
+	fn export(backend: &Backend<Block>, hash: H256, base: Option<H256>) -> Vec<u8> {
+		let mut snapshot = Vec::new();
+		backend.export_snapshot(hash, base, &mut snapshot).unwrap();
+		snapshot
+	}
+
+	#[test]
+	fn full_and_delta_snapshots_round_trip() {
+		let (source, blocks) = source_chain();
+		let backend = Backend::<Block>::new_test(1000, 0);
+
+		let info = backend.import_snapshot(&mut &export(&source, blocks[2], None)[..]).unwrap();
+		assert_eq!((info.hash, info.number, info.base), (blocks[2], 2, None));
+		let chain = backend.blockchain().info();
+		assert_eq!(chain.genesis_hash, blocks[0]);
+		assert_eq!(chain.finalized_hash, blocks[2]);
+		assert_eq!(chain.finalized_state, Some((blocks[2], 2)));
+		assert_eq!(chain.block_gap.map(|gap| (gap.start, gap.end)), Some((1, 1)));
+		assert_eq!(storage(&backend, blocks[2], 2), value(102));
+		assert_eq!(storage(&backend, blocks[2], 51), None);
+		assert_eq!(storage(&backend, blocks[2], 99), value(99));
+		assert_eq!(child_storage(&backend, blocks[2], 1), None);
+		assert_eq!(child_storage(&backend, blocks[2], 3), value(3));
+
+		let snapshot = export(&source, blocks[5], Some(blocks[2]));
+		let info = backend.import_snapshot(&mut &snapshot[..]).unwrap();
+		assert_eq!((info.hash, info.number, info.base), (blocks[5], 5, Some(blocks[2])));
+		let chain = backend.blockchain().info();
+		assert_eq!(chain.finalized_state, Some((blocks[5], 5)));
+		assert_eq!(backend.blockchain().hash(4).unwrap(), Some(blocks[4]));
+		assert!(!backend.have_state_at(blocks[2], 2));
+		assert_eq!(storage(&backend, blocks[5], 2), value(102));
+		assert_eq!(storage(&backend, blocks[5], 5), value(105));
+		assert_eq!(storage(&backend, blocks[5], 55), None);
+		assert_eq!(child_storage(&backend, blocks[5], 5), None);
+		assert_eq!(child_storage(&backend, blocks[5], 6), value(6));
+
+		// The delta can't be applied twice.
+		assert!(backend.import_snapshot(&mut &snapshot[..]).is_err());
+
+		// Blocks are imported on top of the snapshot as usual.
+		insert_state_block(&backend, 6, blocks[5], vec![(vec![6], value(6))], Vec::new());
+		assert_eq!(backend.blockchain().info().finalized_number, 6);
+	}
+
+	#[test]
+	fn incomplete_snapshots_are_rejected() {
+		let (source, blocks) = source_chain();
+		let mut snapshot = export(&source, blocks[3], None);
+
+		// Drop the first node.
+		let header_len = MAGIC.len() +
+			VERSION.encoded_size() +
+			SnapshotHeader::<Block> {
+				genesis_hash: blocks[0],
+				kind: SnapshotKind::Full {
+					genesis: source.blockchain().header(blocks[0]).unwrap().unwrap(),
+				},
+				header: source.blockchain().header(blocks[3]).unwrap().unwrap(),
+				justifications: None,
+			}
+			.encoded_size() +
+			2 * H256::len_bytes();
+		let first = SnapshotEntry::decode(&mut &snapshot[header_len..]).unwrap();
+		let SnapshotEntry::Node { .. } = first else { panic!("Expected a node") };
+		snapshot.drain(header_len..header_len + first.encoded_size());
+		let end = snapshot.len() - SnapshotEntry::End { entries: 0 }.encoded_size();
+		let SnapshotEntry::End { entries } = SnapshotEntry::decode(&mut &snapshot[end..]).unwrap()
+		else {
+			panic!("Expected the end")
+		};
+		snapshot.truncate(end);
+		snapshot.extend(SnapshotEntry::End { entries: entries - 1 }.encode());
+
+		let backend = Backend::<Block>::new_test(1000, 0);
+		let error = backend.import_snapshot(&mut &snapshot[..]).unwrap_err();
+		assert!(error.to_string().contains("incomplete state"), "{error}");
+		assert_eq!(backend.blockchain().info().finalized_state, None);
+	}
+
+	#[test]
+	fn imported_state_is_pruned_by_reference() {
+		let (source, blocks) = source_chain();
+		let snapshot = export(&source, blocks[2], None);
+
+		// ParityDb counts the references of the nodes. Values `0..20` are stored once in the top
+		// trie and once in the child trie, so their nodes are referenced twice.
+		let tempdir = tempfile::tempdir().unwrap();
+		let open = || {
+			let settings = crate::DatabaseSettings {
+				trie_cache_maximum_size: None,
+				state_pruning: Some(crate::PruningMode::blocks_pruning(1)),
+				source: crate::DatabaseSource::ParityDb { path: tempdir.path().to_owned() },
+				blocks_pruning: crate::BlocksPruning::KeepFinalized,
+				metrics_registry: None,
+			};
+			Backend::<Block>::new(settings, 0).unwrap()
+		};
+		open().import_snapshot(&mut &snapshot[..]).unwrap();
+
+		// Restart the node on the imported database, and let it prune the snapshot state.
+		let backend = open();
+		let value_node = |i| HashingFor::<Block>::hash(&value(i).unwrap());
+		let mut parent = blocks[2];
+		for number in 3u32..=8 {
+			let changes = vec![(number.to_be_bytes().to_vec(), value(number + 100))];
+			parent = insert_state_block(&backend, number as u64, parent, changes, Vec::new());
+		}
+
+		// The values replaced in the top trie are still referenced by the child trie.
+		for i in 3u32..=8 {
+			assert_eq!(storage(&backend, parent, i), value(i + 100));
+			assert_eq!(child_storage(&backend, parent, i), value(i));
+			assert!(backend.storage.db.get(columns::STATE, value_node(i).as_ref()).is_some());
+		}
+		for i in 9u32..100 {
+			assert_eq!(storage(&backend, parent, i), value(i).filter(|_| !(51..=52).contains(&i)));
+		}
+
+		// Once no trie references a node, it is pruned.
+		let parent = insert_state_block(
+			&backend,
+			9,
+			parent,
+			Vec::new(),
+			vec![(3u32.to_be_bytes().to_vec(), None)],
+		);
+		insert_state_block(&backend, 10, parent, Vec::new(), Vec::new());
+		assert!(backend.storage.db.get(columns::STATE, value_node(3).as_ref()).is_none());
+		assert!(backend.storage.db.get(columns::STATE, value_node(4).as_ref()).is_some());
+	}
+
+	#[test]
+	fn delta_requires_base_state() {
+		let (source, blocks) = source_chain();
+		let backend = Backend::<Block>::new_test(1000, 0);
+		backend.import_snapshot(&mut &export(&source, blocks[1], None)[..]).unwrap();
+
+		let error = backend
+			.import_snapshot(&mut &export(&source, blocks[5], Some(blocks[2]))[..])
+			.unwrap_err();
+		assert!(error.to_string().contains("is not the last finalized state"), "{error}");
+	}
+}
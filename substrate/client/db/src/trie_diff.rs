@@ -0,0 +1,553 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Walking the stored nodes of state tries.
+//!
+//! [`TrieDiff`] walks the tries of two states side by side and reports every stored node of the
+//! old state with a reference delta of `-1` and every stored node of the new state with a delta
+//! of `+1`. Subtrees with the same root hash at the same position are skipped, so the cost is
+//! proportional to the size of the change. Walking a single state is a diff against no state.
+//!
+//! Default child tries are followed from their root in the top trie. Their nodes are reported
+//! with the key space of the child trie prepended to the prefix, like `KeySpacedDB` stores them.
+
+use hash_db::{Hasher, Prefix};
+use sp_core::{hexdisplay::HexDisplay, storage::well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX};
+use std::{
+	collections::{BTreeSet, HashSet},
+	marker::PhantomData,
+};
+use trie_db::{
+	node::{Node, NodeHandle, Value},
+	NodeCodec as _,
+};
+
+type TrieNodeCodec<H> = sp_trie::NodeCodec<H>;
+
+/// Reads the encoded node with the given hash and prefix.
+pub(crate) type NodeGetter<'a> = dyn Fn(&[u8], Prefix) -> Result<Option<Vec<u8>>, String> + 'a;
+
+/// Position of a stored trie node.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct NodeKey {
+	/// Prefix the node is stored under, including the key space of its trie.
+	pub prefix: (Vec<u8>, Option<u8>),
+	/// Hash of the encoded node.
+	pub hash: Vec<u8>,
+}
+
+impl NodeKey {
+	pub fn as_prefix(&self) -> Prefix<'_> {
+		(&self.prefix.0, self.prefix.1)
+	}
+
+	/// Position of the node in nibbles, including the key space of its trie.
+	fn nibbles(&self) -> Vec<u8> {
+		let mut nibbles = to_nibbles(&self.prefix.0);
+		nibbles.extend(self.prefix.1.map(|padded| padded >> 4));
+		nibbles
+	}
+}
+
+/// Child reference of a decoded node.
+#[derive(Clone, PartialEq, Eq)]
+enum Child {
+	/// Hash of a node stored in the database.
+	Hash(Vec<u8>),
+	/// Encoded node that is stored inline in its parent.
+	Inline(Vec<u8>),
+}
+
+/// Value of a decoded node.
+#[derive(Clone, PartialEq, Eq)]
+enum NodeValue {
+	/// Value stored in the node itself.
+	Inline(Vec<u8>),
+	/// Hash of a value stored in the database.
+	Node(Vec<u8>),
+}
+
+/// Owned version of a decoded trie node.
+#[derive(Default)]
+struct OwnedNode {
+	/// Partial key of the node, one nibble per byte.
+	partial: Vec<u8>,
+	value: Option<NodeValue>,
+	children: [Option<Child>; 16],
+}
+
+/// Side by side walk of two state tries.
+pub(crate) struct TrieDiff<'a, H> {
+	get: &'a NodeGetter<'a>,
+	/// Called with every stored node of the walked states, its data and its reference delta.
+	visit: &'a mut dyn FnMut(&NodeKey, &[u8], i64) -> Result<(), String>,
+	empty_root: Vec<u8>,
+	child_prefix: Vec<u8>,
+	/// Key space of the trie that is currently walked.
+	keyspace: Vec<u8>,
+	/// Whether nodes missing from the database are walked as empty nodes.
+	skip_missing: bool,
+	_marker: PhantomData<H>,
+}
+
+impl<'a, H: Hasher> TrieDiff<'a, H> {
+	pub fn new(
+		get: &'a NodeGetter<'a>,
+		visit: &'a mut dyn FnMut(&NodeKey, &[u8], i64) -> Result<(), String>,
+	) -> Self {
+		TrieDiff {
+			get,
+			visit,
+			empty_root: TrieNodeCodec::<H>::hashed_null_node().as_ref().to_vec(),
+			child_prefix: to_nibbles(DEFAULT_CHILD_STORAGE_KEY_PREFIX),
+			keyspace: Vec::new(),
+			skip_missing: false,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Walk nodes that are missing from the database as empty nodes instead of failing.
+	///
+	/// Used when the database may have deleted parts of the walked states.
+	pub fn skip_missing(mut self) -> Self {
+		self.skip_missing = true;
+		self
+	}
+
+	/// Walks the states with the given roots. A missing root stands for a state without nodes.
+	pub fn diff_roots(
+		&mut self,
+		old_root: Option<&[u8]>,
+		new_root: Option<&[u8]>,
+	) -> Result<(), String> {
+		let old = old_root.map(|root| Child::Hash(root.to_vec()));
+		let new = new_root.map(|root| Child::Hash(root.to_vec()));
+		self.diff(old.as_ref(), new.as_ref(), &mut Vec::new())
+	}
+
+	fn diff(
+		&mut self,
+		old: Option<&Child>,
+		new: Option<&Child>,
+		path: &mut Vec<u8>,
+	) -> Result<(), String> {
+		if old == new {
+			return Ok(())
+		}
+		let old = old.map(|child| self.load(child, -1, path)).transpose()?;
+		let new = new.map(|child| self.load(child, 1, path)).transpose()?;
+		match (old, new) {
+			(Some(old), Some(new)) if old.partial == new.partial => {
+				let len = path.len();
+				path.extend_from_slice(&old.partial);
+				self.diff_value(old.value.as_ref(), new.value.as_ref(), path)?;
+				for (nibble, (old, new)) in old.children.iter().zip(new.children.iter()).enumerate()
+				{
+					path.push(nibble as u8);
+					self.diff(old.as_ref(), new.as_ref(), path)?;
+					path.pop();
+				}
+				path.truncate(len);
+			},
+			(old, new) => {
+				if let Some(old) = old {
+					self.walk_node(&old, -1, path)?;
+				}
+				if let Some(new) = new {
+					self.walk_node(&new, 1, path)?;
+				}
+			},
+		}
+		Ok(())
+	}
+
+	fn diff_value(
+		&mut self,
+		old: Option<&NodeValue>,
+		new: Option<&NodeValue>,
+		path: &mut Vec<u8>,
+	) -> Result<(), String> {
+		if old == new {
+			return Ok(())
+		}
+		if self.is_child_root(path) {
+			let old = old.map(|value| self.child_root(value, -1, path)).transpose()?.flatten();
+			let new = new.map(|value| self.child_root(value, 1, path)).transpose()?.flatten();
+			let keyspace = self.child_keyspace(path);
+			let keyspace = std::mem::replace(&mut self.keyspace, keyspace);
+			let result = self.diff(old.as_ref(), new.as_ref(), &mut Vec::new());
+			self.keyspace = keyspace;
+			return result
+		}
+		for (value, delta) in [(old, -1), (new, 1)] {
+			if let Some(NodeValue::Node(hash)) = value {
+				self.load_value(hash, delta, path)?;
+			}
+		}
+		Ok(())
+	}
+
+	fn walk_node(
+		&mut self,
+		node: &OwnedNode,
+		delta: i64,
+		path: &mut Vec<u8>,
+	) -> Result<(), String> {
+		let len = path.len();
+		path.extend_from_slice(&node.partial);
+		if let Some(value) = &node.value {
+			if self.is_child_root(path) {
+				if let Some(root) = self.child_root(value, delta, path)? {
+					let keyspace = self.child_keyspace(path);
+					let keyspace = std::mem::replace(&mut self.keyspace, keyspace);
+					let result = self
+						.load(&root, delta, &[])
+						.and_then(|root| self.walk_node(&root, delta, &mut Vec::new()));
+					self.keyspace = keyspace;
+					result?;
+				}
+			} else if let NodeValue::Node(hash) = value {
+				self.load_value(hash, delta, path)?;
+			}
+		}
+		for (nibble, child) in node.children.iter().enumerate() {
+			if let Some(child) = child {
+				path.push(nibble as u8);
+				let child = self.load(child, delta, path)?;
+				self.walk_node(&child, delta, path)?;
+				path.pop();
+			}
+		}
+		path.truncate(len);
+		Ok(())
+	}
+
+	/// Decodes the node referenced by `child` at `path`, reporting it if it is stored.
+	fn load(&mut self, child: &Child, delta: i64, path: &[u8]) -> Result<OwnedNode, String> {
+		match child {
+			Child::Hash(hash) if *hash == self.empty_root => Ok(OwnedNode::default()),
+			Child::Hash(hash) => {
+				let key = NodeKey { prefix: self.prefix(path, false), hash: hash.clone() };
+				match self.fetch(&key, delta, "trie node")? {
+					Some(data) => decode_node::<H>(&data),
+					None => Ok(OwnedNode::default()),
+				}
+			},
+			Child::Inline(data) => decode_node::<H>(data),
+		}
+	}
+
+	/// Reports the value node stored for the key at `path`.
+	fn load_value(
+		&mut self,
+		hash: &[u8],
+		delta: i64,
+		path: &[u8],
+	) -> Result<Option<Vec<u8>>, String> {
+		let key = NodeKey { prefix: self.prefix(path, true), hash: hash.to_vec() };
+		self.fetch(&key, delta, "value node")
+	}
+
+	fn fetch(&mut self, key: &NodeKey, delta: i64, kind: &str) -> Result<Option<Vec<u8>>, String> {
+		let Some(data) = self.read(key, kind, self.skip_missing)? else { return Ok(None) };
+		(self.visit)(key, &data, delta)?;
+		Ok(Some(data))
+	}
+
+	fn read(
+		&self,
+		key: &NodeKey,
+		kind: &str,
+		allow_missing: bool,
+	) -> Result<Option<Vec<u8>>, String> {
+		match (self.get)(&key.hash, key.as_prefix())? {
+			None if !allow_missing =>
+				Err(format!("Missing {kind} {}", HexDisplay::from(&key.hash))),
+			data => Ok(data),
+		}
+	}
+
+	fn child_root(
+		&mut self,
+		value: &NodeValue,
+		delta: i64,
+		path: &[u8],
+	) -> Result<Option<Child>, String> {
+		let root = match value {
+			NodeValue::Inline(root) => Some(root.clone()),
+			NodeValue::Node(hash) => self.load_value(hash, delta, path)?,
+		};
+		Ok(root.map(Child::Hash))
+	}
+
+	/// Removes the stored nodes of the state with root `root` from `keys`.
+	///
+	/// Only the parts of the tries that lead to the position of one of the `keys` are walked, so
+	/// the cost is proportional to the number of keys rather than to the size of the state. The
+	/// nodes are not reported to the visitor.
+	pub fn remove_present(
+		&mut self,
+		root: &[u8],
+		keys: &mut HashSet<NodeKey>,
+	) -> Result<(), String> {
+		let targets = keys.iter().map(NodeKey::nibbles).collect();
+		self.find(&Child::Hash(root.to_vec()), &mut Vec::new(), &targets, keys)
+	}
+
+	fn find(
+		&mut self,
+		child: &Child,
+		path: &mut Vec<u8>,
+		targets: &BTreeSet<Vec<u8>>,
+		keys: &mut HashSet<NodeKey>,
+	) -> Result<(), String> {
+		let node = match child {
+			Child::Hash(hash) if *hash == self.empty_root => return Ok(()),
+			Child::Hash(hash) => {
+				let key = NodeKey { prefix: self.prefix(path, false), hash: hash.clone() };
+				let data = self.read(&key, "trie node", false)?.unwrap_or_default();
+				keys.remove(&key);
+				decode_node::<H>(&data)?
+			},
+			Child::Inline(data) => decode_node::<H>(data)?,
+		};
+		let len = path.len();
+		path.extend_from_slice(&node.partial);
+		if let Some(value) = &node.value {
+			if let NodeValue::Node(hash) = value {
+				keys.remove(&NodeKey { prefix: self.prefix(path, true), hash: hash.clone() });
+			}
+			if self.is_child_root(path) {
+				let keyspace = self.child_keyspace(path);
+				if has_target(targets, &to_nibbles(&keyspace)) {
+					let root = match value {
+						NodeValue::Inline(root) => root.clone(),
+						NodeValue::Node(hash) => {
+							let key =
+								NodeKey { prefix: self.prefix(path, true), hash: hash.clone() };
+							self.read(&key, "value node", false)?.unwrap_or_default()
+						},
+					};
+					let keyspace = std::mem::replace(&mut self.keyspace, keyspace);
+					let result = self.find(&Child::Hash(root), &mut Vec::new(), targets, keys);
+					self.keyspace = keyspace;
+					result?;
+				}
+			}
+		}
+		for (nibble, child) in node.children.iter().enumerate() {
+			if let Some(child) = child {
+				path.push(nibble as u8);
+				if self.may_lead_to_target(path, targets) {
+					self.find(child, path, targets, keys)?;
+				}
+				path.pop();
+			}
+		}
+		path.truncate(len);
+		Ok(())
+	}
+
+	/// Whether a node at `path` of the current trie may have one of the `targets` below it.
+	fn may_lead_to_target(&self, path: &[u8], targets: &BTreeSet<Vec<u8>>) -> bool {
+		let mut position = to_nibbles(&self.keyspace);
+		position.extend_from_slice(path);
+		if has_target(targets, &position) {
+			return true
+		}
+		// The child tries are below the child storage prefix of the top trie.
+		self.keyspace.is_empty() &&
+			if path.len() <= self.child_prefix.len() {
+				self.child_prefix.starts_with(path)
+			} else {
+				path.starts_with(&self.child_prefix) &&
+					has_target(targets, &path[self.child_prefix.len()..])
+			}
+	}
+
+	fn is_child_root(&self, path: &[u8]) -> bool {
+		self.keyspace.is_empty() &&
+			path.len().is_multiple_of(2) &&
+			path.len() > self.child_prefix.len() &&
+			path.starts_with(&self.child_prefix)
+	}
+
+	fn child_keyspace(&self, path: &[u8]) -> Vec<u8> {
+		pack(&path[self.child_prefix.len()..]).0
+	}
+
+	/// Prefix of the node at `path` in the current trie. Values are stored under the full key.
+	fn prefix(&self, path: &[u8], value: bool) -> (Vec<u8>, Option<u8>) {
+		let (packed, padded) = pack(path);
+		let mut prefix = self.keyspace.clone();
+		prefix.extend_from_slice(&packed);
+		(prefix, if value { None } else { padded })
+	}
+}
+
+fn decode_node<H: Hasher>(data: &[u8]) -> Result<OwnedNode, String> {
+	let node = TrieNodeCodec::<H>::decode(data).map_err(|e| format!("Invalid trie node: {e:?}"))?;
+	let owned_value = |value: Value| match value {
+		Value::Inline(value) => NodeValue::Inline(value.to_vec()),
+		Value::Node(hash) => NodeValue::Node(hash.to_vec()),
+	};
+	let owned_children = |children: [Option<NodeHandle>; 16]| {
+		children.map(|child| {
+			child.map(|child| match child {
+				NodeHandle::Hash(hash) => Child::Hash(hash.to_vec()),
+				NodeHandle::Inline(data) => Child::Inline(data.to_vec()),
+			})
+		})
+	};
+	Ok(match node {
+		Node::Empty => OwnedNode::default(),
+		Node::Leaf(partial, value) => OwnedNode {
+			partial: partial.iter().collect(),
+			value: Some(owned_value(value)),
+			children: Default::default(),
+		},
+		Node::Branch(children, value) => OwnedNode {
+			partial: Vec::new(),
+			value: value.map(owned_value),
+			children: owned_children(children),
+		},
+		Node::NibbledBranch(partial, children, value) => OwnedNode {
+			partial: partial.iter().collect(),
+			value: value.map(owned_value),
+			children: owned_children(children),
+		},
+		Node::Extension(..) => return Err("Unexpected extension node".into()),
+	})
+}
+
+/// Whether one of the `targets` starts with `position`.
+fn has_target(targets: &BTreeSet<Vec<u8>>, position: &[u8]) -> bool {
+	targets
+		.range(position.to_vec()..)
+		.next()
+		.is_some_and(|target| target.starts_with(position))
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+	key.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect()
+}
+
+/// Packs nibbles into bytes. A trailing odd nibble is returned left-aligned.
+fn pack(nibbles: &[u8]) -> (Vec<u8>, Option<u8>) {
+	let chunks = nibbles.chunks_exact(2);
+	let padded = chunks.remainder().first().map(|nibble| nibble << 4);
+	(chunks.map(|pair| (pair[0] << 4) | pair[1]).collect(), padded)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{storage::ChildInfo, Blake2Hasher, H256};
+	use sp_trie::{
+		trie_types::{TrieDBBuilder, TrieDBMutBuilderV1},
+		HashDBT, KeySpacedDB, KeySpacedDBMut, PrefixedMemoryDB, Trie, TrieMut,
+	};
+	use std::collections::HashMap;
+
+	fn build_state(db: &mut PrefixedMemoryDB<Blake2Hasher>) -> H256 {
+		let child_info = ChildInfo::new_default(b"child");
+		let mut child_root = H256::default();
+		{
+			let mut keyspaced = KeySpacedDBMut::new(db, child_info.keyspace());
+			let mut trie =
+				TrieDBMutBuilderV1::<Blake2Hasher>::new(&mut keyspaced, &mut child_root).build();
+			for i in 0u32..50 {
+				trie.insert(&i.to_le_bytes(), &[i as u8; 40]).unwrap();
+			}
+		}
+		let mut root = H256::default();
+		{
+			let mut trie = TrieDBMutBuilderV1::<Blake2Hasher>::new(db, &mut root).build();
+			for i in 0u32..100 {
+				trie.insert(&i.to_be_bytes(), &[i as u8; 36]).unwrap();
+			}
+			trie.insert(&child_info.prefixed_storage_key(), child_root.as_ref()).unwrap();
+		}
+		root
+	}
+
+	#[test]
+	fn reported_nodes_rebuild_the_state() {
+		let mut db = PrefixedMemoryDB::<Blake2Hasher>::default();
+		let root = build_state(&mut db);
+
+		let mut copy = PrefixedMemoryDB::<Blake2Hasher>::default();
+		let get = |hash: &[u8], prefix: Prefix| Ok(db.get(&H256::from_slice(hash), prefix));
+		let mut visit = |key: &NodeKey, data: &[u8], delta: i64| {
+			assert_eq!(delta, 1);
+			assert_eq!(copy.insert(key.as_prefix(), data).as_ref(), &key.hash[..]);
+			Ok(())
+		};
+		TrieDiff::<Blake2Hasher>::new(&get, &mut visit)
+			.diff_roots(None, Some(root.as_ref()))
+			.unwrap();
+
+		{
+			let trie = TrieDBBuilder::<Blake2Hasher>::new(&copy, &root).build();
+			assert_eq!(trie.iter().unwrap().count(), 101);
+			let child_root = trie.get(&ChildInfo::new_default(b"child").prefixed_storage_key());
+			let child_root = H256::from_slice(&child_root.unwrap().unwrap());
+			let keyspaced = KeySpacedDB::new(&copy, b"child");
+			let child = TrieDBBuilder::<Blake2Hasher>::new(&keyspaced, &child_root).build();
+			assert_eq!(child.get(&7u32.to_le_bytes()).unwrap(), Some(vec![7; 40]));
+		}
+
+		let mut original = db.clone();
+		original.purge();
+		copy.purge();
+		assert_eq!(copy.drain().len(), original.drain().len());
+	}
+
+	#[test]
+	fn diff_reports_only_changed_nodes() {
+		let mut db = PrefixedMemoryDB::<Blake2Hasher>::default();
+		let old_root = build_state(&mut db);
+		let old_db = db.clone();
+		let mut new_root = old_root;
+		{
+			let mut trie =
+				TrieDBMutBuilderV1::<Blake2Hasher>::from_existing(&mut db, &mut new_root).build();
+			trie.insert(&3u32.to_be_bytes(), &[0; 36]).unwrap();
+		}
+
+		let get = |hash: &[u8], prefix: Prefix| {
+			let hash = H256::from_slice(hash);
+			Ok(db.get(&hash, prefix).or_else(|| old_db.get(&hash, prefix)))
+		};
+		let mut references = HashMap::<NodeKey, i64>::new();
+		let mut visit = |key: &NodeKey, _: &[u8], delta: i64| {
+			*references.entry(key.clone()).or_default() += delta;
+			Ok(())
+		};
+		TrieDiff::<Blake2Hasher>::new(&get, &mut visit)
+			.diff_roots(Some(old_root.as_ref()), Some(new_root.as_ref()))
+			.unwrap();
+
+		references.retain(|_, delta| *delta != 0);
+		let added = references.values().filter(|delta| **delta > 0).count();
+		let removed = references.values().filter(|delta| **delta < 0).count();
+		// The path from the root to the changed value, and the value itself.
+		assert!(added > 1 && added < 10);
+		assert_eq!(added, removed);
+	}
+}
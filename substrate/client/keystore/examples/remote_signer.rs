@@ -0,0 +1,44 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reference remote signer.
+//!
+//! Serves the keys of a local keystore directory to nodes started with `--keystore-remote`:
+//!
+//! ```sh
+//! cargo run -p sc-keystore --example remote_signer -- <KEYSTORE_PATH> <SOCKET_PATH>
+//! ```
+//!
+//! The node is then started with `--keystore-remote <SOCKET_PATH>`. Keys can be inserted with
+//! `key insert --keystore-path <KEYSTORE_PATH>` or the `author_insertKey` RPC of the node.
+
+use sc_keystore::{remote::RemoteSigner, LocalKeystore};
+use std::os::unix::net::UnixListener;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+	let mut args = std::env::args().skip(1);
+	let (Some(keystore_path), Some(socket_path)) = (args.next(), args.next()) else {
+		return Err("Usage: remote_signer <KEYSTORE_PATH> <SOCKET_PATH>".into())
+	};
+
+	let keystore = LocalKeystore::open(keystore_path, None)?;
+	let listener = UnixListener::bind(&socket_path)?;
+	println!("Serving keys on {}", socket_path);
+	RemoteSigner::new(keystore.into()).serve(listener)?;
+	Ok(())
+}
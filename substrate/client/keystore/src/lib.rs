@@ -25,7 +25,11 @@ use std::io;
 
 /// Local keystore implementation
 mod local;
+#[cfg(target_family = "unix")]
+pub mod remote;
 pub use local::LocalKeystore;
+#[cfg(target_family = "unix")]
+pub use remote::RemoteKeystore;
 pub use sp_keystore::Keystore;
 
 /// Keystore error.
@@ -0,0 +1,809 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keystore delegating to an external signer.
+//!
+//! The [`RemoteKeystore`] keeps no secret keys itself, every operation is forwarded to a signer
+//! process listening on a unix socket. Each message is a SCALE encoded [`Request`] or
+//! [`Response`], prefixed with its length as a little endian `u32`.
+//!
+//! [`RemoteSigner`] is the reference implementation of the signer side, serving the requests
+//! from any other [`Keystore`]. It can be used for testing or as a starting point for signers
+//! backed by an HSM or a KMS.
+//!
+//! Sr25519 VRF inputs are forwarded as the [`VrfTranscriptData`] they were built from, so only
+//! transcripts built with [`VrfTranscript::new`] can be signed. Bandersnatch VRF operations are
+//! not supported.
+
+use codec::{Decode, Encode};
+use parking_lot::Mutex;
+use sp_core::{
+	crypto::{ByteArray, CryptoTypeId, KeyTypeId, Pair as CorePair},
+	ecdsa, ed25519, sr25519,
+	sr25519::vrf::{VrfSignData, VrfSignature, VrfTranscript, VrfTranscriptData},
+};
+use sp_keystore::{Error as TraitError, Keystore, KeystorePtr};
+use std::{
+	collections::BTreeSet,
+	io::{self, Read, Write},
+	os::unix::net::{UnixListener, UnixStream},
+	path::PathBuf,
+	time::Duration,
+};
+
+sp_keystore::bandersnatch_experimental_enabled! {
+use sp_core::bandersnatch;
+}
+
+sp_keystore::bls_experimental_enabled! {
+use sp_core::{bls381, ecdsa_bls381};
+}
+
+use crate::Result;
+
+const LOG_TARGET: &str = "remote-keystore";
+
+/// Maximum size of a single message exchanged with the signer.
+const MAX_MESSAGE_SIZE: u32 = 16 * 1024 * 1024;
+
+/// Timeout for reading a response from or writing a request to the signer.
+const SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of distinct VRF transcript labels the signer accepts.
+const MAX_VRF_LABELS: usize = 1024;
+
+/// Maximum length of a VRF transcript label.
+const MAX_VRF_LABEL_LEN: usize = 64;
+
+/// Labels of the VRF transcripts rebuilt by the signer.
+///
+/// Transcripts only take static labels, so each distinct label is leaked once and reused.
+static VRF_LABELS: Mutex<BTreeSet<&'static [u8]>> = Mutex::new(BTreeSet::new());
+
+/// A request sent to the signer.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum Request {
+	/// Return the raw public keys of the given crypto type for the key type.
+	PublicKeys { crypto_id: CryptoTypeId, key_type: KeyTypeId },
+	/// Generate a new key pair and return its raw public key.
+	Generate { crypto_id: CryptoTypeId, key_type: KeyTypeId, seed: Option<String> },
+	/// Sign a message, see [`Keystore::sign_with`].
+	Sign { crypto_id: CryptoTypeId, key_type: KeyTypeId, public: Vec<u8>, msg: Vec<u8> },
+	/// Sign a pre-hashed message with an ecdsa key.
+	SignPrehashed { key_type: KeyTypeId, public: Vec<u8>, msg: [u8; 32] },
+	/// Sign a message with an (ecdsa,bls381) key, hashing it with keccak256 for ecdsa.
+	SignWithKeccak256 { key_type: KeyTypeId, public: Vec<u8>, msg: Vec<u8> },
+	/// Generate a proof of possession for a bls381 key.
+	GenerateProofOfPossession { key_type: KeyTypeId, public: Vec<u8> },
+	/// Insert a new secret key.
+	Insert { key_type: KeyTypeId, suri: String, public: Vec<u8> },
+	/// List all raw public keys of the key type.
+	Keys { key_type: KeyTypeId },
+	/// Check if all the given keys are available.
+	HasKeys { public_keys: Vec<(Vec<u8>, KeyTypeId)> },
+	/// Sign VRF data with an sr25519 key, see [`Keystore::sr25519_vrf_sign`].
+	Sr25519VrfSign {
+		key_type: KeyTypeId,
+		public: Vec<u8>,
+		input: VrfTranscriptData,
+		extra: Option<VrfTranscriptData>,
+	},
+	/// Compute a VRF pre-output with an sr25519 key, see [`Keystore::sr25519_vrf_pre_output`].
+	Sr25519VrfPreOutput { key_type: KeyTypeId, public: Vec<u8>, input: VrfTranscriptData },
+}
+
+/// Response of the signer to a [`Request`].
+///
+/// On success it contains the SCALE encoded return value of the request.
+pub type Response = std::result::Result<Vec<u8>, RemoteError>;
+
+/// Error returned by the signer, mirroring [`TraitError`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum RemoteError {
+	/// The key type or crypto type is not supported.
+	KeyNotSupported(KeyTypeId),
+	/// Validation of the request failed.
+	ValidationError(String),
+	/// The signer's keystore is unavailable.
+	Unavailable,
+	/// Any other error.
+	Other(String),
+}
+
+impl From<TraitError> for RemoteError {
+	fn from(error: TraitError) -> Self {
+		match error {
+			TraitError::KeyNotSupported(id) => Self::KeyNotSupported(id),
+			TraitError::ValidationError(e) => Self::ValidationError(e),
+			TraitError::Unavailable => Self::Unavailable,
+			TraitError::Other(e) => Self::Other(e),
+		}
+	}
+}
+
+impl From<RemoteError> for TraitError {
+	fn from(error: RemoteError) -> Self {
+		match error {
+			RemoteError::KeyNotSupported(id) => Self::KeyNotSupported(id),
+			RemoteError::ValidationError(e) => Self::ValidationError(e),
+			RemoteError::Unavailable => Self::Unavailable,
+			RemoteError::Other(e) => Self::Other(e),
+		}
+	}
+}
+
+/// Write a length prefixed message.
+fn write_message(stream: &mut UnixStream, message: &[u8]) -> io::Result<()> {
+	let len = u32::try_from(message.len())
+		.ok()
+		.filter(|len| *len <= MAX_MESSAGE_SIZE)
+		.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Message too large"))?;
+	stream.write_all(&len.to_le_bytes())?;
+	stream.write_all(message)?;
+	stream.flush()
+}
+
+/// Read a length prefixed message.
+fn read_message(stream: &mut UnixStream) -> io::Result<Vec<u8>> {
+	let mut len = [0u8; 4];
+	stream.read_exact(&mut len)?;
+	let len = u32::from_le_bytes(len);
+	if len > MAX_MESSAGE_SIZE {
+		return Err(io::Error::new(io::ErrorKind::InvalidData, "Message too large"))
+	}
+	let mut message = vec![0u8; len as usize];
+	stream.read_exact(&mut message)?;
+	Ok(message)
+}
+
+/// A keystore forwarding all operations to a remote signer.
+///
+/// See the [module documentation](self) for the protocol.
+pub struct RemoteKeystore {
+	socket: PathBuf,
+	connection: Mutex<Option<UnixStream>>,
+}
+
+impl RemoteKeystore {
+	/// Connect to the signer listening at the given unix socket.
+	///
+	/// Fails if the signer isn't reachable. The connection is re-established on demand if it is
+	/// lost afterwards.
+	pub fn open<T: Into<PathBuf>>(socket: T) -> Result<Self> {
+		let socket = socket.into();
+		let connection = Self::connect(&socket)?;
+		Ok(Self { socket, connection: Mutex::new(Some(connection)) })
+	}
+
+	fn connect(socket: &PathBuf) -> io::Result<UnixStream> {
+		let stream = UnixStream::connect(socket)?;
+		stream.set_read_timeout(Some(SIGNER_TIMEOUT))?;
+		stream.set_write_timeout(Some(SIGNER_TIMEOUT))?;
+		Ok(stream)
+	}
+
+	/// Send the request and decode the return value of the response.
+	///
+	/// The request is retried once on a fresh connection if the existing one failed.
+	fn call<R: Decode>(&self, request: Request) -> std::result::Result<R, TraitError> {
+		let request = request.encode();
+		let mut connection = self.connection.lock();
+
+		let attempt = |connection: &mut Option<UnixStream>| -> io::Result<Vec<u8>> {
+			let stream = match connection {
+				Some(stream) => stream,
+				None => connection.insert(Self::connect(&self.socket)?),
+			};
+			write_message(stream, &request)?;
+			read_message(stream)
+		};
+		let response = attempt(&mut connection).or_else(|e| {
+			log::debug!(target: LOG_TARGET, "Signer request failed, reconnecting: {}", e);
+			*connection = None;
+			attempt(&mut connection)
+		});
+		let response = response.map_err(|e| {
+			*connection = None;
+			log::warn!(target: LOG_TARGET, "Remote signer at {:?} unavailable: {}", self.socket, e);
+			TraitError::Unavailable
+		})?;
+
+		let invalid = |e: codec::Error| {
+			TraitError::Other(format!("Invalid response from the remote signer: {}", e))
+		};
+		let value = Response::decode(&mut &response[..]).map_err(invalid)??;
+		R::decode(&mut &value[..]).map_err(invalid)
+	}
+
+	fn public_keys<T: CorePair>(
+		&self,
+		crypto_id: CryptoTypeId,
+		key_type: KeyTypeId,
+	) -> Vec<T::Public> {
+		self.call::<Vec<Vec<u8>>>(Request::PublicKeys { crypto_id, key_type })
+			.map(|keys| keys.iter().filter_map(|k| T::Public::from_slice(k).ok()).collect())
+			.unwrap_or_default()
+	}
+
+	fn generate_new<T: CorePair>(
+		&self,
+		crypto_id: CryptoTypeId,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<T::Public, TraitError> {
+		let seed = seed.map(Into::into);
+		let public = self.call::<Vec<u8>>(Request::Generate { crypto_id, key_type, seed })?;
+		from_raw(&public)
+	}
+
+	fn sign<T: CorePair>(
+		&self,
+		request: Request,
+	) -> std::result::Result<Option<T::Signature>, TraitError> {
+		self.call::<Option<Vec<u8>>>(request)?.map(|s| from_raw(&s)).transpose()
+	}
+
+	fn sign_message<T: CorePair>(
+		&self,
+		crypto_id: CryptoTypeId,
+		key_type: KeyTypeId,
+		public: &T::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<T::Signature>, TraitError> {
+		self.sign::<T>(Request::Sign {
+			crypto_id,
+			key_type,
+			public: public.to_raw_vec(),
+			msg: msg.to_vec(),
+		})
+	}
+}
+
+/// Convert the raw bytes received from the signer into a key or signature.
+fn from_raw<T: ByteArray>(raw: &[u8]) -> std::result::Result<T, TraitError> {
+	T::from_slice(raw).map_err(|_| TraitError::Other("Invalid key or signature length".into()))
+}
+
+/// Get the data of a transcript to forward it to the signer.
+fn vrf_transcript_data(
+	transcript: &VrfTranscript,
+) -> std::result::Result<VrfTranscriptData, TraitError> {
+	transcript.data().cloned().ok_or_else(|| {
+		TraitError::Other("Only VRF transcripts built from data can be signed remotely".into())
+	})
+}
+
+/// Rebuild a transcript received from a node.
+fn vrf_transcript(data: &VrfTranscriptData) -> std::result::Result<VrfTranscript, TraitError> {
+	let mut labels = VRF_LABELS.lock();
+	let mut intern = |label: &[u8]| -> std::result::Result<&'static [u8], TraitError> {
+		if let Some(label) = labels.get(label) {
+			return Ok(*label)
+		}
+		if label.len() > MAX_VRF_LABEL_LEN || labels.len() >= MAX_VRF_LABELS {
+			return Err(TraitError::ValidationError("Unsupported VRF transcript label".into()))
+		}
+		let label: &'static [u8] = Box::leak(label.to_vec().into_boxed_slice());
+		labels.insert(label);
+		Ok(label)
+	};
+
+	let label = intern(&data.label)?;
+	let items = data
+		.items
+		.iter()
+		.map(|(domain, message)| Ok((intern(domain)?, &message[..])))
+		.collect::<std::result::Result<Vec<_>, TraitError>>()?;
+	Ok(VrfTranscript::new(label, &items))
+}
+
+fn bandersnatch_vrf_not_supported() -> TraitError {
+	TraitError::Other("Bandersnatch VRF operations are not supported by the remote keystore".into())
+}
+
+impl Keystore for RemoteKeystore {
+	fn sr25519_public_keys(&self, key_type: KeyTypeId) -> Vec<sr25519::Public> {
+		self.public_keys::<sr25519::Pair>(sr25519::CRYPTO_ID, key_type)
+	}
+
+	fn sr25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<sr25519::Public, TraitError> {
+		self.generate_new::<sr25519::Pair>(sr25519::CRYPTO_ID, key_type, seed)
+	}
+
+	fn sr25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<sr25519::Signature>, TraitError> {
+		self.sign_message::<sr25519::Pair>(sr25519::CRYPTO_ID, key_type, public, msg)
+	}
+
+	fn sr25519_vrf_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		data: &VrfSignData,
+	) -> std::result::Result<Option<VrfSignature>, TraitError> {
+		self.call(Request::Sr25519VrfSign {
+			key_type,
+			public: public.to_raw_vec(),
+			input: vrf_transcript_data(data.as_ref())?,
+			extra: data.extra().map(vrf_transcript_data).transpose()?,
+		})
+	}
+
+	fn sr25519_vrf_pre_output(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		input: &sr25519::vrf::VrfInput,
+	) -> std::result::Result<Option<sr25519::vrf::VrfPreOutput>, TraitError> {
+		self.call(Request::Sr25519VrfPreOutput {
+			key_type,
+			public: public.to_raw_vec(),
+			input: vrf_transcript_data(input)?,
+		})
+	}
+
+	fn ed25519_public_keys(&self, key_type: KeyTypeId) -> Vec<ed25519::Public> {
+		self.public_keys::<ed25519::Pair>(ed25519::CRYPTO_ID, key_type)
+	}
+
+	fn ed25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ed25519::Public, TraitError> {
+		self.generate_new::<ed25519::Pair>(ed25519::CRYPTO_ID, key_type, seed)
+	}
+
+	fn ed25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ed25519::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<ed25519::Signature>, TraitError> {
+		self.sign_message::<ed25519::Pair>(ed25519::CRYPTO_ID, key_type, public, msg)
+	}
+
+	fn ecdsa_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa::Public> {
+		self.public_keys::<ecdsa::Pair>(ecdsa::CRYPTO_ID, key_type)
+	}
+
+	fn ecdsa_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ecdsa::Public, TraitError> {
+		self.generate_new::<ecdsa::Pair>(ecdsa::CRYPTO_ID, key_type, seed)
+	}
+
+	fn ecdsa_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		self.sign_message::<ecdsa::Pair>(ecdsa::CRYPTO_ID, key_type, public, msg)
+	}
+
+	fn ecdsa_sign_prehashed(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8; 32],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		self.sign::<ecdsa::Pair>(Request::SignPrehashed {
+			key_type,
+			public: public.to_raw_vec(),
+			msg: *msg,
+		})
+	}
+
+	sp_keystore::bandersnatch_experimental_enabled! {
+		fn bandersnatch_public_keys(&self, key_type: KeyTypeId) -> Vec<bandersnatch::Public> {
+			self.public_keys::<bandersnatch::Pair>(bandersnatch::CRYPTO_ID, key_type)
+		}
+
+		fn bandersnatch_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bandersnatch::Public, TraitError> {
+			self.generate_new::<bandersnatch::Pair>(bandersnatch::CRYPTO_ID, key_type, seed)
+		}
+
+		fn bandersnatch_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bandersnatch::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bandersnatch::Signature>, TraitError> {
+			self.sign_message::<bandersnatch::Pair>(bandersnatch::CRYPTO_ID, key_type, public, msg)
+		}
+
+		fn bandersnatch_vrf_sign(
+			&self,
+			_key_type: KeyTypeId,
+			_public: &bandersnatch::Public,
+			_data: &bandersnatch::vrf::VrfSignData,
+		) -> std::result::Result<Option<bandersnatch::vrf::VrfSignature>, TraitError> {
+			Err(bandersnatch_vrf_not_supported())
+		}
+
+		fn bandersnatch_vrf_pre_output(
+			&self,
+			_key_type: KeyTypeId,
+			_public: &bandersnatch::Public,
+			_input: &bandersnatch::vrf::VrfInput,
+		) -> std::result::Result<Option<bandersnatch::vrf::VrfPreOutput>, TraitError> {
+			Err(bandersnatch_vrf_not_supported())
+		}
+
+		fn bandersnatch_ring_vrf_sign(
+			&self,
+			_key_type: KeyTypeId,
+			_public: &bandersnatch::Public,
+			_data: &bandersnatch::vrf::VrfSignData,
+			_prover: &bandersnatch::ring_vrf::RingProver,
+		) -> std::result::Result<Option<bandersnatch::ring_vrf::RingVrfSignature>, TraitError> {
+			Err(bandersnatch_vrf_not_supported())
+		}
+	}
+
+	sp_keystore::bls_experimental_enabled! {
+		fn bls381_public_keys(&self, key_type: KeyTypeId) -> Vec<bls381::Public> {
+			self.public_keys::<bls381::Pair>(bls381::CRYPTO_ID, key_type)
+		}
+
+		fn bls381_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bls381::Public, TraitError> {
+			self.generate_new::<bls381::Pair>(bls381::CRYPTO_ID, key_type, seed)
+		}
+
+		fn bls381_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bls381::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bls381::Signature>, TraitError> {
+			self.sign_message::<bls381::Pair>(bls381::CRYPTO_ID, key_type, public, msg)
+		}
+
+		fn bls381_generate_proof_of_possession(
+			&self,
+			key_type: KeyTypeId,
+			public: &bls381::Public,
+		) -> std::result::Result<Option<bls381::Signature>, TraitError> {
+			self.sign::<bls381::Pair>(Request::GenerateProofOfPossession {
+				key_type,
+				public: public.to_raw_vec(),
+			})
+		}
+
+		fn ecdsa_bls381_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa_bls381::Public> {
+			self.public_keys::<ecdsa_bls381::Pair>(ecdsa_bls381::CRYPTO_ID, key_type)
+		}
+
+		fn ecdsa_bls381_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<ecdsa_bls381::Public, TraitError> {
+			self.generate_new::<ecdsa_bls381::Pair>(ecdsa_bls381::CRYPTO_ID, key_type, seed)
+		}
+
+		fn ecdsa_bls381_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &ecdsa_bls381::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<ecdsa_bls381::Signature>, TraitError> {
+			self.sign_message::<ecdsa_bls381::Pair>(ecdsa_bls381::CRYPTO_ID, key_type, public, msg)
+		}
+
+		fn ecdsa_bls381_sign_with_keccak256(
+			&self,
+			key_type: KeyTypeId,
+			public: &ecdsa_bls381::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<ecdsa_bls381::Signature>, TraitError> {
+			self.sign::<ecdsa_bls381::Pair>(Request::SignWithKeccak256 {
+				key_type,
+				public: public.to_raw_vec(),
+				msg: msg.to_vec(),
+			})
+		}
+	}
+
+	fn insert(
+		&self,
+		key_type: KeyTypeId,
+		suri: &str,
+		public: &[u8],
+	) -> std::result::Result<(), ()> {
+		self.call::<()>(Request::Insert { key_type, suri: suri.into(), public: public.to_vec() })
+			.map_err(|_| ())
+	}
+
+	fn keys(&self, key_type: KeyTypeId) -> std::result::Result<Vec<Vec<u8>>, TraitError> {
+		self.call(Request::Keys { key_type })
+	}
+
+	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		self.call(Request::HasKeys { public_keys: public_keys.to_vec() })
+			.unwrap_or(false)
+	}
+
+	fn sign_with(
+		&self,
+		id: KeyTypeId,
+		crypto_id: CryptoTypeId,
+		public: &[u8],
+		msg: &[u8],
+	) -> std::result::Result<Option<Vec<u8>>, TraitError> {
+		self.call(Request::Sign {
+			crypto_id,
+			key_type: id,
+			public: public.to_vec(),
+			msg: msg.to_vec(),
+		})
+	}
+}
+
+/// Reference signer serving the requests of [`RemoteKeystore`]s from a local keystore.
+pub struct RemoteSigner {
+	keystore: KeystorePtr,
+}
+
+impl RemoteSigner {
+	/// Create a new signer using the keys of the given keystore.
+	pub fn new(keystore: KeystorePtr) -> Self {
+		Self { keystore }
+	}
+
+	/// Accept connections on the listener and serve them, each on its own thread.
+	///
+	/// Only returns if accepting a connection fails.
+	pub fn serve(&self, listener: UnixListener) -> io::Result<()> {
+		loop {
+			let (stream, _) = listener.accept()?;
+			let signer = Self::new(self.keystore.clone());
+			std::thread::spawn(move || {
+				if let Err(e) = signer.serve_connection(stream) {
+					log::debug!(target: LOG_TARGET, "Signer connection closed: {}", e);
+				}
+			});
+		}
+	}
+
+	/// Serve the requests of a single connection until it is closed.
+	pub fn serve_connection(&self, mut stream: UnixStream) -> io::Result<()> {
+		loop {
+			let request = match read_message(&mut stream) {
+				Ok(request) => request,
+				Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+				Err(e) => return Err(e),
+			};
+			let response: Response = match Request::decode(&mut &request[..]) {
+				Ok(request) => self.handle(request).map_err(Into::into),
+				Err(e) => Err(RemoteError::Other(format!("Invalid request: {}", e))),
+			};
+			write_message(&mut stream, &response.encode())?;
+		}
+	}
+
+	/// Handle a single request, returning the encoded return value.
+	pub fn handle(&self, request: Request) -> std::result::Result<Vec<u8>, TraitError> {
+		let keystore = &*self.keystore;
+		let response = match request {
+			Request::PublicKeys { crypto_id, key_type } =>
+				public_keys(keystore, crypto_id, key_type)?.encode(),
+			Request::Generate { crypto_id, key_type, seed } =>
+				generate_new(keystore, crypto_id, key_type, seed.as_deref())?.encode(),
+			// All supported signatures are byte arrays, so their SCALE encoding is the raw
+			// signature.
+			Request::Sign { crypto_id, key_type, public, msg } =>
+				keystore.sign_with(key_type, crypto_id, &public, &msg)?.encode(),
+			Request::SignPrehashed { key_type, public, msg } => keystore
+				.ecdsa_sign_prehashed(key_type, &public_from_raw(&public)?, &msg)?
+				.map(|signature| signature.to_raw_vec())
+				.encode(),
+			#[cfg(feature = "bls-experimental")]
+			Request::SignWithKeccak256 { key_type, public, msg } => keystore
+				.ecdsa_bls381_sign_with_keccak256(key_type, &public_from_raw(&public)?, &msg)?
+				.map(|signature| signature.to_raw_vec())
+				.encode(),
+			#[cfg(feature = "bls-experimental")]
+			Request::GenerateProofOfPossession { key_type, public } => keystore
+				.bls381_generate_proof_of_possession(key_type, &public_from_raw(&public)?)?
+				.map(|signature| signature.to_raw_vec())
+				.encode(),
+			#[cfg(not(feature = "bls-experimental"))]
+			Request::SignWithKeccak256 { key_type, .. } |
+			Request::GenerateProofOfPossession { key_type, .. } =>
+				return Err(TraitError::KeyNotSupported(key_type)),
+			Request::Insert { key_type, suri, public } => keystore
+				.insert(key_type, &suri, &public)
+				.map_err(|_| TraitError::Other("Failed to insert key".into()))?
+				.encode(),
+			Request::Keys { key_type } => keystore.keys(key_type)?.encode(),
+			Request::HasKeys { public_keys } => keystore.has_keys(&public_keys).encode(),
+			Request::Sr25519VrfSign { key_type, public, input, extra } => {
+				let mut data = VrfSignData::new(vrf_transcript(&input)?);
+				if let Some(extra) = extra {
+					data = data.with_extra(vrf_transcript(&extra)?);
+				}
+				keystore.sr25519_vrf_sign(key_type, &public_from_raw(&public)?, &data)?.encode()
+			},
+			Request::Sr25519VrfPreOutput { key_type, public, input } => keystore
+				.sr25519_vrf_pre_output(
+					key_type,
+					&public_from_raw(&public)?,
+					&vrf_transcript(&input)?,
+				)?
+				.encode(),
+		};
+		Ok(response)
+	}
+}
+
+fn public_from_raw<T: ByteArray>(raw: &[u8]) -> std::result::Result<T, TraitError> {
+	T::from_slice(raw).map_err(|_| TraitError::ValidationError("Invalid public key format".into()))
+}
+
+fn public_keys(
+	keystore: &dyn Keystore,
+	crypto_id: CryptoTypeId,
+	key_type: KeyTypeId,
+) -> std::result::Result<Vec<Vec<u8>>, TraitError> {
+	fn raw<T: ByteArray>(keys: Vec<T>) -> Vec<Vec<u8>> {
+		keys.iter().map(ByteArray::to_raw_vec).collect()
+	}
+
+	let keys = match crypto_id {
+		sr25519::CRYPTO_ID => raw(keystore.sr25519_public_keys(key_type)),
+		ed25519::CRYPTO_ID => raw(keystore.ed25519_public_keys(key_type)),
+		ecdsa::CRYPTO_ID => raw(keystore.ecdsa_public_keys(key_type)),
+		#[cfg(feature = "bandersnatch-experimental")]
+		bandersnatch::CRYPTO_ID => raw(keystore.bandersnatch_public_keys(key_type)),
+		#[cfg(feature = "bls-experimental")]
+		bls381::CRYPTO_ID => raw(keystore.bls381_public_keys(key_type)),
+		#[cfg(feature = "bls-experimental")]
+		ecdsa_bls381::CRYPTO_ID => raw(keystore.ecdsa_bls381_public_keys(key_type)),
+		_ => return Err(TraitError::KeyNotSupported(key_type)),
+	};
+	Ok(keys)
+}
+
+fn generate_new(
+	keystore: &dyn Keystore,
+	crypto_id: CryptoTypeId,
+	key_type: KeyTypeId,
+	seed: Option<&str>,
+) -> std::result::Result<Vec<u8>, TraitError> {
+	let public = match crypto_id {
+		sr25519::CRYPTO_ID => keystore.sr25519_generate_new(key_type, seed)?.to_raw_vec(),
+		ed25519::CRYPTO_ID => keystore.ed25519_generate_new(key_type, seed)?.to_raw_vec(),
+		ecdsa::CRYPTO_ID => keystore.ecdsa_generate_new(key_type, seed)?.to_raw_vec(),
+		#[cfg(feature = "bandersnatch-experimental")]
+		bandersnatch::CRYPTO_ID => keystore.bandersnatch_generate_new(key_type, seed)?.to_raw_vec(),
+		#[cfg(feature = "bls-experimental")]
+		bls381::CRYPTO_ID => keystore.bls381_generate_new(key_type, seed)?.to_raw_vec(),
+		#[cfg(feature = "bls-experimental")]
+		ecdsa_bls381::CRYPTO_ID => keystore.ecdsa_bls381_generate_new(key_type, seed)?.to_raw_vec(),
+		_ => return Err(TraitError::KeyNotSupported(key_type)),
+	};
+	Ok(public)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::LocalKeystore;
+	use sp_core::{crypto::key_types, Pair};
+	use tempfile::TempDir;
+
+	fn remote_keystore() -> (TempDir, RemoteKeystore, KeystorePtr) {
+		let temp_dir = TempDir::new().unwrap();
+		let socket = temp_dir.path().join("signer.sock");
+		let listener = UnixListener::bind(&socket).unwrap();
+		let local: KeystorePtr = LocalKeystore::in_memory().into();
+		let signer = RemoteSigner::new(local.clone());
+		std::thread::spawn(move || signer.serve(listener));
+		(temp_dir, RemoteKeystore::open(socket).unwrap(), local)
+	}
+
+	#[test]
+	fn keys_are_generated_and_used_by_the_signer() {
+		let (_dir, remote, local) = remote_keystore();
+		let msg = b"hello";
+
+		let public = remote.sr25519_generate_new(key_types::BABE, None).unwrap();
+		assert_eq!(local.sr25519_public_keys(key_types::BABE), vec![public]);
+		assert_eq!(remote.sr25519_public_keys(key_types::BABE), vec![public]);
+		let signature = remote.sr25519_sign(key_types::BABE, &public, msg).unwrap().unwrap();
+		assert!(sr25519::Pair::verify(&signature, msg, &public));
+
+		let public = remote.ed25519_generate_new(key_types::GRANDPA, Some("//Alice")).unwrap();
+		let signature = remote.ed25519_sign(key_types::GRANDPA, &public, msg).unwrap().unwrap();
+		assert!(ed25519::Pair::verify(&signature, msg, &public));
+
+		let public = local.ecdsa_generate_new(key_types::BEEFY, None).unwrap();
+		let signature = remote.ecdsa_sign(key_types::BEEFY, &public, msg).unwrap().unwrap();
+		assert!(ecdsa::Pair::verify(&signature, msg, &public));
+		let prehashed = sp_core::hashing::blake2_256(msg);
+		assert!(remote
+			.ecdsa_sign_prehashed(key_types::BEEFY, &public, &prehashed)
+			.unwrap()
+			.is_some());
+
+		assert!(remote.has_keys(&[(public.to_raw_vec(), key_types::BEEFY)]));
+		assert_eq!(remote.keys(key_types::BEEFY).unwrap(), vec![public.to_raw_vec()]);
+	}
+
+	#[test]
+	fn vrf_data_is_signed_by_the_signer() {
+		use sp_core::crypto::{VrfPublic, VrfSecret};
+
+		let (_dir, remote, local) = remote_keystore();
+		let public = remote.sr25519_generate_new(key_types::BABE, Some("//Alice")).unwrap();
+		let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+
+		let input = VrfTranscript::new(b"label", &[(b"domain", b"data")]);
+		let extra = VrfTranscript::new(b"extra", &[(b"domain", b"more data")]);
+		let data = VrfSignData::new(input.clone()).with_extra(extra);
+		let signature = remote.sr25519_vrf_sign(key_types::BABE, &public, &data).unwrap().unwrap();
+		assert!(public.vrf_verify(&data, &signature));
+		assert_eq!(
+			remote.sr25519_vrf_pre_output(key_types::BABE, &public, &input).unwrap(),
+			Some(pair.vrf_pre_output(&input)),
+		);
+		assert_eq!(
+			local.sr25519_vrf_pre_output(key_types::BABE, &public, &input).unwrap(),
+			Some(signature.pre_output),
+		);
+	}
+
+	#[test]
+	fn unknown_keys_are_reported() {
+		let (_dir, remote, _) = remote_keystore();
+		let public = sr25519::Pair::from_string("//Bob", None).unwrap().public();
+
+		assert_eq!(remote.sr25519_sign(key_types::BABE, &public, b"msg").unwrap(), None);
+		assert!(!remote.has_keys(&[(public.to_raw_vec(), key_types::BABE)]));
+
+		let input = VrfTranscript::new(b"label", &[(b"domain", b"data")]);
+		assert_eq!(remote.sr25519_vrf_pre_output(key_types::BABE, &public, &input).unwrap(), None);
+	}
+
+	#[test]
+	fn unavailable_signer_is_reported() {
+		let temp_dir = TempDir::new().unwrap();
+		assert!(RemoteKeystore::open(temp_dir.path().join("signer.sock")).is_err());
+	}
+}
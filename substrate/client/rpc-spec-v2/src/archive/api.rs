@@ -24,7 +24,8 @@ use crate::{
 		types::MethodResult,
 	},
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+		ArchiveEventsEvent, ArchiveEventsFilter, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+		ArchiveStorageEvent, StorageQuery,
 	},
 };
 use jsonrpsee::proc_macros::rpc;
@@ -130,4 +131,30 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<ArchiveStorageDiffItem<String>>,
 		previous_hash: Option<Hash>,
 	);
+
+	/// Returns the runtime events of the finalized blocks in the provided height range.
+	///
+	/// Only the events matching at least one of the provided filters are reported. An empty
+	/// list of filters reports all events.
+	///
+	/// Events are decoded with the metadata of the runtime of the block. Each one is reported
+	/// with its pallet and event indexes and names, its decoded fields, its raw hex-encoded
+	/// SCALE encoding, its phase and its topics.
+	///
+	/// A single call covers at most 1024 blocks.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and can change in minor or patch releases.
+	#[subscription(
+		name = "archive_v1_events" => "archive_v1_eventsEvent",
+		unsubscribe = "archive_v1_stopEvents",
+		item = ArchiveEventsEvent,
+	)]
+	fn archive_v1_events(
+		&self,
+		start_height: u64,
+		end_height: u64,
+		filter: Vec<ArchiveEventsFilter>,
+	);
 }
@@ -20,6 +20,7 @@
 
 use crate::{
 	archive::{
+		archive_events::ArchiveEvents,
 		archive_storage::ArchiveStorageDiff,
 		error::{Error as ArchiveError, Infallible},
 		types::MethodResult,
@@ -27,7 +28,8 @@ use crate::{
 	},
 	common::{
 		events::{
-			ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+			ArchiveEventsEvent, ArchiveEventsFilter, ArchiveStorageDiffEvent,
+			ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
 		},
 		storage::{QueryResult, StorageSubscriptionClient},
 	},
@@ -63,6 +65,11 @@ pub(crate) const LOG_TARGET: &str = "rpc-spec-v2::archive";
 /// its down buffer capacity per connection as well.
 const STORAGE_QUERY_BUF: usize = 16;
 
+/// The buffer capacity for the blocks of an events query.
+///
+/// Blocks are fetched from the database only when the subscriber keeps up.
+const EVENTS_QUERY_BUF: usize = 16;
+
 /// An API for archive RPC calls.
 pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	/// Substrate client.
@@ -306,6 +313,64 @@ where
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 	}
+
+	fn archive_v1_events(
+		&self,
+		pending: PendingSubscriptionSink,
+		start_height: u64,
+		end_height: u64,
+		filter: Vec<ArchiveEventsFilter>,
+	) {
+		let events_client = ArchiveEvents::new(self.client.clone());
+
+		log::trace!(target: LOG_TARGET, "Events subscription started");
+
+		let fut = async move {
+			let Ok(mut sink) = pending.accept().await.map(Subscription::from) else { return };
+
+			let (tx, mut rx) = tokio::sync::mpsc::channel(EVENTS_QUERY_BUF);
+			let events_fut = events_client.generate_events(start_height, end_height, filter, tx);
+
+			// We don't care about the return value of this join:
+			// - process_events might encounter an error (if the client disconnected)
+			// - events_fut might encounter an error while fetching the events of a block and
+			// the error is propagated via the sink.
+			let _ =
+				futures::future::join(events_fut, process_archive_events(&mut rx, &mut sink)).await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Sends all the events of the `archive_v1_events` method to the sink.
+async fn process_archive_events(
+	rx: &mut mpsc::Receiver<ArchiveEventsEvent>,
+	sink: &mut Subscription,
+) {
+	loop {
+		tokio::select! {
+			_ = sink.closed() => {
+				return
+			},
+
+			maybe_event = rx.recv() => {
+				let Some(event) = maybe_event else {
+					break;
+				};
+
+				if event.is_done() {
+					log::debug!(target: LOG_TARGET, "Finished processing events query");
+				} else if event.is_err() {
+					log::debug!(target: LOG_TARGET, "Error encountered while processing events query");
+				}
+
+				if sink.send(&event).await.is_err() {
+					return
+				}
+			}
+		}
+	}
 }
 
 /// Sends all the events of the storage_diff method to the sink.
@@ -0,0 +1,786 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `archive_events` method.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Compact, Decode, Encode};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use sc_client_api::{Backend, CallExecutor, ExecutorProvider, StorageKey, StorageProvider};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde_json::{Map, Value};
+use sp_api::{CallApiAt, CallContext};
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor},
+	SaturatedConversion,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+	archive::archive::LOG_TARGET,
+	common::events::{
+		ArchiveEventPhase, ArchiveEventResult, ArchiveEventsBlock, ArchiveEventsEvent,
+		ArchiveEventsFilter,
+	},
+	hex_string,
+};
+
+/// The metadata version that exposes the type of the outer `RuntimeEvent` enum.
+const METADATA_VERSION: u32 = 15;
+
+/// The maximum number of blocks covered by a single events query.
+pub const MAX_EVENTS_BLOCK_RANGE: u64 = 1024;
+
+/// The maximum nesting of types walked while decoding or skipping over an encoded event.
+///
+/// Protects the node against malicious or broken type registries.
+const MAX_TYPE_DEPTH: usize = 256;
+
+/// Mirror of `frame_system::Phase` used to decode the phase of an event record.
+#[derive(Decode)]
+enum Phase {
+	ApplyExtrinsic(u32),
+	Finalization,
+	Initialization,
+}
+
+impl From<Phase> for ArchiveEventPhase {
+	fn from(phase: Phase) -> Self {
+		match phase {
+			Phase::ApplyExtrinsic(index) => ArchiveEventPhase::ApplyExtrinsic { index },
+			Phase::Finalization => ArchiveEventPhase::Finalization,
+			Phase::Initialization => ArchiveEventPhase::Initialization,
+		}
+	}
+}
+
+/// Type information needed to decode the encoded `frame_system::Events` storage value.
+struct EventsDecoder {
+	/// The spec name and version of the runtime this decoder was built for.
+	spec: (String, u32),
+	/// The type registry of the runtime metadata.
+	types: PortableRegistry,
+	/// The type ID of the outer `RuntimeEvent` enum.
+	event_ty: u32,
+}
+
+impl EventsDecoder {
+	/// Decode the events from the raw storage value, keeping only the ones that match the filter.
+	///
+	/// An empty filter matches every event.
+	fn decode<Hash: Decode + Encode>(
+		&self,
+		mut input: &[u8],
+		filter: &[ArchiveEventsFilter],
+	) -> Result<Vec<ArchiveEventResult>, String> {
+		let input = &mut input;
+		let Compact(len) = Compact::<u32>::decode(input).map_err(|e| e.to_string())?;
+
+		let mut events = Vec::new();
+		for _ in 0..len {
+			let phase = Phase::decode(input).map_err(|e| e.to_string())?;
+
+			// Every `RuntimeEvent` is encoded as the pallet index followed by the event index.
+			let (pallet_index, event_index) = match **input {
+				[pallet_index, event_index, ..] => (pallet_index, event_index),
+				_ => return Err("Runtime event is too short".into()),
+			};
+
+			if !filter.is_empty() &&
+				!filter.iter().any(|filter| filter.matches(pallet_index, event_index))
+			{
+				skip_type(&self.types, self.event_ty, input, 0)?;
+				Vec::<Hash>::decode(input).map_err(|e| e.to_string())?;
+				continue
+			}
+
+			let start = *input;
+			let (pallet_name, event_name, fields) = self.decode_event(input)?;
+			let event = &start[..start.len() - input.len()];
+
+			let topics = Vec::<Hash>::decode(input).map_err(|e| e.to_string())?;
+
+			events.push(ArchiveEventResult {
+				phase: phase.into(),
+				pallet_index,
+				event_index,
+				pallet_name,
+				event_name,
+				fields,
+				event: hex_string(&event),
+				topics: topics.iter().map(|topic| hex_string(&topic.encode())).collect(),
+			});
+		}
+
+		Ok(events)
+	}
+
+	/// Decode a single `RuntimeEvent` into its pallet name, event name and fields.
+	fn decode_event(&self, input: &mut &[u8]) -> Result<(String, String, Value), String> {
+		let pallet = decode_variant(&self.types, self.event_ty, input)?;
+		let [pallet_event] = &pallet.fields[..] else {
+			return Err(format!("Unexpected fields of the {} runtime event", pallet.name))
+		};
+		let event = decode_variant(&self.types, pallet_event.ty.id, input)?;
+		let fields = decode_fields(&self.types, &event.fields, input, 1)?;
+
+		Ok((pallet.name.clone(), event.name.clone(), fields))
+	}
+}
+
+/// Decode the index of a variant of the given enum type and return the matching variant.
+fn decode_variant<'a>(
+	types: &'a PortableRegistry,
+	id: u32,
+	input: &mut &[u8],
+) -> Result<&'a scale_info::Variant<PortableForm>, String> {
+	let ty = types.resolve(id).ok_or_else(|| format!("Type {id} not found in metadata"))?;
+	let TypeDef::Variant(variant) = &ty.type_def else {
+		return Err(format!("Type {id} is not an enum"))
+	};
+	let index = u8::decode(input).map_err(|e| e.to_string())?;
+	variant
+		.variants
+		.iter()
+		.find(|variant| variant.index == index)
+		.ok_or_else(|| format!("Variant {index} of type {id} not found in metadata"))
+}
+
+/// Decode the fields of a composite or variant.
+///
+/// Named fields become an object. Unnamed fields become an array, or the value itself for a
+/// single field. No fields become `null`.
+fn decode_fields(
+	types: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	input: &mut &[u8],
+	depth: usize,
+) -> Result<Value, String> {
+	if !fields.is_empty() && fields.iter().all(|field| field.name.is_some()) {
+		let mut object = Map::new();
+		for field in fields {
+			let name = field.name.clone().unwrap_or_default();
+			object.insert(name, decode_type(types, field.ty.id, input, depth + 1)?);
+		}
+		return Ok(Value::Object(object))
+	}
+
+	let mut values = fields
+		.iter()
+		.map(|field| decode_type(types, field.ty.id, input, depth + 1))
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(match values.len() {
+		0 => Value::Null,
+		1 => values.remove(0),
+		_ => Value::Array(values),
+	})
+}
+
+/// Decode a single SCALE-encoded value of the given type into JSON.
+///
+/// Variants without fields become their name, other variants an object with the name as
+/// the only key. Byte sequences and arrays become hex strings. Integers wider than 32 bits
+/// become decimal strings, as JSON numbers can't represent them exactly.
+fn decode_type(
+	types: &PortableRegistry,
+	id: u32,
+	input: &mut &[u8],
+	depth: usize,
+) -> Result<Value, String> {
+	if depth > MAX_TYPE_DEPTH {
+		return Err("Type nesting is too deep".into())
+	}
+	let ty = types.resolve(id).ok_or_else(|| format!("Type {id} not found in metadata"))?;
+
+	let value = match &ty.type_def {
+		TypeDef::Composite(composite) => decode_fields(types, &composite.fields, input, depth)?,
+		TypeDef::Variant(_) => {
+			let variant = decode_variant(types, id, input)?;
+			if variant.fields.is_empty() {
+				Value::String(variant.name.clone())
+			} else {
+				let fields = decode_fields(types, &variant.fields, input, depth)?;
+				Value::Object(Map::from_iter([(variant.name.clone(), fields)]))
+			}
+		},
+		TypeDef::Sequence(sequence) => {
+			let Compact(len) = Compact::<u32>::decode(input).map_err(|e| e.to_string())?;
+			decode_items(types, sequence.type_param.id, len, input, depth)?
+		},
+		TypeDef::Array(array) => decode_items(types, array.type_param.id, array.len, input, depth)?,
+		TypeDef::Tuple(tuple) if tuple.fields.is_empty() => Value::Null,
+		TypeDef::Tuple(tuple) => Value::Array(
+			tuple
+				.fields
+				.iter()
+				.map(|field| decode_type(types, field.id, input, depth + 1))
+				.collect::<Result<_, _>>()?,
+		),
+		TypeDef::Primitive(primitive) => decode_primitive(primitive, input)?,
+		TypeDef::Compact(compact) => {
+			let Compact(value) = Compact::<u128>::decode(input).map_err(|e| e.to_string())?;
+			match compact_primitive(types, compact.type_param.id, depth)? {
+				TypeDefPrimitive::U8 | TypeDefPrimitive::U16 | TypeDefPrimitive::U32 =>
+					Value::from(value as u32),
+				_ => Value::String(value.to_string()),
+			}
+		},
+		TypeDef::BitSequence(_) => {
+			let start = *input;
+			skip_type(types, id, input, depth)?;
+			let bits = &start[..start.len() - input.len()];
+			Value::String(hex_string(&bits))
+		},
+	};
+
+	Ok(value)
+}
+
+/// Decode `len` items of the given type, as a hex string for bytes.
+fn decode_items(
+	types: &PortableRegistry,
+	id: u32,
+	len: u32,
+	input: &mut &[u8],
+	depth: usize,
+) -> Result<Value, String> {
+	let ty = types.resolve(id).ok_or_else(|| format!("Type {id} not found in metadata"))?;
+	if matches!(ty.type_def, TypeDef::Primitive(TypeDefPrimitive::U8)) {
+		let start = *input;
+		skip_bytes(input, len as usize)?;
+		let bytes = &start[..len as usize];
+		return Ok(Value::String(hex_string(&bytes)))
+	}
+
+	(0..len)
+		.map(|_| decode_type(types, id, input, depth + 1))
+		.collect::<Result<_, _>>()
+		.map(Value::Array)
+}
+
+/// Decode a primitive value.
+fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<Value, String> {
+	fn decode<T: Decode>(input: &mut &[u8]) -> Result<T, String> {
+		T::decode(input).map_err(|e| e.to_string())
+	}
+
+	let value = match primitive {
+		TypeDefPrimitive::Bool => Value::from(decode::<bool>(input)?),
+		TypeDefPrimitive::Char => {
+			let char =
+				char::from_u32(decode::<u32>(input)?).ok_or_else(|| "Invalid char".to_string())?;
+			Value::String(char.into())
+		},
+		TypeDefPrimitive::Str => Value::String(decode::<String>(input)?),
+		TypeDefPrimitive::U8 => Value::from(decode::<u8>(input)?),
+		TypeDefPrimitive::U16 => Value::from(decode::<u16>(input)?),
+		TypeDefPrimitive::U32 => Value::from(decode::<u32>(input)?),
+		TypeDefPrimitive::U64 => Value::String(decode::<u64>(input)?.to_string()),
+		TypeDefPrimitive::U128 => Value::String(decode::<u128>(input)?.to_string()),
+		TypeDefPrimitive::U256 => Value::String(decode::<U256>(input)?.to_string()),
+		TypeDefPrimitive::I8 => Value::from(decode::<i8>(input)?),
+		TypeDefPrimitive::I16 => Value::from(decode::<i16>(input)?),
+		TypeDefPrimitive::I32 => Value::from(decode::<i32>(input)?),
+		TypeDefPrimitive::I64 => Value::String(decode::<i64>(input)?.to_string()),
+		TypeDefPrimitive::I128 => Value::String(decode::<i128>(input)?.to_string()),
+		// There is no native 256-bit signed integer, report its little-endian bytes.
+		TypeDefPrimitive::I256 => Value::String(hex_string(&decode::<[u8; 32]>(input)?)),
+	};
+
+	Ok(value)
+}
+
+/// The primitive type wrapped by a compact type, looking through single field composites.
+fn compact_primitive(
+	types: &PortableRegistry,
+	id: u32,
+	depth: usize,
+) -> Result<TypeDefPrimitive, String> {
+	if depth > MAX_TYPE_DEPTH {
+		return Err("Type nesting is too deep".into())
+	}
+	let ty = types.resolve(id).ok_or_else(|| format!("Type {id} not found in metadata"))?;
+
+	match &ty.type_def {
+		TypeDef::Primitive(primitive) => Ok(primitive.clone()),
+		TypeDef::Composite(composite) if composite.fields.len() == 1 =>
+			compact_primitive(types, composite.fields[0].ty.id, depth + 1),
+		_ => Err(format!("Unsupported compact type {id}")),
+	}
+}
+
+/// Advance the input over a single SCALE-encoded value of the given type.
+fn skip_type(
+	types: &PortableRegistry,
+	id: u32,
+	input: &mut &[u8],
+	depth: usize,
+) -> Result<(), String> {
+	if depth > MAX_TYPE_DEPTH {
+		return Err("Type nesting is too deep".into())
+	}
+	let ty = types.resolve(id).ok_or_else(|| format!("Type {id} not found in metadata"))?;
+
+	match &ty.type_def {
+		TypeDef::Composite(composite) =>
+			for field in &composite.fields {
+				skip_type(types, field.ty.id, input, depth + 1)?;
+			},
+		TypeDef::Variant(variant) => {
+			let index = u8::decode(input).map_err(|e| e.to_string())?;
+			let variant = variant
+				.variants
+				.iter()
+				.find(|variant| variant.index == index)
+				.ok_or_else(|| format!("Variant {index} of type {id} not found in metadata"))?;
+			for field in &variant.fields {
+				skip_type(types, field.ty.id, input, depth + 1)?;
+			}
+		},
+		TypeDef::Sequence(sequence) => {
+			let Compact(len) = Compact::<u32>::decode(input).map_err(|e| e.to_string())?;
+			for _ in 0..len {
+				skip_type(types, sequence.type_param.id, input, depth + 1)?;
+			}
+		},
+		TypeDef::Array(array) =>
+			for _ in 0..array.len {
+				skip_type(types, array.type_param.id, input, depth + 1)?;
+			},
+		TypeDef::Tuple(tuple) =>
+			for field in &tuple.fields {
+				skip_type(types, field.id, input, depth + 1)?;
+			},
+		TypeDef::Primitive(primitive) => match primitive {
+			TypeDefPrimitive::Str => {
+				let Compact(len) = Compact::<u32>::decode(input).map_err(|e| e.to_string())?;
+				skip_bytes(input, len as usize)?;
+			},
+			primitive => skip_bytes(input, primitive_size(primitive))?,
+		},
+		TypeDef::Compact(_) => skip_compact(input)?,
+		TypeDef::BitSequence(bits) => {
+			let store = types
+				.resolve(bits.bit_store_type.id)
+				.ok_or_else(|| format!("Type {} not found in metadata", bits.bit_store_type.id))?;
+			let store_bits = match &store.type_def {
+				TypeDef::Primitive(primitive) => primitive_size(primitive) * 8,
+				_ => return Err("Unsupported bit sequence store type".into()),
+			};
+			let Compact(len) = Compact::<u32>::decode(input).map_err(|e| e.to_string())?;
+			let words = (len as usize).div_ceil(store_bits);
+			skip_bytes(input, words * store_bits / 8)?;
+		},
+	}
+
+	Ok(())
+}
+
+/// The encoded size of a fixed-width primitive type.
+fn primitive_size(primitive: &TypeDefPrimitive) -> usize {
+	match primitive {
+		TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+		TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+		TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+		TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+		TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+		// Strings are length prefixed and handled by the caller.
+		TypeDefPrimitive::Str => 0,
+	}
+}
+
+/// Advance the input over a compact encoded integer of any width.
+fn skip_compact(input: &mut &[u8]) -> Result<(), String> {
+	let first = *input.first().ok_or_else(|| "Unexpected end of input".to_string())?;
+	let len = match first & 0b11 {
+		0b00 => 1,
+		0b01 => 2,
+		0b10 => 4,
+		_ => (first >> 2) as usize + 5,
+	};
+	skip_bytes(input, len)
+}
+
+/// Advance the input by the given number of bytes.
+fn skip_bytes(input: &mut &[u8], len: usize) -> Result<(), String> {
+	if input.len() < len {
+		return Err("Unexpected end of input".into())
+	}
+	*input = &input[len..];
+	Ok(())
+}
+
+/// The storage key of the `frame_system::Events` storage value.
+fn events_storage_key() -> StorageKey {
+	let mut key = sp_core::hashing::twox_128(b"System").to_vec();
+	key.extend(sp_core::hashing::twox_128(b"Events"));
+	StorageKey(key)
+}
+
+/// Generates the events of the `archive_events` method.
+pub struct ArchiveEvents<Client, Block, BE> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Decoder of the most recently used runtime.
+	decoder: Option<EventsDecoder>,
+	/// Phantom member to pin the block type.
+	_phantom: PhantomData<(Block, BE)>,
+}
+
+impl<Client, Block, BE> ArchiveEvents<Client, Block, BE> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, decoder: None, _phantom: PhantomData }
+	}
+}
+
+impl<Client, Block, BE> ArchiveEvents<Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: ExecutorProvider<Block>
+		+ HeaderBackend<Block>
+		+ CallApiAt<Block>
+		+ StorageProvider<Block, BE>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	/// Fetch the metadata of the runtime at the given block, unless the spec name and version
+	/// of the runtime did not change since the last fetched block.
+	fn ensure_decoder(&mut self, hash: Block::Hash) -> Result<&EventsDecoder, String> {
+		let version = self
+			.client
+			.runtime_version_at(hash)
+			.map_err(|error| format!("Failed to fetch runtime version: {error}"))?;
+		// The spec version alone doesn't identify a runtime, different chains reuse it.
+		let spec = (version.spec_name.to_string(), version.spec_version);
+
+		if self.decoder.as_ref().map_or(true, |decoder| decoder.spec != spec) {
+			let result = self
+				.client
+				.executor()
+				.call(
+					hash,
+					"Metadata_metadata_at_version",
+					&METADATA_VERSION.encode(),
+					CallContext::Offchain,
+				)
+				.map_err(|error| format!("Failed to fetch metadata: {error}"))?;
+
+			let metadata = Option::<Vec<u8>>::decode(&mut &result[..])
+				.map_err(|error| format!("Failed to decode metadata: {error}"))?
+				.ok_or_else(|| format!("Metadata V{METADATA_VERSION} is not supported"))?;
+			let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+				.map_err(|error| format!("Failed to decode metadata: {error}"))?;
+
+			let RuntimeMetadata::V15(metadata) = metadata.1 else {
+				return Err(format!("Metadata V{METADATA_VERSION} is not supported"))
+			};
+
+			self.decoder = Some(EventsDecoder {
+				spec,
+				event_ty: metadata.outer_enums.event_enum_ty.id,
+				types: metadata.types,
+			});
+		}
+
+		self.decoder.as_ref().ok_or_else(|| "Metadata is not available".into())
+	}
+
+	/// Fetch and decode the events of a single block.
+	fn block_events(
+		&mut self,
+		hash: Block::Hash,
+		filter: &[ArchiveEventsFilter],
+	) -> Result<Vec<ArchiveEventResult>, String> {
+		let Some(value) = self
+			.client
+			.storage(hash, &events_storage_key())
+			.map_err(|error| format!("Failed to fetch events: {error}"))?
+		else {
+			return Ok(Vec::new())
+		};
+
+		self.ensure_decoder(hash)?.decode::<Block::Hash>(&value.0, filter)
+	}
+
+	/// Generate the events for the finalized blocks in the `[start_height, end_height]` range.
+	///
+	/// Events are produced block by block, such that a slow consumer applies backpressure
+	/// on the block iteration. The blocks are read from the database on a blocking task.
+	pub async fn generate_events(
+		self,
+		start_height: u64,
+		end_height: u64,
+		filter: Vec<ArchiveEventsFilter>,
+		tx: mpsc::Sender<ArchiveEventsEvent>,
+	) -> Result<(), tokio::task::JoinError> {
+		tokio::task::spawn_blocking(move || {
+			self.generate_events_blocking(start_height, end_height, filter, tx)
+		})
+		.await
+	}
+
+	fn generate_events_blocking(
+		mut self,
+		start_height: u64,
+		end_height: u64,
+		filter: Vec<ArchiveEventsFilter>,
+		tx: mpsc::Sender<ArchiveEventsEvent>,
+	) {
+		let finalized_height: u64 = self.client.info().finalized_number.saturated_into();
+
+		if start_height > end_height {
+			let message = format!("Invalid block range: {start_height} > {end_height}");
+			let _ = tx.blocking_send(ArchiveEventsEvent::err(message));
+			return
+		}
+
+		if end_height - start_height >= MAX_EVENTS_BLOCK_RANGE {
+			let message = format!(
+				"Block range {start_height}..={end_height} exceeds the maximum of \
+				 {MAX_EVENTS_BLOCK_RANGE} blocks"
+			);
+			let _ = tx.blocking_send(ArchiveEventsEvent::err(message));
+			return
+		}
+
+		if end_height > finalized_height {
+			let message = format!(
+				"Block range end {end_height} is above the finalized height {finalized_height}"
+			);
+			let _ = tx.blocking_send(ArchiveEventsEvent::err(message));
+			return
+		}
+
+		for height in start_height..=end_height {
+			let number: NumberFor<Block> = match U256::from(height).try_into().ok() {
+				Some(number) => number,
+				None => {
+					let message = format!("Invalid block height: {height}");
+					let _ = tx.blocking_send(ArchiveEventsEvent::err(message));
+					return
+				},
+			};
+
+			let hash = match self.client.hash(number) {
+				Ok(Some(hash)) => hash,
+				Ok(None) => {
+					let message = format!("Block at height {height} is not present");
+					let _ = tx.blocking_send(ArchiveEventsEvent::err(message));
+					return
+				},
+				Err(error) => {
+					let _ = tx.blocking_send(ArchiveEventsEvent::err(error.to_string()));
+					return
+				},
+			};
+
+			let events = match self.block_events(hash, &filter) {
+				Ok(events) => events,
+				Err(error) => {
+					log::debug!(
+						target: LOG_TARGET,
+						"Failed to fetch events for block {hash:?}: {error}"
+					);
+					let _ = tx.blocking_send(ArchiveEventsEvent::err(error));
+					return
+				},
+			};
+
+			if events.is_empty() {
+				continue
+			}
+
+			let event = ArchiveEventsEvent::Events(ArchiveEventsBlock {
+				block_hash: hex_string(&hash.as_ref()),
+				block_number: height,
+				events,
+			});
+
+			// The receiver is dropped when the subscription is closed.
+			if tx.blocking_send(event).is_err() {
+				return
+			}
+		}
+
+		let _ = tx.blocking_send(ArchiveEventsEvent::EventsDone);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use scale_info::{meta_type, Registry, TypeInfo};
+	use sp_core::H256;
+
+	#[allow(dead_code)]
+	#[derive(Encode, TypeInfo)]
+	enum PalletAEvent {
+		Created { who: [u8; 32], amount: u128 },
+		Removed(u32),
+	}
+
+	#[allow(dead_code)]
+	#[derive(Encode, TypeInfo)]
+	enum PalletBEvent {
+		Remarked { data: Vec<u8>, note: String, count: Compact<u64> },
+	}
+
+	#[allow(dead_code)]
+	#[derive(Encode, TypeInfo)]
+	enum RuntimeEvent {
+		#[codec(index = 0)]
+		A(PalletAEvent),
+		#[codec(index = 5)]
+		B(PalletBEvent),
+	}
+
+	#[derive(Encode)]
+	enum EncodePhase {
+		ApplyExtrinsic(u32),
+		#[allow(dead_code)]
+		Finalization,
+		Initialization,
+	}
+
+	#[derive(Encode)]
+	struct EventRecord {
+		phase: EncodePhase,
+		event: RuntimeEvent,
+		topics: Vec<H256>,
+	}
+
+	fn decoder() -> EventsDecoder {
+		let mut registry = Registry::new();
+		let event_ty = registry.register_type(&meta_type::<RuntimeEvent>()).id;
+		EventsDecoder { spec: ("test".into(), 0), types: registry.into(), event_ty }
+	}
+
+	fn records() -> Vec<u8> {
+		vec![
+			EventRecord {
+				phase: EncodePhase::Initialization,
+				event: RuntimeEvent::A(PalletAEvent::Removed(7)),
+				topics: vec![],
+			},
+			EventRecord {
+				phase: EncodePhase::ApplyExtrinsic(1),
+				event: RuntimeEvent::B(PalletBEvent::Remarked {
+					data: vec![1, 2, 3],
+					note: "note".into(),
+					count: Compact(u64::MAX),
+				}),
+				topics: vec![H256::repeat_byte(1)],
+			},
+			EventRecord {
+				phase: EncodePhase::ApplyExtrinsic(2),
+				event: RuntimeEvent::A(PalletAEvent::Created { who: [2; 32], amount: 10 }),
+				topics: vec![],
+			},
+		]
+		.encode()
+	}
+
+	#[test]
+	fn decode_all_events() {
+		let events = decoder().decode::<H256>(&records(), &[]).unwrap();
+		assert_eq!(events.len(), 3);
+
+		assert_eq!(events[0].phase, ArchiveEventPhase::Initialization);
+		assert_eq!((events[0].pallet_index, events[0].event_index), (0, 1));
+		assert_eq!(
+			events[0].event,
+			hex_string(&RuntimeEvent::A(PalletAEvent::Removed(7)).encode())
+		);
+
+		assert_eq!(events[1].phase, ArchiveEventPhase::ApplyExtrinsic { index: 1 });
+		assert_eq!((events[1].pallet_index, events[1].event_index), (5, 0));
+		assert_eq!(events[1].topics, vec![hex_string(&H256::repeat_byte(1).as_ref())]);
+
+		assert_eq!(events[2].phase, ArchiveEventPhase::ApplyExtrinsic { index: 2 });
+		assert_eq!((events[2].pallet_index, events[2].event_index), (0, 0));
+	}
+
+	#[test]
+	fn decode_event_fields() {
+		let events = decoder().decode::<H256>(&records(), &[]).unwrap();
+
+		assert_eq!(
+			(events[0].pallet_name.as_str(), events[0].event_name.as_str()),
+			("A", "Removed")
+		);
+		assert_eq!(events[0].fields, serde_json::json!(7));
+
+		assert_eq!(
+			(events[1].pallet_name.as_str(), events[1].event_name.as_str()),
+			("B", "Remarked")
+		);
+		assert_eq!(
+			events[1].fields,
+			serde_json::json!({
+				"data": "0x010203",
+				"note": "note",
+				"count": u64::MAX.to_string(),
+			})
+		);
+
+		assert_eq!(
+			(events[2].pallet_name.as_str(), events[2].event_name.as_str()),
+			("A", "Created")
+		);
+		assert_eq!(
+			events[2].fields,
+			serde_json::json!({ "who": hex_string(&[2u8; 32]), "amount": "10" })
+		);
+	}
+
+	#[test]
+	fn decode_filtered_events() {
+		let decoder = decoder();
+
+		let filter = [ArchiveEventsFilter { pallet_index: 0, event_index: None }];
+		let events = decoder.decode::<H256>(&records(), &filter).unwrap();
+		assert_eq!(events.len(), 2);
+		assert!(events.iter().all(|event| event.pallet_index == 0));
+
+		let filter = [
+			ArchiveEventsFilter { pallet_index: 0, event_index: Some(0) },
+			ArchiveEventsFilter { pallet_index: 5, event_index: Some(0) },
+		];
+		let events = decoder.decode::<H256>(&records(), &filter).unwrap();
+		assert_eq!(events.len(), 2);
+		assert_eq!((events[0].pallet_index, events[0].event_index), (5, 0));
+		assert_eq!((events[1].pallet_index, events[1].event_index), (0, 0));
+
+		let filter = [ArchiveEventsFilter { pallet_index: 1, event_index: None }];
+		let events = decoder.decode::<H256>(&records(), &filter).unwrap();
+		assert!(events.is_empty());
+	}
+
+	#[test]
+	fn decode_truncated_events() {
+		let records = records();
+		let result = decoder().decode::<H256>(&records[..records.len() - 1], &[]);
+		assert!(result.is_err());
+	}
+}
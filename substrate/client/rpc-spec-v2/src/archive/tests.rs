@@ -19,9 +19,10 @@
 use crate::{
 	archive::MethodResult,
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
-		ArchiveStorageDiffResult, ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery,
-		StorageQueryType, StorageResult, StorageResultType,
+		ArchiveEventPhase, ArchiveEventsEvent, ArchiveEventsFilter, ArchiveStorageDiffEvent,
+		ArchiveStorageDiffItem, ArchiveStorageDiffOperationType, ArchiveStorageDiffResult,
+		ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery, StorageQueryType, StorageResult,
+		StorageResultType,
 	},
 	hex_string,
 };
@@ -999,3 +1000,97 @@ async fn archive_storage_diff_invalid_params() {
 		ArchiveStorageDiffEvent::StorageDiffError(ref err) if err.error.contains("Header was not found")
 	);
 }
+
+#[tokio::test]
+async fn archive_events_filtered() {
+	let (client, api) = setup_api();
+
+	// Import a new block with an extrinsic.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(runtime::Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = block.header.hash();
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+	client.finalize_block(block_hash, None).unwrap();
+
+	// Only `frame_system::Event::ExtrinsicSuccess` events.
+	let filter = vec![ArchiveEventsFilter { pallet_index: 0, event_index: Some(0) }];
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_events", rpc_params![0, 1, filter])
+		.await
+		.unwrap();
+
+	let event = get_next_event::<ArchiveEventsEvent>(&mut sub).await;
+	let ArchiveEventsEvent::Events(block) = event else { panic!("Expected events, got {event:?}") };
+	assert_eq!(block.block_hash, hex_string(&block_hash.as_ref()));
+	assert_eq!(block.block_number, 1);
+	assert_eq!(block.events.len(), 1);
+	assert_eq!(block.events[0].phase, ArchiveEventPhase::ApplyExtrinsic { index: 0 });
+	assert_eq!((block.events[0].pallet_index, block.events[0].event_index), (0, 0));
+	assert_eq!(block.events[0].pallet_name, "System");
+	assert_eq!(block.events[0].event_name, "ExtrinsicSuccess");
+	assert!(block.events[0].fields["dispatch_info"].is_object());
+
+	let event = get_next_event::<ArchiveEventsEvent>(&mut sub).await;
+	assert_eq!(ArchiveEventsEvent::EventsDone, event);
+
+	// Filter that doesn't match any event.
+	let filter = vec![ArchiveEventsFilter { pallet_index: 200, event_index: None }];
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_events", rpc_params![0, 1, filter])
+		.await
+		.unwrap();
+
+	let event = get_next_event::<ArchiveEventsEvent>(&mut sub).await;
+	assert_eq!(ArchiveEventsEvent::EventsDone, event);
+}
+
+#[tokio::test]
+async fn archive_events_invalid_range() {
+	let (_, api) = setup_api();
+	let filter: Vec<ArchiveEventsFilter> = Vec::new();
+
+	// Start is above the end.
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_events", rpc_params![1, 0, filter.clone()])
+		.await
+		.unwrap();
+	let event = get_next_event::<ArchiveEventsEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveEventsEvent::EventsError(ref err) if err.error.contains("Invalid block range")
+	);
+
+	// Range is too large.
+	let mut sub = api
+		.subscribe_unbounded(
+			"archive_v1_events",
+			rpc_params![0, archive_events::MAX_EVENTS_BLOCK_RANGE, filter.clone()],
+		)
+		.await
+		.unwrap();
+	let event = get_next_event::<ArchiveEventsEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveEventsEvent::EventsError(ref err) if err.error.contains("exceeds the maximum")
+	);
+
+	// End is above the finalized height.
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_events", rpc_params![0, 10, filter])
+		.await
+		.unwrap();
+	let event = get_next_event::<ArchiveEventsEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveEventsEvent::EventsError(ref err) if err.error.contains("above the finalized height")
+	);
+}
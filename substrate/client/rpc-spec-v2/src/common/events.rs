@@ -262,6 +262,114 @@ impl ArchiveStorageDiffEvent {
 	}
 }
 
+/// Filter applied to the events reported by the `archive_events` method.
+///
+/// An event matches the filter when it was emitted by the pallet with the provided index and,
+/// if provided, its variant index within the pallet event enum matches the `event_index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveEventsFilter {
+	/// The index of the pallet in the runtime.
+	pub pallet_index: u8,
+	/// The index of the event variant within the pallet.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub event_index: Option<u8>,
+}
+
+impl ArchiveEventsFilter {
+	/// Checks if the given pallet and event indexes match the filter.
+	pub fn matches(&self, pallet_index: u8, event_index: u8) -> bool {
+		self.pallet_index == pallet_index &&
+			self.event_index.map_or(true, |expected| expected == event_index)
+	}
+}
+
+/// The phase of the block execution during which an event was emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum ArchiveEventPhase {
+	/// The event was emitted while applying the extrinsic at the given index.
+	ApplyExtrinsic {
+		/// The index of the extrinsic in the block.
+		index: u32,
+	},
+	/// The event was emitted during the block finalization.
+	Finalization,
+	/// The event was emitted during the block initialization.
+	Initialization,
+}
+
+/// A single event emitted by the runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveEventResult {
+	/// The phase during which the event was emitted.
+	pub phase: ArchiveEventPhase,
+	/// The index of the pallet that emitted the event.
+	pub pallet_index: u8,
+	/// The index of the event variant within the pallet.
+	pub event_index: u8,
+	/// The name of the pallet that emitted the event.
+	pub pallet_name: String,
+	/// The name of the event variant within the pallet.
+	pub event_name: String,
+	/// The fields of the event, decoded with the metadata of the runtime of the block.
+	pub fields: serde_json::Value,
+	/// The hex-encoded SCALE-encoded runtime event, including the pallet and event indexes.
+	pub event: String,
+	/// The hex-encoded topics of the event.
+	pub topics: Vec<String>,
+}
+
+/// The events of a single block that matched the provided filters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveEventsBlock {
+	/// The hex-encoded hash of the block.
+	pub block_hash: String,
+	/// The height of the block.
+	pub block_number: u64,
+	/// The events of the block that matched the filters.
+	pub events: Vec<ArchiveEventResult>,
+}
+
+/// The event generated by the `archive_events` method.
+///
+/// The `archive_events` can generate the following events:
+///  - `events` event - generated for every block of the range that has matching events.
+///  - `eventsError` event - generated when an error is produced.
+///  - `eventsDone` event - generated when the `archive_events` method completed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum ArchiveEventsEvent {
+	/// The `events` event.
+	Events(ArchiveEventsBlock),
+	/// The `eventsError` event.
+	EventsError(ArchiveStorageMethodErr),
+	/// The `eventsDone` event.
+	EventsDone,
+}
+
+impl ArchiveEventsEvent {
+	/// Create a new `ArchiveEventsEvent::EventsError` event.
+	pub fn err(error: String) -> Self {
+		Self::EventsError(ArchiveStorageMethodErr { error })
+	}
+
+	/// Checks if the event is a `EventsDone` event.
+	pub fn is_done(&self) -> bool {
+		matches!(self, Self::EventsDone)
+	}
+
+	/// Checks if the event is a `EventsError` event.
+	pub fn is_err(&self) -> bool {
+		matches!(self, Self::EventsError(_))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -503,4 +611,73 @@ mod tests {
 		let dec: PaginatedStorageQuery<&str> = serde_json::from_str(exp).unwrap();
 		assert_eq!(dec, item);
 	}
+
+	#[test]
+	fn archive_events_filter() {
+		let item = ArchiveEventsFilter { pallet_index: 1, event_index: None };
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"palletIndex":1}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveEventsFilter = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+		assert!(dec.matches(1, 0));
+		assert!(dec.matches(1, 7));
+		assert!(!dec.matches(2, 0));
+
+		let item = ArchiveEventsFilter { pallet_index: 1, event_index: Some(3) };
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"palletIndex":1,"eventIndex":3}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveEventsFilter = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+		assert!(dec.matches(1, 3));
+		assert!(!dec.matches(1, 2));
+	}
+
+	#[test]
+	fn archive_events_output() {
+		let item = ArchiveEventsEvent::Events(ArchiveEventsBlock {
+			block_hash: "0x1".into(),
+			block_number: 2,
+			events: vec![ArchiveEventResult {
+				phase: ArchiveEventPhase::ApplyExtrinsic { index: 1 },
+				pallet_index: 0,
+				event_index: 0,
+				pallet_name: "System".into(),
+				event_name: "Remarked".into(),
+				fields: serde_json::json!({ "sender": "0x01" }),
+				event: "0x0000".into(),
+				topics: vec![],
+			}],
+		});
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"event":"events","blockHash":"0x1","blockNumber":2,"events":[{"phase":{"type":"applyExtrinsic","index":1},"palletIndex":0,"eventIndex":0,"palletName":"System","eventName":"Remarked","fields":{"sender":"0x01"},"event":"0x0000","topics":[]}]}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveEventsEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+
+		let item = ArchiveEventsEvent::err("error".into());
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"event":"eventsError","error":"error"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveEventsEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+
+		let item = ArchiveEventsEvent::EventsDone;
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"event":"eventsDone"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveEventsEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+	}
 }
@@ -305,13 +305,12 @@ async fn tx_broadcast_stop_after_broadcast_finishes() {
 #[tokio::test]
 async fn tx_broadcast_resubmits_invalid_tx() {
 	let limits = PoolLimit { count: 8192, total_bytes: 20 * 1024 * 1024 };
-	let options = Options {
-		ready: limits.clone(),
-		future: limits,
-		reject_future_transactions: false,
-		// This ensures that a transaction is not banned.
-		ban_time: std::time::Duration::ZERO,
-	};
+	let mut options = Options::default();
+	options.ready = limits.clone();
+	options.future = limits;
+	options.reject_future_transactions = false;
+	// This ensures that a transaction is not banned.
+	options.ban_time = std::time::Duration::ZERO;
 
 	let (api, pool, client_mock, tx_api, mut exec_middleware, mut pool_middleware) =
 		setup_api(options, MAX_TX_PER_CONNECTION);
@@ -426,13 +425,12 @@ async fn tx_broadcast_resubmits_invalid_tx() {
 #[tokio::test]
 async fn tx_broadcast_resubmits_dropped_tx() {
 	let limits = PoolLimit { count: 1, total_bytes: 1000 };
-	let options = Options {
-		ready: limits.clone(),
-		future: limits,
-		reject_future_transactions: false,
-		// This ensures that a transaction is not banned.
-		ban_time: std::time::Duration::ZERO,
-	};
+	let mut options = Options::default();
+	options.ready = limits.clone();
+	options.future = limits;
+	options.reject_future_transactions = false;
+	// This ensures that a transaction is not banned.
+	options.ban_time = std::time::Duration::ZERO;
 
 	let (api, pool, client_mock, tx_api, _, mut pool_middleware) =
 		setup_api(options, MAX_TX_PER_CONNECTION);
@@ -112,28 +112,62 @@ type TFullParts<TBl, TRtApi, TExec> =
 	(TFullClient<TBl, TRtApi, TExec>, Arc<TFullBackend<TBl>>, KeystoreContainer, TaskManager);
 
 /// Construct a local keystore shareable container
-pub struct KeystoreContainer(Arc<LocalKeystore>);
+pub struct KeystoreContainer {
+	keystore: KeystorePtr,
+	local: Arc<LocalKeystore>,
+	remote: bool,
+}
 
 impl KeystoreContainer {
 	/// Construct KeystoreContainer
+	///
+	/// For [`KeystoreConfig::Remote`] the local keystore is kept in memory and is not used by
+	/// [`Self::keystore`].
 	pub fn new(config: &KeystoreConfig) -> Result<Self, Error> {
-		let keystore = Arc::new(match config {
+		let local = Arc::new(match config {
 			KeystoreConfig::Path { path, password } =>
 				LocalKeystore::open(path.clone(), password.clone())?,
-			KeystoreConfig::InMemory => LocalKeystore::in_memory(),
+			KeystoreConfig::InMemory | KeystoreConfig::Remote { .. } => LocalKeystore::in_memory(),
 		});
+		let keystore: KeystorePtr = match config {
+			#[cfg(target_family = "unix")]
+			KeystoreConfig::Remote { socket } =>
+				Arc::new(sc_keystore::RemoteKeystore::open(socket.clone())?),
+			#[cfg(not(target_family = "unix"))]
+			KeystoreConfig::Remote { .. } =>
+				return Err(Error::Other("Remote keystore is only supported on unix".into())),
+			_ => local.clone(),
+		};
 
-		Ok(Self(keystore))
+		Ok(Self { keystore, local, remote: matches!(config, KeystoreConfig::Remote { .. }) })
 	}
 
 	/// Returns a shared reference to a dynamic `Keystore` trait implementation.
 	pub fn keystore(&self) -> KeystorePtr {
-		self.0.clone()
+		self.keystore.clone()
 	}
 
 	/// Returns a shared reference to the local keystore .
+	///
+	/// For [`KeystoreConfig::Remote`] this is an empty in-memory keystore, see
+	/// [`Self::require_local_keystore`].
 	pub fn local_keystore(&self) -> Arc<LocalKeystore> {
-		self.0.clone()
+		self.local.clone()
+	}
+
+	/// Returns a shared reference to the local keystore, or an error if the keys are kept by a
+	/// remote signer.
+	///
+	/// Remote signers never reveal the secret keys, so this should be used at startup by roles
+	/// which need them.
+	pub fn require_local_keystore(&self) -> Result<Arc<LocalKeystore>, Error> {
+		if self.remote {
+			return Err(Error::Other(
+				"This role requires a local keystore, remote signers don't reveal the secret keys"
+					.into(),
+			))
+		}
+		Ok(self.local.clone())
 	}
 }
 
@@ -373,6 +407,9 @@ pub fn new_wasm_executor<H: HostFunctions>(config: &ExecutorConfiguration) -> Wa
 		.build()
 }
 
+/// The number of blocks after which a block is canonicalized in the state database.
+const CANONICALIZATION_DELAY: u64 = 4096;
+
 /// Create an instance of default DB-backend backend.
 pub fn new_db_backend<Block>(
 	settings: DatabaseSettings,
@@ -380,11 +417,22 @@ pub fn new_db_backend<Block>(
 where
 	Block: BlockT,
 {
-	const CANONICALIZATION_DELAY: u64 = 4096;
-
 	Ok(Arc::new(Backend::new(settings, CANONICALIZATION_DELAY)?))
 }
 
+/// Create an instance of default DB-backend backend, switching the state pruning mode of an
+/// existing database to the one in `settings`.
+///
+/// See [`Backend::new_with_state_pruning_migration`].
+pub fn new_db_backend_with_state_pruning_migration<Block>(
+	settings: DatabaseSettings,
+) -> Result<Arc<Backend<Block>>, sp_blockchain::Error>
+where
+	Block: BlockT,
+{
+	Ok(Arc::new(Backend::new_with_state_pruning_migration(settings, CANONICALIZATION_DELAY)?))
+}
+
 /// Create an instance of client backed by given backend.
 pub fn new_client<E, Block, RA, G>(
 	backend: Arc<Backend<Block>>,
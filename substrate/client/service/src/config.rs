@@ -154,6 +154,13 @@ pub enum KeystoreConfig {
 	},
 	/// In-memory keystore. Recommended for in-browser nodes.
 	InMemory,
+	/// Keystore forwarding all signing to an external signer listening at a unix socket.
+	///
+	/// Keeps the keys out of the node's filesystem, see [`sc_keystore::remote`].
+	Remote {
+		/// The path of the signer's socket.
+		socket: PathBuf,
+	},
 }
 
 impl KeystoreConfig {
@@ -161,7 +168,7 @@ impl KeystoreConfig {
 	pub fn path(&self) -> Option<&Path> {
 		match self {
 			Self::Path { path, .. } => Some(path),
-			Self::InMemory => None,
+			Self::InMemory | Self::Remote { .. } => None,
 		}
 	}
 }
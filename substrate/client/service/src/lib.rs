@@ -58,11 +58,11 @@ pub use self::{
 	builder::{
 		build_default_block_downloader, build_default_syncing_engine, build_network,
 		build_network_advanced, build_polkadot_syncing_strategy, gen_rpc_module, init_telemetry,
-		new_client, new_db_backend, new_full_client, new_full_parts, new_full_parts_record_import,
-		new_full_parts_with_genesis_builder, new_wasm_executor,
-		propagate_transaction_notifications, spawn_tasks, BuildNetworkAdvancedParams,
-		BuildNetworkParams, DefaultSyncingEngineConfig, KeystoreContainer, SpawnTasksParams,
-		TFullBackend, TFullCallExecutor, TFullClient,
+		new_client, new_db_backend, new_db_backend_with_state_pruning_migration, new_full_client,
+		new_full_parts, new_full_parts_record_import, new_full_parts_with_genesis_builder,
+		new_wasm_executor, propagate_transaction_notifications, spawn_tasks,
+		BuildNetworkAdvancedParams, BuildNetworkParams, DefaultSyncingEngineConfig,
+		KeystoreContainer, SpawnTasksParams, TFullBackend, TFullCallExecutor, TFullClient,
 	},
 	client::{ClientConfig, LocalCallExecutor},
 	error::Error,
@@ -40,19 +40,29 @@
 //! # Pruning.
 //! See `RefWindow` for pruning algorithm details. `StateDb` prunes on each canonicalization until
 //! pruning constraints are satisfied.
+//!
+//! # Pruning mode migration.
+//! The pruning mode of an existing database can be changed with `StateDb::migrate_pruning_mode`.
+//! Switching from an archive mode to `PruningMode::Constrained` starts a new pruning window at the
+//! last canonicalized block. The state of older blocks was never journaled, so it is tracked as
+//! `LegacyPruning` and removed in batches by the database backend, which knows how to compute
+//! the nodes that died in each block. If the database does not support reference counting, the
+//! removed nodes are deleted together with the last block of the pruning window, like the nodes
+//! that died in that block. Switching to an archive mode keeps the state of the blocks that are
+//! still present in the database.
 
 mod noncanonical;
 mod pruning;
 #[cfg(test)]
 mod test;
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use log::trace;
 use noncanonical::NonCanonicalOverlay;
 use parking_lot::RwLock;
 use pruning::{HaveBlock, RefWindow};
 use std::{
-	collections::{hash_map::Entry, HashMap},
+	collections::{hash_map::Entry, HashMap, HashSet},
 	fmt,
 };
 
@@ -62,6 +72,7 @@ const PRUNING_MODE: &[u8] = b"mode";
 const PRUNING_MODE_ARCHIVE: &[u8] = b"archive";
 const PRUNING_MODE_ARCHIVE_CANON: &[u8] = b"archive_canonical";
 const PRUNING_MODE_CONSTRAINED: &[u8] = b"constrained";
+const LEGACY_PRUNING: &[u8] = b"legacy_pruning";
 pub(crate) const DEFAULT_MAX_BLOCK_CONSTRAINT: u32 = 256;
 
 /// Database value type.
@@ -145,6 +156,10 @@ pub enum StateDbError {
 	BlockUnavailable,
 	/// Block record is missing from the pruning window
 	BlockMissing,
+	/// The pruning mode can not be changed.
+	PruningModeMigration(String),
+	/// Trying to reset the canonical chain while there are non-canonical blocks.
+	NonCanonicalBlocks,
 }
 
 impl<E> From<StateDbError> for Error<E> {
@@ -196,6 +211,9 @@ impl fmt::Debug for StateDbError {
 				write!(f, "Trying to get a block record from db while it is not commit to db yet")
 			},
 			Self::BlockMissing => write!(f, "Block record is missing from the pruning window"),
+			Self::PruningModeMigration(message) =>
+				write!(f, "Can't migrate the pruning mode: {}", message),
+			Self::NonCanonicalBlocks => write!(f, "There are non-canonical blocks"),
 		}
 	}
 }
@@ -288,6 +306,20 @@ fn to_meta_key<S: Codec>(suffix: &[u8], data: &S) -> Vec<u8> {
 	buffer
 }
 
+/// Range of blocks whose state was kept by an archive pruning mode and still has to be removed
+/// after switching to [`PruningMode::Constrained`].
+///
+/// The pruning window only tracks the blocks canonicalized after the switch. The nodes that died
+/// in the blocks `next..end` are removed by the database backend, which reports the progress with
+/// [`StateDb::note_legacy_pruned`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct LegacyPruning {
+	/// The next block whose dead nodes have to be removed.
+	pub next: u64,
+	/// The last block of the legacy range, exclusive.
+	pub end: u64,
+}
+
 /// Status information about the last canonicalized block.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LastCanonicalized {
@@ -305,6 +337,10 @@ pub struct StateDbSync<BlockHash: Hash, Key: Hash, D: MetaDb> {
 	pruning: Option<RefWindow<BlockHash, Key, D>>,
 	pinned: HashMap<BlockHash, u32>,
 	ref_counting: bool,
+	legacy_pruning: Option<LegacyPruning>,
+	/// Keys inserted by the blocks canonicalized since the start of the current legacy pruning
+	/// batch. Only tracked if the database does not support reference counting.
+	legacy_inserted: Option<HashSet<Key>>,
 }
 
 impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
@@ -316,13 +352,182 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 		trace!(target: LOG_TARGET, "StateDb settings: {:?}. Ref-counting: {}", mode, ref_counting);
 
 		let non_canonical: NonCanonicalOverlay<BlockHash, Key> = NonCanonicalOverlay::new(&db)?;
+		let legacy_pruning = match mode {
+			PruningMode::Constrained(_) => db
+				.get_meta(&to_meta_key(LEGACY_PRUNING, &()))
+				.map_err(Error::Db)?
+				.map(|buffer| LegacyPruning::decode(&mut buffer.as_slice()))
+				.transpose()?,
+			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
+		};
 		let pruning: Option<RefWindow<BlockHash, Key, D>> = match mode {
 			PruningMode::Constrained(Constraints { max_blocks }) =>
 				Some(RefWindow::new(db, max_blocks.unwrap_or(0), ref_counting)?),
 			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
 		};
 
-		Ok(StateDbSync { mode, non_canonical, pruning, pinned: Default::default(), ref_counting })
+		Ok(StateDbSync {
+			mode,
+			non_canonical,
+			pruning,
+			pinned: Default::default(),
+			ref_counting,
+			legacy_pruning,
+			legacy_inserted: None,
+		})
+	}
+
+	fn migrate_pruning_mode(
+		&mut self,
+		db: D,
+		mode: PruningMode,
+		last_finalized: Option<(BlockHash, u64)>,
+	) -> Result<CommitSet<Key>, Error<D::Error>> {
+		let mut commit = CommitSet::default();
+		if self.mode == mode {
+			return Ok(commit)
+		}
+		trace!(target: LOG_TARGET, "Migrating pruning mode {:?} -> {:?}", self.mode, mode);
+
+		match (&self.mode, &mode) {
+			(PruningMode::Constrained(_), PruningMode::ArchiveCanonical) => {
+				if let Some(pruning) = self.pruning.take() {
+					pruning.discard(&mut commit);
+				}
+			},
+			(
+				PruningMode::Constrained(_) | PruningMode::ArchiveCanonical,
+				PruningMode::ArchiveAll,
+			) => {
+				self.non_canonical.flush(&mut commit)?;
+				if let Some(pruning) = self.pruning.take() {
+					pruning.discard(&mut commit);
+				}
+			},
+			(
+				PruningMode::ArchiveAll,
+				PruningMode::ArchiveCanonical | PruningMode::Constrained(_),
+			) => {
+				let (hash, number) = last_finalized.ok_or_else(|| {
+					StateDbError::PruningModeMigration(
+						"The last finalized block is required to leave the archive mode".into(),
+					)
+				})?;
+				self.non_canonical.reset_last_canonicalized(&hash, number, &mut commit)?;
+			},
+			_ => {},
+		}
+
+		if let (PruningMode::Constrained(constraints), true) = (&mode, self.mode.is_archive()) {
+			// The state of the blocks that were canonicalized before the switch is not tracked
+			// by the pruning window.
+			let last_pruned = db
+				.get_meta(&to_meta_key(pruning::LAST_PRUNED, &()))
+				.map_err(Error::Db)?
+				.map(|buffer| u64::decode(&mut buffer.as_slice()))
+				.transpose()?;
+			let legacy_start = last_pruned.map_or(0, |n| n + 1);
+			let last_canonicalized = self.non_canonical.last_canonicalized_block_number();
+			let base = last_canonicalized.map_or(0, |n| n + 1);
+
+			self.pruning = Some(RefWindow::new_empty(
+				db,
+				base,
+				constraints.max_blocks.unwrap_or(0),
+				self.ref_counting,
+				&mut commit,
+			));
+
+			if let Some(end) = last_canonicalized.filter(|end| legacy_start < *end) {
+				let legacy_pruning = LegacyPruning { next: legacy_start, end };
+				commit
+					.meta
+					.inserted
+					.push((to_meta_key(LEGACY_PRUNING, &()), legacy_pruning.encode()));
+				self.legacy_pruning = Some(legacy_pruning);
+			}
+		}
+
+		if !matches!(mode, PruningMode::Constrained(_)) && self.legacy_pruning.take().is_some() {
+			commit.meta.deleted.push(to_meta_key(LEGACY_PRUNING, &()));
+		}
+		self.legacy_inserted = None;
+
+		commit
+			.meta
+			.inserted
+			.push((to_meta_key(PRUNING_MODE, &()), mode.id().to_owned()));
+		self.mode = mode;
+		Ok(commit)
+	}
+
+	fn start_legacy_pruning(&mut self) -> Option<(LegacyPruning, Option<BlockHash>)> {
+		let legacy_pruning = self.legacy_pruning.clone()?;
+		if !self.ref_counting {
+			return Some((legacy_pruning, None))
+		}
+		let last_canonicalized = self.non_canonical.last_canonicalized_hash()?;
+		self.legacy_inserted = Some(HashSet::new());
+		Some((legacy_pruning, Some(last_canonicalized)))
+	}
+
+	fn note_legacy_pruned(&mut self, next: u64, mut deleted: Vec<Key>) -> CommitSet<Key> {
+		let mut commit = CommitSet::default();
+		let Some(legacy_pruning) = self.legacy_pruning.as_mut() else { return commit };
+
+		if self.ref_counting {
+			// Without a batch that was started with `start_legacy_pruning` the keys may be part
+			// of a state that is still served.
+			let (Some(inserted), Some(pruning)) = (self.legacy_inserted.take(), &mut self.pruning)
+			else {
+				return commit
+			};
+			deleted.retain(|key| !inserted.contains(key));
+			pruning.note_legacy_deleted(deleted, &mut commit);
+		} else {
+			commit.data.deleted = deleted;
+		}
+		legacy_pruning.next = next;
+		if legacy_pruning.next >= legacy_pruning.end {
+			trace!(target: LOG_TARGET, "Finished pruning legacy state");
+			commit.meta.deleted.push(to_meta_key(LEGACY_PRUNING, &()));
+			self.legacy_pruning = None;
+		} else {
+			commit
+				.meta
+				.inserted
+				.push((to_meta_key(LEGACY_PRUNING, &()), legacy_pruning.encode()));
+		}
+		commit
+	}
+
+	fn reset_canonical_chain(
+		&mut self,
+		db: D,
+		hash: &BlockHash,
+		number: u64,
+	) -> Result<CommitSet<Key>, Error<D::Error>> {
+		let mut commit = CommitSet::default();
+		if self.mode == PruningMode::ArchiveAll {
+			return Ok(commit)
+		}
+		self.non_canonical.reset_last_canonicalized(hash, number, &mut commit)?;
+		self.legacy_inserted = None;
+		if let (Some(mut pruning), PruningMode::Constrained(constraints)) =
+			(self.pruning.take(), &self.mode)
+		{
+			while pruning.window_size() > 0 {
+				pruning.prune_one(&mut commit)?;
+			}
+			self.pruning = Some(RefWindow::new_empty(
+				db,
+				number + 1,
+				constraints.max_blocks.unwrap_or(0),
+				self.ref_counting,
+				&mut commit,
+			));
+		}
+		Ok(commit)
 	}
 
 	fn insert_block(
@@ -355,6 +560,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 			return Ok(commit)
 		}
 		let number = self.non_canonical.canonicalize(hash, &mut commit)?;
+		if let Some(legacy_inserted) = &mut self.legacy_inserted {
+			legacy_inserted.extend(commit.data.inserted.iter().map(|(k, _)| k.clone()));
+		}
 		if self.mode == PruningMode::ArchiveCanonical {
 			commit.data.deleted.clear();
 		}
@@ -579,6 +787,60 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 		self.db.read().mode.clone()
 	}
 
+	/// Switch to a different pruning mode. Returns the database changes of the switch.
+	///
+	/// `last_finalized` is required when leaving [`PruningMode::ArchiveAll`], which does not
+	/// track canonicalized blocks. Blocks above it have to be re-inserted by the caller.
+	/// Switching to [`PruningMode::ArchiveAll`] fails when some non-canonical block is pinned.
+	pub fn migrate_pruning_mode(
+		&self,
+		db: D,
+		mode: PruningMode,
+		last_finalized: Option<(BlockHash, u64)>,
+	) -> Result<CommitSet<Key>, Error<D::Error>> {
+		self.db.write().migrate_pruning_mode(db, mode, last_finalized)
+	}
+
+	/// Returns the range of blocks whose state still has to be removed after switching from an
+	/// archive pruning mode.
+	pub fn legacy_pruning(&self) -> Option<LegacyPruning> {
+		self.db.read().legacy_pruning.clone()
+	}
+
+	/// Start pruning a batch of the legacy state. Returns the range of blocks whose state still
+	/// has to be removed.
+	///
+	/// If the database does not support reference counting, the hash of the last canonicalized
+	/// block is returned as well: the nodes passed to [`StateDb::note_legacy_pruned`] must not be
+	/// part of its state. The nodes inserted by the blocks canonicalized in the meantime are kept.
+	pub fn start_legacy_pruning(&self) -> Option<(LegacyPruning, Option<BlockHash>)> {
+		self.db.write().start_legacy_pruning()
+	}
+
+	/// Note that the state of legacy blocks before `next` has been pruned by deleting the
+	/// `deleted` nodes.
+	///
+	/// If the database does not support reference counting, the batch must have been started
+	/// with [`StateDb::start_legacy_pruning`], otherwise nothing is pruned.
+	pub fn note_legacy_pruned(&self, next: u64, deleted: Vec<Key>) -> CommitSet<Key> {
+		self.db.write().note_legacy_pruned(next, deleted)
+	}
+
+	/// Make `hash` at `number` the last canonicalized block without canonicalizing the blocks in
+	/// between. Returns the database changes.
+	///
+	/// Used before inserting a block whose state was imported without the state of its
+	/// ancestors. All blocks of the pruning window are pruned. Fails when there are
+	/// non-canonical blocks.
+	pub fn reset_canonical_chain(
+		&self,
+		db: D,
+		hash: &BlockHash,
+		number: u64,
+	) -> Result<CommitSet<Key>, Error<D::Error>> {
+		self.db.write().reset_canonical_chain(db, hash, number)
+	}
+
 	/// Add a new non-canonical block.
 	pub fn insert_block(
 		&self,
@@ -706,7 +968,8 @@ fn choose_pruning_mode(
 mod tests {
 	use crate::{
 		test::{make_changeset, make_db, TestDb},
-		Constraints, Error, IsPruned, PruningMode, StateDb, StateDbError,
+		Constraints, Error, IsPruned, LastCanonicalized, LegacyPruning, PruningMode, StateDb,
+		StateDbError,
 	};
 	use sp_core::H256;
 
@@ -946,4 +1209,233 @@ mod tests {
 			check_stored_and_requested_mode_compatibility(created, reopened, expected);
 		}
 	}
+
+	#[test]
+	fn archive_canonical_to_constrained() {
+		let (mut db, sdb) = make_test_db(PruningMode::ArchiveCanonical);
+		db.commit(
+			&sdb.migrate_pruning_mode(db.clone(), PruningMode::blocks_pruning(1), None)
+				.unwrap(),
+		);
+		assert_eq!(sdb.pruning_mode(), PruningMode::blocks_pruning(1));
+		assert_eq!(sdb.legacy_pruning(), Some(LegacyPruning { next: 0, end: 3 }));
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(21), 2), IsPruned::Pruned);
+
+		// The pruning window starts after the last canonicalized block.
+		db.commit(
+			&sdb.insert_block(
+				&H256::from_low_u64_be(5),
+				5,
+				&H256::from_low_u64_be(4),
+				make_changeset(&[5], &[4]),
+			)
+			.unwrap(),
+		);
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(4)).unwrap());
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(5)).unwrap());
+		assert!(db.data_eq(&make_db(&[1, 21, 3, 4, 5, 91, 921, 922, 93])));
+
+		// Legacy pruning progress survives a restart.
+		let (_, sdb) = StateDb::<H256, H256, TestDb>::open(db.clone(), None, false, false).unwrap();
+		assert_eq!(sdb.legacy_pruning(), Some(LegacyPruning { next: 0, end: 3 }));
+		db.commit(&sdb.note_legacy_pruned(2, vec![H256::from_low_u64_be(91)]));
+		assert_eq!(sdb.legacy_pruning(), Some(LegacyPruning { next: 2, end: 3 }));
+		db.commit(&sdb.note_legacy_pruned(3, vec![H256::from_low_u64_be(1)]));
+		assert_eq!(sdb.legacy_pruning(), None);
+		assert!(db.data_eq(&make_db(&[21, 3, 4, 5, 921, 922, 93])));
+
+		let (_, sdb) = StateDb::<H256, H256, TestDb>::open(db.clone(), None, false, false).unwrap();
+		assert_eq!(sdb.legacy_pruning(), None);
+	}
+
+	#[test]
+	fn constrained_to_archive_canonical() {
+		let (mut db, sdb) = make_test_db(PruningMode::blocks_pruning(2));
+		db.commit(
+			&sdb.migrate_pruning_mode(db.clone(), PruningMode::ArchiveCanonical, None)
+				.unwrap(),
+		);
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(4)).unwrap());
+		// The blocks that were still present are kept.
+		assert!(db.data_eq(&make_db(&[1, 21, 3, 4, 921, 922, 93, 94])));
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(21), 2), IsPruned::MaybePruned);
+
+		let (_, sdb) = StateDb::<H256, H256, TestDb>::open(db.clone(), None, false, false).unwrap();
+		assert_eq!(sdb.pruning_mode(), PruningMode::ArchiveCanonical);
+	}
+
+	#[test]
+	fn constrained_to_archive_all_flushes_overlay() {
+		let (mut db, sdb) = make_test_db(PruningMode::blocks_pruning(2));
+		// Canonicalized blocks stay pinned until the changes are on disk.
+		sdb.sync();
+		db.commit(&sdb.migrate_pruning_mode(db.clone(), PruningMode::ArchiveAll, None).unwrap());
+		// Block 4 was not canonicalized yet.
+		assert!(db.data_eq(&make_db(&[1, 21, 3, 4, 921, 922, 93, 94])));
+		assert_eq!(sdb.last_canonicalized(), LastCanonicalized::NotCanonicalizing);
+	}
+
+	#[test]
+	fn archive_all_to_constrained() {
+		let (mut db, sdb) = make_test_db(PruningMode::ArchiveAll);
+		let result = sdb.migrate_pruning_mode(db.clone(), PruningMode::blocks_pruning(1), None);
+		assert!(matches!(result, Err(Error::StateDb(StateDbError::PruningModeMigration(_)))));
+
+		db.commit(
+			&sdb.migrate_pruning_mode(
+				db.clone(),
+				PruningMode::blocks_pruning(1),
+				Some((H256::from_low_u64_be(3), 3)),
+			)
+			.unwrap(),
+		);
+		assert_eq!(sdb.legacy_pruning(), Some(LegacyPruning { next: 0, end: 3 }));
+
+		// Blocks above the last finalized one are re-inserted by the caller.
+		db.commit(
+			&sdb.insert_block(
+				&H256::from_low_u64_be(4),
+				4,
+				&H256::from_low_u64_be(3),
+				make_changeset(&[], &[93]),
+			)
+			.unwrap(),
+		);
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(4)).unwrap());
+		assert_eq!(sdb.last_canonicalized(), LastCanonicalized::Block(4));
+	}
+
+	#[test]
+	fn reset_canonical_chain_prunes_window() {
+		let (mut db, sdb) = make_test_db(PruningMode::blocks_pruning(2));
+		let result = sdb.reset_canonical_chain(db.clone(), &H256::from_low_u64_be(9), 9);
+		assert!(matches!(result, Err(Error::StateDb(StateDbError::NonCanonicalBlocks))));
+
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(4)).unwrap());
+		db.commit(&sdb.reset_canonical_chain(db.clone(), &H256::from_low_u64_be(9), 9).unwrap());
+		assert!(db.data_eq(&make_db(&[21, 3, 4, 922])));
+		assert_eq!(sdb.last_canonicalized(), LastCanonicalized::Block(9));
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(4), 4), IsPruned::Pruned);
+
+		db.commit(
+			&sdb.insert_block(
+				&H256::from_low_u64_be(10),
+				10,
+				&H256::from_low_u64_be(9),
+				make_changeset(&[10], &[3]),
+			)
+			.unwrap(),
+		);
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(10)).unwrap());
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(10), 10), IsPruned::NotPruned);
+
+		let (_, sdb) = StateDb::<H256, H256, TestDb>::open(db.clone(), None, false, false).unwrap();
+		assert_eq!(sdb.last_canonicalized(), LastCanonicalized::Block(10));
+	}
+
+	#[test]
+	fn shrinking_window_prunes_all_blocks() {
+		let mut db = make_db(&[]);
+		let (state_db_init, sdb) = StateDb::<H256, H256, TestDb>::open(
+			db.clone(),
+			Some(PruningMode::blocks_pruning(100)),
+			false,
+			true,
+		)
+		.unwrap();
+		db.commit(&state_db_init);
+
+		let add_block = |db: &mut TestDb, number: u64| {
+			db.commit(
+				&sdb.insert_block(
+					&H256::from_low_u64_be(number),
+					number,
+					&H256::from_low_u64_be(number - 1),
+					make_changeset(&[], &[]),
+				)
+				.unwrap(),
+			);
+			db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(number)).unwrap());
+		};
+
+		for number in 1..=50 {
+			add_block(&mut db, number);
+		}
+		db.commit(
+			&sdb.migrate_pruning_mode(db.clone(), PruningMode::blocks_pruning(1), None)
+				.unwrap(),
+		);
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(1), 1), IsPruned::NotPruned);
+
+		add_block(&mut db, 51);
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(50), 50), IsPruned::Pruned);
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(51), 51), IsPruned::NotPruned);
+	}
+
+	#[test]
+	fn legacy_pruning_without_reference_counting() {
+		let mut db = make_db(&[91, 921, 922]);
+		let (state_db_init, sdb) = StateDb::<H256, H256, TestDb>::open(
+			db.clone(),
+			Some(PruningMode::ArchiveCanonical),
+			true,
+			true,
+		)
+		.unwrap();
+		db.commit(&state_db_init);
+
+		let add_block = |db: &mut TestDb,
+		                 sdb: &StateDb<H256, H256, TestDb>,
+		                 number: u64,
+		                 inserted: &[u64],
+		                 deleted: &[u64]| {
+			db.commit(
+				&sdb.insert_block(
+					&H256::from_low_u64_be(number),
+					number,
+					&H256::from_low_u64_be(number - 1),
+					make_changeset(inserted, deleted),
+				)
+				.unwrap(),
+			);
+			db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(number)).unwrap());
+		};
+
+		add_block(&mut db, &sdb, 1, &[1], &[91]);
+		add_block(&mut db, &sdb, 2, &[2], &[921]);
+		add_block(&mut db, &sdb, 3, &[3], &[922]);
+		db.commit(
+			&sdb.migrate_pruning_mode(db.clone(), PruningMode::blocks_pruning(1), None)
+				.unwrap(),
+		);
+		assert_eq!(sdb.legacy_pruning(), Some(LegacyPruning { next: 0, end: 3 }));
+
+		// The nodes may still be part of a served state without a started batch.
+		db.commit(&sdb.note_legacy_pruned(3, vec![H256::from_low_u64_be(91)]));
+		assert_eq!(sdb.legacy_pruning(), Some(LegacyPruning { next: 0, end: 3 }));
+
+		assert_eq!(
+			sdb.start_legacy_pruning(),
+			Some((LegacyPruning { next: 0, end: 3 }, Some(H256::from_low_u64_be(3))))
+		);
+		// Block 4 inserts 921 again while the batch is computed.
+		add_block(&mut db, &sdb, 4, &[4, 921], &[]);
+		db.commit(
+			&sdb.note_legacy_pruned(3, vec![H256::from_low_u64_be(91), H256::from_low_u64_be(921)]),
+		);
+		assert_eq!(sdb.legacy_pruning(), None);
+		// The nodes are deleted together with block 4, which is still in the window.
+		assert!(db.data_eq(&make_db(&[1, 2, 3, 4, 91, 921, 922])));
+
+		// The deletion survives a restart.
+		let (_, sdb) = StateDb::<H256, H256, TestDb>::open(
+			db.clone(),
+			Some(PruningMode::blocks_pruning(1)),
+			true,
+			false,
+		)
+		.unwrap();
+		add_block(&mut db, &sdb, 5, &[5], &[]);
+		assert!(db.data_eq(&make_db(&[1, 2, 3, 4, 5, 921, 922])));
+	}
 }
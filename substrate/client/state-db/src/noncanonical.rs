@@ -369,6 +369,67 @@ impl<BlockHash: Hash, Key: Hash> NonCanonicalOverlay<BlockHash, Key> {
 		self.last_canonicalized.as_ref().map(|&(_, n)| n)
 	}
 
+	pub fn last_canonicalized_hash(&self) -> Option<BlockHash> {
+		self.last_canonicalized.as_ref().map(|(h, _)| h.clone())
+	}
+
+	/// Set the last canonicalized block of an empty overlay.
+	///
+	/// Used when switching from a pruning mode that does not canonicalize blocks, or when the
+	/// state of a block is imported without the state of its ancestors.
+	pub fn reset_last_canonicalized(
+		&mut self,
+		hash: &BlockHash,
+		number: u64,
+		commit: &mut CommitSet<Key>,
+	) -> Result<(), StateDbError> {
+		if !self.levels.is_empty() {
+			return Err(StateDbError::NonCanonicalBlocks)
+		}
+		let last_canonicalized = (hash.clone(), number);
+		commit
+			.meta
+			.inserted
+			.push((to_meta_key(LAST_CANONICAL, &()), last_canonicalized.encode()));
+		self.last_canonicalized = Some(last_canonicalized);
+		Ok(())
+	}
+
+	/// Write the nodes of all non-canonical blocks to the backing database and clear the overlay.
+	///
+	/// Used when switching to a pruning mode that does not canonicalize blocks.
+	pub fn flush(&mut self, commit: &mut CommitSet<Key>) -> Result<(), StateDbError> {
+		if !self.pinned.is_empty() {
+			return Err(StateDbError::PruningModeMigration(
+				"Non-canonical overlay has pinned blocks".into(),
+			))
+		}
+		for level in self.levels.drain(..) {
+			for overlay in level.blocks {
+				commit.data.inserted.extend(overlay.inserted.into_iter().map(|k| {
+					let value = self
+						.values
+						.get(&k)
+						.expect("For each key in overlays there's a value in values")
+						.1
+						.clone();
+					(k, value)
+				}));
+				commit.meta.deleted.push(overlay.journal_key);
+			}
+		}
+		commit.meta.deleted.push(to_meta_key(LAST_CANONICAL, &()));
+		trace!(
+			target: LOG_TARGET,
+			"Flushed non-canonical overlay ({} inserted)",
+			commit.data.inserted.len()
+		);
+		self.parents.clear();
+		self.values.clear();
+		self.last_canonicalized = None;
+		Ok(())
+	}
+
 	/// Confirm that all changes made to commit sets are on disk. Allows for temporarily pinned
 	/// blocks to be released.
 	pub fn sync(&mut self) {
@@ -30,10 +30,11 @@ use crate::{
 };
 use codec::{Decode, Encode};
 use log::trace;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
 
 pub(crate) const LAST_PRUNED: &[u8] = b"last_pruned";
 const PRUNING_JOURNAL: &[u8] = b"pruning_journal";
+const LEGACY_PRUNING_JOURNAL: &[u8] = b"legacy_pruning_journal";
 
 /// See module documentation.
 pub struct RefWindow<BlockHash: Hash, Key: Hash, D: MetaDb> {
@@ -55,6 +56,9 @@ enum DeathRowQueue<BlockHash: Hash, Key: Hash, D: MetaDb> {
 		death_rows: VecDeque<DeathRow<BlockHash, Key>>,
 		/// An index that maps each key from `death_rows` to block number.
 		death_index: HashMap<Key, u64>,
+		/// Keys of the state kept by an archive pruning mode that are deleted together with a
+		/// block of the window, see `RefWindow::note_legacy_deleted`.
+		legacy_rows: HashMap<u64, Vec<Key>>,
 	},
 	DbBacked {
 		// The backend database
@@ -77,6 +81,7 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> DeathRowQueue<BlockHash, Key, D> {
 		let mut queue = DeathRowQueue::<BlockHash, Key, D>::Mem {
 			death_rows: VecDeque::new(),
 			death_index: HashMap::new(),
+			legacy_rows: HashMap::new(),
 		};
 		// read the journal
 		trace!(
@@ -101,6 +106,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> DeathRowQueue<BlockHash, Key, D> {
 				},
 				None => break,
 			}
+			if let Some(record) = db.get_meta(&to_legacy_journal_key(block)).map_err(Error::Db)? {
+				queue.import_legacy(base, Decode::decode(&mut record.as_slice())?);
+			}
 			block += 1;
 		}
 		Ok(queue)
@@ -140,7 +148,7 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> DeathRowQueue<BlockHash, Key, D> {
 				}
 				*last = Some(num);
 			},
-			DeathRowQueue::Mem { death_rows, death_index } => {
+			DeathRowQueue::Mem { death_rows, death_index, .. } => {
 				// remove all re-inserted keys from death rows
 				for k in inserted {
 					if let Some(block) = death_index.remove(&k) {
@@ -157,6 +165,24 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> DeathRowQueue<BlockHash, Key, D> {
 		}
 	}
 
+	/// Add keys to the death row of the last block of the queue. Keys that are already
+	/// scheduled for deletion are skipped. Returns the number of that block and all keys added
+	/// to it this way, or `None` if the queue is empty.
+	fn import_legacy(&mut self, base: u64, keys: Vec<Key>) -> Option<(u64, &Vec<Key>)> {
+		let DeathRowQueue::Mem { death_rows, death_index, legacy_rows } = self else { return None };
+		let block = (base + death_rows.len() as u64).checked_sub(1)?;
+		let row = death_rows.back_mut()?;
+		let legacy = legacy_rows.entry(block).or_default();
+		for k in keys {
+			if let Entry::Vacant(entry) = death_index.entry(k.clone()) {
+				entry.insert(block);
+				row.deleted.insert(k.clone());
+				legacy.push(k);
+			}
+		}
+		Some((block, legacy))
+	}
+
 	/// Pop out one block from the front of the queue, `base` is the block number
 	/// of the first block of the queue
 	fn pop_front(
@@ -170,15 +196,17 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> DeathRowQueue<BlockHash, Key, D> {
 				}
 				Ok(cache.pop_front())
 			},
-			DeathRowQueue::Mem { death_rows, death_index } => match death_rows.pop_front() {
-				Some(row) => {
-					for k in row.deleted.iter() {
-						death_index.remove(k);
-					}
-					Ok(Some(row))
+			DeathRowQueue::Mem { death_rows, death_index, legacy_rows } =>
+				match death_rows.pop_front() {
+					Some(row) => {
+						for k in row.deleted.iter() {
+							death_index.remove(k);
+						}
+						legacy_rows.remove(&base);
+						Ok(Some(row))
+					},
+					None => Ok(None),
 				},
-				None => Ok(None),
-			},
 		}
 	}
 
@@ -233,7 +261,7 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> DeathRowQueue<BlockHash, Key, D> {
 	) -> Option<(&VecDeque<DeathRow<BlockHash, Key>>, &HashMap<Key, u64>)> {
 		match self {
 			DeathRowQueue::DbBacked { .. } => None,
-			DeathRowQueue::Mem { death_rows, death_index } => Some((death_rows, death_index)),
+			DeathRowQueue::Mem { death_rows, death_index, .. } => Some((death_rows, death_index)),
 		}
 	}
 
@@ -279,6 +307,10 @@ fn to_journal_key(block: u64) -> Vec<u8> {
 	to_meta_key(PRUNING_JOURNAL, &block)
 }
 
+fn to_legacy_journal_key(block: u64) -> Vec<u8> {
+	to_meta_key(LEGACY_PRUNING_JOURNAL, &block)
+}
+
 /// The result return by `RefWindow::have_block`
 #[derive(Debug, PartialEq, Eq)]
 pub enum HaveBlock {
@@ -350,6 +382,52 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> RefWindow<BlockHash, Key, D> {
 		Ok(RefWindow { queue, base })
 	}
 
+	/// Create an empty window which starts at block `base`, ignoring any journal stored in the
+	/// database. Adds the new window start to `commit`.
+	///
+	/// Used when switching from a pruning mode that does not maintain a pruning window.
+	pub fn new_empty(
+		db: D,
+		base: u64,
+		window_size: u32,
+		count_insertions: bool,
+		commit: &mut CommitSet<Key>,
+	) -> RefWindow<BlockHash, Key, D> {
+		if base > 0 {
+			commit.meta.inserted.push((to_meta_key(LAST_PRUNED, &()), (base - 1).encode()));
+		} else {
+			commit.meta.deleted.push(to_meta_key(LAST_PRUNED, &()));
+		}
+		let queue = if count_insertions {
+			DeathRowQueue::Mem {
+				death_rows: VecDeque::new(),
+				death_index: HashMap::new(),
+				legacy_rows: HashMap::new(),
+			}
+		} else {
+			let cache_capacity = window_size.clamp(1, DEFAULT_MAX_BLOCK_CONSTRAINT) as usize;
+			DeathRowQueue::DbBacked {
+				db,
+				cache: VecDeque::with_capacity(cache_capacity),
+				cache_capacity,
+				last: None,
+			}
+		};
+		RefWindow { queue, base }
+	}
+
+	/// Stop tracking all blocks of the window. Their state is kept in the database.
+	///
+	/// Used when switching to a pruning mode that does not maintain a pruning window.
+	pub fn discard(self, commit: &mut CommitSet<Key>) {
+		for number in self.base..self.base + self.window_size() {
+			commit.meta.deleted.push(to_journal_key(number));
+		}
+		if let DeathRowQueue::Mem { legacy_rows, .. } = self.queue {
+			commit.meta.deleted.extend(legacy_rows.into_keys().map(to_legacy_journal_key));
+		}
+	}
+
 	pub fn window_size(&self) -> u64 {
 		self.queue.len(self.base) as u64
 	}
@@ -384,12 +462,19 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> RefWindow<BlockHash, Key, D> {
 
 	/// Prune next block. Expects at least one block in the window. Adds changes to `commit`.
 	pub fn prune_one(&mut self, commit: &mut CommitSet<Key>) -> Result<(), Error<D::Error>> {
+		let has_legacy_row = matches!(
+			&self.queue,
+			DeathRowQueue::Mem { legacy_rows, .. } if legacy_rows.contains_key(&self.base)
+		);
 		if let Some(pruned) = self.queue.pop_front(self.base)? {
 			trace!(target: LOG_TARGET, "Pruning {:?} ({} deleted)", pruned.hash, pruned.deleted.len());
 			let index = self.base;
 			commit.data.deleted.extend(pruned.deleted.into_iter());
 			commit.meta.inserted.push((to_meta_key(LAST_PRUNED, &()), index.encode()));
 			commit.meta.deleted.push(to_journal_key(self.base));
+			if has_legacy_row {
+				commit.meta.deleted.push(to_legacy_journal_key(self.base));
+			}
 			self.base += 1;
 			Ok(())
 		} else {
@@ -435,6 +520,24 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> RefWindow<BlockHash, Key, D> {
 		self.queue.import(self.base, number, journal_record);
 		Ok(())
 	}
+
+	/// Delete keys of the state that was kept by an archive pruning mode. Adds changes to
+	/// `commit`.
+	///
+	/// When the keys of the database are not reference counted, the keys are deleted together
+	/// with the last block of the window, so that they stay available for the states of the
+	/// window and are kept if a later block inserts them again. Otherwise, or if the window is
+	/// empty, they are deleted right away.
+	pub fn note_legacy_deleted(&mut self, keys: Vec<Key>, commit: &mut CommitSet<Key>) {
+		if matches!(self.queue, DeathRowQueue::Mem { .. }) && !self.is_empty() {
+			if let Some((block, legacy)) = self.queue.import_legacy(self.base, keys) {
+				trace!(target: LOG_TARGET, "Adding {} legacy keys to #{}", legacy.len(), block);
+				commit.meta.inserted.push((to_legacy_journal_key(block), legacy.encode()));
+			}
+		} else {
+			commit.data.deleted.extend(keys);
+		}
+	}
 }
 
 #[cfg(test)]
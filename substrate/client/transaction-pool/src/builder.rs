@@ -23,13 +23,13 @@ use crate::{
 	fork_aware_txpool::ForkAwareTxPool as ForkAwareFullPool,
 	graph::{base_pool::Transaction, ChainApi, ExtrinsicFor, ExtrinsicHash, IsValidator, Options},
 	single_state_txpool::BasicPool as SingleStateFullPool,
-	TransactionPoolWrapper, LOG_TARGET,
+	ReservedLanes, TagQuotas, TransactionPoolWrapper, LOG_TARGET,
 };
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{LocalTransactionPool, MaintainedTransactionPool};
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, sync::Arc, time::Duration};
+use std::{marker::PhantomData, path::PathBuf, sync::Arc, time::Duration};
 
 /// The type of transaction pool.
 #[derive(Debug, Clone)]
@@ -45,11 +45,16 @@ pub enum TransactionPoolType {
 pub struct TransactionPoolOptions {
 	txpool_type: TransactionPoolType,
 	options: Options,
+	mempool_journal: Option<PathBuf>,
 }
 
 impl Default for TransactionPoolOptions {
 	fn default() -> Self {
-		Self { txpool_type: TransactionPoolType::SingleState, options: Default::default() }
+		Self {
+			txpool_type: TransactionPoolType::SingleState,
+			options: Default::default(),
+			mempool_journal: None,
+		}
 	}
 }
 
@@ -81,7 +86,33 @@ impl TransactionPoolOptions {
 			Duration::from_secs(30 * 60)
 		};
 
-		TransactionPoolOptions { options, txpool_type }
+		TransactionPoolOptions { options, txpool_type, mempool_journal: None }
+	}
+
+	/// Persists the content of the fork-aware transaction pool in the file at the given path.
+	///
+	/// The transactions found in the file are restored when the pool is created. Ignored by the
+	/// single-state transaction pool.
+	pub fn with_mempool_journal(mut self, path: Option<PathBuf>) -> Self {
+		self.mempool_journal = path;
+		self
+	}
+
+	/// Limits the number of transactions sharing a prefix of their provided tags.
+	///
+	/// See [`TagQuotas`] for the details.
+	pub fn with_quotas(mut self, quotas: TagQuotas) -> Self {
+		self.options = self.options.with_quotas(quotas);
+		self
+	}
+
+	/// Reserves separate capacities of the fork-aware mempool for particular kinds of
+	/// transactions.
+	///
+	/// Ignored by the single-state transaction pool.
+	pub fn with_reserved_lanes(mut self, reserved_lanes: ReservedLanes) -> Self {
+		self.options = self.options.with_reserved_lanes(reserved_lanes);
+		self
 	}
 
 	/// Creates predefined options for benchmarking
@@ -98,8 +129,11 @@ impl TransactionPoolOptions {
 				},
 				reject_future_transactions: false,
 				ban_time: Duration::from_secs(30 * 60),
+				quotas: Default::default(),
+				reserved_lanes: Default::default(),
 			},
 			txpool_type: TransactionPoolType::SingleState,
+			mempool_journal: None,
 		}
 	}
 }
@@ -229,6 +263,9 @@ where
 			txpool_type = ?self.options.txpool_type,
 			ready = ?self.options.options.ready,
 			future = ?self.options.options.future,
+			quotas = ?self.options.options.quotas,
+			reserved_lanes = ?self.options.options.reserved_lanes,
+			mempool_journal = ?self.options.mempool_journal,
 			"Creating transaction pool"
 		);
 		TransactionPoolWrapper::<Block, Client>(match self.options.txpool_type {
@@ -239,12 +276,13 @@ where
 				self.spawner,
 				self.client,
 			)),
-			TransactionPoolType::ForkAware => Box::new(ForkAwareFullPool::new_full(
+			TransactionPoolType::ForkAware => Box::new(ForkAwareFullPool::new_full_with_journal(
 				self.options.options,
 				self.is_validator,
 				self.prometheus,
 				self.spawner,
 				self.client,
+				self.options.mempool_journal,
 			)),
 		})
 	}
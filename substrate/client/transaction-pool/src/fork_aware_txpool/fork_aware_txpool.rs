@@ -71,6 +71,7 @@ use sp_runtime::{
 };
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
+	path::PathBuf,
 	pin::Pin,
 	sync::Arc,
 	time::{Duration, Instant},
@@ -267,6 +268,7 @@ where
 			Default::default(),
 			mempool_max_transactions_count,
 			ready_limits.total_bytes + future_limits.total_bytes,
+			Default::default(),
 		);
 		let mempool = Arc::from(mempool);
 
@@ -285,6 +287,7 @@ where
 			mempool.clone(),
 			view_store.clone(),
 			import_notification_sink.clone(),
+			Default::default(),
 		);
 
 		let combined_tasks = async move {
@@ -342,6 +345,7 @@ where
 			Block::Hash,
 			ExtrinsicHash<ChainApi>,
 		>,
+		metrics: PrometheusMetrics,
 	) {
 		let dropped_stats = DurationSlidingStats::new(Duration::from_secs(STAT_SLIDING_WINDOW));
 		loop {
@@ -369,7 +373,11 @@ where
 						);
 					};
 				},
-				DroppedReason::LimitsEnforced | DroppedReason::Invalid => {
+				DroppedReason::LimitsEnforced => {
+					view_store.remove_transaction_subtree(tx_hash, |_, _| {});
+					metrics.report(|metrics| metrics.limits_enforced_txs.inc());
+				},
+				DroppedReason::Invalid => {
 					view_store.remove_transaction_subtree(tx_hash, |_, _| {});
 				},
 			};
@@ -387,6 +395,37 @@ where
 		}
 	}
 
+	/// Restores the mempool content from the journal at the given path and keeps journaling it.
+	///
+	/// The restored transactions are revalidated at the finalized block by the revalidation
+	/// worker. The task completes once the journal is restored.
+	async fn mempool_journal_task(
+		path: PathBuf,
+		mempool: Arc<TxMemPool<ChainApi, Block>>,
+		view_store: Arc<ViewStore<ChainApi, Block>>,
+		revalidation_queue: Arc<revalidation_worker::RevalidationQueue<ChainApi, Block>>,
+		finalized_block: Option<HashAndNumber<Block>>,
+	) {
+		let is_banned = |hash: &ExtrinsicHash<ChainApi>| view_store.is_banned(hash);
+		match mempool.open_journal(&path, is_banned).await {
+			Ok(restored) if restored > 0 =>
+				if let Some(finalized_block) = finalized_block {
+					revalidation_queue
+						.revalidate_mempool(mempool, view_store, finalized_block)
+						.await;
+				},
+			Ok(_) => {},
+			Err(error) => {
+				warn!(
+					target: LOG_TARGET,
+					?path,
+					%error,
+					"fatp::mempool_journal_task: journal could not be opened, mempool will not be persisted"
+				);
+			},
+		}
+	}
+
 	/// Creates new fork aware transaction pool with the background revalidation worker.
 	///
 	/// The txpool essential tasks (including a revalidation worker) are spawned using provided
@@ -399,6 +438,32 @@ where
 		spawner: impl SpawnEssentialNamed,
 		best_block_hash: Block::Hash,
 		finalized_hash: Block::Hash,
+	) -> Self {
+		Self::new_with_background_worker_and_journal(
+			options,
+			is_validator,
+			pool_api,
+			prometheus,
+			spawner,
+			best_block_hash,
+			finalized_hash,
+			None,
+		)
+	}
+
+	/// Creates new fork aware transaction pool with the background revalidation worker.
+	///
+	/// If `mempool_journal` is given, the mempool content is persisted in that file and restored
+	/// from it when the pool is created.
+	pub(crate) fn new_with_background_worker_and_journal(
+		options: Options,
+		is_validator: IsValidator,
+		pool_api: Arc<ChainApi>,
+		prometheus: Option<&PrometheusRegistry>,
+		spawner: impl SpawnEssentialNamed,
+		best_block_hash: Block::Hash,
+		finalized_hash: Block::Hash,
+		mempool_journal: Option<PathBuf>,
 	) -> Self {
 		let metrics = PrometheusMetrics::new(prometheus);
 		let (events_metrics_collector, event_metrics_task) =
@@ -410,6 +475,7 @@ where
 
 		let (revalidation_queue, revalidation_task) =
 			revalidation_worker::RevalidationQueue::new_with_worker();
+		let revalidation_queue = Arc::from(revalidation_queue);
 
 		let (import_notification_sink, import_notification_sink_task) =
 			MultiViewImportNotificationSink::new_with_worker();
@@ -420,6 +486,7 @@ where
 			metrics.clone(),
 			options.total_count(),
 			options.ready.total_bytes + options.future.total_bytes,
+			options.reserved_lanes.clone(),
 		);
 		let mempool = Arc::from(mempool);
 
@@ -438,8 +505,28 @@ where
 			mempool.clone(),
 			view_store.clone(),
 			import_notification_sink.clone(),
+			metrics.clone(),
 		);
 
+		let mempool_journal_task = match mempool_journal {
+			Some(path) => {
+				let finalized_block = pool_api
+					.block_id_to_number(&BlockId::Hash(finalized_hash))
+					.ok()
+					.flatten()
+					.map(|number| HashAndNumber { hash: finalized_hash, number });
+				Self::mempool_journal_task(
+					path,
+					mempool.clone(),
+					view_store.clone(),
+					revalidation_queue.clone(),
+					finalized_block,
+				)
+				.boxed()
+			},
+			None => future::ready(()).boxed(),
+		};
+
 		let combined_tasks = async move {
 			tokio::select! {
 				_ = listener_task => {}
@@ -447,6 +534,8 @@ where
 				_ = import_notification_sink_task => {},
 				_ = dropped_monitor_task => {}
 				_ = event_metrics_task => {},
+				// the journal is restored once, it shall not terminate the other tasks.
+				_ = mempool_journal_task.then(|_| future::pending::<()>()) => {},
 			}
 		}
 		.boxed();
@@ -466,7 +555,7 @@ where
 				best_block_hash,
 				finalized_hash,
 			))),
-			revalidation_queue: Arc::from(revalidation_queue),
+			revalidation_queue,
 			import_notification_sink,
 			options,
 			metrics,
@@ -2085,9 +2174,23 @@ where
 		prometheus: Option<&PrometheusRegistry>,
 		spawner: impl SpawnEssentialNamed,
 		client: Arc<Client>,
+	) -> Self {
+		Self::new_full_with_journal(options, is_validator, prometheus, spawner, client, None)
+	}
+
+	/// Create new fork aware transaction pool for a full node with the provided api.
+	///
+	/// If `mempool_journal` is given, the mempool content is persisted in that file.
+	pub(crate) fn new_full_with_journal(
+		options: Options,
+		is_validator: IsValidator,
+		prometheus: Option<&PrometheusRegistry>,
+		spawner: impl SpawnEssentialNamed,
+		client: Arc<Client>,
+		mempool_journal: Option<PathBuf>,
 	) -> Self {
 		let pool_api = Arc::new(FullChainApi::new(client.clone(), prometheus, &spawner));
-		let pool = Self::new_with_background_worker(
+		let pool = Self::new_with_background_worker_and_journal(
 			options,
 			is_validator,
 			pool_api,
@@ -2095,6 +2198,7 @@ where
 			spawner,
 			client.usage_info().chain.best_hash,
 			client.usage_info().chain.finalized_hash,
+			mempool_journal,
 		);
 
 		pool
@@ -75,6 +75,10 @@ pub struct Metrics {
 	pub submitted_from_mempool_txs: Counter<U64>,
 	/// Total number of transactions found as invalid during mempool revalidation.
 	pub mempool_revalidation_invalid_txs: Counter<U64>,
+	/// Total number of transactions evicted from mempool to make room for better ones.
+	pub mempool_evicted_txs: Counter<U64>,
+	/// Total number of transactions dropped by views to enforce the limits and quotas.
+	pub limits_enforced_txs: Counter<U64>,
 	/// Total number of transactions found as invalid during view revalidation.
 	pub view_revalidation_invalid_txs: Counter<U64>,
 	/// Total number of valid transactions processed during view revalidation.
@@ -319,6 +323,20 @@ impl MetricsRegistrant for Metrics {
 				)?,
 				registry,
 			)?,
+			mempool_evicted_txs: register(
+				Counter::new(
+					"substrate_sub_txpool_mempool_evicted_txs_total",
+					"Total number of transactions evicted from mempool to make room for better ones.",
+				)?,
+				registry,
+			)?,
+			limits_enforced_txs: register(
+				Counter::new(
+					"substrate_sub_txpool_limits_enforced_txs_total",
+					"Total number of transactions dropped by views to enforce the limits and quotas.",
+				)?,
+				registry,
+			)?,
 			view_revalidation_invalid_txs: register(
 				Counter::new(
 					"substrate_sub_txpool_view_revalidation_invalid_txs_total",
@@ -119,6 +119,19 @@
 //! are also periodically verified at every finalized block and removed from the *mempool* if no
 //! longer valid. This is process is called [*mempool* revalidation](#mempool-pruningrevalidation).
 //!
+//! Optionally, the *mempool* content can be [journaled][`open_journal`] to a file, so pending
+//! transactions survive the restart of the node. On startup the journaled transactions are
+//! inserted into the *mempool* as unwatched transactions (obeying the *mempool* limits) and are
+//! scheduled for the *mempool* revalidation. As for any other transaction, they are submitted to
+//! the views where they are validated and checked against the ban list.
+//!
+//! The *mempool* can also reserve separate capacities for unsigned and operational transactions
+//! (see [`ReservedLanes`]). Such transactions are kept in their own lanes: they neither count
+//! against the regular limits nor can be replaced by (or replace) other transactions. A
+//! transaction moves to the operational lane once its priority is reported by a view.
+//! Transactions evicted from the *mempool* to make room for better ones are reported as dropped
+//! and counted in the metrics.
+//!
 //! ## Flows
 //!
 //! The transaction pool internally is executing numerous tasks. This includes handling submitted
@@ -321,6 +334,7 @@
 //! [`inactive_views`]: crate::fork_aware_txpool::view_store::ViewStore::inactive_views
 //! [`TxMemPool`]: crate::fork_aware_txpool::tx_mem_pool::TxMemPool
 //! [`mp::revalidate`]: crate::fork_aware_txpool::tx_mem_pool::TxMemPool::revalidate
+//! [`open_journal`]: crate::fork_aware_txpool::tx_mem_pool::TxMemPool::open_journal
 //! [`batch_size`]: crate::fork_aware_txpool::tx_mem_pool::TXMEMPOOL_MAX_REVALIDATION_BATCH_SIZE
 //! [`TxInMemPool`]: crate::fork_aware_txpool::tx_mem_pool::TxInMemPool
 //! [`MultiViewListener`]: crate::fork_aware_txpool::multi_view_listener::MultiViewListener
@@ -328,6 +342,7 @@
 //! [`Watcher`]: crate::graph::watcher::Watcher
 //! [`AggregatedStream`]: crate::fork_aware_txpool::view::AggregatedStream
 //! [`Options`]: crate::graph::Options
+//! [`ReservedLanes`]: crate::graph::ReservedLanes
 //! [`vp::import_notification_stream`]: ../graph/validated_pool/struct.ValidatedPool.html#method.import_notification_stream
 //! [`vp::enforce_limits`]: ../graph/validated_pool/struct.ValidatedPool.html#method.enforce_limits
 //! [`create_dropped_by_limits_stream`]: ../graph/validated_pool/struct.ValidatedPool.html#method.create_dropped_by_limits_stream
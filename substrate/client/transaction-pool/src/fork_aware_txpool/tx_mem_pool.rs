@@ -37,12 +37,14 @@ use parking_lot::RwLock;
 use sc_transaction_pool_api::{TransactionPriority, TransactionSource};
 use sp_blockchain::HashAndNumber;
 use sp_runtime::{
-	traits::Block as BlockT,
+	traits::{Block as BlockT, ExtrinsicLike},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
 };
 use std::{
 	collections::HashSet,
 	future::Future,
+	io,
+	path::Path,
 	pin::Pin,
 	sync::{
 		atomic::{self, AtomicU64},
@@ -54,12 +56,12 @@ use std::{
 	},
 	time::Instant,
 };
-use tracing::{debug, trace};
+use tracing::{debug, info, trace};
 
 use crate::{
 	common::tracing_log_xt::log_xt_trace,
 	graph,
-	graph::{base_pool::TimedTransactionSource, ExtrinsicFor, ExtrinsicHash},
+	graph::{base_pool::TimedTransactionSource, ExtrinsicFor, ExtrinsicHash, ReservedLanes},
 	ValidateTransactionPriority, LOG_TARGET,
 };
 
@@ -68,8 +70,11 @@ use super::{
 	view_store::ViewStore,
 };
 
+mod journal;
 mod tx_mem_pool_map;
 
+use journal::MempoolJournal;
+
 /// The minimum interval between single transaction revalidations. Given in blocks.
 pub(crate) const TXMEMPOOL_REVALIDATION_PERIOD: u64 = 10;
 
@@ -78,6 +83,31 @@ pub(crate) const TXMEMPOOL_MAX_REVALIDATION_BATCH_SIZE: usize = 1000;
 
 const SYNC_BRIDGE_EXPECT: &str = "The mempool blocking task shall not be terminated. qed.";
 
+/// The lane of the memory pool a transaction is kept in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MempoolLane {
+	/// The lane bounded by the regular limits of the memory pool.
+	Regular,
+	/// The lane with capacity reserved for unsigned transactions.
+	Unsigned,
+	/// The lane with capacity reserved for operational transactions.
+	Operational,
+}
+
+/// The number of transactions and their total size in a lane of the memory pool.
+#[derive(Debug, Default, Clone, Copy)]
+struct LaneUsage {
+	count: usize,
+	bytes: usize,
+}
+
+/// The usage of the reserved lanes of the memory pool.
+#[derive(Debug, Default)]
+struct ReservedLanesUsage {
+	unsigned: LaneUsage,
+	operational: LaneUsage,
+}
+
 /// Represents the transaction in the intermediary buffer.
 pub(crate) struct TxInMemPool<ChainApi, Block>
 where
@@ -100,6 +130,10 @@ where
 	/// Priority of transaction at some block. It is assumed it will not be changed often. None if
 	/// not known.
 	priority: RwLock<Option<TransactionPriority>>,
+	/// The lane of the memory pool the transaction is kept in.
+	///
+	/// A regular transaction moves to the operational lane once its priority is known.
+	lane: RwLock<MempoolLane>,
 }
 
 impl<ChainApi, Block> TxInMemPool<ChainApi, Block>
@@ -173,6 +207,7 @@ where
 			validated_at: AtomicU64::new(validated_at),
 			bytes,
 			priority: priority.into(),
+			lane: MempoolLane::Regular.into(),
 		}
 	}
 
@@ -192,6 +227,11 @@ where
 	pub(crate) fn priority(&self) -> Option<TransactionPriority> {
 		*self.priority.read()
 	}
+
+	/// Returns the lane of the memory pool the transaction is kept in.
+	fn lane(&self) -> MempoolLane {
+		*self.lane.read()
+	}
 }
 
 impl<ChainApi, Block> std::fmt::Debug for TxInMemPool<ChainApi, Block>
@@ -207,6 +247,7 @@ where
 			.field("source", &self.source)
 			.field("validated_at", &self.validated_at)
 			.field("priority", &self.priority)
+			.field("lane", &self.lane())
 			.finish()
 	}
 }
@@ -222,6 +263,7 @@ where
 			self.bytes == other.bytes &&
 			self.source == other.source &&
 			*self.priority.read() == *other.priority.read() &&
+			self.lane() == other.lane() &&
 			self.validated_at.load(atomic::Ordering::Relaxed) ==
 				other.validated_at.load(atomic::Ordering::Relaxed)
 	}
@@ -285,6 +327,14 @@ type InternalTxMemPoolMap<ChainApi, Block> = tx_mem_pool_map::SizeTrackedStore<
 	Arc<TxInMemPool<ChainApi, Block>>,
 >;
 
+type InternalTxMemPoolMapWriteAccess<'a, ChainApi, Block> =
+	tx_mem_pool_map::SizeTrackedStoreWriteAccess<
+		'a,
+		ExtrinsicHash<ChainApi>,
+		tx_mem_pool_map::PriorityKey<MempoolTxPriority, Option<Instant>>,
+		Arc<TxInMemPool<ChainApi, Block>>,
+	>;
+
 /// Internal (blocking) task for bridging sync and async code.
 ///
 /// Should be polled in blocking task.
@@ -329,6 +379,21 @@ where
 
 	/// Maximal size of encodings of all transactions in the memory pool.
 	max_transactions_total_bytes: usize,
+
+	/// The capacities reserved for particular kinds of transactions, in addition to the regular
+	/// limits.
+	reserved_lanes: ReservedLanes,
+
+	/// The usage of the reserved lanes.
+	///
+	/// Updated while holding the lock on `transactions`.
+	reserved_lanes_usage: parking_lot::Mutex<ReservedLanesUsage>,
+
+	/// The optional on-disk journal of the memory pool content.
+	///
+	/// Updated while holding the lock on `transactions`, so the order of records matches the order
+	/// of changes.
+	journal: parking_lot::Mutex<Option<MempoolJournal<ChainApi>>>,
 }
 
 /// Helper structure to encapsulate a result of [`TxMemPool::try_insert`].
@@ -362,6 +427,7 @@ where
 		metrics: PrometheusMetrics,
 		max_transactions_count: usize,
 		max_transactions_total_bytes: usize,
+		reserved_lanes: ReservedLanes,
 	) -> (Self, TxMemPoolBlockingTask) {
 		let (sync_channel, rx) = sync_bridge_channel();
 		let task = Self::sync_bridge_task(rx);
@@ -374,6 +440,9 @@ where
 				metrics,
 				max_transactions_count,
 				max_transactions_total_bytes,
+				reserved_lanes,
+				reserved_lanes_usage: Default::default(),
+				journal: Default::default(),
 			},
 			task.boxed(),
 		)
@@ -396,6 +465,9 @@ where
 			sync_channel,
 			max_transactions_count,
 			max_transactions_total_bytes,
+			reserved_lanes: Default::default(),
+			reserved_lanes_usage: Default::default(),
+			journal: Default::default(),
 		}
 	}
 
@@ -425,10 +497,127 @@ where
 		return self.transactions.bytes()
 	}
 
-	/// Returns true if provided values would exceed defined limits.
-	fn is_limit_exceeded(&self, length: usize, current_total_bytes: usize) -> bool {
-		length > self.max_transactions_count ||
-			current_total_bytes > self.max_transactions_total_bytes
+	/// Returns the lane the given transaction shall be kept in.
+	fn lane_for(
+		&self,
+		tx: &ExtrinsicFor<ChainApi>,
+		priority: Option<TransactionPriority>,
+	) -> MempoolLane {
+		match (&self.reserved_lanes, priority) {
+			(ReservedLanes { unsigned: Some(_), .. }, _) if tx.is_bare() => MempoolLane::Unsigned,
+			(ReservedLanes { operational: Some((min_priority, _)), .. }, Some(priority))
+				if priority >= *min_priority =>
+				MempoolLane::Operational,
+			_ => MempoolLane::Regular,
+		}
+	}
+
+	/// Returns the maximal number of transactions and their maximal total size in the given lane.
+	fn lane_limits(&self, lane: MempoolLane) -> (usize, usize) {
+		let reserved = match lane {
+			MempoolLane::Regular =>
+				return (self.max_transactions_count, self.max_transactions_total_bytes),
+			MempoolLane::Unsigned => self.reserved_lanes.unsigned.as_ref(),
+			MempoolLane::Operational =>
+				self.reserved_lanes.operational.as_ref().map(|(_, limit)| limit),
+		};
+		reserved.map_or((0, 0), |limit| (limit.count, limit.total_bytes))
+	}
+
+	/// Returns the number of transactions and their total size in the given lane.
+	///
+	/// Shall be called while holding the lock on `transactions`.
+	fn lane_usage(&self, lane: MempoolLane) -> LaneUsage {
+		let reserved = self.reserved_lanes_usage.lock();
+		match lane {
+			MempoolLane::Unsigned => reserved.unsigned,
+			MempoolLane::Operational => reserved.operational,
+			MempoolLane::Regular => LaneUsage {
+				count: self
+					.transactions
+					.len()
+					.saturating_sub(reserved.unsigned.count + reserved.operational.count),
+				bytes: self
+					.transactions
+					.bytes()
+					.saturating_sub(reserved.unsigned.bytes + reserved.operational.bytes),
+			},
+		}
+	}
+
+	/// Returns true if provided values would exceed the limits of the given lane.
+	fn is_limit_exceeded(
+		&self,
+		lane: MempoolLane,
+		length: usize,
+		current_total_bytes: usize,
+	) -> bool {
+		let (max_count, max_bytes) = self.lane_limits(lane);
+		length > max_count || current_total_bytes > max_bytes
+	}
+
+	/// Updates the usage of the reserved lanes with the inserted and removed transactions.
+	///
+	/// The usage of the regular lane is derived from the size of the whole memory pool.
+	fn lane_updated(&self, lane: MempoolLane, inserted: LaneUsage, removed: LaneUsage) {
+		let mut reserved = self.reserved_lanes_usage.lock();
+		let usage = match lane {
+			MempoolLane::Regular => return,
+			MempoolLane::Unsigned => &mut reserved.unsigned,
+			MempoolLane::Operational => &mut reserved.operational,
+		};
+		usage.count = (usage.count + inserted.count).saturating_sub(removed.count);
+		usage.bytes = (usage.bytes + inserted.bytes).saturating_sub(removed.bytes);
+	}
+
+	/// Moves the regular transaction to the operational lane if its priority makes it an
+	/// operational one and the lane has room for it.
+	///
+	/// Shall be called while holding the lock on `transactions`.
+	fn promote_to_operational(
+		&self,
+		tx: &TxInMemPool<ChainApi, Block>,
+		priority: TransactionPriority,
+	) {
+		if tx.lane() != MempoolLane::Regular ||
+			self.lane_for(&tx.tx, Some(priority)) != MempoolLane::Operational
+		{
+			return
+		}
+		let usage = self.lane_usage(MempoolLane::Operational);
+		if !self.is_limit_exceeded(
+			MempoolLane::Operational,
+			usage.count + 1,
+			usage.bytes + tx.bytes,
+		) {
+			let moved = LaneUsage { count: 1, bytes: tx.bytes };
+			self.lane_updated(MempoolLane::Operational, moved, Default::default());
+			*tx.lane.write() = MempoolLane::Operational;
+		}
+	}
+
+	/// Removes the transactions with given hashes from the locked map.
+	///
+	/// Keeps the usage of the reserved lane and the journal up to date. Returns the hashes of the
+	/// actually removed transactions.
+	fn remove_locked<'a>(
+		&self,
+		transactions: &mut InternalTxMemPoolMapWriteAccess<'_, ChainApi, Block>,
+		tx_hashes: impl IntoIterator<Item = &'a ExtrinsicHash<ChainApi>>,
+	) -> Vec<&'a ExtrinsicHash<ChainApi>> {
+		let removed = tx_hashes
+			.into_iter()
+			.filter(|tx_hash| match transactions.remove(tx_hash) {
+				Some(tx) => {
+					let usage = LaneUsage { count: 1, bytes: tx.bytes };
+					self.lane_updated(tx.lane(), Default::default(), usage);
+					true
+				},
+				None => false,
+			})
+			.collect_vec();
+		self.journal_removed(removed.iter().copied());
+		removed
 	}
 
 	/// Attempts to insert a transaction into the memory pool, ensuring it does not
@@ -440,14 +629,22 @@ where
 	) -> Result<InsertionInfo<ExtrinsicHash<ChainApi>>, sc_transaction_pool_api::error::Error> {
 		let mut transactions = self.transactions.write().await;
 
-		let bytes = self.transactions.bytes();
+		let lane = self.lane_for(&tx.tx, tx.priority());
+		*tx.lane.write() = lane;
+		let usage = self.lane_usage(lane);
 
 		let result = match (
-			self.is_limit_exceeded(transactions.len() + 1, bytes + tx.bytes),
+			self.is_limit_exceeded(lane, usage.count + 1, usage.bytes + tx.bytes),
 			transactions.contains_key(&tx_hash),
 		) {
 			(false, false) => {
 				let source = tx.source();
+				self.journal_inserted(tx_hash, &tx);
+				self.lane_updated(
+					lane,
+					LaneUsage { count: 1, bytes: tx.bytes },
+					Default::default(),
+				);
 				transactions.insert(tx_hash, Arc::from(tx));
 				Ok(InsertionInfo::new(tx_hash, source))
 			},
@@ -468,7 +665,7 @@ where
 	/// transactions.
 	///
 	/// A "worse" transaction means transaction with lower priority, or older transaction with the
-	/// same prio.
+	/// same prio. Only the transactions from the same lane of the mempool can be dropped.
 	///
 	/// This operation will not overflow the limit of the mempool. It means that cumulative
 	/// size of removed transactions will be equal (or greated) then size of newly inserted
@@ -487,7 +684,10 @@ where
 		let (hash, length) = self.api.hash_and_length(&new_tx);
 		let new_tx =
 			TxInMemPool::new_with_priority(watched, source, new_tx, length, priority, validated_at);
-		if new_tx.bytes > self.max_transactions_total_bytes {
+		let lane = self.lane_for(&new_tx.tx, Some(priority));
+		*new_tx.lane.write() = lane;
+		let (_, max_lane_bytes) = self.lane_limits(lane);
+		if new_tx.bytes > max_lane_bytes {
 			return Err(sc_transaction_pool_api::error::Error::ImmediatelyDropped);
 		}
 
@@ -504,15 +704,31 @@ where
 		// reverse (oldest, lowest prio last)
 		let source = new_tx.source();
 		let new_tx = Arc::new(new_tx);
-		let insertion_result = transactions.try_insert_with_replacement(
-			self.max_transactions_total_bytes,
+		let free_bytes = max_lane_bytes.saturating_sub(self.lane_usage(lane).bytes);
+		let (insertion_result, removed_bytes) = transactions.try_insert_with_filtered_replacement(
+			free_bytes,
 			hash,
-			new_tx,
+			new_tx.clone(),
+			|tx| tx.lane() == lane,
 		);
-		debug_assert!(!self.is_limit_exceeded(transactions.len(), self.transactions.bytes()));
 		match insertion_result {
 			None => Err(sc_transaction_pool_api::error::Error::ImmediatelyDropped),
-			Some(to_be_removed) => Ok(InsertionInfo::new_with_removed(hash, source, to_be_removed)),
+			Some(to_be_removed) => {
+				self.lane_updated(
+					lane,
+					LaneUsage { count: 1, bytes: new_tx.bytes },
+					LaneUsage { count: to_be_removed.len(), bytes: removed_bytes },
+				);
+				debug_assert!({
+					let usage = self.lane_usage(lane);
+					!self.is_limit_exceeded(lane, usage.count, usage.bytes)
+				});
+				self.journal_inserted(hash, &new_tx);
+				self.journal_removed(&to_be_removed);
+				self.metrics
+					.report(|metrics| metrics.mempool_evicted_txs.inc_by(to_be_removed.len() as _));
+				Ok(InsertionInfo::new_with_removed(hash, source, to_be_removed))
+			},
 		}
 	}
 
@@ -576,9 +792,7 @@ where
 	pub(super) async fn remove_transactions(&self, tx_hashes: &[ExtrinsicHash<ChainApi>]) {
 		log_xt_trace!(target: LOG_TARGET, tx_hashes, "mempool::remove_transaction");
 		let mut transactions = self.transactions.write().await;
-		for tx_hash in tx_hashes {
-			transactions.remove(tx_hash);
-		}
+		self.remove_locked(&mut transactions, tx_hashes);
 	}
 
 	/// Revalidates a batch of transactions against the provided finalized block.
@@ -673,10 +887,19 @@ where
 			"purge_finalized_transactions"
 		);
 		log_xt_trace!(target: LOG_TARGET, finalized_xts, "purged finalized transactions");
-		let mut transactions = self.transactions.write().await;
-		finalized_xts.iter().for_each(|t| {
-			transactions.remove(t);
-		});
+		{
+			let mut transactions = self.transactions.write().await;
+			self.remove_locked(&mut transactions, finalized_xts);
+		}
+
+		let transactions = self.transactions.read().await;
+		if let Some(journal) = self.journal.lock().as_mut() {
+			if journal.needs_compaction(transactions.len()) {
+				transactions.with_items(|iter| {
+					journal.compact(iter.map(|(hash, tx)| (hash, tx.source.source, &tx.tx)))
+				});
+			}
+		}
 	}
 
 	/// Revalidates transactions in the memory pool against a given finalized block and removes
@@ -701,9 +924,7 @@ where
 
 		{
 			let mut transactions = self.transactions.write().await;
-			invalid_hashes_subtrees.iter().for_each(|tx_hash| {
-				transactions.remove(&tx_hash);
-			});
+			self.remove_locked(&mut transactions, &invalid_hashes_subtrees);
 		};
 
 		self.metrics.report(|metrics| {
@@ -748,6 +969,7 @@ where
 
 			transactions.update_item(&hash, |t| {
 				*t.priority.write() = Some(priority);
+				self.promote_to_operational(t, priority);
 			});
 		}
 	}
@@ -761,6 +983,67 @@ where
 		let transactions = self.transactions.read().await;
 		hashes.filter(|tx_hash| !transactions.contains_key(tx_hash)).count()
 	}
+
+	/// Enables the on-disk journal of the memory pool at the given path.
+	///
+	/// The transactions stored in an existing journal are inserted into the memory pool as
+	/// unwatched transactions, obeying the memory pool limits. They were never validated at the
+	/// current chain state, so they will be picked by the next [revalidation][`Self::revalidate`]
+	/// of the memory pool and validated by the views when submitted to them. The transactions for
+	/// which `is_banned` returns true are not restored.
+	///
+	/// Returns the number of restored transactions.
+	pub(super) async fn open_journal(
+		&self,
+		path: &Path,
+		is_banned: impl Fn(&ExtrinsicHash<ChainApi>) -> bool,
+	) -> io::Result<usize> {
+		let (mut journal, journaled) = MempoolJournal::open(path)?;
+		let journaled_count = journaled.len();
+
+		let mut restored = 0;
+		for (source, xt) in journaled {
+			let (hash, length) = self.api.hash_and_length(&xt);
+			if is_banned(&hash) {
+				continue
+			}
+			let tx = TxInMemPool::new_unwatched(source, xt, length, 0);
+			if self.try_insert(hash, tx).await.is_ok() {
+				restored += 1;
+			}
+		}
+
+		// Transactions which did not fit in the memory pool are dropped from the journal, while
+		// the ones submitted meanwhile are added.
+		let transactions = self.transactions.read().await;
+		transactions.with_items(|iter| {
+			journal.compact(iter.map(|(hash, tx)| (hash, tx.source.source, &tx.tx)))
+		});
+		*self.journal.lock() = Some(journal);
+
+		info!(
+			target: LOG_TARGET,
+			?path,
+			restored,
+			dropped = journaled_count - restored,
+			"mempool::journal restored transactions"
+		);
+		Ok(restored)
+	}
+
+	/// Records the insertion of the transaction in the journal (if enabled).
+	fn journal_inserted(&self, hash: ExtrinsicHash<ChainApi>, tx: &TxInMemPool<ChainApi, Block>) {
+		if let Some(journal) = self.journal.lock().as_mut() {
+			journal.inserted(hash, tx.source.source, &tx.tx);
+		}
+	}
+
+	/// Records the removal of the transactions in the journal (if enabled).
+	fn journal_removed<'a>(&self, hashes: impl IntoIterator<Item = &'a ExtrinsicHash<ChainApi>>) {
+		if let Some(journal) = self.journal.lock().as_mut() {
+			journal.removed(hashes);
+		}
+	}
 }
 
 /// Convenient return type of extend_unwatched
@@ -975,8 +1258,9 @@ mod tx_mem_pool_tests {
 	use substrate_test_runtime_client::Sr25519Keyring::*;
 
 	use crate::{
-		common::tests::TestApi, fork_aware_txpool::view_store::ViewStoreSubmitOutcome,
-		graph::ChainApi,
+		common::tests::TestApi,
+		fork_aware_txpool::view_store::ViewStoreSubmitOutcome,
+		graph::{base_pool::Limit, ChainApi},
 	};
 
 	use super::*;
@@ -1006,6 +1290,59 @@ mod tx_mem_pool_tests {
 		));
 	}
 
+	#[tokio::test]
+	async fn journal_restores_mempool_within_limits() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("mempool");
+		let max = 10;
+		let api = Arc::from(TestApi::default());
+
+		let mempool = TxMemPool::new_test(api.clone(), max, usize::MAX);
+		assert_eq!(mempool.open_journal(&path, |_| false).await.unwrap(), 0);
+		let xts = (0..max).map(|x| Arc::from(uxt(x as _))).collect::<Vec<_>>();
+		let results = mempool.extend_unwatched(TransactionSource::External, 0, &xts).await;
+		assert!(results.iter().all(Result::is_ok));
+		let removed = api.hash_and_length(&xts[0]).0;
+		mempool.remove_transactions(&[removed]).await;
+		drop(mempool);
+
+		// the journal content exceeding the limits is dropped.
+		let mempool = TxMemPool::new_test(api.clone(), max - 3, usize::MAX);
+		assert_eq!(mempool.open_journal(&path, |_| false).await.unwrap(), max - 3);
+		assert_eq!(mempool.len(), max - 3);
+		assert!(mempool.get_by_hash(removed).await.is_none());
+		drop(mempool);
+
+		let mempool = TxMemPool::new_test(api, max, usize::MAX);
+		assert_eq!(mempool.open_journal(&path, |_| false).await.unwrap(), max - 3);
+		assert_eq!(mempool.unwatched_and_watched_count().await, (max - 3, 0));
+	}
+
+	#[tokio::test]
+	async fn journal_does_not_restore_banned_transactions() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("mempool");
+		let max = 10;
+		let api = Arc::from(TestApi::default());
+
+		let mempool = TxMemPool::new_test(api.clone(), max, usize::MAX);
+		assert_eq!(mempool.open_journal(&path, |_| false).await.unwrap(), 0);
+		let xts = (0..max).map(|x| Arc::from(uxt(x as _))).collect::<Vec<_>>();
+		let results = mempool.extend_unwatched(TransactionSource::External, 0, &xts).await;
+		assert!(results.iter().all(Result::is_ok));
+		drop(mempool);
+
+		let banned = api.hash_and_length(&xts[0]).0;
+		let mempool = TxMemPool::new_test(api.clone(), max, usize::MAX);
+		assert_eq!(mempool.open_journal(&path, |hash| *hash == banned).await.unwrap(), max - 1);
+		assert!(mempool.get_by_hash(banned).await.is_none());
+		drop(mempool);
+
+		// the banned transaction is dropped from the journal.
+		let mempool = TxMemPool::new_test(api, max, usize::MAX);
+		assert_eq!(mempool.open_journal(&path, |_| false).await.unwrap(), max - 1);
+	}
+
 	#[tokio::test]
 	async fn extend_unwatched_detects_already_imported() {
 		sp_tracing::try_init_simple();
@@ -1141,6 +1478,118 @@ mod tx_mem_pool_tests {
 		));
 	}
 
+	#[tokio::test]
+	async fn reserved_lane_keeps_unsigned_txs_apart() {
+		sp_tracing::try_init_simple();
+		let max = 2;
+		let api = Arc::from(TestApi::default());
+		let mut mempool = TxMemPool::new_test(api.clone(), max, usize::MAX);
+		mempool.reserved_lanes =
+			ReservedLanes::default().with_unsigned(Limit { count: 1, total_bytes: usize::MAX });
+
+		let signed_xts = (0..max + 1).map(|x| Arc::from(uxt(x as _))).collect::<Vec<_>>();
+		let unsigned_xts = (0..3)
+			.map(|x| Arc::from(ExtrinsicBuilder::new_include_data(vec![x]).unsigned().build()))
+			.collect::<Vec<_>>();
+		let hash = |xt: &ExtrinsicFor<TestApi>| api.hash_and_length(xt).0;
+
+		// signed transactions do not use the reserved capacity
+		let results = mempool.extend_unwatched(TransactionSource::External, 0, &signed_xts).await;
+		assert!(results.iter().take(max).all(Result::is_ok));
+		assert!(matches!(
+			results.into_iter().last().unwrap().unwrap_err(),
+			sc_transaction_pool_api::error::Error::ImmediatelyDropped
+		));
+
+		// unsigned transactions do not use the regular capacity
+		let results = mempool
+			.extend_unwatched(TransactionSource::External, 0, &unsigned_xts[..2])
+			.await;
+		assert!(results[0].is_ok());
+		assert!(matches!(
+			results[1].as_ref().unwrap_err(),
+			sc_transaction_pool_api::error::Error::ImmediatelyDropped
+		));
+		assert_eq!(mempool.len(), max + 1);
+		let tx = mempool.get_by_hash(hash(&unsigned_xts[0])).await.unwrap();
+		assert_eq!(tx.lane(), MempoolLane::Unsigned);
+
+		// replacement only drops transactions from the same lane
+		for xt in &signed_xts[..max] {
+			mempool.update_transaction_priority(hash(xt), Some(0)).await;
+		}
+		mempool.update_transaction_priority(hash(&unsigned_xts[0]), Some(1)).await;
+		let result = mempool
+			.try_insert_with_replacement(
+				unsigned_xts[1].clone(),
+				2,
+				TransactionSource::External,
+				0,
+				false,
+			)
+			.await
+			.unwrap();
+		assert_eq!(result.removed, vec![hash(&unsigned_xts[0])]);
+		assert_eq!(mempool.len(), max + 1);
+
+		// removal frees the reserved capacity
+		mempool.remove_transactions(&[hash(&unsigned_xts[1])]).await;
+		let results = mempool
+			.extend_unwatched(TransactionSource::External, 0, &unsigned_xts[2..])
+			.await;
+		assert!(results[0].is_ok());
+		assert_eq!(mempool.len(), max + 1);
+	}
+
+	#[tokio::test]
+	async fn operational_lane_keeps_high_priority_txs_apart() {
+		sp_tracing::try_init_simple();
+		let max = 2;
+		let api = Arc::from(TestApi::default());
+		let mut mempool = TxMemPool::new_test(api.clone(), max, usize::MAX);
+		mempool.reserved_lanes = ReservedLanes::default()
+			.with_operational(100, Limit { count: 1, total_bytes: usize::MAX });
+
+		let xts = (0..max + 2).map(|x| Arc::from(uxt(x as _))).collect::<Vec<_>>();
+		let hash = |xt: &ExtrinsicFor<TestApi>| api.hash_and_length(xt).0;
+
+		let results = mempool.extend_unwatched(TransactionSource::External, 0, &xts[..max]).await;
+		assert!(results.iter().all(Result::is_ok));
+
+		// the regular transaction moves to the operational lane once its priority is known
+		mempool.update_transaction_priority(hash(&xts[0]), Some(100)).await;
+		let tx = mempool.get_by_hash(hash(&xts[0])).await.unwrap();
+		assert_eq!(tx.lane(), MempoolLane::Operational);
+		mempool.update_transaction_priority(hash(&xts[1]), Some(99)).await;
+		let tx = mempool.get_by_hash(hash(&xts[1])).await.unwrap();
+		assert_eq!(tx.lane(), MempoolLane::Regular);
+
+		// the freed regular capacity is available to other transactions
+		let results = mempool.extend_unwatched(TransactionSource::External, 0, &xts[max..]).await;
+		assert!(results[0].is_ok());
+		assert!(matches!(
+			results[1].as_ref().unwrap_err(),
+			sc_transaction_pool_api::error::Error::ImmediatelyDropped
+		));
+		assert_eq!(mempool.len(), max + 1);
+
+		// operational transactions are only replaced by operational ones
+		let result = mempool
+			.try_insert_with_replacement(
+				xts[max + 1].clone(),
+				200,
+				TransactionSource::External,
+				0,
+				false,
+			)
+			.await
+			.unwrap();
+		assert_eq!(result.removed, vec![hash(&xts[0])]);
+		let tx = mempool.get_by_hash(hash(&xts[max + 1])).await.unwrap();
+		assert_eq!(tx.lane(), MempoolLane::Operational);
+		assert_eq!(mempool.len(), max + 1);
+	}
+
 	#[tokio::test]
 	async fn replacing_txs_works_for_same_tx_size() {
 		sp_tracing::try_init_simple();
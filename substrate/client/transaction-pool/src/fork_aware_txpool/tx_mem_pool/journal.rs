@@ -0,0 +1,477 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! On-disk journal of the `TxMemPool` content.
+//!
+//! Every insertion into and removal from the mempool is appended to the journal file as a
+//! length-prefixed SCALE encoded [`JournalRecord`]. When the journal is opened, the records are
+//! replayed to rebuild the set of transactions which were in the mempool when the node stopped.
+//! The file is then rewritten to contain only those transactions, and it is compacted in the same
+//! way whenever the number of appended records grows well beyond the size of the mempool.
+//!
+//! The records are written by a dedicated thread, so the mempool only queues them while holding
+//! its locks. The thread buffers the writes and flushes them every [`JOURNAL_FLUSH_INTERVAL`],
+//! and when the journal is dropped. The queue holds at most [`JOURNAL_QUEUE_CAPACITY`] requests:
+//! if the writer falls behind, the records which do not fit are dropped and the journal is
+//! compacted at the next opportunity to bring it back in sync with the mempool.
+//!
+//! A truncated trailing record (e.g. left by a crash in the middle of a write) is ignored.
+
+use codec::{Decode, Encode};
+use indexmap::IndexMap;
+use sc_transaction_pool_api::TransactionSource;
+use std::{
+	fs::{self, File, OpenOptions},
+	io::{self, BufWriter, Write},
+	path::{Path, PathBuf},
+	sync::mpsc::{sync_channel, Receiver, RecvTimeoutError, SyncSender, TrySendError},
+	thread::JoinHandle,
+	time::Duration,
+};
+use tracing::{debug, warn};
+
+use crate::{
+	graph::{self, ExtrinsicFor, ExtrinsicHash},
+	LOG_TARGET,
+};
+
+/// The minimal number of appended records which triggers the compaction of the journal.
+const JOURNAL_COMPACTION_MIN_RECORDS: usize = 1024;
+
+/// The maximal time the appended records are buffered before being written to the file.
+const JOURNAL_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The maximal number of requests queued for the journal writer thread.
+const JOURNAL_QUEUE_CAPACITY: usize = 16 * 1024;
+
+/// A single entry of the journal.
+#[derive(Encode, Decode)]
+enum JournalRecord<Hash, Extrinsic> {
+	/// The transaction was inserted into the mempool.
+	Inserted { hash: Hash, source: TransactionSource, xt: Extrinsic },
+	/// The transaction was removed from the mempool.
+	Removed(Hash),
+}
+
+/// A transaction restored from the journal.
+pub(super) type JournaledTransaction<ChainApi> = (TransactionSource, ExtrinsicFor<ChainApi>);
+
+/// A transaction kept by the compaction of the journal.
+type CompactedTransaction<ChainApi> =
+	(ExtrinsicHash<ChainApi>, TransactionSource, ExtrinsicFor<ChainApi>);
+
+/// A request to the journal writer thread.
+enum JournalCommand<ChainApi>
+where
+	ChainApi: graph::ChainApi + 'static,
+{
+	/// Append the records to the journal file.
+	Append(Vec<JournalRecord<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>>),
+	/// Rewrite the journal file so it contains only the given transactions.
+	Compact(Vec<CompactedTransaction<ChainApi>>),
+}
+
+/// Append-only journal of the mempool content.
+pub(super) struct MempoolJournal<ChainApi>
+where
+	ChainApi: graph::ChainApi + 'static,
+{
+	/// Queue of the journal writer thread.
+	commands: Option<SyncSender<JournalCommand<ChainApi>>>,
+	/// The journal writer thread, joined when the journal is dropped.
+	writer: Option<JoinHandle<()>>,
+	/// The number of records in the journal file.
+	records: usize,
+	/// Whether some records were dropped because the queue of the writer was full.
+	out_of_sync: bool,
+}
+
+impl<ChainApi> MempoolJournal<ChainApi>
+where
+	ChainApi: graph::ChainApi + 'static,
+{
+	/// Opens the journal at the given path, creating it if needed.
+	///
+	/// Returns the journal together with the transactions it contains, in the order of their
+	/// insertion.
+	pub(super) fn open(path: &Path) -> io::Result<(Self, Vec<JournaledTransaction<ChainApi>>)> {
+		let transactions = match fs::read(path) {
+			Ok(content) => Self::replay(&content),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => Default::default(),
+			Err(e) => return Err(e),
+		};
+
+		let file = rewrite::<ChainApi>(
+			path,
+			transactions.iter().map(|(hash, (source, xt))| (hash, *source, xt)),
+		)?;
+		let (commands, receiver) = sync_channel(JOURNAL_QUEUE_CAPACITY);
+		let writer = {
+			let path = path.to_path_buf();
+			std::thread::Builder::new().name("mempool-journal".into()).spawn(move || {
+				JournalWriter::<ChainApi> { path, file, dirty: false, _phantom: Default::default() }
+					.run(receiver)
+			})?
+		};
+		debug!(
+			target: LOG_TARGET,
+			?path,
+			count = transactions.len(),
+			"mempool::journal opened"
+		);
+		let journal = Self {
+			commands: Some(commands),
+			writer: Some(writer),
+			records: transactions.len(),
+			out_of_sync: false,
+		};
+		Ok((journal, transactions.into_values().collect()))
+	}
+
+	/// Records the insertion of the transaction.
+	pub(super) fn inserted(
+		&mut self,
+		hash: ExtrinsicHash<ChainApi>,
+		source: TransactionSource,
+		xt: &ExtrinsicFor<ChainApi>,
+	) {
+		self.append(vec![JournalRecord::Inserted { hash, source, xt: xt.clone() }]);
+	}
+
+	/// Records the removal of the transactions.
+	pub(super) fn removed<'a>(
+		&mut self,
+		hashes: impl IntoIterator<Item = &'a ExtrinsicHash<ChainApi>>,
+	) {
+		let records: Vec<_> =
+			hashes.into_iter().map(|hash| JournalRecord::Removed(*hash)).collect();
+		if !records.is_empty() {
+			self.append(records);
+		}
+	}
+
+	/// Returns true if the journal holds many more records than the mempool has transactions, or
+	/// if it missed some of the records.
+	pub(super) fn needs_compaction(&self, mempool_len: usize) -> bool {
+		self.out_of_sync || self.records > JOURNAL_COMPACTION_MIN_RECORDS.max(2 * mempool_len)
+	}
+
+	/// Rewrites the journal so it contains only the given transactions.
+	pub(super) fn compact<'a>(
+		&mut self,
+		transactions: impl Iterator<
+			Item = (&'a ExtrinsicHash<ChainApi>, TransactionSource, &'a ExtrinsicFor<ChainApi>),
+		>,
+	) {
+		let transactions: Vec<_> =
+			transactions.map(|(hash, source, xt)| (*hash, source, xt.clone())).collect();
+		let count = transactions.len();
+		if self.send(JournalCommand::Compact(transactions)) {
+			self.records = count;
+			self.out_of_sync = false;
+		}
+	}
+
+	fn append(
+		&mut self,
+		records: Vec<JournalRecord<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>>,
+	) {
+		let count = records.len();
+		if self.send(JournalCommand::Append(records)) {
+			self.records += count;
+		} else {
+			self.out_of_sync = true;
+		}
+	}
+
+	/// Queues the command for the writer thread, never blocking the caller.
+	///
+	/// Returns false if the command was dropped.
+	fn send(&self, command: JournalCommand<ChainApi>) -> bool {
+		let Some(commands) = self.commands.as_ref() else { return false };
+		match commands.try_send(command) {
+			Ok(()) => true,
+			Err(TrySendError::Full(_)) => {
+				warn!(
+					target: LOG_TARGET,
+					capacity = JOURNAL_QUEUE_CAPACITY,
+					"mempool::journal writer queue is full, records are dropped"
+				);
+				false
+			},
+			Err(TrySendError::Disconnected(_)) => {
+				warn!(target: LOG_TARGET, "mempool::journal writer is not running");
+				false
+			},
+		}
+	}
+
+	/// Decodes the journal content and returns the transactions that were not removed.
+	fn replay(
+		mut content: &[u8],
+	) -> IndexMap<ExtrinsicHash<ChainApi>, JournaledTransaction<ChainApi>> {
+		let mut transactions = IndexMap::new();
+		while !content.is_empty() {
+			let record = Vec::<u8>::decode(&mut content).and_then(|record| {
+				JournalRecord::<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>::decode(
+					&mut &record[..],
+				)
+			});
+			match record {
+				Ok(JournalRecord::Inserted { hash, source, xt }) => {
+					transactions.insert(hash, (source, xt));
+				},
+				Ok(JournalRecord::Removed(hash)) => {
+					transactions.shift_remove(&hash);
+				},
+				Err(error) => {
+					warn!(
+						target: LOG_TARGET,
+						%error,
+						skipped_bytes = content.len(),
+						"mempool::journal ends with a corrupted record"
+					);
+					break
+				},
+			}
+		}
+		transactions
+	}
+}
+
+impl<ChainApi> Drop for MempoolJournal<ChainApi>
+where
+	ChainApi: graph::ChainApi + 'static,
+{
+	fn drop(&mut self) {
+		// Closing the queue makes the writer flush the pending records and exit.
+		self.commands.take();
+		if let Some(writer) = self.writer.take() {
+			if writer.join().is_err() {
+				warn!(target: LOG_TARGET, "mempool::journal writer panicked");
+			}
+		}
+	}
+}
+
+/// The state of the journal writer thread.
+struct JournalWriter<ChainApi> {
+	/// The path of the journal file.
+	path: PathBuf,
+	/// The journal file opened for appending.
+	file: BufWriter<File>,
+	/// Whether records were written since the last flush.
+	dirty: bool,
+	_phantom: std::marker::PhantomData<ChainApi>,
+}
+
+impl<ChainApi> JournalWriter<ChainApi>
+where
+	ChainApi: graph::ChainApi + 'static,
+{
+	/// Processes the commands until the journal is dropped.
+	///
+	/// Write errors are logged and otherwise ignored: a broken journal must not affect the pool.
+	fn run(mut self, commands: Receiver<JournalCommand<ChainApi>>) {
+		loop {
+			let command = if self.dirty {
+				commands.recv_timeout(JOURNAL_FLUSH_INTERVAL)
+			} else {
+				commands.recv().map_err(|_| RecvTimeoutError::Disconnected)
+			};
+			match command {
+				Ok(JournalCommand::Append(records)) => {
+					self.dirty = true;
+					let result = records
+						.iter()
+						.try_for_each(|record| self.file.write_all(&record.encode().encode()));
+					if let Err(error) = result {
+						warn!(target: LOG_TARGET, path = ?self.path, %error, "mempool::journal write failed");
+					}
+				},
+				Ok(JournalCommand::Compact(transactions)) => self.compact(transactions),
+				Err(RecvTimeoutError::Timeout) => self.flush(),
+				Err(RecvTimeoutError::Disconnected) => {
+					self.flush();
+					break
+				},
+			}
+		}
+	}
+
+	fn flush(&mut self) {
+		self.dirty = false;
+		if let Err(error) = self.file.flush() {
+			warn!(target: LOG_TARGET, path = ?self.path, %error, "mempool::journal write failed");
+		}
+	}
+
+	fn compact(&mut self, transactions: Vec<CompactedTransaction<ChainApi>>) {
+		self.flush();
+		let count = transactions.len();
+		match rewrite::<ChainApi>(
+			&self.path,
+			transactions.iter().map(|(hash, source, xt)| (hash, *source, xt)),
+		) {
+			Ok(file) => {
+				debug!(target: LOG_TARGET, count, "mempool::journal compacted");
+				self.file = file;
+			},
+			Err(error) => {
+				warn!(target: LOG_TARGET, path = ?self.path, %error, "mempool::journal compaction failed");
+			},
+		}
+	}
+}
+
+/// Atomically replaces the journal file with the given transactions.
+///
+/// Returns the new journal file opened for appending.
+fn rewrite<'a, ChainApi>(
+	path: &Path,
+	transactions: impl Iterator<
+		Item = (&'a ExtrinsicHash<ChainApi>, TransactionSource, &'a ExtrinsicFor<ChainApi>),
+	>,
+) -> io::Result<BufWriter<File>>
+where
+	ChainApi: graph::ChainApi + 'static,
+{
+	let tmp_path = path.with_extension("tmp");
+	let mut tmp = BufWriter::new(File::create(&tmp_path)?);
+	for (hash, source, xt) in transactions {
+		let record = JournalRecord::Inserted { hash: *hash, source, xt };
+		tmp.write_all(&record.encode().encode())?;
+	}
+	tmp.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+	fs::rename(&tmp_path, path)?;
+	Ok(BufWriter::new(OpenOptions::new().append(true).open(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::tests::{uxt, TestApi};
+	use std::sync::Arc;
+	use substrate_test_runtime::{AccountId, Transfer, H256};
+	use substrate_test_runtime_client::Sr25519Keyring::Alice;
+
+	fn xts(count: u64) -> Vec<ExtrinsicFor<TestApi>> {
+		(0..count)
+			.map(|nonce| {
+				Arc::from(uxt(Transfer {
+					from: Alice.into(),
+					to: AccountId::from_h256(H256::from_low_u64_be(2)),
+					amount: 5,
+					nonce,
+				}))
+			})
+			.collect()
+	}
+
+	fn hash(nonce: usize) -> ExtrinsicHash<TestApi> {
+		H256::from_low_u64_be(nonce as u64)
+	}
+
+	fn restored_xts(
+		transactions: Vec<JournaledTransaction<TestApi>>,
+	) -> Vec<ExtrinsicFor<TestApi>> {
+		transactions.into_iter().map(|(_, xt)| xt).collect()
+	}
+
+	#[test]
+	fn journal_replays_live_transactions() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("mempool");
+		let xts = xts(8);
+
+		let (mut journal, restored) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		assert!(restored.is_empty());
+		for nonce in 0..5 {
+			journal.inserted(hash(nonce), TransactionSource::External, &xts[nonce]);
+		}
+		journal.removed(&[hash(1), hash(3)]);
+		journal.inserted(hash(7), TransactionSource::Local, &xts[7]);
+		drop(journal);
+
+		let (journal, restored) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		assert_eq!(journal.records, 4);
+		assert_eq!(restored[3].0, TransactionSource::Local);
+		assert_eq!(
+			restored_xts(restored),
+			vec![xts[0].clone(), xts[2].clone(), xts[4].clone(), xts[7].clone()]
+		);
+	}
+
+	#[test]
+	fn journal_ignores_truncated_record() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("mempool");
+		let xts = xts(2);
+
+		let (mut journal, _) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		journal.inserted(hash(0), TransactionSource::External, &xts[0]);
+		journal.inserted(hash(1), TransactionSource::External, &xts[1]);
+		drop(journal);
+		let len = fs::metadata(&path).unwrap().len();
+		OpenOptions::new().write(true).open(&path).unwrap().set_len(len - 3).unwrap();
+
+		let (_, restored) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		assert_eq!(restored_xts(restored), vec![xts[0].clone()]);
+	}
+
+	#[test]
+	fn journal_compaction_keeps_given_transactions() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("mempool");
+		let xts = xts(2);
+
+		let (mut journal, _) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		for nonce in 0..JOURNAL_COMPACTION_MIN_RECORDS {
+			journal.inserted(hash(nonce), TransactionSource::External, &xts[0]);
+			journal.removed(&[hash(nonce)]);
+		}
+		assert!(journal.needs_compaction(1));
+		journal.compact([(&hash(1), TransactionSource::External, &xts[1])].into_iter());
+		assert!(!journal.needs_compaction(1));
+		drop(journal);
+
+		let (journal, restored) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		assert_eq!(journal.records, 1);
+		assert_eq!(restored_xts(restored), vec![xts[1].clone()]);
+	}
+
+	#[test]
+	fn journal_is_flushed_periodically() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("mempool");
+		let xts = xts(1);
+
+		let (mut journal, _) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		journal.inserted(hash(0), TransactionSource::External, &xts[0]);
+
+		// The record reaches the file while the journal is still open.
+		let start = std::time::Instant::now();
+		while fs::metadata(&path).unwrap().len() == 0 {
+			assert!(start.elapsed() < 10 * JOURNAL_FLUSH_INTERVAL);
+			std::thread::sleep(JOURNAL_FLUSH_INTERVAL / 10);
+		}
+		let (_, restored) = MempoolJournal::<TestApi>::open(&path).unwrap();
+		assert_eq!(restored_xts(restored), vec![xts[0].clone()]);
+		drop(journal);
+	}
+}
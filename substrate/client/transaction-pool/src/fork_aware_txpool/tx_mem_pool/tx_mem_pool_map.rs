@@ -156,7 +156,7 @@ where
 
 	pub fn with_items<F, R>(&self, f: F) -> R
 	where
-		F: Fn(std::collections::hash_map::Iter<K, V>) -> R,
+		F: FnOnce(std::collections::hash_map::Iter<K, V>) -> R,
 	{
 		f(self.items_by_hashes.iter())
 	}
@@ -229,12 +229,15 @@ where
 	///
 	/// Insertion always results with other item's removal, the len bound is kept elsewhere
 	///
+	/// Only the items matching the `replaceable` filter are considered for removal.
+	///
 	/// If nothing was inserted `(None,0)` is returned.
 	pub fn try_insert_with_replacement(
 		&mut self,
 		free_bytes: usize,
 		key: K,
 		item: V,
+		replaceable: impl Fn(&V) -> bool,
 	) -> (Option<Vec<K>>, usize) {
 		let mut total_size_removed = 0usize;
 		let mut to_be_removed = vec![];
@@ -248,7 +251,7 @@ where
 		}
 
 		for (SortKey(PriorityKey(worst_priority, worst_timestamp), worst_key), worst_item) in
-			&self.items_by_priority
+			self.items_by_priority.iter().filter(|(_, item)| replaceable(item))
 		{
 			if *worst_priority > item.priority() {
 				return (None, 0);
@@ -358,7 +361,7 @@ where
 
 	pub fn with_items<F, R>(&self, f: F) -> R
 	where
-		F: Fn(std::collections::hash_map::Iter<K, V>) -> R,
+		F: FnOnce(std::collections::hash_map::Iter<K, V>) -> R,
 	{
 		self.inner_guard.with_items(f)
 	}
@@ -404,17 +407,33 @@ where
 	}
 
 	/// Refer to [`IndexedStorage::try_insert_with_replacement`]
+	#[cfg(test)]
 	pub fn try_insert_with_replacement(
 		&mut self,
 		max_total_bytes: usize,
 		key: K,
 		item: V,
 	) -> Option<Vec<K>> {
-		let item_size = item.size();
 		let current_bytes = std::cmp::max(self.bytes.load(AtomicOrdering::Relaxed), 0) as usize;
 		let free_bytes = max_total_bytes - current_bytes;
+		self.try_insert_with_filtered_replacement(free_bytes, key, item, |_| true).0
+	}
+
+	/// Refer to [`IndexedStorage::try_insert_with_replacement`]
+	///
+	/// Only the items matching the `replaceable` filter are considered for removal, and
+	/// `free_bytes` shall account for the space available to them. Returns the keys and the total
+	/// size in bytes of removed items.
+	pub fn try_insert_with_filtered_replacement(
+		&mut self,
+		free_bytes: usize,
+		key: K,
+		item: V,
+		replaceable: impl Fn(&V) -> bool,
+	) -> (Option<Vec<K>>, usize) {
+		let item_size = item.size();
 		let (removed_keys, removed_bytes) =
-			self.inner_guard.try_insert_with_replacement(free_bytes, key, item);
+			self.inner_guard.try_insert_with_replacement(free_bytes, key, item, replaceable);
 
 		if let Some(ref removed_keys) = removed_keys {
 			let delta = item_size as isize - removed_bytes as isize;
@@ -422,7 +441,7 @@ where
 			self.length.fetch_sub(removed_keys.len() as isize, AtomicOrdering::Relaxed);
 			self.length.fetch_add(1, AtomicOrdering::Relaxed);
 		}
-		removed_keys
+		(removed_keys, removed_bytes)
 	}
 
 	/// Allows to mutate item for given key, if exists.
@@ -446,11 +465,6 @@ where
 		self.inner_guard.contains_key(key)
 	}
 
-	/// Returns the number of elements in the map.
-	pub fn len(&mut self) -> usize {
-		self.inner_guard.len()
-	}
-
 	#[cfg(test)]
 	pub fn pop_first(&mut self) -> Option<V> {
 		self.inner_guard.pop_first()
@@ -641,6 +655,40 @@ mod tests {
 		assert_eq!(map.write().await.pop_first().unwrap(), i3);
 	}
 
+	#[tokio::test]
+	async fn try_insert_with_filtered_replacement_skips_filtered_items() {
+		let map = SizeTrackedStore::default();
+
+		let i0 = TestItem::new(1, 0, 20);
+		let i1 = TestItem::new(2, 1, 30);
+		let i2 = TestItem::new(3, 1, 50);
+
+		map.write().await.insert(0xa, i0.clone());
+		map.write().await.insert(0xb, i1.clone());
+		map.write().await.insert(0xc, i2.clone());
+
+		// only items with odd timestamp can be replaced
+		let i3 = TestItem::new(4, 1, 40);
+		let (removed, removed_bytes) =
+			map.write()
+				.await
+				.try_insert_with_filtered_replacement(0, 0xd, i3.clone(), |i| i.ts % 2 == 1);
+
+		assert_eq!(removed, Some(vec![0xb, 0xc]));
+		assert_eq!(removed_bytes, 80);
+		assert_eq!(map.bytes(), 60);
+		assert_eq!(map.len(), 2);
+
+		// nothing replaceable
+		let i4 = TestItem::new(5, 0, 10);
+		let (removed, _) =
+			map.write().await.try_insert_with_filtered_replacement(0, 0xe, i4, |_| false);
+		assert!(removed.is_none());
+		assert_eq!(map.len(), 2);
+		assert_eq!(map.write().await.pop_first().unwrap(), i0);
+		assert_eq!(map.write().await.pop_first().unwrap(), i3);
+	}
+
 	#[tokio::test]
 	async fn try_insert_with_replacement_works_known_key_reject() {
 		let map = SizeTrackedStore::default();
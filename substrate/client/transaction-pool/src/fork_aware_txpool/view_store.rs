@@ -346,6 +346,14 @@ where
 		self.active_views.read().is_empty() && self.inactive_views.read().is_empty()
 	}
 
+	/// Returns true if the transaction is temporarily banned by any of the views.
+	pub(super) fn is_banned(&self, tx_hash: &ExtrinsicHash<ChainApi>) -> bool {
+		let is_banned = |views: &HashMap<Block::Hash, Arc<View<ChainApi>>>| {
+			views.values().any(|view| view.pool.validated_pool().is_banned(tx_hash))
+		};
+		is_banned(&self.active_views.read()) || is_banned(&self.inactive_views.read())
+	}
+
 	/// Searches in the view store for the first descendant view by iterating through the fork of
 	/// the `at` block, up to the provided `block_number`.
 	///
@@ -20,7 +20,13 @@
 //!
 //! For a more full-featured pool, have a look at the `pool` module.
 
-use std::{cmp::Ordering, collections::HashSet, fmt, hash, sync::Arc, time::Instant};
+use std::{
+	cmp::{Ordering, Reverse},
+	collections::{HashMap, HashSet},
+	fmt, hash,
+	sync::Arc,
+	time::Instant,
+};
 
 use crate::LOG_TARGET;
 use sc_transaction_pool_api::{error, InPoolTransaction, PoolStatus};
@@ -256,6 +262,8 @@ pub struct BasePool<Hash: hash::Hash + Eq, Ex> {
 	/// transactions to future in case they were just stuck in verification.
 	recently_pruned: [HashSet<Tag>; RECENTLY_PRUNED_TAGS],
 	recently_pruned_index: usize,
+	/// The transactions counted towards the quotas.
+	quota_groups: QuotaGroups<Hash>,
 }
 
 impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> Default for BasePool<Hash, Ex> {
@@ -273,9 +281,24 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			ready: Default::default(),
 			recently_pruned: Default::default(),
 			recently_pruned_index: 0,
+			quota_groups: QuotaGroups {
+				quotas: Default::default(),
+				members: Default::default(),
+				exceeded: Default::default(),
+				next_import: 0,
+			},
 		}
 	}
 
+	/// Sets the quotas of the transactions sharing a prefix of their provided tags.
+	///
+	/// The quotas are enforced with [`Self::enforce_quotas`]. Shall be set before any
+	/// transaction is imported.
+	pub fn with_quotas(mut self, quotas: TagQuotas) -> Self {
+		self.quota_groups.quotas = quotas;
+		self
+	}
+
 	/// Clears buffer keeping recently pruned transaction.
 	pub fn clear_recently_pruned(&mut self) {
 		self.recently_pruned = Default::default();
@@ -331,11 +354,15 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			}
 
 			let hash = tx.transaction.hash.clone();
+			self.quota_groups.inserted(&tx.transaction);
 			self.future.import(tx);
 			return Ok(Imported::Future { hash })
 		}
 
-		self.import_to_ready(tx)
+		let transaction = tx.transaction.clone();
+		let imported = self.import_to_ready(tx)?;
+		self.quota_groups.inserted(&transaction);
+		Ok(imported)
 	}
 
 	/// Imports transaction to ready queue.
@@ -406,6 +433,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			}
 			first = false;
 		}
+		self.quota_groups.removed(&removed);
 
 		// An edge case when importing transaction caused
 		// some future transactions to be imported and that
@@ -415,7 +443,8 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		if removed.iter().any(|tx| tx.hash == tx_hash) {
 			// We still need to remove all transactions that we promoted
 			// since they depend on each other and will never get to the best iterator.
-			self.ready.remove_subtree(&promoted);
+			let promoted = self.ready.remove_subtree(&promoted);
+			self.quota_groups.removed(&promoted);
 
 			trace!(
 				target: LOG_TARGET,
@@ -534,6 +563,66 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		removed
 	}
 
+	/// Returns true if some groups of transactions might exceed their quota.
+	pub fn is_quota_exceeded(&self) -> bool {
+		!self.quota_groups.exceeded.is_empty()
+	}
+
+	/// Makes sure that the groups of transactions defined by the quotas stay within their limits.
+	///
+	/// Removes and returns the worst transactions of the groups exceeding their quota and all
+	/// transactions that depend on them. Unlike in [`Self::enforce_limits`], transactions from the
+	/// future queue are removed first, followed by the ready transactions with the lowest priority
+	/// and the most recent ones in case priority is the same, so the chains of dependent
+	/// transactions are cut from their end.
+	///
+	/// Only the groups which grew since the last call are checked.
+	pub fn enforce_quotas(&mut self) -> Vec<Arc<Transaction<Hash, Ex>>> {
+		let mut removed = vec![];
+		for key in std::mem::take(&mut self.quota_groups.exceeded) {
+			while let Some(worst) = self.worst_over_quota(&key) {
+				removed.append(&mut self.remove_subtree(&[worst]))
+			}
+		}
+		removed
+	}
+
+	/// Returns the worst transaction of the group if it exceeds its quota.
+	fn worst_over_quota(&mut self, key: &QuotaKey) -> Option<Hash> {
+		// The worst transaction has the lowest rank.
+		#[derive(PartialEq, Eq, PartialOrd, Ord)]
+		enum Rank {
+			Future(Priority, Reverse<u64>),
+			Ready(Priority, Reverse<u64>),
+		}
+
+		let quota = self.quota_groups.quotas.quota(key)?;
+		let members = self.quota_groups.members.get_mut(key)?;
+		// Some transactions (e.g. the ones which failed to be promoted) leave the pool without
+		// being reported, so make sure the group only counts the transactions still in the pool.
+		if members.len() > quota {
+			members.retain(|hash, _| self.ready.contains(hash) || self.future.contains(hash));
+		}
+		if members.len() <= quota {
+			return None
+		}
+
+		members
+			.iter()
+			.filter_map(|(hash, import)| {
+				let rank = match self.ready.by_hash(hash) {
+					Some(tx) => Rank::Ready(tx.priority, Reverse(*import)),
+					None => {
+						let tx = self.future.by_hashes(&[hash.clone()]).pop().flatten()?;
+						Rank::Future(tx.priority, Reverse(*import))
+					},
+				};
+				Some((rank, hash))
+			})
+			.min_by(|(a, _), (b, _)| a.cmp(b))
+			.map(|(_, hash)| hash.clone())
+	}
+
 	/// Removes all transactions represented by the hashes and all other transactions
 	/// that depend on them.
 	///
@@ -545,12 +634,15 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 	pub fn remove_subtree(&mut self, hashes: &[Hash]) -> Vec<Arc<Transaction<Hash, Ex>>> {
 		let mut removed = self.ready.remove_subtree(hashes);
 		removed.extend(self.future.remove(hashes));
+		self.quota_groups.removed(&removed);
 		removed
 	}
 
 	/// Removes and returns all transactions from the future queue.
 	pub fn clear_future(&mut self) -> Vec<Arc<Transaction<Hash, Ex>>> {
-		self.future.clear()
+		let removed = self.future.clear();
+		self.quota_groups.removed(&removed);
+		removed
 	}
 
 	/// Prunes transactions that provide given list of tags.
@@ -578,6 +670,8 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			recently_pruned.insert(tag);
 		}
 
+		self.quota_groups.removed(futures_removed.iter().chain(&pruned));
+
 		let mut promoted = vec![];
 		let mut failed = vec![];
 		for tx in futures_removed {
@@ -630,6 +724,163 @@ impl Limit {
 	}
 }
 
+/// The default length of the account id starting the nonce tags.
+///
+/// In FRAME based runtimes the tags provided by `CheckNonce` are the encoded `(AccountId, Nonce)`
+/// pairs, so the sender is identified by a 32 bytes long account id.
+pub const DEFAULT_SENDER_TAG_PREFIX_LEN: usize = 32;
+
+/// The default length of the nonce ending the nonce tags, matching a `u32` nonce.
+pub const DEFAULT_NONCE_TAG_SUFFIX_LEN: usize = 4;
+
+/// Quotas limiting the number of transactions sharing a prefix of their `provides` tags.
+///
+/// A transaction counts towards a prefix quota if any of the tags it provides starts with the
+/// prefix defined by the quota. The sender of a transaction is only derived from its nonce tag,
+/// i.e. a provided tag made of an account id followed by a nonce. The quotas prevent a single
+/// sender (or any other group of transactions identified by their tags) from crowding out
+/// everyone else from the pool.
+///
+/// No quota is defined by default, they are added with the `with_*` methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagQuotas {
+	/// Maximal number of transactions of a single sender, if any.
+	sender: Option<usize>,
+	/// The length of the account id starting the nonce tag.
+	sender_tag_len: usize,
+	/// The length of the nonce ending the nonce tag.
+	nonce_tag_len: usize,
+	/// Maximal numbers of transactions providing a tag with the given prefix.
+	prefixes: Vec<(Tag, usize)>,
+}
+
+impl Default for TagQuotas {
+	fn default() -> Self {
+		Self {
+			sender: None,
+			sender_tag_len: DEFAULT_SENDER_TAG_PREFIX_LEN,
+			nonce_tag_len: DEFAULT_NONCE_TAG_SUFFIX_LEN,
+			prefixes: vec![],
+		}
+	}
+}
+
+/// A group of transactions subject to a quota.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum QuotaKey {
+	/// The transactions of a single sender.
+	Sender(Tag),
+	/// The transactions providing a tag with the prefix at the given index.
+	Prefix(usize),
+}
+
+impl TagQuotas {
+	/// Limits the number of transactions of a single sender.
+	pub fn with_sender_limit(mut self, limit: usize) -> Self {
+		self.sender = Some(limit);
+		self
+	}
+
+	/// Sets the format of the nonce tags identifying the sender of a transaction.
+	///
+	/// A nonce tag is made of `sender_len` bytes of the account id followed by `nonce_len` bytes
+	/// of the nonce.
+	pub fn with_nonce_tag_format(mut self, sender_len: usize, nonce_len: usize) -> Self {
+		self.sender_tag_len = sender_len;
+		self.nonce_tag_len = nonce_len;
+		self
+	}
+
+	/// Limits the number of transactions providing a tag with the given prefix.
+	pub fn with_prefix_limit(mut self, prefix: Tag, limit: usize) -> Self {
+		self.prefixes.push((prefix, limit));
+		self
+	}
+
+	/// Returns true if no quota is defined.
+	pub fn is_empty(&self) -> bool {
+		self.sender.is_none() && self.prefixes.is_empty()
+	}
+
+	/// Returns the quota of the given group.
+	fn quota(&self, key: &QuotaKey) -> Option<usize> {
+		match key {
+			QuotaKey::Sender(_) => self.sender,
+			QuotaKey::Prefix(index) => self.prefixes.get(*index).map(|(_, quota)| *quota),
+		}
+	}
+
+	/// Returns the distinct groups the transaction providing given tags belongs to.
+	fn keys(&self, provides: &[Tag]) -> HashSet<QuotaKey> {
+		let mut keys = HashSet::new();
+		for tag in provides {
+			if self.sender.is_some() && tag.len() == self.sender_tag_len + self.nonce_tag_len {
+				keys.insert(QuotaKey::Sender(tag[..self.sender_tag_len].to_vec()));
+			}
+			for (index, (prefix, _)) in self.prefixes.iter().enumerate() {
+				if tag.starts_with(prefix) {
+					keys.insert(QuotaKey::Prefix(index));
+				}
+			}
+		}
+		keys
+	}
+}
+
+/// The transactions of the pool counted towards the quotas.
+///
+/// The groups are updated when the transactions are imported to and removed from the pool, so the
+/// quotas are checked without going through the whole pool.
+#[derive(Debug, Clone, Default)]
+struct QuotaGroups<Hash: hash::Hash + Eq> {
+	/// The configured quotas.
+	quotas: TagQuotas,
+	/// The members of every group, with the import sequence numbers.
+	members: HashMap<QuotaKey, HashMap<Hash, u64>>,
+	/// The groups which might exceed their quota since the last enforcement.
+	exceeded: HashSet<QuotaKey>,
+	/// The sequence number of the next imported transaction.
+	next_import: u64,
+}
+
+impl<Hash: hash::Hash + Member> QuotaGroups<Hash> {
+	/// Adds the imported transaction to its groups.
+	fn inserted<Ex>(&mut self, tx: &Transaction<Hash, Ex>) {
+		if self.quotas.is_empty() {
+			return
+		}
+		let import = self.next_import;
+		self.next_import += 1;
+		for key in self.quotas.keys(&tx.provides) {
+			let members = self.members.entry(key.clone()).or_default();
+			members.insert(tx.hash.clone(), import);
+			if self.quotas.quota(&key).is_some_and(|quota| members.len() > quota) {
+				self.exceeded.insert(key);
+			}
+		}
+	}
+
+	/// Removes the transactions removed from the pool from their groups.
+	fn removed<'a, Ex: 'a>(&mut self, txs: impl IntoIterator<Item = &'a Arc<Transaction<Hash, Ex>>>)
+	where
+		Hash: 'a,
+	{
+		if self.quotas.is_empty() {
+			return
+		}
+		for tx in txs {
+			for key in self.quotas.keys(&tx.provides) {
+				if let Some(members) = self.members.get_mut(&key) {
+					members.remove(&tx.hash);
+					if members.is_empty() {
+						self.members.remove(&key);
+					}
+				}
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -848,6 +1099,55 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_enforce_quotas() {
+		// given
+		let quotas = TagQuotas::default()
+			.with_sender_limit(2)
+			.with_nonce_tag_format(2, 1)
+			.with_prefix_limit(vec![2], 0);
+		let mut pool = pool().with_quotas(quotas);
+		let sender_tx = |hash: u64, requires: Vec<Tag>, provides: Tag| Transaction {
+			data: vec![hash as u8],
+			hash,
+			requires,
+			provides: vec![provides],
+			..default_tx().clone()
+		};
+		pool.import(sender_tx(1, vec![], vec![1, 1, 0])).unwrap();
+		pool.import(sender_tx(2, vec![vec![1, 1, 0]], vec![1, 1, 1])).unwrap();
+		assert!(!pool.is_quota_exceeded());
+		pool.import(sender_tx(3, vec![vec![1, 1, 1]], vec![1, 1, 2])).unwrap();
+		pool.import(sender_tx(4, vec![vec![1, 1, 3]], vec![1, 1, 4])).unwrap();
+		pool.import(sender_tx(5, vec![], vec![1, 2, 0])).unwrap();
+		pool.import(sender_tx(6, vec![], vec![2, 2, 0])).unwrap();
+		// not a nonce tag, so it does not count towards the quota of the sender.
+		pool.import(sender_tx(7, vec![], vec![1, 1, 7, 7])).unwrap();
+		assert!(pool.is_quota_exceeded());
+		assert_eq!(pool.ready().count(), 6);
+		assert_eq!(pool.future.len(), 1);
+
+		// when
+		let removed = pool.enforce_quotas();
+
+		// then
+		let mut removed = removed.iter().map(|tx| tx.hash).collect::<Vec<_>>();
+		removed.sort();
+		assert_eq!(removed, vec![3, 4, 6]);
+		let mut ready = pool.ready().map(|tx| tx.hash).collect::<Vec<_>>();
+		ready.sort();
+		assert_eq!(ready, vec![1, 2, 5, 7]);
+		assert_eq!(pool.future.len(), 0);
+		assert!(!pool.is_quota_exceeded());
+		assert!(pool.enforce_quotas().is_empty());
+
+		// the removed transactions free the quota.
+		pool.remove_subtree(&[2]);
+		pool.import(sender_tx(8, vec![], vec![1, 1, 8])).unwrap();
+		assert!(!pool.is_quota_exceeded());
+		assert!(pool.enforce_quotas().is_empty());
+	}
+
 	#[test]
 	fn should_remove_conflicting_future() {
 		let mut pool = pool();
@@ -26,7 +26,8 @@ use sp_runtime::{
 	generic::BlockId,
 	traits::{self, Block as BlockT, SaturatedConversion},
 	transaction_validity::{
-		TransactionSource, TransactionTag as Tag, TransactionValidity, TransactionValidityError,
+		TransactionPriority, TransactionSource, TransactionTag as Tag, TransactionValidity,
+		TransactionValidityError,
 	},
 };
 use std::{
@@ -158,6 +159,10 @@ pub struct Options {
 	pub reject_future_transactions: bool,
 	/// How long the extrinsic is banned for.
 	pub ban_time: Duration,
+	/// Quotas of transactions sharing a prefix of their provided tags.
+	pub(crate) quotas: base::TagQuotas,
+	/// Capacity of the fork-aware mempool reserved for particular kinds of transactions.
+	pub(crate) reserved_lanes: ReservedLanes,
 }
 
 impl Default for Options {
@@ -167,6 +172,8 @@ impl Default for Options {
 			future: base::Limit { count: 512, total_bytes: 1 * 1024 * 1024 },
 			reject_future_transactions: false,
 			ban_time: Duration::from_secs(60 * 30),
+			quotas: Default::default(),
+			reserved_lanes: Default::default(),
 		}
 	}
 }
@@ -176,6 +183,54 @@ impl Options {
 	pub fn total_count(&self) -> usize {
 		self.ready.count + self.future.count
 	}
+
+	/// Sets the quotas of transactions sharing a prefix of their provided tags.
+	pub fn with_quotas(mut self, quotas: base::TagQuotas) -> Self {
+		self.quotas = quotas;
+		self
+	}
+
+	/// Sets the capacity of the fork-aware mempool reserved for particular kinds of transactions.
+	pub fn with_reserved_lanes(mut self, reserved_lanes: ReservedLanes) -> Self {
+		self.reserved_lanes = reserved_lanes;
+		self
+	}
+}
+
+/// Capacity of the fork-aware mempool reserved for particular kinds of transactions.
+///
+/// The transactions kept in a reserved lane neither compete for space with the other ones, which
+/// keep using the capacity given by the `ready` and `future` limits of [`Options`], nor can be
+/// replaced by them. No capacity is reserved by default.
+#[derive(Debug, Clone, Default)]
+pub struct ReservedLanes {
+	/// Capacity reserved for unsigned transactions.
+	pub(crate) unsigned: Option<base::Limit>,
+	/// Capacity reserved for operational transactions, with their minimal priority.
+	pub(crate) operational: Option<(TransactionPriority, base::Limit)>,
+}
+
+impl ReservedLanes {
+	/// Reserves the capacity for unsigned transactions.
+	pub fn with_unsigned(mut self, limit: base::Limit) -> Self {
+		self.unsigned = Some(limit);
+		self
+	}
+
+	/// Reserves the capacity for operational transactions.
+	///
+	/// The transactions with at least `min_priority` are considered operational, e.g. the
+	/// operational dispatches of FRAME based runtimes get their priority boosted by the
+	/// `OperationalFeeMultiplier`. As the priority of a transaction is only known once it was
+	/// validated, the transactions move to this lane when their priority is reported.
+	pub fn with_operational(
+		mut self,
+		min_priority: TransactionPriority,
+		limit: base::Limit,
+	) -> Self {
+		self.operational = Some((min_priority, limit));
+		self
+	}
 }
 
 /// Should we check that the transaction is banned
@@ -1174,6 +1229,45 @@ mod tests {
 			assert_eq!(stream.next(), Some(TransactionStatus::Broadcast(peers)));
 		}
 
+		#[test]
+		fn should_trigger_dropped_when_quota_exceeded() {
+			// given
+			let quotas = base::TagQuotas::default().with_prefix_limit(vec![], 1);
+			let options = Options::default().with_quotas(quotas);
+
+			let api = Arc::new(TestApi::default());
+			let pool = Pool::new_with_staticly_sized_rotator(options, true.into(), api.clone());
+
+			let xt = uxt(Transfer {
+				from: Alice.into(),
+				to: AccountId::from_h256(H256::from_low_u64_be(2)),
+				amount: 5,
+				nonce: 1,
+			});
+			let watcher =
+				block_on(pool.submit_and_watch(&api.expect_hash_and_number(0), SOURCE, xt.into()))
+					.unwrap()
+					.expect_watcher();
+			assert_eq!(pool.validated_pool().status().future, 1);
+
+			// when
+			let xt = uxt(Transfer {
+				from: Alice.into(),
+				to: AccountId::from_h256(H256::from_low_u64_be(2)),
+				amount: 5,
+				nonce: 0,
+			});
+			block_on(pool.submit_one(&api.expect_hash_and_number(0), SOURCE, xt.into())).unwrap();
+			assert_eq!(pool.validated_pool().status().ready, 1);
+			assert_eq!(pool.validated_pool().status().future, 0);
+
+			// then
+			let mut stream = futures::executor::block_on_stream(watcher.into_stream());
+			assert_eq!(stream.next(), Some(TransactionStatus::Future));
+			assert_eq!(stream.next(), Some(TransactionStatus::Ready));
+			assert_eq!(stream.next(), Some(TransactionStatus::Dropped));
+		}
+
 		#[test]
 		fn should_trigger_dropped_older() {
 			// given
@@ -245,7 +245,8 @@ impl<B: ChainApi, L: EventHandler<B>> ValidatedPool<B, L> {
 		rotator: PoolRotator<ExtrinsicHash<B>>,
 		event_handler: Option<L>,
 	) -> Self {
-		let base_pool = base::BasePool::new(options.reject_future_transactions);
+		let base_pool = base::BasePool::new(options.reject_future_transactions)
+			.with_quotas(options.quotas.clone());
 		Self {
 			is_validator,
 			options,
@@ -390,12 +391,17 @@ impl<B: ChainApi, L: EventHandler<B>> ValidatedPool<B, L> {
 	}
 
 	fn enforce_limits(&self) -> HashSet<ExtrinsicHash<B>> {
-		let status = self.pool.read().status();
+		let (status, is_quota_exceeded) = {
+			let pool = self.pool.read();
+			(pool.status(), pool.is_quota_exceeded())
+		};
 		let ready_limit = &self.options.ready;
 		let future_limit = &self.options.future;
+		let quotas = &self.options.quotas;
 
 		if ready_limit.is_exceeded(status.ready, status.ready_bytes) ||
-			future_limit.is_exceeded(status.future, status.future_bytes)
+			future_limit.is_exceeded(status.future, status.future_bytes) ||
+			is_quota_exceeded
 		{
 			trace!(
 				target: LOG_TARGET,
@@ -403,17 +409,16 @@ impl<B: ChainApi, L: EventHandler<B>> ValidatedPool<B, L> {
 				ready_kb = ready_limit.total_bytes / 1024,
 				future_count = future_limit.count,
 				future_kb = future_limit.total_bytes / 1024,
+				?quotas,
 				"Enforcing limits"
 			);
 
 			// clean up the pool
 			let removed = {
 				let mut pool = self.pool.write();
-				let removed = pool
-					.enforce_limits(ready_limit, future_limit)
-					.into_iter()
-					.map(|x| x.hash)
-					.collect::<HashSet<_>>();
+				let mut removed = pool.enforce_quotas();
+				removed.append(&mut pool.enforce_limits(ready_limit, future_limit));
+				let removed = removed.into_iter().map(|x| x.hash).collect::<HashSet<_>>();
 				// ban all removed transactions
 				self.rotator.ban(&Instant::now(), removed.iter().copied());
 				removed
@@ -37,8 +37,8 @@ pub use builder::{Builder, TransactionPoolHandle, TransactionPoolOptions, Transa
 pub use common::notification_future;
 pub use fork_aware_txpool::{ForkAwareTxPool, ForkAwareTxPoolTask};
 pub use graph::{
-	base_pool::{Limit as PoolLimit, TimedTransactionSource},
-	ChainApi, Options, Pool, ValidateTransactionPriority,
+	base_pool::{Limit as PoolLimit, TagQuotas, TimedTransactionSource},
+	ChainApi, Options, Pool, ReservedLanes, ValidateTransactionPriority,
 };
 use single_state_txpool::prune_known_txs_for_block;
 pub use single_state_txpool::{BasicPool, RevalidationType};
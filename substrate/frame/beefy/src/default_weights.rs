@@ -19,7 +19,10 @@
 //! This file was not auto-generated.
 
 use frame_support::weights::{
-	constants::{RocksDbWeight as DbWeight, WEIGHT_REF_TIME_PER_MICROS, WEIGHT_REF_TIME_PER_NANOS},
+	constants::{
+		RocksDbWeight as DbWeight, WEIGHT_REF_TIME_PER_MICROS, WEIGHT_REF_TIME_PER_MILLIS,
+		WEIGHT_REF_TIME_PER_NANOS,
+	},
 	Weight,
 };
 
@@ -60,4 +63,12 @@ impl crate::WeightInfo for () {
 	fn set_new_genesis() -> Weight {
 		DbWeight::get().writes(1)
 	}
+
+	fn register_proof_of_possession() -> Weight {
+		// verifying the proof of possession, which takes two pairings
+		Weight::from_parts(25u64 * WEIGHT_REF_TIME_PER_MILLIS, 0)
+			// checking the authority sets
+			.saturating_add(DbWeight::get().reads(2))
+			.saturating_add(DbWeight::get().writes(1))
+	}
 }
@@ -26,7 +26,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, collections::btree_set::BTreeSet, vec::Vec};
 use codec::{Encode, MaxEncodedLen};
 use log;
 
@@ -60,6 +60,12 @@ pub use pallet::*;
 
 const LOG_TARGET: &str = "runtime::beefy";
 
+/// Maximum size of the proof of possession of an authority key.
+pub const MAX_PROOF_OF_POSSESSION_SIZE: u32 = 128;
+
+/// A proof of possession of an authority key.
+pub type ProofOfPossession = BoundedVec<u8, ConstU32<MAX_PROOF_OF_POSSESSION_SIZE>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -158,6 +164,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type GenesisBlock<T: Config> = StorageValue<_, Option<BlockNumberFor<T>>, ValueQuery>;
 
+	/// Proofs of possession of the keys of the current and next authorities.
+	///
+	/// They are needed to verify aggregated finality proofs, which are only produced once the
+	/// proofs of all the authorities of a set are registered.
+	#[pallet::storage]
+	pub type ProofsOfPossession<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::BeefyId, ProofOfPossession>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Initial set of BEEFY authorities.
@@ -205,6 +219,10 @@ pub mod pallet {
 		DuplicateOffenceReport,
 		/// Submitted configuration is invalid.
 		InvalidConfiguration,
+		/// The key is neither a current nor a next authority.
+		NotAuthority,
+		/// The proof of possession of the key is invalid.
+		InvalidProofOfPossession,
 	}
 
 	#[pallet::call]
@@ -411,6 +429,33 @@ pub mod pallet {
 			// Waive the fee since the report is valid and beneficial
 			Ok(Pays::No.into())
 		}
+
+		/// Register the proof of possession of the key of a current or next authority.
+		///
+		/// The proof is removed once the key is not used by any current or next authority
+		/// anymore.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_proof_of_possession())]
+		pub fn register_proof_of_possession(
+			origin: OriginFor<T>,
+			authority: T::BeefyId,
+			proof: ProofOfPossession,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				Authorities::<T>::get().contains(&authority) ||
+					NextAuthorities::<T>::get().contains(&authority),
+				Error::<T>::NotAuthority,
+			);
+			ensure!(
+				<T::BeefyId as BeefyAuthorityId<sp_runtime::traits::Keccak256>>::verify_proof_of_possession(
+					&authority, &proof,
+				),
+				Error::<T>::InvalidProofOfPossession,
+			);
+			ProofsOfPossession::<T>::insert(authority, proof);
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -539,6 +584,15 @@ impl<T: Config> Pallet<T> {
 		ValidatorSet::<T::BeefyId>::new(validators, id)
 	}
 
+	/// Return the registered proofs of possession of the given authority keys, in the same order,
+	/// or `None` if any of them is missing.
+	pub fn proofs_of_possession(authorities: Vec<T::BeefyId>) -> Option<Vec<Vec<u8>>> {
+		authorities
+			.iter()
+			.map(|authority| ProofsOfPossession::<T>::get(authority).map(Into::into))
+			.collect()
+	}
+
 	/// Submits an extrinsic to report a double voting equivocation. This method will create
 	/// an unsigned extrinsic with a call to `report_double_voting_unsigned` and
 	/// will push the transaction to the pool. Only useful in an offchain context.
@@ -592,6 +646,14 @@ impl<T: Config> Pallet<T> {
 		new: BoundedVec<T::BeefyId, T::MaxAuthorities>,
 		queued: BoundedVec<T::BeefyId, T::MaxAuthorities>,
 	) {
+		// drop the proofs of possession of the keys which are not used anymore.
+		let used: BTreeSet<Vec<u8>> = new.iter().chain(queued.iter()).map(Encode::encode).collect();
+		for authority in Authorities::<T>::get().iter().chain(NextAuthorities::<T>::get().iter()) {
+			if !used.contains(&authority.encode()) {
+				ProofsOfPossession::<T>::remove(authority);
+			}
+		}
+
 		Authorities::<T>::put(&new);
 
 		let new_id = ValidatorSetId::<T>::get() + 1u64;
@@ -743,6 +805,8 @@ pub trait WeightInfo {
 	) -> Weight;
 
 	fn set_new_genesis() -> Weight;
+
+	fn register_proof_of_possession() -> Weight;
 }
 
 pub(crate) trait WeightInfoExt: WeightInfo {
@@ -1205,3 +1205,50 @@ fn set_new_genesis_works() {
 		);
 	});
 }
+
+#[test]
+fn proofs_of_possession_are_registered_and_pruned() {
+	ExtBuilder::default()
+		.add_authorities(mock_authorities(vec![1, 2, 3, 4]))
+		.build_and_execute(|| {
+			let proof = beefy::ProofOfPossession::truncate_from(vec![0u8; 112]);
+
+			// only the keys of the current and next authorities can be registered
+			assert_err!(
+				Beefy::register_proof_of_possession(
+					RuntimeOrigin::signed(1),
+					mock_beefy_id(9),
+					proof.clone(),
+				),
+				Error::<Test>::NotAuthority,
+			);
+			// and only with a valid proof, which ECDSA keys have none of
+			assert_err!(
+				Beefy::register_proof_of_possession(
+					RuntimeOrigin::signed(1),
+					mock_beefy_id(1),
+					proof.clone(),
+				),
+				Error::<Test>::InvalidProofOfPossession,
+			);
+
+			for id in 1..=4 {
+				beefy::ProofsOfPossession::<Test>::insert(mock_beefy_id(id), proof.clone());
+			}
+			assert_eq!(
+				Beefy::proofs_of_possession(vec![mock_beefy_id(2), mock_beefy_id(1)]),
+				Some(vec![proof.to_vec(), proof.to_vec()]),
+			);
+			assert_eq!(Beefy::proofs_of_possession(vec![mock_beefy_id(1), mock_beefy_id(9)]), None);
+
+			// authorities 1 and 3 are still current in the first session
+			init_block(1);
+			assert!(beefy::ProofsOfPossession::<Test>::contains_key(mock_beefy_id(1)));
+
+			// and their proofs are dropped once they are neither current nor next
+			init_block(2);
+			assert!(!beefy::ProofsOfPossession::<Test>::contains_key(mock_beefy_id(1)));
+			assert!(!beefy::ProofsOfPossession::<Test>::contains_key(mock_beefy_id(3)));
+			assert!(Beefy::proofs_of_possession(vec![mock_beefy_id(2), mock_beefy_id(4)]).is_some());
+		});
+}
@@ -129,6 +129,58 @@ fn setup_and_start_sale<T: Config>() -> Result<StartedSale<BalanceOf<T>>, Benchm
 	Ok(sale_data)
 }
 
+/// Purchase a Region for `owner` in a newly started sale, returning it and the price paid.
+fn setup_owned_region<T: Config>(
+	owner: &T::AccountId,
+) -> Result<(RegionId, BalanceOf<T>), BenchmarkError> {
+	let sale_data = setup_and_start_sale::<T>()?;
+
+	advance_to::<T>(2);
+
+	T::Currency::set_balance(
+		owner,
+		T::Currency::minimum_balance()
+			.saturating_add(sale_data.start_price)
+			.saturating_add(T::ListingDeposit::get()),
+	);
+	let region = Broker::<T>::do_purchase(owner.clone(), sale_data.start_price)
+		.expect("Offer not high enough for configuration.");
+	let price = sale_data.start_price.max(T::Currency::minimum_balance());
+
+	Ok((region, price))
+}
+
+/// Purchase a Region for `owner` and list it for sale at a fixed price.
+fn setup_listed_region<T: Config>(
+	owner: &T::AccountId,
+) -> Result<(RegionId, BalanceOf<T>), BenchmarkError> {
+	let (region, price) = setup_owned_region::<T>(owner)?;
+	Broker::<T>::do_list_region(region, owner.clone(), price, price, 10u32.into())
+		.map_err(|_| BenchmarkError::Weightless)?;
+
+	Ok((region, price))
+}
+
+/// Place `n` bids of `amount` on a listed Region, from distinct bidders.
+fn place_bids<T: Config>(
+	region: RegionId,
+	amount: BalanceOf<T>,
+	n: u32,
+) -> Result<(), BenchmarkError> {
+	for i in 0..n {
+		let bidder: T::AccountId = account("bidder", i, SEED);
+		T::Currency::set_balance(
+			&bidder,
+			T::Currency::minimum_balance()
+				.saturating_add(amount)
+				.saturating_add(T::BidDeposit::get()),
+		);
+		Broker::<T>::do_place_bid(region, bidder, amount)
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}
+	Ok(())
+}
+
 fn get_start_end_price<T: Config>(initial_price: BalanceOf<T>) -> (BalanceOf<T>, BalanceOf<T>) {
 	let end_price = <T as Config>::PriceAdapter::adapt_price(SalePerformance {
 		sellout_price: None,
@@ -646,21 +698,12 @@ mod benches {
 	}
 
 	#[benchmark]
-	fn drop_region() -> Result<(), BenchmarkError> {
-		let sale_data = setup_and_start_sale::<T>()?;
-		let core = sale_data.first_core;
-		let region_len = Configuration::<T>::get().unwrap().region_length;
-
-		advance_to::<T>(2);
-
+	fn drop_region(b: Linear<0, { T::MaxBids::get() }>) -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
-		T::Currency::set_balance(
-			&caller.clone(),
-			T::Currency::minimum_balance().saturating_add(sale_data.start_price),
-		);
-
-		let region = Broker::<T>::do_purchase(caller.clone(), sale_data.start_price)
-			.expect("Offer not high enough for configuration.");
+		let (region, price) = setup_listed_region::<T>(&caller)?;
+		place_bids::<T>(region, price, b)?;
+		let core = region.core;
+		let region_len = Configuration::<T>::get().unwrap().region_length;
 
 		advance_to::<T>(
 			(T::TimeslicePeriod::get() * (region_len * 4).into()).try_into().ok().unwrap(),
@@ -1321,5 +1364,119 @@ mod benches {
 
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-broker --features runtime-benchmarks`.
+	#[benchmark]
+	fn list_region() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (region, price) = setup_owned_region::<T>(&caller)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, price, price, 10u32.into());
+
+		assert_last_event::<T>(
+			Event::Listed {
+				region_id: region,
+				seller: caller,
+				start_price: price,
+				end_price: price,
+				decay_period: 10u32.into(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlist_region(b: Linear<0, { T::MaxBids::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (region, price) = setup_listed_region::<T>(&caller)?;
+		place_bids::<T>(region, price, b)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region);
+
+		assert_last_event::<T>(Event::Unlisted { region_id: region }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn purchase_listed_region(b: Linear<0, { T::MaxBids::get() }>) -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let (region, price) = setup_listed_region::<T>(&seller)?;
+		place_bids::<T>(region, price, b)?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(&caller, T::Currency::minimum_balance().saturating_add(price));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, price);
+
+		assert_last_event::<T>(
+			Event::ListingSold { region_id: region, seller, buyer: caller, price }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn place_bid() -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let (region, price) = setup_listed_region::<T>(&seller)?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller,
+			T::Currency::minimum_balance()
+				.saturating_add(price)
+				.saturating_add(T::BidDeposit::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, price);
+
+		assert_last_event::<T>(
+			Event::BidPlaced { region_id: region, bidder: caller, amount: price }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_bid() -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let (region, price) = setup_listed_region::<T>(&seller)?;
+
+		let caller: T::AccountId = account("bidder", 0, SEED);
+		place_bids::<T>(region, price, 1)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region);
+
+		assert_last_event::<T>(
+			Event::BidWithdrawn { region_id: region, bidder: caller, amount: price }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_bid(b: Linear<1, { T::MaxBids::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (region, price) = setup_listed_region::<T>(&caller)?;
+
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		place_bids::<T>(region, price, b)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, bidder.clone());
+
+		assert_last_event::<T>(
+			Event::ListingSold { region_id: region, seller: caller, buyer: bidder, price }.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
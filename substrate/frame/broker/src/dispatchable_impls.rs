@@ -20,7 +20,11 @@ use core::cmp;
 use super::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::Preservation::Expendable,
+		DefensiveResult,
+	},
 };
 use sp_arithmetic::traits::{CheckedDiv, Saturating, Zero};
 use sp_runtime::traits::{BlockNumberProvider, Convert};
@@ -234,6 +238,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(check_owner) = maybe_check_owner {
 			ensure!(Some(check_owner) == region.owner, Error::<T>::NotOwner);
 		}
+		Self::ensure_not_listed(&region_id)?;
 
 		let old_owner = region.owner;
 		region.owner = Some(new_owner);
@@ -249,6 +254,191 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_list_region(
+		region_id: RegionId,
+		who: T::AccountId,
+		start_price: BalanceOf<T>,
+		end_price: BalanceOf<T>,
+		decay_period: RelayBlockNumberOf<T>,
+	) -> DispatchResult {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(Some(&who) == region.owner.as_ref(), Error::<T>::NotOwner);
+		ensure!(region.end > status.last_committed_timeslice, Error::<T>::RegionExpired);
+		ensure!(end_price <= start_price, Error::<T>::InvalidListingPrice);
+		Self::ensure_not_listed(&region_id)?;
+		let listing_count = ListingCount::<T>::get();
+		ensure!(listing_count < T::MaxListings::get(), Error::<T>::TooManyListings);
+		let account_listing_count = AccountListingCount::<T>::get(&who);
+		ensure!(
+			account_listing_count < T::MaxListingsPerAccount::get(),
+			Error::<T>::TooManyAccountListings
+		);
+
+		let deposit = T::ListingDeposit::get();
+		T::Currency::transfer(&who, &Self::market_account_id(), deposit, Expendable)?;
+		let since = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
+		let listing = ListingRecord {
+			seller: who.clone(),
+			start_price,
+			end_price,
+			since,
+			decay_period,
+			deposit,
+			bids: 0,
+		};
+		Listings::<T>::insert(&region_id, &listing);
+		ListingCount::<T>::put(listing_count.saturating_add(1));
+		AccountListingCount::<T>::insert(&who, account_listing_count.saturating_add(1));
+		Self::deposit_event(Event::Listed {
+			region_id,
+			seller: who,
+			start_price,
+			end_price,
+			decay_period,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_unlist_region(region_id: RegionId, who: T::AccountId) -> DispatchResult {
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		ensure!(who == listing.seller, Error::<T>::NotOwner);
+
+		Self::remove_listing(region_id, &listing)?;
+		Self::deposit_event(Event::Unlisted { region_id });
+		Ok(())
+	}
+
+	pub(crate) fn do_purchase_listed_region(
+		region_id: RegionId,
+		who: T::AccountId,
+		price_limit: BalanceOf<T>,
+	) -> DispatchResult {
+		let listing = Self::sellable_listing(&region_id)?;
+		let now = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
+		let price = Self::listed_price(&listing, now);
+		ensure!(price_limit >= price, Error::<T>::Overpriced);
+
+		Self::remove_listing(region_id, &listing)?;
+		Self::do_transfer(region_id, Some(listing.seller.clone()), who.clone())?;
+		T::Currency::transfer(&who, &listing.seller, price, Expendable)?;
+		Self::deposit_event(Event::ListingSold {
+			region_id,
+			seller: listing.seller,
+			buyer: who,
+			price,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_place_bid(
+		region_id: RegionId,
+		who: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		ensure!(who != listing.seller, Error::<T>::SellerBid);
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(amount >= T::Currency::minimum_balance(), Error::<T>::BidTooLow);
+
+		let market = Self::market_account_id();
+		let deposit = match Bids::<T>::get(&region_id, &who) {
+			// Only move the difference with the previous bid in or out of escrow.
+			Some(previous) => {
+				if amount > previous.amount {
+					let raise = amount.saturating_sub(previous.amount);
+					T::Currency::transfer(&who, &market, raise, Expendable)?;
+				} else if previous.amount > amount {
+					let cut = previous.amount.saturating_sub(amount);
+					T::Currency::transfer(&market, &who, cut, Expendable)?;
+				}
+				previous.deposit
+			},
+			None => {
+				ensure!(listing.bids < T::MaxBids::get(), Error::<T>::TooManyBids);
+				let deposit = T::BidDeposit::get();
+				T::Currency::transfer(&who, &market, amount.saturating_add(deposit), Expendable)?;
+				listing.bids.saturating_inc();
+				Listings::<T>::insert(&region_id, &listing);
+				deposit
+			},
+		};
+
+		let bid = BidRecord { amount, deposit, region_id, end: region.end };
+		Bids::<T>::insert(&region_id, &who, bid);
+		Self::deposit_event(Event::BidPlaced { region_id, bidder: who, amount });
+		Ok(())
+	}
+
+	pub(crate) fn do_withdraw_bid(region_id: RegionId, who: T::AccountId) -> DispatchResult {
+		let bid = Bids::<T>::take(&region_id, &who).ok_or(Error::<T>::UnknownBid)?;
+		Listings::<T>::mutate_extant(&region_id, |listing| listing.bids.saturating_dec());
+		Self::release_bid(region_id, who, bid)
+	}
+
+	pub(crate) fn do_accept_bid(
+		region_id: RegionId,
+		who: T::AccountId,
+		bidder: T::AccountId,
+	) -> DispatchResult {
+		let listing = Self::sellable_listing(&region_id)?;
+		ensure!(who == listing.seller, Error::<T>::NotOwner);
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		let bid = Bids::<T>::get(&region_id, &bidder).ok_or(Error::<T>::UnknownBid)?;
+		ensure!(bid.region_id == region_id && bid.end == region.end, Error::<T>::StaleBid);
+
+		Bids::<T>::remove(&region_id, &bidder);
+		Self::remove_listing(region_id, &listing)?;
+		Self::do_transfer(region_id, Some(who.clone()), bidder.clone())?;
+		let market = Self::market_account_id();
+		T::Currency::transfer(&market, &who, bid.amount, Expendable)?;
+		T::Currency::transfer(&market, &bidder, bid.deposit, Expendable)?;
+		Self::deposit_event(Event::ListingSold {
+			region_id,
+			seller: who,
+			buyer: bidder,
+			price: bid.amount,
+		});
+		Ok(())
+	}
+
+	/// Get the listing of a Region which can still be sold by its seller.
+	fn sellable_listing(region_id: &RegionId) -> Result<ListingRecordOf<T>, DispatchError> {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let listing = Listings::<T>::get(region_id).ok_or(Error::<T>::NotListed)?;
+		let region = Regions::<T>::get(region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(region.owner.as_ref() == Some(&listing.seller), Error::<T>::NotOwner);
+		ensure!(region.end > status.last_committed_timeslice, Error::<T>::RegionExpired);
+		Ok(listing)
+	}
+
+	/// Remove the listing of a Region, releasing its deposit and all the bids placed on it.
+	fn remove_listing(region_id: RegionId, listing: &ListingRecordOf<T>) -> DispatchResult {
+		Listings::<T>::remove(&region_id);
+		ListingCount::<T>::mutate(|count| count.saturating_dec());
+		AccountListingCount::<T>::mutate_exists(&listing.seller, |count| {
+			*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+		});
+		let market = Self::market_account_id();
+		T::Currency::transfer(&market, &listing.seller, listing.deposit, Expendable)?;
+		for (bidder, bid) in Bids::<T>::drain_prefix(&region_id) {
+			Self::release_bid(region_id, bidder, bid)?;
+		}
+		Ok(())
+	}
+
+	/// Release the amount and the deposit of a bid from escrow.
+	fn release_bid(
+		region_id: RegionId,
+		bidder: T::AccountId,
+		bid: BidRecordOf<T>,
+	) -> DispatchResult {
+		let released = bid.amount.saturating_add(bid.deposit);
+		T::Currency::transfer(&Self::market_account_id(), &bidder, released, Expendable)?;
+		Self::deposit_event(Event::BidWithdrawn { region_id, bidder, amount: bid.amount });
+		Ok(())
+	}
+
 	pub(crate) fn do_partition(
 		region_id: RegionId,
 		maybe_check_owner: Option<T::AccountId>,
@@ -260,6 +450,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(check_owner) = maybe_check_owner {
 			ensure!(Some(check_owner) == region.owner, Error::<T>::NotOwner);
 		}
+		Self::ensure_not_listed(&region_id)?;
 		let pivot = region_id.begin.saturating_add(pivot_offset);
 		ensure!(pivot < region.end, Error::<T>::PivotTooLate);
 		ensure!(pivot > region_id.begin, Error::<T>::PivotTooEarly);
@@ -292,6 +483,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(check_owner) = maybe_check_owner {
 			ensure!(Some(check_owner) == region.owner, Error::<T>::NotOwner);
 		}
+		Self::ensure_not_listed(&region_id)?;
 
 		ensure!((pivot & !region_id.mask).is_void(), Error::<T>::ExteriorPivot);
 		ensure!(!pivot.is_void(), Error::<T>::VoidPivot);
@@ -483,6 +675,9 @@ impl<T: Config> Pallet<T> {
 		ensure!(status.last_committed_timeslice >= region.end, Error::<T>::StillValid);
 
 		Regions::<T>::remove(&region_id);
+		if let Some(listing) = Listings::<T>::get(&region_id) {
+			Self::remove_listing(region_id, &listing)?;
+		}
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::RegionDropped { region_id, duration });
 		Ok(())
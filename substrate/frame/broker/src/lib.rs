@@ -65,7 +65,7 @@ pub mod pallet {
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{Convert, ConvertBack, MaybeConvert};
+	use sp_runtime::traits::{BlockNumberProvider, Convert, ConvertBack, MaybeConvert};
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
@@ -131,6 +131,27 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.
 		#[pallet::constant]
 		type MinimumCreditPurchase: Get<BalanceOf<Self>>;
+
+		/// The amount held from the seller of a Region for as long as it is listed for sale.
+		#[pallet::constant]
+		type ListingDeposit: Get<BalanceOf<Self>>;
+
+		/// The amount held from a bidder, in addition to the amount bid, for as long as the bid
+		/// stands.
+		#[pallet::constant]
+		type BidDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of Regions listed for sale at once.
+		#[pallet::constant]
+		type MaxListings: Get<u32>;
+
+		/// Maximum number of Regions listed for sale at once by a single account.
+		#[pallet::constant]
+		type MaxListingsPerAccount: Get<u32>;
+
+		/// Maximum number of bids on a single listed Region.
+		#[pallet::constant]
+		type MaxBids: Get<u32>;
 	}
 
 	/// The current configuration of this pallet.
@@ -202,6 +223,32 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RevenueInbox<T> = StorageValue<_, OnDemandRevenueRecordOf<T>, OptionQuery>;
 
+	/// The Regions offered for sale on the secondary market.
+	#[pallet::storage]
+	pub type Listings<T> =
+		StorageMap<_, Blake2_128Concat, RegionId, ListingRecordOf<T>, OptionQuery>;
+
+	/// The number of Regions listed for sale.
+	#[pallet::storage]
+	pub type ListingCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of Regions listed for sale by each seller.
+	#[pallet::storage]
+	pub type AccountListingCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The bids placed on listed Regions, with their amount held in escrow.
+	#[pallet::storage]
+	pub type Bids<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RegionId,
+		Blake2_128Concat,
+		T::AccountId,
+		BidRecordOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -494,6 +541,53 @@ pub mod pallet {
 		/// This should never happen, given that enable_auto_renew checks for this before enabling
 		/// auto-renewal.
 		AutoRenewalLimitReached,
+		/// A Region has been offered for sale on the secondary market.
+		Listed {
+			/// The Region which has been listed.
+			region_id: RegionId,
+			/// The owner of the Region.
+			seller: T::AccountId,
+			/// The price asked for the Region at the time of listing.
+			start_price: BalanceOf<T>,
+			/// The price asked for the Region once it has fully decayed.
+			end_price: BalanceOf<T>,
+			/// The number of relay blocks over which the price decays.
+			decay_period: RelayBlockNumberOf<T>,
+		},
+		/// A Region is no longer offered for sale on the secondary market.
+		Unlisted {
+			/// The Region which has been unlisted.
+			region_id: RegionId,
+		},
+		/// A listed Region has been sold, either at its asking price or to a bidder.
+		ListingSold {
+			/// The Region which has been sold.
+			region_id: RegionId,
+			/// The previous owner of the Region.
+			seller: T::AccountId,
+			/// The new owner of the Region.
+			buyer: T::AccountId,
+			/// The price paid for the Region.
+			price: BalanceOf<T>,
+		},
+		/// A bid has been placed on a listed Region.
+		BidPlaced {
+			/// The Region which is bid on.
+			region_id: RegionId,
+			/// The account which placed the bid.
+			bidder: T::AccountId,
+			/// The amount offered, held in escrow.
+			amount: BalanceOf<T>,
+		},
+		/// A bid has been withdrawn and its amount released from escrow.
+		BidWithdrawn {
+			/// The Region which was bid on.
+			region_id: RegionId,
+			/// The account which placed the bid.
+			bidder: T::AccountId,
+			/// The amount released.
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -577,6 +671,28 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.The amount of credits the user attempted to purchase is
 		/// below `T::MinimumCreditPurchase`.
 		CreditPurchaseTooSmall,
+		/// The Region is listed for sale and must be unlisted before it can be altered.
+		RegionListed,
+		/// The Region is not listed for sale.
+		NotListed,
+		/// The end price of a listing is higher than its start price.
+		InvalidListingPrice,
+		/// The Region has already expired.
+		RegionExpired,
+		/// The bid does not exist.
+		UnknownBid,
+		/// The bid was placed before the Region was partitioned and no longer applies to it.
+		StaleBid,
+		/// The bid is below the minimum balance.
+		BidTooLow,
+		/// The maximum number of Regions are already listed for sale.
+		TooManyListings,
+		/// The account already has the maximum number of Regions listed for sale.
+		TooManyAccountListings,
+		/// The maximum number of bids are already placed on the Region.
+		TooManyBids,
+		/// The seller of a Region cannot bid on it.
+		SellerBid,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -843,9 +959,13 @@ pub mod pallet {
 
 		/// Drop an expired Region from the chain.
 		///
+		/// If the Region is listed, the deposit of the listing and the bids placed on the Region
+		/// are released.
+		///
 		/// - `origin`: Can be any kind of origin.
 		/// - `region_id`: The Region which has expired.
 		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::drop_region(T::MaxBids::get()))]
 		pub fn drop_region(
 			_origin: OriginFor<T>,
 			region_id: RegionId,
@@ -1022,6 +1142,114 @@ pub mod pallet {
 			Self::do_remove_assignment(region_id)
 		}
 
+		/// Offer a Bulk Coretime Region for sale on the secondary market.
+		///
+		/// The asking price decays linearly from `start_price` to `end_price` over
+		/// `decay_period` relay blocks. A listed Region cannot be transferred, partitioned,
+		/// interlaced, assigned or pooled until it is unlisted. `ListingDeposit` is held from the
+		/// seller until then. An account may have at most `MaxListingsPerAccount` Regions listed.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which should be listed.
+		/// - `start_price`: The price asked for the Region now.
+		/// - `end_price`: The price asked for the Region once the price has fully decayed. Must not
+		///   be higher than `start_price`.
+		/// - `decay_period`: The number of relay blocks over which the price decays.
+		#[pallet::call_index(27)]
+		pub fn list_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			start_price: BalanceOf<T>,
+			end_price: BalanceOf<T>,
+			decay_period: RelayBlockNumberOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_list_region(region_id, who, start_price, end_price, decay_period)?;
+			Ok(())
+		}
+
+		/// Withdraw a Region from sale on the secondary market.
+		///
+		/// The deposit of the listing and the bids placed on the Region are released.
+		///
+		/// - `origin`: Must be a Signed origin of the account which listed the Region.
+		/// - `region_id`: The Region which should be unlisted.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::unlist_region(T::MaxBids::get()))]
+		pub fn unlist_region(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_unlist_region(region_id, who)?;
+			Ok(())
+		}
+
+		/// Buy a listed Region at its current asking price.
+		///
+		/// The deposit of the listing and the bids placed on the Region are released.
+		///
+		/// - `origin`: Must be a Signed origin with at least enough funds to pay the asking price.
+		/// - `region_id`: The Region to buy.
+		/// - `price_limit`: An amount no more than which should be paid.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::purchase_listed_region(T::MaxBids::get()))]
+		pub fn purchase_listed_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			price_limit: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_purchase_listed_region(region_id, who, price_limit)?;
+			Ok(())
+		}
+
+		/// Bid on a listed Region. The amount and `BidDeposit` are held in escrow until the bid is
+		/// accepted or withdrawn, or the Region is no longer listed. Any previous bid of the origin
+		/// on the same Region is replaced.
+		///
+		/// - `origin`: Must be a Signed origin with at least enough funds to pay `amount` and the
+		///   deposit. Must not be the seller of the Region.
+		/// - `region_id`: The Region to bid on.
+		/// - `amount`: The amount offered for the Region.
+		#[pallet::call_index(30)]
+		pub fn place_bid(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_place_bid(region_id, who, amount)?;
+			Ok(())
+		}
+
+		/// Withdraw a bid and release its amount and deposit from escrow.
+		///
+		/// - `origin`: Must be a Signed origin of the account which placed the bid.
+		/// - `region_id`: The Region which was bid on.
+		#[pallet::call_index(31)]
+		pub fn withdraw_bid(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw_bid(region_id, who)?;
+			Ok(())
+		}
+
+		/// Sell a listed Region to a bidder for the amount of their bid.
+		///
+		/// The deposit of the listing and the other bids placed on the Region are released.
+		///
+		/// - `origin`: Must be a Signed origin of the account which listed the Region.
+		/// - `region_id`: The Region to sell.
+		/// - `bidder`: The account whose bid is accepted.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::accept_bid(T::MaxBids::get()))]
+		pub fn accept_bid(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			bidder: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_accept_bid(region_id, who, bidder)?;
+			Ok(())
+		}
+
 		#[pallet::call_index(99)]
 		#[pallet::weight(T::WeightInfo::swap_leases())]
 		pub fn swap_leases(origin: OriginFor<T>, id: TaskId, other: TaskId) -> DispatchResult {
@@ -1030,4 +1258,33 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The current asking price of a listed Region, if it is listed.
+		pub fn listing_price(region_id: RegionId) -> Option<BalanceOf<T>> {
+			let listing = Listings::<T>::get(region_id)?;
+			let now = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
+			Some(Self::listed_price(&listing, now))
+		}
+
+		/// All Regions listed for sale, along with their listing and current asking price.
+		///
+		/// There are at most `MaxListings` of them.
+		pub fn listings() -> Vec<(RegionId, ListingRecordOf<T>, BalanceOf<T>)> {
+			let now = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
+			Listings::<T>::iter()
+				.take(T::MaxListings::get() as usize)
+				.map(|(region_id, listing)| {
+					let price = Self::listed_price(&listing, now);
+					(region_id, listing, price)
+				})
+				.collect()
+		}
+
+		/// All bids placed on a Region. There are at most `MaxBids` of them.
+		pub fn bids(region_id: RegionId) -> Vec<(T::AccountId, BidRecordOf<T>)> {
+			Bids::<T>::iter_prefix(region_id).collect()
+		}
+	}
 }
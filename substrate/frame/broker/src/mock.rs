@@ -217,6 +217,11 @@ impl crate::Config for Test {
 	type MaxAutoRenewals = ConstU32<3>;
 	type PriceAdapter = CenterTargetPrice<BalanceOf<Self>>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
+	type ListingDeposit = ConstU64<10>;
+	type BidDeposit = ConstU64<5>;
+	type MaxListings = ConstU32<3>;
+	type MaxListingsPerAccount = ConstU32<2>;
+	type MaxBids = ConstU32<2>;
 }
 
 pub fn advance_to(b: u64) {
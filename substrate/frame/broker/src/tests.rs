@@ -2636,3 +2636,296 @@ fn force_reserve_works() {
 		assert_eq!(Workplan::<Test>::get((10, 0)), Some(system_workload.clone()));
 	});
 }
+
+#[test]
+fn listed_region_can_be_purchased_at_decaying_price() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let balance_1 = balance(1);
+
+		assert_noop!(Broker::do_list_region(region, 2, 100, 50, 10), Error::<Test>::NotOwner);
+		assert_noop!(
+			Broker::do_list_region(region, 1, 50, 100, 10),
+			Error::<Test>::InvalidListingPrice
+		);
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, 100, 50, 10));
+		System::assert_last_event(
+			Event::<Test>::Listed {
+				region_id: region,
+				seller: 1,
+				start_price: 100,
+				end_price: 50,
+				decay_period: 10,
+			}
+			.into(),
+		);
+		assert_eq!(Broker::listing_price(region), Some(100));
+		// The listing deposit is held until the region is sold.
+		assert_eq!(balance(1), balance_1 - 10);
+		assert_eq!(ListingCount::<Test>::get(), 1);
+		assert_noop!(Broker::do_list_region(region, 1, 100, 50, 10), Error::<Test>::RegionListed);
+
+		// The price decays linearly over the decay period.
+		advance_to(4);
+		assert_eq!(Broker::listing_price(region), Some(90));
+		assert_eq!(Broker::listings(), vec![(region, Listings::<Test>::get(region).unwrap(), 90)]);
+
+		// A listed region cannot be altered.
+		assert_noop!(Broker::do_transfer(region, Some(1), 2), Error::<Test>::RegionListed);
+		assert_noop!(Broker::do_partition(region, Some(1), 1), Error::<Test>::RegionListed);
+		assert_noop!(
+			Broker::do_interlace(region, Some(1), CoreMask::from_chunk(0, 30)),
+			Error::<Test>::RegionListed
+		);
+		assert_noop!(Broker::do_assign(region, Some(1), 1001, Final), Error::<Test>::RegionListed);
+		assert_noop!(Broker::do_pool(region, Some(1), 1, Provisional), Error::<Test>::RegionListed);
+
+		assert_noop!(Broker::do_purchase_listed_region(region, 2, 89), Error::<Test>::Overpriced);
+		assert_ok!(Broker::purchase_listed_region(RuntimeOrigin::signed(2), region, 100));
+		System::assert_last_event(
+			Event::<Test>::ListingSold { region_id: region, seller: 1, buyer: 2, price: 90 }.into(),
+		);
+		assert_eq!(balance(1), balance_1 + 90);
+		assert_eq!(balance(2), 910);
+		assert_eq!(balance(Broker::market_account_id()), 0);
+		assert_eq!(Regions::<Test>::get(region).unwrap().owner, Some(2));
+		assert_eq!(Broker::listing_price(region), None);
+		assert_eq!(ListingCount::<Test>::get(), 0);
+
+		// The buyer is free to use the region.
+		assert_noop!(Broker::do_purchase_listed_region(region, 1, 100), Error::<Test>::NotListed);
+		assert_ok!(Broker::do_assign(region, Some(2), 1001, Final));
+	});
+}
+
+#[test]
+fn listed_region_is_only_sold_by_its_owner() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+		assert_ok!(Broker::do_place_bid(region, 2, 30));
+
+		// The region changed hands behind the back of the market.
+		Regions::<Test>::mutate(region, |region| region.as_mut().unwrap().owner = Some(3));
+		assert_noop!(Broker::do_purchase_listed_region(region, 2, 100), Error::<Test>::NotOwner);
+		assert_noop!(Broker::do_accept_bid(region, 1, 2), Error::<Test>::NotOwner);
+	});
+}
+
+#[test]
+fn unlisting_region_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let balance_1 = balance(1);
+		assert_noop!(Broker::do_unlist_region(region, 1), Error::<Test>::NotListed);
+		assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+		assert_ok!(Broker::do_place_bid(region, 2, 30));
+		assert_eq!(balance(2), 965);
+		assert_noop!(Broker::do_unlist_region(region, 2), Error::<Test>::NotOwner);
+
+		// Unlisting releases the deposit and the bids.
+		assert_ok!(Broker::unlist_region(RuntimeOrigin::signed(1), region));
+		System::assert_has_event(
+			Event::<Test>::BidWithdrawn { region_id: region, bidder: 2, amount: 30 }.into(),
+		);
+		System::assert_last_event(Event::<Test>::Unlisted { region_id: region }.into());
+		assert_eq!(balance(1), balance_1);
+		assert_eq!(balance(2), 1000);
+		assert_eq!(balance(Broker::market_account_id()), 0);
+		assert_eq!(Broker::bids(region), vec![]);
+		assert_eq!(ListingCount::<Test>::get(), 0);
+		assert_ok!(Broker::do_transfer(region, Some(1), 2));
+	});
+}
+
+#[test]
+fn listings_are_bounded() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 5));
+		advance_to(2);
+		let regions: Vec<_> =
+			(0..3).map(|_| Broker::do_purchase(1, u64::max_value()).unwrap()).collect();
+		let others: Vec<_> =
+			(0..2).map(|_| Broker::do_purchase(2, u64::max_value()).unwrap()).collect();
+		assert_ok!(Broker::do_list_region(regions[0], 1, 100, 100, 0));
+		assert_ok!(Broker::do_list_region(regions[1], 1, 100, 100, 0));
+		assert_noop!(
+			Broker::do_list_region(regions[2], 1, 100, 100, 0),
+			Error::<Test>::TooManyAccountListings
+		);
+		assert_eq!(AccountListingCount::<Test>::get(1), 2);
+
+		assert_ok!(Broker::do_list_region(others[0], 2, 100, 100, 0));
+		assert_noop!(
+			Broker::do_list_region(others[1], 2, 100, 100, 0),
+			Error::<Test>::TooManyListings
+		);
+		assert_eq!(Broker::listings().len(), 3);
+
+		assert_ok!(Broker::do_unlist_region(regions[0], 1));
+		assert_eq!(AccountListingCount::<Test>::get(1), 1);
+		assert_ok!(Broker::do_list_region(regions[2], 1, 100, 100, 0));
+		assert_ok!(Broker::do_unlist_region(others[0], 2));
+		assert!(!AccountListingCount::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn bids_on_listed_region_work() {
+	TestExt::new()
+		.endow(1, 1000)
+		.endow(2, 1000)
+		.endow(3, 1000)
+		.endow(4, 1000)
+		.execute_with(|| {
+			assert_ok!(Broker::do_start_sales(100, 1));
+			advance_to(2);
+			let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+			let balance_1 = balance(1);
+			assert_noop!(Broker::do_place_bid(region, 2, 30), Error::<Test>::NotListed);
+			assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+			assert_noop!(Broker::do_place_bid(region, 1, 30), Error::<Test>::SellerBid);
+
+			assert_ok!(Broker::place_bid(RuntimeOrigin::signed(2), region, 30));
+			System::assert_last_event(
+				Event::<Test>::BidPlaced { region_id: region, bidder: 2, amount: 30 }.into(),
+			);
+			// Bids can be raised or lowered, only the difference moves in or out of escrow.
+			assert_ok!(Broker::do_place_bid(region, 2, 60));
+			assert_ok!(Broker::do_place_bid(region, 2, 40));
+			assert_eq!(balance(2), 955);
+			assert_ok!(Broker::do_place_bid(region, 3, 50));
+			assert_eq!(balance(Broker::market_account_id()), 110);
+			let mut bids = Broker::bids(region);
+			bids.sort_by_key(|(bidder, _)| *bidder);
+			assert_eq!(
+				bids,
+				vec![
+					(2, BidRecord { amount: 40, deposit: 5, region_id: region, end: 7 }),
+					(3, BidRecord { amount: 50, deposit: 5, region_id: region, end: 7 }),
+				]
+			);
+			assert_noop!(Broker::do_place_bid(region, 4, 70), Error::<Test>::TooManyBids);
+
+			assert_noop!(Broker::do_accept_bid(region, 2, 3), Error::<Test>::NotOwner);
+			assert_noop!(Broker::do_accept_bid(region, 1, 4), Error::<Test>::UnknownBid);
+			assert_ok!(Broker::accept_bid(RuntimeOrigin::signed(1), region, 3));
+			System::assert_last_event(
+				Event::<Test>::ListingSold { region_id: region, seller: 1, buyer: 3, price: 50 }
+					.into(),
+			);
+			assert_eq!(balance(1), balance_1 + 50);
+			assert_eq!(balance(3), 950);
+			assert_eq!(Regions::<Test>::get(region).unwrap().owner, Some(3));
+			assert_eq!(Listings::<Test>::get(region), None);
+
+			// The remaining bids are released once the region is sold.
+			assert_eq!(balance(2), 1000);
+			assert_eq!(balance(Broker::market_account_id()), 0);
+			assert_noop!(Broker::do_accept_bid(region, 3, 2), Error::<Test>::NotListed);
+			assert_noop!(Broker::do_withdraw_bid(region, 2), Error::<Test>::UnknownBid);
+		});
+}
+
+#[test]
+fn withdrawing_bid_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).endow(3, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+		assert_ok!(Broker::do_place_bid(region, 2, 40));
+		assert_ok!(Broker::do_place_bid(region, 3, 50));
+
+		assert_ok!(Broker::withdraw_bid(RuntimeOrigin::signed(2), region));
+		System::assert_last_event(
+			Event::<Test>::BidWithdrawn { region_id: region, bidder: 2, amount: 40 }.into(),
+		);
+		assert_eq!(balance(2), 1000);
+		assert_eq!(Listings::<Test>::get(region).unwrap().bids, 1);
+		assert_noop!(Broker::do_withdraw_bid(region, 2), Error::<Test>::UnknownBid);
+
+		// The withdrawn bid makes room for another one.
+		assert_ok!(Broker::do_place_bid(region, 2, 30));
+		assert_eq!(Listings::<Test>::get(region).unwrap().bids, 2);
+	});
+}
+
+#[test]
+fn bids_do_not_apply_to_partitioned_or_interlaced_regions() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+		assert_ok!(Broker::do_place_bid(region, 2, 30));
+
+		// The partitioned region keeps its identity, but not its end. The bid was released when
+		// the region was unlisted to partition it.
+		assert_ok!(Broker::do_unlist_region(region, 1));
+		let (region, other) = Broker::do_partition(region, Some(1), 1).unwrap();
+		assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+		assert_noop!(Broker::do_accept_bid(region, 1, 2), Error::<Test>::UnknownBid);
+
+		// A bid recorded for another region or end does not apply.
+		assert_ok!(Broker::do_place_bid(region, 2, 20));
+		Bids::<Test>::mutate(region, 2, |bid| bid.as_mut().unwrap().region_id = other);
+		assert_noop!(Broker::do_accept_bid(region, 1, 2), Error::<Test>::StaleBid);
+		Bids::<Test>::mutate(region, 2, |bid| {
+			let bid = bid.as_mut().unwrap();
+			bid.region_id = region;
+			bid.end = 7;
+		});
+		assert_noop!(Broker::do_accept_bid(region, 1, 2), Error::<Test>::StaleBid);
+
+		// Bidding again updates the bid to the new end of the region.
+		assert_ok!(Broker::do_place_bid(region, 2, 20));
+		assert_eq!(balance(2), 975);
+		assert_ok!(Broker::do_accept_bid(region, 1, 2));
+		assert_eq!(balance(2), 980);
+		assert_eq!(Regions::<Test>::get(region).unwrap().owner, Some(2));
+
+		// Interlaced regions are listed and sold on their own.
+		let (one, two) = Broker::do_interlace(other, Some(1), CoreMask::from_chunk(0, 30)).unwrap();
+		assert_ok!(Broker::do_list_region(one, 1, 100, 100, 0));
+		assert_noop!(Broker::do_place_bid(other, 2, 30), Error::<Test>::NotListed);
+		assert_noop!(Broker::do_place_bid(two, 2, 30), Error::<Test>::NotListed);
+		assert_ok!(Broker::do_purchase_listed_region(one, 2, 100));
+		assert_eq!(Regions::<Test>::get(one).unwrap().owner, Some(2));
+		assert_eq!(Regions::<Test>::get(two).unwrap().owner, Some(1));
+	});
+}
+
+#[test]
+fn expired_listed_region_cannot_be_sold() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let balance_1 = balance(1);
+		assert_ok!(Broker::do_list_region(region, 1, 100, 100, 0));
+		assert_ok!(Broker::do_place_bid(region, 2, 30));
+
+		advance_to(12);
+		assert_noop!(
+			Broker::do_purchase_listed_region(region, 2, 100),
+			Error::<Test>::RegionExpired
+		);
+		assert_noop!(Broker::do_accept_bid(region, 1, 2), Error::<Test>::RegionExpired);
+
+		// Dropping the region drops its listing, releasing the deposit and the bid.
+		assert_ok!(Broker::do_drop_region(region));
+		assert_eq!(Listings::<Test>::get(region), None);
+		assert_eq!(ListingCount::<Test>::get(), 0);
+		assert_eq!(balance(1), balance_1);
+		assert_eq!(balance(2), 1000);
+		assert_eq!(balance(Broker::market_account_id()), 0);
+		assert_noop!(Broker::do_withdraw_bid(region, 2), Error::<Test>::UnknownBid);
+	});
+}
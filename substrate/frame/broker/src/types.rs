@@ -112,6 +112,43 @@ pub struct RegionRecord<AccountId, Balance> {
 }
 pub type RegionRecordOf<T> = RegionRecord<<T as SConfig>::AccountId, BalanceOf<T>>;
 
+/// A Region offered for sale on the secondary market.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ListingRecord<AccountId, Balance, RelayBlockNumber> {
+	/// The owner of the Region, who receives the proceeds of the sale.
+	pub seller: AccountId,
+	/// The price asked for the Region at the time it was listed.
+	pub start_price: Balance,
+	/// The price asked for the Region once it has fully decayed.
+	pub end_price: Balance,
+	/// The relay block number at which the Region was listed.
+	pub since: RelayBlockNumber,
+	/// The number of relay blocks over which the price decays from `start_price` to `end_price`.
+	pub decay_period: RelayBlockNumber,
+	/// The amount held from the seller for as long as the Region is listed.
+	pub deposit: Balance,
+	/// The number of bids placed on the Region.
+	pub bids: u32,
+}
+pub type ListingRecordOf<T> =
+	ListingRecord<<T as SConfig>::AccountId, BalanceOf<T>, RelayBlockNumberOf<T>>;
+
+/// A bid on a listed Region. The amount bid and the deposit are held in escrow until the bid is
+/// accepted, withdrawn or the Region stops being listed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BidRecord<Balance> {
+	/// The amount offered for the Region.
+	pub amount: Balance,
+	/// The amount held from the bidder in addition to `amount` for as long as the bid stands.
+	pub deposit: Balance,
+	/// The Region the bid was placed on.
+	pub region_id: RegionId,
+	/// The end of the Region when the bid was placed. A Region which has since been partitioned
+	/// keeps its identity but not its end, so the bid no longer applies to it.
+	pub end: Timeslice,
+}
+pub type BidRecordOf<T> = BidRecord<BalanceOf<T>>;
+
 /// An distinct item which can be scheduled on a Polkadot Core.
 #[derive(
 	Encode,
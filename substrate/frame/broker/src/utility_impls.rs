@@ -26,7 +26,7 @@ use frame_support::{
 };
 use sp_arithmetic::{
 	traits::{SaturatedConversion, Saturating},
-	FixedPointNumber, FixedU64,
+	FixedPointNumber, FixedU64, Perbill,
 };
 use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider};
 
@@ -59,6 +59,25 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The account holding the bids placed on listed Regions in escrow.
+	pub fn market_account_id() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"market")
+	}
+
+	/// The asking price of a listing at relay block `now`.
+	pub fn listed_price(listing: &ListingRecordOf<T>, now: RelayBlockNumberOf<T>) -> BalanceOf<T> {
+		let elapsed: u32 = now.saturating_sub(listing.since).saturated_into();
+		let through = Perbill::from_rational(elapsed, listing.decay_period.saturated_into());
+		let decay = through.mul_floor(listing.start_price.saturating_sub(listing.end_price));
+		listing.start_price.saturating_sub(decay)
+	}
+
+	/// Ensure a Region is not listed for sale, and thus may be altered.
+	pub(crate) fn ensure_not_listed(region_id: &RegionId) -> Result<(), Error<T>> {
+		ensure!(!Listings::<T>::contains_key(region_id), Error::<T>::RegionListed);
+		Ok(())
+	}
+
 	pub fn sale_price(sale: &SaleInfoRecordOf<T>, now: RelayBlockNumberOf<T>) -> BalanceOf<T> {
 		let num = now.saturating_sub(sale.sale_start).min(sale.leadin_length).saturated_into();
 		let through = FixedU64::from_rational(num, sale.leadin_length.saturated_into());
@@ -115,6 +134,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(check_owner) = maybe_check_owner {
 			ensure!(Some(check_owner) == region.owner, Error::<T>::NotOwner);
 		}
+		Self::ensure_not_listed(&region_id)?;
 
 		Regions::<T>::remove(&region_id);
 
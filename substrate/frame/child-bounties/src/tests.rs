@@ -113,6 +113,9 @@ impl pallet_treasury::Config for Test {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type BudgetHolder = ();
+	type BudgetOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ManageBudgetOrigin = frame_system::EnsureRoot<AccountId>;
 }
 parameter_types! {
 	// This will be 50% of the bounty fee.
@@ -252,6 +252,98 @@ benchmarks_instance_pallet! {
 		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
 	}
 
+	delegate_part {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// Fill up all the other parts the caller may delegate.
+		for i in 1 .. T::MaxDelegates::get() {
+			let target = T::Lookup::unlookup(funded_account::<T, I>("target", i));
+			ConvictionVoting::<T, I>::delegate_part(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				target,
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+		}
+
+		// We need to create existing delegations
+		for i in polls.iter().take(r as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&voter, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+		);
+
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup, Conviction::Locked1x, delegated_balance)
+	verify {
+		assert_eq!(
+			PartialDelegationsFor::<T, I>::get(&caller, &class).len() as u32,
+			T::MaxDelegates::get(),
+		);
+	}
+
+	undelegate_part {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		ConvictionVoting::<T, I>::delegate_part(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			voter_lookup.clone(),
+			Conviction::Locked1x,
+			delegated_balance,
+		)?;
+		// Fill up all the other parts the caller may delegate.
+		for i in 1 .. T::MaxDelegates::get() {
+			let target = T::Lookup::unlookup(funded_account::<T, I>("target", i));
+			ConvictionVoting::<T, I>::delegate_part(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				target,
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+		}
+
+		// We need to create delegations
+		for i in polls.iter().take(r as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&voter, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+		);
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup)
+	verify {
+		assert_eq!(
+			PartialDelegationsFor::<T, I>::get(&caller, &class).len() as u32,
+			T::MaxDelegates::get() - 1,
+		);
+	}
+
 	unlock {
 		let caller = funded_account::<T, I>("caller", 0);
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
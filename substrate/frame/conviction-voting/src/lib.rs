@@ -53,7 +53,7 @@ pub use self::{
 	pallet::*,
 	traits::{Status, VotingHooks},
 	types::{Delegations, Tally, UnvoteScope},
-	vote::{AccountVote, Casting, Delegating, Vote, Voting},
+	vote::{AccountVote, Casting, Delegating, PartialDelegation, Vote, Voting},
 	weights::WeightInfo,
 };
 use sp_runtime::traits::BlockNumberProvider;
@@ -82,6 +82,8 @@ pub type VotingOf<T, I = ()> = Voting<
 #[allow(dead_code)]
 type DelegatingOf<T, I = ()> =
 	Delegating<BalanceOf<T, I>, <T as frame_system::Config>::AccountId, BlockNumberFor<T, I>>;
+pub type PartialDelegationOf<T, I = ()> =
+	PartialDelegation<BalanceOf<T, I>, <T as frame_system::Config>::AccountId>;
 pub type TallyOf<T, I = ()> = Tally<BalanceOf<T, I>, <T as Config<I>>::MaxTurnout>;
 pub type VotesOf<T, I = ()> = BalanceOf<T, I>;
 pub type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
@@ -137,6 +139,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVotes: Get<u32>;
 
+		/// The maximum number of targets the voting power of an account in a class may be split
+		/// across with `delegate_part`.
+		#[pallet::constant]
+		type MaxDelegates: Get<u32>;
+
 		/// The minimum period of vote locking.
 		///
 		/// It should be no shorter than enactment period to ensure that in the case of an approval,
@@ -184,6 +191,20 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The parts of the voting power of a voter in a voting class which are delegated, each to a
+	/// different target. An account with partial delegations in a class can neither vote nor
+	/// delegate its whole voting power in that class.
+	#[pallet::storage]
+	pub type PartialDelegationsFor<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		BoundedVec<PartialDelegationOf<T, I>, T::MaxDelegates>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -205,6 +226,16 @@ pub mod pallet {
 		},
 		/// The lockup period of a conviction vote expired, and the funds have been unlocked.
 		VoteUnlocked { who: T::AccountId, class: ClassOf<T, I> },
+		/// An account has delegated a part of their voting power to another account.
+		PartDelegated {
+			who: T::AccountId,
+			target: T::AccountId,
+			class: ClassOf<T, I>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+		},
+		/// An account has cancelled the delegation of a part of their voting power.
+		PartUndelegated { who: T::AccountId, target: T::AccountId, class: ClassOf<T, I> },
 	}
 
 	#[pallet::error]
@@ -234,6 +265,10 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// The account already delegates a part of its voting power to the target.
+		AlreadyDelegatingTo,
+		/// Maximum number of partial delegations reached.
+		MaxDelegatesReached,
 	}
 
 	#[pallet::call]
@@ -269,6 +304,8 @@ pub mod pallet {
 		///   - have no voting activity (if there is, then it will need to be removed through
 		///     `remove_vote`).
 		///
+		/// Parts of the voting power delegated through `delegate_part` must be undelegated first.
+		///
 		/// - `to`: The account whose voting the `target` account's voting power will follow.
 		/// - `class`: The class of polls to delegate. To delegate multiple classes, multiple calls
 		///   to this function are required.
@@ -419,6 +456,75 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Delegate a part of the voting power (with some given conviction) of the sending account
+		/// for a particular class of polls.
+		///
+		/// The voting power of an account in a class may be split across up to
+		/// `T::MaxDelegates` different targets, each with its own conviction. The balances of all
+		/// parts add up and are locked for as long as they're delegated, and thereafter for the
+		/// time appropriate for the conviction's lock period.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signing account must neither
+		/// be delegating its whole voting power nor have voting activity in `class`.
+		///
+		/// - `class`: The class of polls to delegate in.
+		/// - `to`: The account whose voting this part of the voting power will follow.
+		/// - `conviction`: The conviction that will be attached to the delegated votes. When the
+		///   part is undelegated, the funds will be locked for the corresponding period.
+		/// - `balance`: The amount of the account's balance to be used in this part. Together with
+		///   the other parts, this must not be more than the account's current balance.
+		///
+		/// Emits `PartDelegated`.
+		///
+		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::delegate_part(T::MaxVotes::get()))]
+		pub fn delegate_part(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			to: AccountIdLookupOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let votes = Self::try_delegate_part(who, class, to, conviction, balance)?;
+
+			Ok(Some(T::WeightInfo::delegate_part(votes)).into())
+		}
+
+		/// Undelegate the part of the voting power of the sending account delegated to `to` for a
+		/// particular class of polls.
+		///
+		/// Tokens may be unlocked once an amount of time consistent with the lock period of the
+		/// conviction with which the part was delegated has passed. Until then, the balances of
+		/// all parts delegated at the time of this call stay locked.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating a part of its voting power to `to`.
+		///
+		/// - `class`: The class of polls to remove the partial delegation from.
+		/// - `to`: The target of the partial delegation.
+		///
+		/// Emits `PartUndelegated`.
+		///
+		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::undelegate_part(T::MaxVotes::get()))]
+		pub fn undelegate_part(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			to: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let votes = Self::try_undelegate_part(who, class, to)?;
+
+			Ok(Some(T::WeightInfo::undelegate_part(votes)).into())
+		}
 	}
 }
 
@@ -438,6 +544,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		T::Polls::try_access_poll(poll_index, |poll_status| {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T, I>::NotOngoing)?;
+			ensure!(
+				PartialDelegationsFor::<T, I>::decode_len(who, &class).unwrap_or(0) == 0,
+				Error::<T, I>::AlreadyDelegating
+			);
 			VotingFor::<T, I>::try_mutate(who, &class, |voting| {
 				if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
 					match votes.binary_search_by_key(&poll_index, |i| i.0) {
@@ -632,6 +742,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(who != target, Error::<T, I>::Nonsense);
 		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
 		ensure!(balance <= T::Currency::total_balance(&who), Error::<T, I>::InsufficientFunds);
+		ensure!(
+			PartialDelegationsFor::<T, I>::decode_len(&who, &class).unwrap_or(0) == 0,
+			Error::<T, I>::AlreadyDelegating
+		);
 		let votes =
 			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
 				let old = core::mem::replace(
@@ -704,6 +818,112 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(votes)
 	}
 
+	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`,
+	/// alongside any other parts of the voting power of `who` already delegated.
+	///
+	/// Return the upstream number of votes.
+	fn try_delegate_part(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+		conviction: Conviction,
+		balance: BalanceOf<T, I>,
+	) -> Result<u32, DispatchError> {
+		ensure!(who != target, Error::<T, I>::Nonsense);
+		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
+		match VotingFor::<T, I>::get(&who, &class) {
+			Voting::Delegating(_) => return Err(Error::<T, I>::AlreadyDelegating.into()),
+			Voting::Casting(Casting { votes, .. }) =>
+				ensure!(votes.is_empty(), Error::<T, I>::AlreadyVoting),
+		}
+		let votes = PartialDelegationsFor::<T, I>::try_mutate(
+			&who,
+			&class,
+			|parts| -> Result<u32, DispatchError> {
+				ensure!(
+					parts.iter().all(|part| part.target != target),
+					Error::<T, I>::AlreadyDelegatingTo
+				);
+				let delegated =
+					parts.iter().fold(balance, |total, part| total.saturating_add(part.balance));
+				ensure!(
+					delegated <= T::Currency::total_balance(&who),
+					Error::<T, I>::InsufficientFunds
+				);
+				parts
+					.try_push(PartialDelegation { target: target.clone(), conviction, balance })
+					.map_err(|_| Error::<T, I>::MaxDelegatesReached)?;
+
+				let votes =
+					Self::increase_upstream_delegation(&target, &class, conviction.votes(balance));
+				// The parts are disjoint, so all of them must be locked at once.
+				Self::extend_lock(&who, &class, delegated);
+				Ok(votes)
+			},
+		)?;
+		Self::deposit_event(Event::<T, I>::PartDelegated {
+			who,
+			target,
+			class,
+			conviction,
+			balance,
+		});
+		Ok(votes)
+	}
+
+	/// Attempt to end the delegation of the part of the voting power of `who` delegated to
+	/// `target`.
+	///
+	/// Return the number of votes of upstream.
+	fn try_undelegate_part(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+	) -> Result<u32, DispatchError> {
+		let votes = PartialDelegationsFor::<T, I>::try_mutate_exists(
+			&who,
+			&class,
+			|maybe_parts| -> Result<u32, DispatchError> {
+				let parts = maybe_parts.as_mut().ok_or(Error::<T, I>::NotDelegating)?;
+				let delegated = Self::delegated_balance(parts);
+				let i = parts
+					.iter()
+					.position(|part| part.target == target)
+					.ok_or(Error::<T, I>::NotDelegating)?;
+				let PartialDelegation { conviction, balance, .. } = parts.remove(i);
+				if parts.is_empty() {
+					*maybe_parts = None;
+				}
+
+				// remove any delegation votes to the target of the part.
+				let votes =
+					Self::reduce_upstream_delegation(&target, &class, conviction.votes(balance));
+				let now = T::BlockNumberProvider::current_block_number();
+				let lock_periods = conviction.lock_periods().into();
+				// The balance of the part cannot be told apart from the balances of the other
+				// parts, so all of them remain locked for the lock period of this part.
+				VotingFor::<T, I>::mutate(&who, &class, |voting| {
+					AsMut::<vote::PriorLock<_, _>>::as_mut(voting).accumulate(
+						now.saturating_add(
+							T::VoteLockingPeriod::get().saturating_mul(lock_periods),
+						),
+						delegated,
+					)
+				});
+				Ok(votes)
+			},
+		)?;
+		Self::deposit_event(Event::<T, I>::PartUndelegated { who, target, class });
+		Ok(votes)
+	}
+
+	/// The total balance of `parts`.
+	fn delegated_balance(parts: &[PartialDelegationOf<T, I>]) -> BalanceOf<T, I> {
+		parts
+			.iter()
+			.fold(Zero::zero(), |total, part| total.saturating_add(part.balance))
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -733,7 +953,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let class_lock_needed = VotingFor::<T, I>::mutate(who, class, |voting| {
 			voting.rejig(T::BlockNumberProvider::current_block_number());
 			voting.locked_balance()
-		});
+		})
+		.max(Self::delegated_balance(&PartialDelegationsFor::<T, I>::get(who, class)));
 		let lock_needed = ClassLocksFor::<T, I>::mutate(who, |locks| {
 			locks.retain(|x| &x.0 != class);
 			if !class_lock_needed.is_zero() {
@@ -151,6 +151,7 @@ impl Config for Test {
 	type Currency = pallet_balances::Pallet<Self>;
 	type VoteLockingPeriod = ConstU64<3>;
 	type MaxVotes = ConstU32<3>;
+	type MaxDelegates = ConstU32<2>;
 	type WeightInfo = ();
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = TestPolls;
@@ -889,6 +890,101 @@ fn errors_with_delegating_work() {
 	});
 }
 
+#[test]
+fn partial_delegation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::delegate_part(RuntimeOrigin::signed(5), 0, 2, Conviction::Locked1x, 10));
+		assert_ok!(Voting::delegate_part(RuntimeOrigin::signed(5), 0, 3, Conviction::Locked2x, 20));
+		assert_eq!(PartialDelegationsFor::<Test>::get(5, 0).len(), 2);
+		assert_eq!(Balances::usable_balance(5), 20);
+
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(11, 41, 20));
+
+		assert_ok!(Voting::undelegate_part(RuntimeOrigin::signed(5), 0, 3));
+		assert_eq!(tally(0), Tally::from_parts(11, 1, 20));
+		assert_eq!(PartialDelegationsFor::<Test>::get(5, 0).len(), 1);
+
+		// The balances of both parts stay locked until the conviction of the removed part ends.
+		run_to(6);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 20);
+		run_to(7);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 40);
+
+		assert_ok!(Voting::undelegate_part(RuntimeOrigin::signed(5), 0, 2));
+		assert_eq!(tally(0), Tally::from_parts(1, 1, 10));
+		assert!(!PartialDelegationsFor::<Test>::contains_key(5, 0));
+		run_to(10);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 50);
+	});
+}
+
+#[test]
+fn errors_with_partial_delegating_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(1), 0, 1, Conviction::None, 5),
+			Error::<Test>::Nonsense
+		);
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(1), 3, 2, Conviction::None, 5),
+			Error::<Test>::BadClass
+		);
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 11),
+			Error::<Test>::InsufficientFunds
+		);
+
+		assert_ok!(Voting::delegate_part(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 6));
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 1),
+			Error::<Test>::AlreadyDelegatingTo
+		);
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 5),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_ok!(Voting::delegate_part(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 2));
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(1), 0, 4, Conviction::None, 2),
+			Error::<Test>::MaxDelegatesReached
+		);
+
+		assert_noop!(
+			Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 0, 4, Conviction::None, 10),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(
+			Voting::undelegate_part(RuntimeOrigin::signed(1), 0, 4),
+			Error::<Test>::NotDelegating
+		);
+		assert_noop!(
+			Voting::undelegate_part(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::NotDelegating
+		);
+
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 3, aye(10, 0)));
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(2), 0, 3, Conviction::None, 10),
+			Error::<Test>::AlreadyVoting
+		);
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 0, 4, Conviction::None, 10));
+		assert_noop!(
+			Voting::delegate_part(RuntimeOrigin::signed(3), 0, 5, Conviction::None, 10),
+			Error::<Test>::AlreadyDelegating
+		);
+	});
+}
+
 #[test]
 fn remove_other_vote_works() {
 	new_test_ext().execute_with(|| {
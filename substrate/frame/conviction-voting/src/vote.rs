@@ -210,6 +210,28 @@ pub struct Delegating<Balance, AccountId, BlockNumber> {
 	pub prior: PriorLock<BlockNumber, Balance>,
 }
 
+/// A part of the voting power of an account in a class, delegated to a single target.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct PartialDelegation<Balance, AccountId> {
+	/// The account to which this part of the voting power is delegated.
+	pub target: AccountId,
+	/// The conviction with which this part of the voting power is delegated. When this gets
+	/// undelegated, the relevant lock begins.
+	pub conviction: Conviction,
+	/// The amount of balance delegated.
+	pub balance: Balance,
+}
+
 /// Information concerning the direct vote-casting of some voting power.
 #[derive(
 	Encode,
@@ -95,6 +95,12 @@ impl pallet_preimage::Config for Test {
 	type Consideration = ();
 }
 
+pub struct MockUnixTime;
+impl frame_support::traits::UnixTime for MockUnixTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(System::block_number() * 6_000)
+	}
+}
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -107,6 +113,8 @@ impl pallet_scheduler::Config for Test {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type UnixTime = MockUnixTime;
+	type ExpectedBlockTime = ConstU64<6_000>;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -359,5 +359,68 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn create_managed_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s as u16);
+		T::Currency::make_free_balance_be(&multi_account_id, BalanceOf::<T>::max_value());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multi_account_id.clone()), signatories, s as u16);
+
+		assert!(ManagedMultisigs::<T>::contains_key(multi_account_id));
+		Ok(())
+	}
+
+	/// `s`: Signatories of the new set, need at least 2 people
+	#[benchmark]
+	fn set_managed_signatories(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let max_signatories = T::MaxSignatories::get();
+		let (signatories, _) = setup_multi::<T>(max_signatories, 0)?;
+		let multi_account_id =
+			Multisig::<T>::multi_account_id(&signatories, max_signatories as u16);
+		T::Currency::make_free_balance_be(&multi_account_id, BalanceOf::<T>::max_value());
+		Multisig::<T>::create_managed_multisig(
+			RawOrigin::Signed(multi_account_id.clone()).into(),
+			signatories.clone(),
+			max_signatories as u16,
+		)?;
+		let new_signatories = signatories[..s as usize].to_vec();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multi_account_id.clone()), new_signatories, s as u16);
+
+		let managed =
+			ManagedMultisigs::<T>::get(&multi_account_id).ok_or("managed multisig not found")?;
+		assert_eq!(managed.signatories.len(), s as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dissolve_managed_multisig() -> Result<(), BenchmarkError> {
+		let max_signatories = T::MaxSignatories::get();
+		let (signatories, _) = setup_multi::<T>(max_signatories, 0)?;
+		let multi_account_id =
+			Multisig::<T>::multi_account_id(&signatories, max_signatories as u16);
+		T::Currency::make_free_balance_be(&multi_account_id, BalanceOf::<T>::max_value());
+		Multisig::<T>::create_managed_multisig(
+			RawOrigin::Signed(multi_account_id.clone()).into(),
+			signatories,
+			max_signatories as u16,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multi_account_id.clone()));
+
+		assert!(!ManagedMultisigs::<T>::contains_key(&multi_account_id));
+		assert!(T::Currency::reserved_balance(&multi_account_id).is_zero());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -39,6 +39,24 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_managed_multisig` - Turn a multisig account into a managed multisig, whose signatories
+//!   and threshold are stored on-chain.
+//! * `set_managed_signatories` - Change the signatories and threshold of a managed multisig.
+//! * `as_managed_multi` - Approve and if possible dispatch a call from a managed multisig.
+//! * `approve_as_managed_multi` - Approve a call from a managed multisig.
+//! * `cancel_as_managed_multi` - Cancel a call from a managed multisig.
+//! * `dissolve_managed_multisig` - Turn a managed multisig back into a regular multisig and return
+//!   its deposit.
+//!
+//! ### Managed multisigs
+//!
+//! The account of a regular multisig is derived from its signatories and threshold, so changing
+//! either of them means moving to a new account. A managed multisig keeps its signatories and
+//! threshold in storage instead, under the account it was created from, and the current set
+//! can change them by dispatching `set_managed_signatories` from the account. Once an account
+//! is managed, it can only be operated through the `*_managed_multi` calls, until the current
+//! set dissolves it with `dissolve_managed_multisig`. The account is then controlled again by
+//! the signatories and threshold it was derived from.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -120,6 +138,34 @@ where
 	pub approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// The on-chain signatories and threshold of a managed multisig account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxSignatories))]
+pub struct ManagedMultisig<Balance, AccountId, MaxSignatories>
+where
+	MaxSignatories: Get<u32>,
+{
+	/// The accounts which can approve operations of the multisig. Always sorted.
+	pub signatories: BoundedVec<AccountId, MaxSignatories>,
+	/// The number of approvals needed to dispatch an operation.
+	pub threshold: u16,
+	/// The amount held in reserve of the managed account itself for this record.
+	pub deposit: Balance,
+}
+
+/// The multisig accounts which are managed or have an open operation, and so may be used without
+/// sending any transaction themselves.
+///
+/// A regular multisig account without any open operation is not included, even though its
+/// signatories can still use it through `as_multi_threshold_1` or an operation opened later on.
+pub struct HasMultisigOperations<T>(PhantomData<T>);
+impl<T: Config> Contains<T::AccountId> for HasMultisigOperations<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		ManagedMultisigs::<T>::contains_key(who) ||
+			Multisigs::<T>::iter_prefix(who).next().is_some()
+	}
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -211,6 +257,15 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The signatories and threshold of managed multisig accounts.
+	#[pallet::storage]
+	pub type ManagedMultisigs<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		ManagedMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -242,6 +297,18 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The threshold is greater than the number of signatories.
+		ThresholdTooHigh,
+		/// The origin is not the multisig account of the given signatories and threshold.
+		NotMultisigAccount,
+		/// The multisig account is already managed.
+		AlreadyManaged,
+		/// The account is not a managed multisig.
+		NotManaged,
+		/// The multisig account is managed and must be operated through the managed calls.
+		Managed,
+		/// The sender is not a signatory of the managed multisig.
+		NotSignatory,
 	}
 
 	#[pallet::event]
@@ -278,6 +345,12 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// A multisig account has become managed.
+		ManagedMultisigCreated { multisig: T::AccountId, threshold: u16 },
+		/// The signatories or threshold of a managed multisig have been changed.
+		ManagedSignatoriesChanged { multisig: T::AccountId, threshold: u16 },
+		/// A managed multisig has been dissolved and its deposit returned.
+		ManagedMultisigDissolved { multisig: T::AccountId, deposit: BalanceOf<T> },
 	}
 
 	#[pallet::hooks]
@@ -304,6 +377,7 @@ pub mod pallet {
 				T::WeightInfo::as_multi_threshold_1(call.using_encoded(|c| c.len() as u32))
 					// AccountData for inner call origin accountdata.
 					.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+					.saturating_add(Pallet::<T>::managed_read_weight())
 					.saturating_add(dispatch_info.call_weight),
 				dispatch_info.class,
 			)
@@ -321,6 +395,7 @@ pub mod pallet {
 			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
 			let id = Self::multi_account_id(&signatories, 1);
+			ensure!(!ManagedMultisigs::<T>::contains_key(&id), Error::<T>::Managed);
 
 			let (call_len, call_hash) = call.using_encoded(|c| (c.len(), blake2_256(&c)));
 			let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
@@ -339,6 +414,7 @@ pub mod pallet {
 						.actual_weight
 						.map(|actual_weight| {
 							T::WeightInfo::as_multi_threshold_1(call_len as u32)
+								.saturating_add(Self::managed_read_weight())
 								.saturating_add(actual_weight)
 						})
 						.into()
@@ -346,6 +422,7 @@ pub mod pallet {
 				.map_err(|err| match err.post_info.actual_weight {
 					Some(actual_weight) => {
 						let weight_used = T::WeightInfo::as_multi_threshold_1(call_len as u32)
+							.saturating_add(Self::managed_read_weight())
 							.saturating_add(actual_weight);
 						let post_info = Some(weight_used).into();
 						DispatchErrorWithPostInfo { post_info, error: err.error }
@@ -401,6 +478,7 @@ pub mod pallet {
 			T::WeightInfo::as_multi_create(s, z)
 			.max(T::WeightInfo::as_multi_approve(s, z))
 			.max(T::WeightInfo::as_multi_complete(s, z))
+			.saturating_add(Pallet::<T>::managed_read_weight())
 			.saturating_add(*max_weight)
 		})]
 		pub fn as_multi(
@@ -458,6 +536,7 @@ pub mod pallet {
 
 			T::WeightInfo::approve_as_multi_create(s)
 				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.saturating_add(Pallet::<T>::managed_read_weight())
 				.saturating_add(*max_weight)
 		})]
 		pub fn approve_as_multi(
@@ -518,21 +597,7 @@ pub mod pallet {
 
 			let id = Self::multi_account_id(&signatories, threshold);
 
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
-
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
-
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
-				multisig: id,
-				call_hash,
-			});
-			Ok(())
+			Self::do_cancel(who, id, timepoint, call_hash)
 		}
 
 		/// Poke the deposit reserved for an existing multisig operation.
@@ -612,6 +677,233 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Turn the multisig account of `signatories` and `threshold` into a managed multisig.
+		///
+		/// The account keeps its ID, but from now on its signatories and threshold are read from
+		/// storage, and can be changed with `set_managed_signatories`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the multisig account itself,
+		/// i.e. be dispatched through `as_multi` by its signatories.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for each signatory is reserved from the
+		/// multisig account.
+		///
+		/// - `signatories`: All the accounts of the multisig, sorted.
+		/// - `threshold`: The threshold of the multisig.
+		///
+		/// Emits `ManagedMultisigCreated` if successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_managed_multisig(signatories.len() as u32))]
+		pub fn create_managed_multisig(
+			origin: OriginFor<T>,
+			signatories: Vec<T::AccountId>,
+			threshold: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Self::multi_account_id(&signatories, threshold) == who,
+				Error::<T>::NotMultisigAccount
+			);
+			ensure!(!ManagedMultisigs::<T>::contains_key(&who), Error::<T>::AlreadyManaged);
+			let signatories = Self::ensure_valid_managed_set(signatories, threshold)?;
+
+			let deposit = Self::managed_deposit(signatories.len());
+			T::Currency::reserve(&who, deposit)?;
+			ManagedMultisigs::<T>::insert(
+				&who,
+				ManagedMultisig { signatories, threshold, deposit },
+			);
+
+			Self::deposit_event(Event::ManagedMultisigCreated { multisig: who, threshold });
+			Ok(())
+		}
+
+		/// Change the signatories and threshold of a managed multisig.
+		///
+		/// Approvals of pending operations given by removed signatories stop counting.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the managed multisig account,
+		/// i.e. be dispatched through `as_managed_multi` by its current signatories.
+		///
+		/// - `signatories`: The new accounts of the multisig, sorted.
+		/// - `threshold`: The new threshold of the multisig.
+		///
+		/// Emits `ManagedSignatoriesChanged` if successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_managed_signatories(signatories.len() as u32))]
+		pub fn set_managed_signatories(
+			origin: OriginFor<T>,
+			signatories: Vec<T::AccountId>,
+			threshold: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let signatories = Self::ensure_valid_managed_set(signatories, threshold)?;
+
+			ManagedMultisigs::<T>::try_mutate(&who, |maybe_managed| -> DispatchResult {
+				let managed = maybe_managed.as_mut().ok_or(Error::<T>::NotManaged)?;
+
+				let new_deposit = Self::managed_deposit(signatories.len());
+				let old_deposit = managed.deposit;
+				if new_deposit > old_deposit {
+					T::Currency::reserve(&who, new_deposit.saturating_sub(old_deposit))?;
+				} else {
+					let excess = old_deposit.saturating_sub(new_deposit);
+					let remaining_unreserved = T::Currency::unreserve(&who, excess);
+					if !remaining_unreserved.is_zero() {
+						defensive!(
+							"Failed to unreserve for full amount for managed multisig. (Requested, Actual): ",
+							(excess, excess.saturating_sub(remaining_unreserved))
+						);
+					}
+				}
+
+				*managed = ManagedMultisig { signatories, threshold, deposit: new_deposit };
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ManagedSignatoriesChanged { multisig: who, threshold });
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from the managed multisig `multisig`,
+		/// dispatching it if the approvals reach its threshold.
+		///
+		/// Works like `as_multi`, with the signatories and threshold read from storage.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The managed multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+				.max(T::WeightInfo::as_multi_approve(s, z))
+				.max(T::WeightInfo::as_multi_complete(s, z))
+				.saturating_add(Pallet::<T>::managed_read_weight())
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_managed_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let managed = Self::ensure_managed_signatory(&multisig, &who)?;
+			Self::do_operate(
+				who,
+				multisig,
+				managed.threshold,
+				&managed.signatories,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from the managed multisig `multisig`.
+		///
+		/// Works like `approve_as_multi`, with the signatories and threshold read from storage.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The managed multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+
+			T::WeightInfo::approve_as_multi_create(s)
+				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.saturating_add(Pallet::<T>::managed_read_weight())
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_managed_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let managed = Self::ensure_managed_signatory(&multisig, &who)?;
+			Self::do_operate(
+				who,
+				multisig,
+				managed.threshold,
+				&managed.signatories,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going operation of the managed multisig `multisig`. Any
+		/// deposit reserved previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account that opened the
+		/// operation, which does not need to still be a signatory. This keeps working after
+		/// `multisig` has been dissolved, so that deposits of operations opened by signatories
+		/// the account was not derived from can always be returned.
+		///
+		/// - `multisig`: The managed multisig account.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::cancel_as_multi(T::MaxSignatories::get()))]
+		pub fn cancel_as_managed_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel(who, multisig, timepoint, call_hash)
+		}
+
+		/// Turn a managed multisig back into a regular multisig, returning its deposit.
+		///
+		/// Afterwards the account is operated again through `as_multi`, by the signatories and
+		/// threshold it was derived from, whatever its managed signatories were. Operations
+		/// still pending are kept and can be cancelled by their depositors through
+		/// `cancel_as_managed_multi`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the managed multisig account,
+		/// i.e. be dispatched through `as_managed_multi` by its current signatories.
+		///
+		/// Emits `ManagedMultisigDissolved` if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::dissolve_managed_multisig())]
+		pub fn dissolve_managed_multisig(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let managed = ManagedMultisigs::<T>::take(&who).ok_or(Error::<T>::NotManaged)?;
+
+			let remaining_unreserved = T::Currency::unreserve(&who, managed.deposit);
+			if !remaining_unreserved.is_zero() {
+				defensive!(
+					"Failed to unreserve for full amount for managed multisig. (Requested, Actual): ",
+					(managed.deposit, managed.deposit.saturating_sub(remaining_unreserved))
+				);
+			}
+
+			Self::deposit_event(Event::ManagedMultisigDissolved {
+				multisig: who,
+				deposit: managed.deposit,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -642,6 +934,31 @@ impl<T: Config> Pallet<T> {
 		let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
 		let id = Self::multi_account_id(&signatories, threshold);
+		ensure!(!ManagedMultisigs::<T>::contains_key(&id), Error::<T>::Managed);
+
+		Self::do_operate(
+			who,
+			id,
+			threshold,
+			&signatories,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	/// Approve and, once `threshold` is reached, dispatch an operation of the multisig `id`
+	/// whose sorted `signatories` include `who`.
+	fn do_operate(
+		who: T::AccountId,
+		id: T::AccountId,
+		threshold: u16,
+		signatories: &[T::AccountId],
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let other_signatories_len = signatories.len().saturating_sub(1);
 
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
@@ -658,6 +975,10 @@ impl<T: Config> Pallet<T> {
 			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 
+			// Approvals of accounts which are no longer signatories of a managed multisig don't
+			// count. This is a no-op for regular multisigs.
+			m.approvals.retain(|approval| signatories.binary_search(approval).is_ok());
+
 			// Ensure that either we have not yet signed or that it is at threshold.
 			let mut approvals = m.approvals.len() as u16;
 			// We only bother with the approval if we're below threshold.
@@ -694,6 +1015,7 @@ impl<T: Config> Pallet<T> {
 							other_signatories_len as u32,
 							call_len as u32,
 						)
+						.saturating_add(Self::managed_read_weight())
 						.saturating_add(actual_weight)
 					})
 					.into())
@@ -720,7 +1042,8 @@ impl<T: Config> Pallet<T> {
 				}
 
 				let final_weight =
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
+					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32)
+						.saturating_add(Self::managed_read_weight());
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
 			}
@@ -749,12 +1072,83 @@ impl<T: Config> Pallet<T> {
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
 			let final_weight =
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32)
+					.saturating_add(Self::managed_read_weight());
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	/// Cancel the operation `call_hash` of the multisig `id`, opened by `who` at `timepoint`.
+	fn do_cancel(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: CallHash,
+	) -> DispatchResult {
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
+	/// Return the managed multisig `multisig`, ensuring `who` is one of its signatories.
+	fn ensure_managed_signatory(
+		multisig: &T::AccountId,
+		who: &T::AccountId,
+	) -> Result<ManagedMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>, DispatchError> {
+		let managed = ManagedMultisigs::<T>::get(multisig).ok_or(Error::<T>::NotManaged)?;
+		ensure!(managed.signatories.binary_search(who).is_ok(), Error::<T>::NotSignatory);
+		Ok(managed)
+	}
+
+	/// Check that `signatories` is sorted, without duplicates, and can reach `threshold`.
+	fn ensure_valid_managed_set(
+		signatories: Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<BoundedVec<T::AccountId, T::MaxSignatories>, DispatchError> {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+		ensure!(signatories.len() >= threshold as usize, Error::<T>::ThresholdTooHigh);
+		ensure!(
+			signatories.windows(2).all(|pair| pair[0] < pair[1]),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		signatories.try_into().map_err(|_| Error::<T>::TooManySignatories.into())
+	}
+
+	/// Calculate the deposit for storing a managed multisig.
+	///
+	/// The deposit is calculated as `DepositBase + DepositFactor * signatories`.
+	pub fn managed_deposit(signatories: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (signatories as u32).into()
+	}
+
+	/// The weight of reading the `ManagedMultisigs` entry of a multisig, which every operation
+	/// does on top of what its benchmarked weight covers.
+	pub fn managed_read_weight() -> Weight {
+		let proof_size = T::AccountId::max_encoded_len()
+			// The `Twox64Concat` hash of the key.
+			.saturating_add(8)
+			.saturating_add(
+				ManagedMultisig::<BalanceOf<T>, T::AccountId, T::MaxSignatories>::max_encoded_len(),
+			);
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(Weight::from_parts(0, proof_size as u64))
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -52,6 +52,11 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
 		match *c {
 			RuntimeCall::Balances(_) => true,
+			RuntimeCall::Multisig(
+				Call::create_managed_multisig { .. } |
+				Call::set_managed_signatories { .. } |
+				Call::dissolve_managed_multisig { .. },
+			) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
 			_ => false,
@@ -620,6 +625,40 @@ fn multisig_filters() {
 	});
 }
 
+#[test]
+fn weight_includes_the_managed_check() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15);
+		let call_len = call.using_encoded(|c| c.len()) as u32;
+
+		let post_info = Multisig::as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			call.clone(),
+			Weight::zero(),
+		)
+		.unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(
+				<() as WeightInfo>::as_multi_create(2, call_len)
+					.saturating_add(Multisig::managed_read_weight())
+			)
+		);
+
+		let dispatch_info = RuntimeCall::Multisig(Call::as_multi_threshold_1 {
+			other_signatories: vec![2],
+			call: call.clone(),
+		})
+		.get_dispatch_info();
+		assert!(dispatch_info.call_weight.all_gte(
+			<() as WeightInfo>::as_multi_threshold_1(call_len) + Multisig::managed_read_weight()
+		));
+	});
+}
+
 #[test]
 fn weight_check_works() {
 	new_test_ext().execute_with(|| {
@@ -1015,3 +1054,471 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+/// Turn the 2-of-{1, 2, 3} multisig into a managed multisig and fund it.
+fn create_managed_1_2_3() -> u64 {
+	let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+	assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+	assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+	assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+	let call = Box::new(RuntimeCall::Multisig(Call::create_managed_multisig {
+		signatories: vec![1, 2, 3],
+		threshold: 2,
+	}));
+	let call_weight = call.get_dispatch_info().call_weight;
+	assert_ok!(Multisig::as_multi(
+		RuntimeOrigin::signed(1),
+		2,
+		vec![2, 3],
+		None,
+		call.clone(),
+		Weight::zero()
+	));
+	assert_ok!(Multisig::as_multi(
+		RuntimeOrigin::signed(2),
+		2,
+		vec![1, 3],
+		Some(now()),
+		call,
+		call_weight
+	));
+	System::assert_last_event(
+		pallet_multisig::Event::MultisigExecuted {
+			approving: 2,
+			timepoint: now(),
+			multisig: multi,
+			call_hash: blake2_256(
+				&RuntimeCall::Multisig(Call::create_managed_multisig {
+					signatories: vec![1, 2, 3],
+					threshold: 2,
+				})
+				.encode(),
+			),
+			result: Ok(()),
+		}
+		.into(),
+	);
+	multi
+}
+
+#[test]
+fn create_managed_multisig_works() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+
+		let managed = ManagedMultisigs::<Test>::get(multi).unwrap();
+		assert_eq!(managed.signatories.into_inner(), vec![1, 2, 3]);
+		assert_eq!(managed.threshold, 2);
+		assert_eq!(managed.deposit, 4);
+		assert_eq!(Balances::reserved_balance(multi), 4);
+		assert_eq!(Balances::free_balance(multi), 11);
+	});
+}
+
+#[test]
+fn create_managed_multisig_checks_origin_and_set() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 10));
+
+		assert_noop!(
+			Multisig::create_managed_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2),
+			Error::<Test>::NotMultisigAccount
+		);
+		assert_noop!(
+			Multisig::create_managed_multisig(RuntimeOrigin::signed(multi), vec![1, 2, 3], 3),
+			Error::<Test>::NotMultisigAccount
+		);
+
+		let unsorted = Multisig::multi_account_id(&[2, 1, 3][..], 2);
+		assert_noop!(
+			Multisig::create_managed_multisig(RuntimeOrigin::signed(unsorted), vec![2, 1, 3], 2),
+			Error::<Test>::SignatoriesOutOfOrder
+		);
+		let too_high = Multisig::multi_account_id(&[1, 2][..], 3);
+		assert_noop!(
+			Multisig::create_managed_multisig(RuntimeOrigin::signed(too_high), vec![1, 2], 3),
+			Error::<Test>::ThresholdTooHigh
+		);
+
+		assert_ok!(Multisig::create_managed_multisig(
+			RuntimeOrigin::signed(multi),
+			vec![1, 2, 3],
+			2
+		));
+		assert_noop!(
+			Multisig::create_managed_multisig(RuntimeOrigin::signed(multi), vec![1, 2, 3], 2),
+			Error::<Test>::AlreadyManaged
+		);
+	});
+}
+
+#[test]
+fn managed_multisig_cannot_be_operated_as_regular_multisig() {
+	new_test_ext().execute_with(|| {
+		create_managed_1_2_3();
+
+		let call = call_transfer(6, 5);
+		assert_noop!(
+			Multisig::as_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![2, 3],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::Managed
+		);
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![2, 3],
+				None,
+				call.using_encoded(blake2_256),
+				Weight::zero()
+			),
+			Error::<Test>::Managed
+		);
+	});
+}
+
+#[test]
+fn managed_multisig_dispatches_calls() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_noop!(
+			Multisig::as_managed_multi(
+				RuntimeOrigin::signed(4),
+				multi,
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::NotSignatory
+		);
+		assert_noop!(
+			Multisig::as_managed_multi(
+				RuntimeOrigin::signed(4),
+				6,
+				None,
+				call.clone(),
+				call_weight
+			),
+			Error::<Test>::NotManaged
+		);
+
+		assert_ok!(Multisig::approve_as_managed_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call.using_encoded(blake2_256),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn managed_signatories_can_be_rotated_without_moving_funds() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+
+		// {1, 2, 3} replace 3 by 4 and raise the threshold to 3.
+		let rotate = Box::new(RuntimeCall::Multisig(Call::set_managed_signatories {
+			signatories: vec![1, 2, 4],
+			threshold: 3,
+		}));
+		let rotate_weight = rotate.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			rotate.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			rotate,
+			rotate_weight
+		));
+		System::assert_has_event(
+			pallet_multisig::Event::ManagedSignatoriesChanged { multisig: multi, threshold: 3 }
+				.into(),
+		);
+		assert_eq!(
+			ManagedMultisigs::<Test>::get(multi).map(|m| m.signatories.into_inner()),
+			Some(vec![1, 2, 4])
+		);
+
+		// 3 is out.
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_noop!(
+			Multisig::as_managed_multi(
+				RuntimeOrigin::signed(3),
+				multi,
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::NotSignatory
+		);
+
+		// The new set needs all three approvals, on the same account.
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+	});
+}
+
+#[test]
+fn approvals_of_removed_signatories_do_not_count() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+
+		// 3 approves a transfer, then gets removed.
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		let transfer_timepoint = now();
+
+		assert_ok!(Multisig::set_managed_signatories(RuntimeOrigin::signed(multi), vec![1, 2], 2));
+		assert_eq!(Balances::reserved_balance(multi), 3);
+
+		// 1 alone does not reach the threshold.
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			Some(transfer_timepoint),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(transfer_timepoint),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		// The deposit went back to the removed depositor.
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn set_managed_signatories_requires_managed_origin() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+
+		assert_noop!(
+			Multisig::set_managed_signatories(RuntimeOrigin::signed(1), vec![1, 2], 2),
+			Error::<Test>::NotManaged
+		);
+		assert_noop!(
+			Multisig::set_managed_signatories(RuntimeOrigin::signed(multi), vec![1], 2),
+			Error::<Test>::TooFewSignatories
+		);
+		assert_noop!(
+			Multisig::set_managed_signatories(RuntimeOrigin::signed(multi), vec![1, 2, 3, 4], 2),
+			Error::<Test>::TooManySignatories
+		);
+	});
+}
+
+#[test]
+fn cancel_as_managed_multi_works() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+
+		let hash = call_transfer(6, 5).using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_managed_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(3), 3);
+		assert_ok!(Multisig::set_managed_signatories(RuntimeOrigin::signed(multi), vec![1, 2], 2));
+
+		assert_noop!(
+			Multisig::cancel_as_managed_multi(RuntimeOrigin::signed(1), multi, now(), hash),
+			Error::<Test>::NotOwner
+		);
+		// 3 can still cancel its own operation.
+		assert_ok!(Multisig::cancel_as_managed_multi(RuntimeOrigin::signed(3), multi, now(), hash));
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn dissolve_managed_multisig_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+		assert_eq!(Balances::reserved_balance(multi), 4);
+
+		assert_noop!(
+			Multisig::dissolve_managed_multisig(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotManaged
+		);
+
+		let dissolve = Box::new(RuntimeCall::Multisig(Call::dissolve_managed_multisig {}));
+		let dissolve_weight = dissolve.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			dissolve.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_managed_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			dissolve,
+			dissolve_weight
+		));
+		System::assert_has_event(
+			pallet_multisig::Event::ManagedMultisigDissolved { multisig: multi, deposit: 4 }.into(),
+		);
+		assert!(!ManagedMultisigs::<Test>::contains_key(multi));
+		assert_eq!(Balances::reserved_balance(multi), 0);
+		assert_eq!(Balances::free_balance(multi), 15);
+
+		// The account is a regular multisig again.
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+	});
+}
+
+#[test]
+fn managed_operations_can_be_cancelled_after_dissolving() {
+	new_test_ext().execute_with(|| {
+		let multi = create_managed_1_2_3();
+		assert_ok!(Multisig::set_managed_signatories(
+			RuntimeOrigin::signed(multi),
+			vec![1, 2, 4],
+			2
+		));
+
+		// 4 opens an operation, then the multisig is dissolved.
+		let hash = call_transfer(6, 5).using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_managed_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		let timepoint = now();
+		assert_eq!(Balances::reserved_balance(4), 3);
+		assert_ok!(Multisig::dissolve_managed_multisig(RuntimeOrigin::signed(multi)));
+
+		// 4 is not one of the signatories the account was derived from, but can still get its
+		// deposit back.
+		assert_ok!(Multisig::cancel_as_managed_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			timepoint,
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(4), 0);
+	});
+}
+
+#[test]
+fn has_multisig_operations_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert!(!HasMultisigOperations::<Test>::contains(&multi));
+
+		// An open operation.
+		let call = call_transfer(6, 5);
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		assert!(HasMultisigOperations::<Test>::contains(&multi));
+		assert!(!HasMultisigOperations::<Test>::contains(&1));
+		assert_ok!(Multisig::cancel_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			now(),
+			blake2_256(&call.encode())
+		));
+		assert!(!HasMultisigOperations::<Test>::contains(&multi));
+
+		// A managed multisig.
+		let multi = create_managed_1_2_3();
+		assert!(HasMultisigOperations::<Test>::contains(&multi));
+		assert_ok!(Multisig::dissolve_managed_multisig(RuntimeOrigin::signed(multi)));
+		assert!(!HasMultisigOperations::<Test>::contains(&multi));
+	});
+}
@@ -52,6 +52,29 @@ fn add_proxies<T: Config>(n: u32, maybe_who: Option<T::AccountId>) -> Result<(),
 	Ok(())
 }
 
+/// Budget the first `n` proxies added by [`add_proxies`] for `who`, limiting the maximum number
+/// of assets.
+fn add_budgets<T: Config>(n: u32, who: &T::AccountId) -> Result<(), &'static str> {
+	let limit = SpendLimit {
+		per_call: Some(BalanceOf::<T>::max_value()),
+		per_window: Some(BalanceOf::<T>::max_value()),
+	};
+	for i in 0..n {
+		let assets = (0..T::MaxBudgetAssets::get())
+			.map(|j| (T::BenchmarkHelper::budget_asset(j), limit))
+			.collect();
+		Proxy::<T>::set_proxy_budget(
+			RawOrigin::Signed(who.clone()).into(),
+			T::Lookup::unlookup(account("target", i, SEED)),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::one(),
+			limit,
+			assets,
+		)?;
+	}
+	Ok(())
+}
+
 fn add_announcements<T: Config>(
 	n: u32,
 	maybe_who: Option<T::AccountId>,
@@ -255,6 +278,8 @@ mod benchmarks {
 	fn remove_proxy(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
+		// the removed proxy is budgeted
+		add_budgets::<T>(1, &caller)?;
 		let delegate = T::Lookup::unlookup(account("target", 0, SEED));
 
 		#[extrinsic_call]
@@ -265,8 +290,9 @@ mod benchmarks {
 			BlockNumberFor::<T>::zero(),
 		);
 
-		let (proxies, _) = Proxies::<T>::get(caller);
+		let (proxies, _) = Proxies::<T>::get(&caller);
 		assert_eq!(proxies.len() as u32, p - 1);
+		assert_eq!(ProxyBudgets::<T>::iter_prefix(&caller).count(), 0);
 
 		Ok(())
 	}
@@ -275,12 +301,15 @@ mod benchmarks {
 	fn remove_proxies(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
+		// all the proxies are budgeted
+		add_budgets::<T>(p, &caller)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
 
-		let (proxies, _) = Proxies::<T>::get(caller);
+		let (proxies, _) = Proxies::<T>::get(&caller);
 		assert_eq!(proxies.len() as u32, 0);
+		assert_eq!(ProxyBudgets::<T>::iter_prefix(&caller).count(), 0);
 
 		Ok(())
 	}
@@ -330,6 +359,8 @@ mod benchmarks {
 		let pure_account = Pallet::<T>::pure_account(&caller, &T::ProxyType::default(), 0, None);
 
 		add_proxies::<T>(p, Some(pure_account.clone()))?;
+		// all the proxies of the pure account are budgeted
+		add_budgets::<T>(p, &pure_account)?;
 		ensure!(Proxies::<T>::contains_key(&pure_account), "pure proxy not created");
 
 		#[extrinsic_call]
@@ -343,6 +374,7 @@ mod benchmarks {
 		);
 
 		assert!(!Proxies::<T>::contains_key(&pure_account));
+		assert_eq!(ProxyBudgets::<T>::iter_prefix(&pure_account).count(), 0);
 
 		Ok(())
 	}
@@ -459,5 +491,105 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_proxy_budget(
+		b: Linear<0, { T::MaxBudgetAssets::get() }>,
+		p: Linear<1, { T::MaxProxies::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let limit = SpendLimit {
+			per_call: Some(BalanceOf::<T>::max_value()),
+			per_window: Some(BalanceOf::<T>::max_value()),
+		};
+		let assets = (0..b).map(|i| (T::BenchmarkHelper::budget_asset(i), limit)).collect();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::one(),
+			limit,
+			assets,
+		);
+
+		assert_last_event::<T>(
+			Event::ProxyBudgetSet {
+				delegator: caller,
+				delegatee: delegate,
+				proxy_type: T::ProxyType::default(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_proxy_budget() -> Result<(), BenchmarkError> {
+		add_proxies::<T>(1, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		Proxy::<T>::set_proxy_budget(
+			RawOrigin::Signed(caller.clone()).into(),
+			delegate_lookup.clone(),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::one(),
+			SpendLimit::default(),
+			vec![],
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), delegate_lookup, T::ProxyType::default());
+
+		assert_last_event::<T>(
+			Event::ProxyBudgetRemoved {
+				delegator: caller,
+				delegatee: delegate,
+				proxy_type: T::ProxyType::default(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn budgeted_dispatch(
+		b: Linear<0, { T::MaxBudgetAssets::get() }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(1, None)?;
+		let real: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", 0, SEED);
+		let limit = SpendLimit {
+			per_call: Some(BalanceOf::<T>::max_value()),
+			per_window: Some(BalanceOf::<T>::max_value()),
+		};
+		let assets = (0..b).map(|i| (T::BenchmarkHelper::budget_asset(i), limit)).collect();
+		Proxy::<T>::set_proxy_budget(
+			RawOrigin::Signed(real.clone()).into(),
+			T::Lookup::unlookup(delegate.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::one(),
+			limit,
+			assets,
+		)?;
+		let def = Proxy::<T>::find_proxy(&real, &delegate, None)?;
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+
+		#[block]
+		{
+			Proxy::<T>::do_proxy(def, real, call)?;
+		}
+
+		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -23,6 +23,13 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! A delegator may also attach a budget to a proxy, which limits the value of native and
+//! fungible-asset balance that the proxy may spend per call and per rolling window of blocks.
+//! Spending is measured as the decrease of the delegator's balances while the proxied call is
+//! dispatched, and a call which exceeds the budget is reverted. Budgeted proxies can only dispatch
+//! the calls allowed by [`Config::BudgetCallFilter`], and each budget holds a deposit from the
+//! delegator.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -34,10 +41,14 @@ mod tests;
 pub mod weights;
 
 extern crate alloc;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
+	deps::{
+		frame_support::{dispatch::extract_actual_weight, storage::with_transaction},
+		sp_runtime::{traits::AtLeast32BitUnsigned, TransactionOutcome},
+	},
 	prelude::*,
-	traits::{Currency, InstanceFilter, ReservableCurrency},
+	traits::{fungibles, Currency, InstanceFilter, ReservableCurrency},
 };
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -52,6 +63,15 @@ pub type BlockNumberFor<T> =
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+type AssetKindOf<T> = <<T as Config>::BudgetAssets as BudgetAssets<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+>>::AssetKind;
+
+/// The budget of a proxy of the given runtime.
+pub type ProxyBudgetOf<T> =
+	ProxyBudget<BalanceOf<T>, AssetKindOf<T>, BlockNumberFor<T>, <T as Config>::MaxBudgetAssets>;
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
 #[derive(
@@ -120,6 +140,222 @@ pub enum DepositKind {
 	Announcements,
 }
 
+/// The fungible assets whose spending can be limited by a proxy budget.
+pub trait BudgetAssets<AccountId, Balance> {
+	/// The kind of asset a budget can refer to.
+	type AssetKind: Parameter + MaxEncodedLen;
+
+	/// The balance of `asset` held by `who`.
+	fn balance(asset: &Self::AssetKind, who: &AccountId) -> Balance;
+
+	/// The balance of `asset` held by `who`, including any amount on hold.
+	fn total_balance(asset: &Self::AssetKind, who: &AccountId) -> Balance;
+}
+
+/// No budgetable assets besides the native currency.
+impl<AccountId, Balance: Zero> BudgetAssets<AccountId, Balance> for () {
+	type AssetKind = ();
+
+	fn balance(_: &Self::AssetKind, _: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn total_balance(_: &Self::AssetKind, _: &AccountId) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// Budget the assets of a [`fungibles::Inspect`] implementation, e.g. `pallet-assets`.
+pub struct FungiblesBudget<F>(PhantomData<F>);
+impl<AccountId, F> BudgetAssets<AccountId, F::Balance> for FungiblesBudget<F>
+where
+	F: fungibles::Inspect<AccountId>,
+	F::AssetId: Parameter + MaxEncodedLen,
+{
+	type AssetKind = F::AssetId;
+
+	fn balance(asset: &Self::AssetKind, who: &AccountId) -> F::Balance {
+		F::balance(asset.clone(), who)
+	}
+
+	fn total_balance(asset: &Self::AssetKind, who: &AccountId) -> F::Balance {
+		F::total_balance(asset.clone(), who)
+	}
+}
+
+/// The accounts which have delegated to any proxy, and so may be used without sending any
+/// transaction themselves.
+pub struct HasProxies<T>(PhantomData<T>);
+impl<T: Config> Contains<T::AccountId> for HasProxies<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		Proxies::<T>::contains_key(who)
+	}
+}
+
+/// Provides the asset kinds used to benchmark proxy budgets.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetKind> {
+	/// Return a distinct asset kind for each `seed`.
+	fn budget_asset(seed: u32) -> AssetKind;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<()> for () {
+	fn budget_asset(_: u32) {}
+}
+
+/// Benchmark helper for asset kinds which can be created from a `u32`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetKindFromSeed;
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetKind: From<u32>> BenchmarkHelper<AssetKind> for AssetKindFromSeed {
+	fn budget_asset(seed: u32) -> AssetKind {
+		seed.into()
+	}
+}
+
+/// The amounts a proxy may spend of a single asset. `None` means unlimited.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Default,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct SpendLimit<Balance> {
+	/// The maximum amount a single proxied call may spend.
+	pub per_call: Option<Balance>,
+	/// The maximum amount all proxied calls within one budget window may spend together.
+	pub per_window: Option<Balance>,
+}
+
+/// The number of slots in which the spending of a proxy budget is tracked.
+///
+/// All slots but the current one cover a full budget window, see [`ProxyBudget::slot`].
+pub const BUDGET_SLOTS: usize = 8;
+
+/// A [`SpendLimit`] together with the amounts spent in the slots of the budget window.
+#[derive(
+	Encode, Decode, Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct Allowance<Balance> {
+	/// The limits of the allowance.
+	pub limit: SpendLimit<Balance>,
+	/// The amounts spent per slot, indexed by the slot number modulo [`BUDGET_SLOTS`].
+	pub spent_per_slot: [Balance; BUDGET_SLOTS],
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> Allowance<Balance> {
+	/// An allowance with nothing spent yet.
+	fn new(limit: SpendLimit<Balance>) -> Self {
+		Self { limit, spent_per_slot: [Zero::zero(); BUDGET_SLOTS] }
+	}
+
+	/// The amount spent in the current window.
+	pub fn spent(&self) -> Balance {
+		self.spent_per_slot
+			.iter()
+			.fold(Zero::zero(), |total, spent| total.saturating_add(*spent))
+	}
+
+	/// Record spending `amount` in slot `index`, failing if it exceeds the per-call or
+	/// per-window limit.
+	fn try_spend(&mut self, index: usize, amount: Balance) -> Result<(), ()> {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		if self.limit.per_call.is_some_and(|max| amount > max) {
+			return Err(())
+		}
+		if self
+			.limit
+			.per_window
+			.is_some_and(|max| self.spent().saturating_add(amount) > max)
+		{
+			return Err(())
+		}
+		self.spent_per_slot[index] = self.spent_per_slot[index].saturating_add(amount);
+		Ok(())
+	}
+}
+
+/// The spending limits of a proxy over the account of its delegator.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxAssets))]
+pub struct ProxyBudget<Balance, AssetKind, BlockNumber, MaxAssets: Get<u32>> {
+	/// The number of blocks over which the per-window limits apply.
+	pub window: BlockNumber,
+	/// The slot in which spending was last tracked.
+	///
+	/// Blocks are grouped in slots of `ceil((window - 1) / (BUDGET_SLOTS - 1))` blocks, so that
+	/// the [`BUDGET_SLOTS`] tracked slots always cover the last `window` blocks. An amount spent
+	/// stops counting towards the window once its whole slot has left the window.
+	pub slot: BlockNumber,
+	/// The allowance of the native currency.
+	pub native: Allowance<Balance>,
+	/// The allowances of fungible assets. Assets not listed here are not limited.
+	pub assets: BoundedVec<(AssetKind, Allowance<Balance>), MaxAssets>,
+	/// The amount held in reserve of the delegator for this budget.
+	pub deposit: Balance,
+}
+
+impl<Balance, AssetKind, BlockNumber, MaxAssets>
+	ProxyBudget<Balance, AssetKind, BlockNumber, MaxAssets>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	MaxAssets: Get<u32>,
+{
+	/// A budget with nothing spent yet.
+	fn new(
+		window: BlockNumber,
+		now: BlockNumber,
+		native: SpendLimit<Balance>,
+		assets: BoundedVec<(AssetKind, Allowance<Balance>), MaxAssets>,
+		deposit: Balance,
+	) -> Self {
+		let mut budget =
+			Self { window, slot: Zero::zero(), native: Allowance::new(native), assets, deposit };
+		budget.slot = now / budget.slot_length();
+		budget
+	}
+
+	/// The number of blocks per slot.
+	fn slot_length(&self) -> BlockNumber {
+		let others = BlockNumber::from(BUDGET_SLOTS as u32 - 1);
+		let length =
+			self.window.saturating_sub(One::one()).saturating_add(others - One::one()) / others;
+		length.max(One::one())
+	}
+
+	/// The index of the current slot in the allowances.
+	fn slot_index(&self) -> usize {
+		(self.slot % BlockNumber::from(BUDGET_SLOTS as u32)).saturated_into::<u32>() as usize
+	}
+
+	/// Move to the slot of block `now`, forgetting the amounts spent in the slots which have left
+	/// the window since.
+	fn roll_window(&mut self, now: BlockNumber) {
+		let slot = now / self.slot_length();
+		let passed = slot.saturating_sub(self.slot).min(BlockNumber::from(BUDGET_SLOTS as u32));
+		for _ in 0..passed.saturated_into::<u32>() {
+			self.slot = self.slot.saturating_add(One::one());
+			let index = self.slot_index();
+			self.native.spent_per_slot[index] = Zero::zero();
+			self.assets
+				.iter_mut()
+				.for_each(|(_, allowance)| allowance.spent_per_slot[index] = Zero::zero());
+		}
+		self.slot = self.slot.max(slot);
+	}
+}
+
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
@@ -136,7 +372,7 @@ pub mod pallet {
 
 		/// The overarching call type.
 		type RuntimeCall: Parameter
-			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ GetDispatchInfo
 			+ From<frame_system::Call<Self>>
 			+ IsSubType<Call<Self>>
@@ -223,6 +459,40 @@ pub mod pallet {
 		///     providers can be used. Relay provider can be a bit better in cases where the
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The fungible assets that proxy budgets can limit besides the native currency.
+		///
+		/// Use `()` if only the native currency should be budgeted.
+		type BudgetAssets: BudgetAssets<Self::AccountId, BalanceOf<Self>>;
+
+		/// The maximum number of fungible assets a single proxy budget can limit.
+		#[pallet::constant]
+		type MaxBudgetAssets: Get<u32>;
+
+		/// The calls a budgeted proxy may dispatch.
+		///
+		/// Budgets only see what a call spends while it is dispatched. Calls which let the
+		/// delegator's funds be spent later without going through the proxy, such as approving
+		/// transfers of fungible assets to another account, should be excluded here.
+		type BudgetCallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+		/// The base amount of currency needed to reserve for setting a proxy budget.
+		///
+		/// This is held for a storage item holding the budget of the native currency, keyed by
+		/// two `AccountId`s and a `ProxyType` (typically 156 bytes).
+		#[pallet::constant]
+		type BudgetDepositBase: Get<BalanceOf<Self>>;
+
+		/// The amount of currency needed per fungible asset limited by a proxy budget.
+		///
+		/// This is held for adding an `AssetKind` and its allowance (typically 54 bytes) into
+		/// the budget.
+		#[pallet::constant]
+		type BudgetDepositFactor: Get<BalanceOf<Self>>;
+
+		/// Helper for benchmarking proxy budgets.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetKindOf<Self>>;
 	}
 
 	#[pallet::call]
@@ -242,6 +512,7 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::WeightInfo::budgeted_dispatch(T::MaxBudgetAssets::get()))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -250,15 +521,19 @@ pub mod pallet {
 			real: AccountIdLookupOf<T>,
 			force_proxy_type: Option<T::ProxyType>,
 			call: Box<<T as Config>::RuntimeCall>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let real = T::Lookup::lookup(real)?;
-			let def = Self::find_proxy(&real, &who, force_proxy_type)?;
+			let (def, proxies) = Self::find_proxy_and_count(&real, &who, force_proxy_type)?;
 			ensure!(def.delay.is_zero(), Error::<T>::Unannounced);
 
-			Self::do_proxy(def, real, *call);
-
-			Ok(())
+			let dispatch_weight = Self::do_proxy(def, real, *call)?;
+			Ok(Some(
+				T::WeightInfo::proxy(proxies)
+					.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+					.saturating_add(dispatch_weight),
+			)
+			.into())
 		}
 
 		/// Register a proxy account for the sender that is able to make calls on its behalf.
@@ -291,7 +566,8 @@ pub mod pallet {
 		/// - `proxy`: The account that the `caller` would like to remove as a proxy.
 		/// - `proxy_type`: The permissions currently enabled for the removed proxy account.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get())
+			.saturating_add(T::DbWeight::get().writes(1)))]
 		pub fn remove_proxy(
 			origin: OriginFor<T>,
 			delegate: AccountIdLookupOf<T>,
@@ -310,7 +586,8 @@ pub mod pallet {
 		/// WARNING: This may be called on accounts created by `create_pure`, however if done, then
 		/// the unreserved fees will be inaccessible. **All access to this account will be lost.**
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::remove_proxies(T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::remove_proxies(T::MaxProxies::get())
+			.saturating_add(T::DbWeight::get().writes(T::MaxProxies::get() as u64)))]
 		pub fn remove_proxies(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			Self::remove_all_proxy_delegates(&who);
@@ -387,7 +664,8 @@ pub mod pallet {
 		/// Fails with `NoPermission` in case the caller is not a previously created pure
 		/// account whose `create_pure` call has corresponding parameters.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::kill_pure(T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::kill_pure(T::MaxProxies::get())
+			.saturating_add(T::DbWeight::get().writes(T::MaxProxies::get() as u64)))]
 		pub fn kill_pure(
 			origin: OriginFor<T>,
 			spawner: AccountIdLookupOf<T>,
@@ -405,6 +683,7 @@ pub mod pallet {
 
 			let (_, deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&spawner, deposit);
+			Self::remove_all_budgets(&who);
 
 			Self::deposit_event(Event::PureKilled {
 				pure: who,
@@ -544,6 +823,7 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::WeightInfo::budgeted_dispatch(T::MaxBudgetAssets::get()))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -553,24 +833,28 @@ pub mod pallet {
 			real: AccountIdLookupOf<T>,
 			force_proxy_type: Option<T::ProxyType>,
 			call: Box<<T as Config>::RuntimeCall>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 			let delegate = T::Lookup::lookup(delegate)?;
 			let real = T::Lookup::lookup(real)?;
-			let def = Self::find_proxy(&real, &delegate, force_proxy_type)?;
+			let (def, proxies) = Self::find_proxy_and_count(&real, &delegate, force_proxy_type)?;
 
 			let call_hash = T::CallHasher::hash_of(&call);
 			let now = T::BlockNumberProvider::current_block_number();
-			Self::edit_announcements(&delegate, |ann| {
+			let announcements = Self::edit_announcements(&delegate, |ann| {
 				ann.real != real ||
 					ann.call_hash != call_hash ||
 					now.saturating_sub(ann.height) < def.delay
 			})
 			.map_err(|_| Error::<T>::Unannounced)?;
 
-			Self::do_proxy(def, real, *call);
-
-			Ok(())
+			let dispatch_weight = Self::do_proxy(def, real, *call)?;
+			Ok(Some(
+				T::WeightInfo::proxy_announced(announcements, proxies)
+					.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+					.saturating_add(dispatch_weight),
+			)
+			.into())
 		}
 
 		/// Poke / Adjust deposits made for proxies and announcements based on current values.
@@ -671,6 +955,109 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No.into() } else { Pays::Yes.into() })
 		}
+
+		/// Limit what a proxy of the sender may spend from the sender's account.
+		///
+		/// Replaces any budget the proxy had before, with nothing spent.
+		///
+		/// The dispatch origin for this call must be _Signed_, and may not be a budgeted proxy.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy account.
+		/// - `proxy_type`: The type of the proxy relationship to be budgeted.
+		/// - `window`: The number of blocks over which the per-window limits apply.
+		/// - `native`: The limits on spending the native currency.
+		/// - `assets`: The limits on spending fungible assets, at most one per asset.
+		///
+		/// Emits `ProxyBudgetSet` if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_proxy_budget(
+			assets.len() as u32,
+			T::MaxProxies::get()
+		))]
+		pub fn set_proxy_budget(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			window: BlockNumberFor<T>,
+			native: SpendLimit<BalanceOf<T>>,
+			assets: Vec<(AssetKindOf<T>, SpendLimit<BalanceOf<T>>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::find_proxy(&who, &delegate, Some(proxy_type.clone()))?;
+
+			let has_window_limit = native.per_window.is_some() ||
+				assets.iter().any(|(_, limit)| limit.per_window.is_some());
+			ensure!(!has_window_limit || !window.is_zero(), Error::<T>::InvalidBudget);
+			for (i, (asset, _)) in assets.iter().enumerate() {
+				ensure!(
+					!assets[..i].iter().any(|(other, _)| other == asset),
+					Error::<T>::InvalidBudget
+				);
+			}
+			let assets: BoundedVec<_, T::MaxBudgetAssets> = assets
+				.into_iter()
+				.map(|(asset, limit)| (asset, Allowance::new(limit)))
+				.collect::<Vec<_>>()
+				.try_into()
+				.map_err(|_| Error::<T>::TooMany)?;
+
+			let deposit = Self::budget_deposit(assets.len() as u32);
+			let old_deposit = ProxyBudgets::<T>::get(&who, (&delegate, &proxy_type))
+				.map_or_else(Zero::zero, |budget| budget.deposit);
+			if deposit > old_deposit {
+				T::Currency::reserve(&who, deposit - old_deposit)?;
+			} else if deposit < old_deposit {
+				T::Currency::unreserve(&who, old_deposit - deposit);
+			}
+
+			let budget = ProxyBudget::new(
+				window,
+				T::BlockNumberProvider::current_block_number(),
+				native,
+				assets,
+				deposit,
+			);
+			ProxyBudgets::<T>::insert(&who, (&delegate, &proxy_type), budget);
+			Self::deposit_event(Event::ProxyBudgetSet {
+				delegator: who,
+				delegatee: delegate,
+				proxy_type,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the budget of a proxy of the sender, so that its spending is no longer limited.
+		///
+		/// The dispatch origin for this call must be _Signed_, and may not be a budgeted proxy.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy account.
+		/// - `proxy_type`: The type of the budgeted proxy relationship.
+		///
+		/// Emits `ProxyBudgetRemoved` if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_proxy_budget())]
+		pub fn remove_proxy_budget(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let budget = ProxyBudgets::<T>::take(&who, (&delegate, &proxy_type))
+				.ok_or(Error::<T>::NotFound)?;
+			T::Currency::unreserve(&who, budget.deposit);
+			Self::deposit_event(Event::ProxyBudgetRemoved {
+				delegator: who,
+				delegatee: delegate,
+				proxy_type,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -722,6 +1109,18 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// The budget of a proxy was set.
+		ProxyBudgetSet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+		},
+		/// The budget of a proxy was removed.
+		ProxyBudgetRemoved {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+		},
 	}
 
 	#[pallet::error]
@@ -742,6 +1141,10 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The budget limits the same asset twice, or has per-window limits but no window.
+		InvalidBudget,
+		/// The proxied call spends more than the budget of the proxy allows.
+		BudgetExceeded,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -774,6 +1177,18 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The spending budgets of proxies. Maps the account which has delegated, and the delegate
+	/// account and proxy type of the budgeted proxy, to the budget.
+	#[pallet::storage]
+	pub type ProxyBudgets<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		(T::AccountId, T::ProxyType),
+		ProxyBudgetOf<T>,
+	>;
+
 	#[pallet::view_functions]
 	impl<T: Config> Pallet<T> {
 		/// Check if a `RuntimeCall` is allowed for a given `ProxyType`.
@@ -788,6 +1203,19 @@ pub mod pallet {
 		pub fn is_superset(to_check: T::ProxyType, against: T::ProxyType) -> bool {
 			to_check.is_superset(&against)
 		}
+
+		/// The budget of the proxy `delegate` of type `proxy_type` over `delegator`, with the
+		/// amounts spent in the current window, if it is budgeted.
+		pub fn proxy_budget(
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+		) -> Option<ProxyBudgetOf<T>> {
+			ProxyBudgets::<T>::get(delegator, (delegate, proxy_type)).map(|mut budget| {
+				budget.roll_window(T::BlockNumberProvider::current_block_number());
+				budget
+			})
+		}
 	}
 }
 
@@ -911,6 +1339,12 @@ impl<T: Config> Pallet<T> {
 			} else if new_deposit < old_deposit {
 				T::Currency::unreserve(delegator, old_deposit - new_deposit);
 			}
+			if !proxies.iter().any(|p| p.delegate == delegatee && p.proxy_type == proxy_type) {
+				if let Some(budget) = ProxyBudgets::<T>::take(delegator, (&delegatee, &proxy_type))
+				{
+					T::Currency::unreserve(delegator, budget.deposit);
+				}
+			}
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
@@ -932,6 +1366,11 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The deposit held for a proxy budget limiting `num_assets` fungible assets.
+	pub fn budget_deposit(num_assets: u32) -> BalanceOf<T> {
+		T::BudgetDepositBase::get() + T::BudgetDepositFactor::get() * num_assets.into()
+	}
+
 	fn rejig_deposit(
 		who: &T::AccountId,
 		old_deposit: BalanceOf<T>,
@@ -961,7 +1400,7 @@ impl<T: Config> Pallet<T> {
 	>(
 		delegate: &T::AccountId,
 		f: F,
-	) -> DispatchResult {
+	) -> Result<u32, DispatchError> {
 		Announcements::<T>::try_mutate_exists(delegate, |x| {
 			let (mut pending, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
 			let orig_pending_len = pending.len();
@@ -975,7 +1414,7 @@ impl<T: Config> Pallet<T> {
 				pending.len(),
 			)?
 			.map(|deposit| (pending, deposit));
-			Ok(())
+			Ok(orig_pending_len as u32)
 		})
 	}
 
@@ -984,21 +1423,43 @@ impl<T: Config> Pallet<T> {
 		delegate: &T::AccountId,
 		force_proxy_type: Option<T::ProxyType>,
 	) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError> {
+		Self::find_proxy_and_count(real, delegate, force_proxy_type).map(|(def, _)| def)
+	}
+
+	/// Like [`Self::find_proxy`], but also returns the number of proxies of `real`.
+	fn find_proxy_and_count(
+		real: &T::AccountId,
+		delegate: &T::AccountId,
+		force_proxy_type: Option<T::ProxyType>,
+	) -> Result<(ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, u32), DispatchError>
+	{
 		let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>| -> bool {
 			&x.delegate == delegate &&
 				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
 		};
-		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
+		let proxies = Proxies::<T>::get(real).0;
+		let count = proxies.len() as u32;
+		let def = proxies.into_iter().find(f).ok_or(Error::<T>::NotProxy)?;
+		Ok((def, count))
 	}
 
+	/// Dispatch `call` from `real` through the proxy `def`, within its budget if it has one.
+	///
+	/// Returns the actual weight of the dispatch, including the cost of checking the budget.
 	fn do_proxy(
 		def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
 		real: T::AccountId,
 		call: <T as Config>::RuntimeCall,
-	) {
+	) -> Result<Weight, DispatchError> {
 		use frame::traits::{InstanceFilter as _, OriginTrait as _};
+		let budget_key = (def.delegate.clone(), def.proxy_type.clone());
+		let maybe_budget = ProxyBudgets::<T>::get(&real, &budget_key).map(|mut budget| {
+			budget.roll_window(T::BlockNumberProvider::current_block_number());
+			budget
+		});
+		let budgeted = maybe_budget.is_some();
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
@@ -1014,11 +1475,83 @@ impl<T: Config> Pallet<T> {
 				Some(Call::remove_proxies { .. }) | Some(Call::kill_pure { .. })
 					if def.proxy_type != T::ProxyType::default() =>
 					false,
+				// A budgeted proxy cannot change budgets, nor add unbudgeted proxies.
+				Some(Call::add_proxy { .. }) |
+				Some(Call::set_proxy_budget { .. }) |
+				Some(Call::remove_proxy_budget { .. })
+					if budgeted =>
+					false,
+				_ if budgeted && !T::BudgetCallFilter::contains(c) => false,
 				_ => def.proxy_type.filter(c),
 			}
 		});
-		let e = call.dispatch(origin);
+
+		let info = call.get_dispatch_info();
+		let Some(mut budget) = maybe_budget else {
+			let e = call.dispatch(origin);
+			let call_weight = extract_actual_weight(&e, &info);
+			Self::deposit_event(Event::ProxyExecuted {
+				result: e.map(|_| ()).map_err(|e| e.error),
+			});
+			// Looking for a budget is paid for even if there is none.
+			return Ok(call_weight.saturating_add(Self::budget_read_weight()))
+		};
+
+		// Spending is the larger decrease of the free and of the total balance, so that neither
+		// reserving funds nor spending funds which were unreserved by the same call escapes it.
+		let native_balances =
+			|who: &T::AccountId| (T::Currency::free_balance(who), T::Currency::total_balance(who));
+		let asset_balances = |asset: &AssetKindOf<T>, who: &T::AccountId| {
+			(T::BudgetAssets::balance(asset, who), T::BudgetAssets::total_balance(asset, who))
+		};
+		let spent = |(free_before, total_before): (BalanceOf<T>, BalanceOf<T>),
+		             (free_after, total_after): (BalanceOf<T>, BalanceOf<T>)| {
+			free_before
+				.saturating_sub(free_after)
+				.max(total_before.saturating_sub(total_after))
+		};
+
+		let native_before = native_balances(&real);
+		let assets_before = budget
+			.assets
+			.iter()
+			.map(|(asset, _)| asset_balances(asset, &real))
+			.collect::<Vec<_>>();
+		let e = with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+			let e = call.dispatch(origin);
+
+			let slot = budget.slot_index();
+			let native_spent = spent(native_before, native_balances(&real));
+			let mut within_budget = budget.native.try_spend(slot, native_spent).is_ok();
+			for ((asset, allowance), before) in budget.assets.iter_mut().zip(assets_before) {
+				let asset_spent = spent(before, asset_balances(asset, &real));
+				within_budget &= allowance.try_spend(slot, asset_spent).is_ok();
+			}
+
+			if within_budget {
+				TransactionOutcome::Commit(Ok(e))
+			} else {
+				TransactionOutcome::Rollback(Err(Error::<T>::BudgetExceeded.into()))
+			}
+		})?;
+		// Covers reading and writing the budget, as well as checking the balances.
+		let budget_weight = T::WeightInfo::budgeted_dispatch(budget.assets.len() as u32);
+		ProxyBudgets::<T>::insert(&real, budget_key, budget);
+		let call_weight = extract_actual_weight(&e, &info);
 		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
+		Ok(call_weight.saturating_add(budget_weight))
+	}
+
+	/// The weight of looking for the budget of a proxy, i.e. reading its `ProxyBudgets` entry.
+	fn budget_read_weight() -> Weight {
+		let proof_size = T::AccountId::max_encoded_len()
+			.saturating_add(<(T::AccountId, T::ProxyType)>::max_encoded_len())
+			// The `Twox64Concat` hashes of both keys.
+			.saturating_add(16)
+			.saturating_add(ProxyBudgetOf::<T>::max_encoded_len());
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(Weight::from_parts(0, proof_size as u64))
 	}
 
 	/// Removes all proxy delegates for a given delegator.
@@ -1028,5 +1561,15 @@ impl<T: Config> Pallet<T> {
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
 		T::Currency::unreserve(&delegator, old_deposit);
+		Self::remove_all_budgets(delegator);
+	}
+
+	/// Remove the budgets of all proxies of `delegator`, returning their deposits.
+	fn remove_all_budgets(delegator: &T::AccountId) {
+		let deposit = ProxyBudgets::<T>::drain_prefix(delegator)
+			.fold(BalanceOf::<T>::zero(), |total, (_, budget)| {
+				total.saturating_add(budget.deposit)
+			});
+		T::Currency::unreserve(delegator, deposit);
 	}
 }
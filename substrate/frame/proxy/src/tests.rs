@@ -22,7 +22,7 @@
 use super::*;
 use crate as proxy;
 use alloc::{vec, vec::Vec};
-use frame::testing_prelude::*;
+use frame::{deps::frame_support::weights::RuntimeDbWeight, testing_prelude::*};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -113,6 +113,8 @@ parameter_types! {
 	pub static ProxyDepositFactor: u64 = 1;
 	pub static AnnouncementDepositBase: u64 = 1;
 	pub static AnnouncementDepositFactor: u64 = 1;
+	pub static BudgetDepositBase: u64 = 1;
+	pub static BudgetDepositFactor: u64 = 1;
 }
 
 impl Config for Test {
@@ -129,6 +131,43 @@ impl Config for Test {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type BudgetAssets = TestBudgetAssets;
+	type MaxBudgetAssets = ConstU32<2>;
+	type BudgetCallFilter = TestBudgetCallFilter;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetKindFromSeed;
+}
+
+/// Budgeted proxies may not use `transfer_all`.
+pub struct TestBudgetCallFilter;
+impl Contains<RuntimeCall> for TestBudgetCallFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		!matches!(c, RuntimeCall::Balances(BalancesCall::transfer_all { .. }))
+	}
+}
+
+/// Budget assets where asset `1` mirrors the native balance and all others are empty.
+pub struct TestBudgetAssets;
+impl BudgetAssets<u64, u64> for TestBudgetAssets {
+	type AssetKind = u32;
+
+	fn balance(asset: &u32, who: &u64) -> u64 {
+		if *asset == 1 {
+			Balances::free_balance(who)
+		} else {
+			0
+		}
+	}
+
+	fn total_balance(asset: &u32, who: &u64) -> u64 {
+		if *asset == 1 {
+			Balances::total_balance(who)
+		} else {
+			0
+		}
+	}
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -482,6 +521,18 @@ fn add_remove_proxies_works() {
 	});
 }
 
+#[test]
+fn has_proxies_works() {
+	new_test_ext().execute_with(|| {
+		assert!(!HasProxies::<Test>::contains(&1));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert!(HasProxies::<Test>::contains(&1));
+		assert!(!HasProxies::<Test>::contains(&2));
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert!(!HasProxies::<Test>::contains(&1));
+	});
+}
+
 #[test]
 fn cannot_add_proxy_without_balance() {
 	new_test_ext().execute_with(|| {
@@ -838,3 +889,435 @@ fn poke_deposit_fails_for_unsigned_origin() {
 		assert_noop!(Proxy::poke_deposit(RuntimeOrigin::none()), DispatchError::BadOrigin,);
 	});
 }
+
+fn limit(per_call: Option<u64>, per_window: Option<u64>) -> SpendLimit<u64> {
+	SpendLimit { per_call, per_window }
+}
+
+#[test]
+fn set_proxy_budget_checks_budget() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+
+		assert_noop!(
+			Proxy::set_proxy_budget(
+				RuntimeOrigin::signed(1),
+				3,
+				ProxyType::Any,
+				10,
+				limit(Some(1), None),
+				vec![]
+			),
+			Error::<Test>::NotProxy
+		);
+		assert_noop!(
+			Proxy::set_proxy_budget(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::Any,
+				0,
+				limit(None, Some(1)),
+				vec![]
+			),
+			Error::<Test>::InvalidBudget
+		);
+		assert_noop!(
+			Proxy::set_proxy_budget(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::Any,
+				10,
+				limit(None, None),
+				vec![(1, limit(Some(1), None)), (1, limit(Some(2), None))]
+			),
+			Error::<Test>::InvalidBudget
+		);
+		assert_noop!(
+			Proxy::set_proxy_budget(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::Any,
+				10,
+				limit(None, None),
+				vec![(1, limit(Some(1), None)), (2, limit(None, None)), (3, limit(None, None))]
+			),
+			Error::<Test>::TooMany
+		);
+
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(2), Some(5)),
+			vec![]
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyBudgetSet { delegator: 1, delegatee: 2, proxy_type: ProxyType::Any }
+				.into(),
+		);
+		let budget = Proxy::proxy_budget(1, 2, ProxyType::Any).unwrap();
+		assert_eq!(budget.window, 10);
+		assert_eq!(
+			budget.native,
+			Allowance { limit: limit(Some(2), Some(5)), spent_per_slot: [0; BUDGET_SLOTS] }
+		);
+
+		assert_ok!(Proxy::remove_proxy_budget(RuntimeOrigin::signed(1), 2, ProxyType::Any));
+		System::assert_last_event(
+			ProxyEvent::ProxyBudgetRemoved {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+			}
+			.into(),
+		);
+		assert!(Proxy::proxy_budget(1, 2, ProxyType::Any).is_none());
+		assert_noop!(
+			Proxy::remove_proxy_budget(RuntimeOrigin::signed(1), 2, ProxyType::Any),
+			Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn budget_limits_spending_per_call() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 20);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(3), None),
+			vec![]
+		));
+
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 4))),
+			Error::<Test>::BudgetExceeded
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 3);
+
+		// The effects of a batch are measured as a whole.
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![call_transfer(6, 2), call_transfer(6, 2)],
+		}));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch),
+			Error::<Test>::BudgetExceeded
+		);
+
+		// Receiving funds is not spending.
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), 1, 5));
+		let call = Box::new(RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![call_transfer(6, 1)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call));
+		assert_eq!(Balances::free_balance(6), 4);
+	});
+}
+
+#[test]
+fn budget_limits_spending_per_window() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::JustTransfer, 0));
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::JustTransfer,
+			10,
+			limit(None, Some(5)),
+			vec![]
+		));
+
+		let spent = || Proxy::proxy_budget(1, 2, ProxyType::JustTransfer).unwrap().native.spent();
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		assert_eq!(spent(), 3);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))),
+			Error::<Test>::BudgetExceeded
+		);
+
+		System::set_block_number(8);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		assert_eq!(spent(), 5);
+
+		// The window rolls: what was spent at block 8 still counts 10 blocks after block 1.
+		System::set_block_number(11);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 1))),
+			Error::<Test>::BudgetExceeded
+		);
+
+		// Slots are 2 blocks long, the one of block 1 has left the window by block 16.
+		System::set_block_number(16);
+		assert_eq!(spent(), 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), 1, 5));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 1))),
+			Error::<Test>::BudgetExceeded
+		);
+
+		// Nothing was spent in the last window.
+		System::set_block_number(40);
+		assert_eq!(spent(), 0);
+		assert_eq!(Balances::free_balance(6), 8);
+	});
+}
+
+#[test]
+fn budget_limits_asset_spending() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(None, None),
+			vec![(1, limit(Some(2), None)), (2, limit(Some(0), None))]
+		));
+
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))),
+			Error::<Test>::BudgetExceeded
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		let budget = Proxy::proxy_budget(1, 2, ProxyType::Any).unwrap();
+		assert_eq!(budget.assets[0].1.spent(), 2);
+		assert_eq!(budget.assets[1].1.spent(), 0);
+	});
+}
+
+#[test]
+fn budgeted_proxy_cannot_escape_its_budget() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(1), None),
+			vec![]
+		));
+
+		let calls = [
+			RuntimeCall::Proxy(ProxyCall::remove_proxy_budget {
+				delegate: 2,
+				proxy_type: ProxyType::Any,
+			}),
+			RuntimeCall::Proxy(ProxyCall::set_proxy_budget {
+				delegate: 2,
+				proxy_type: ProxyType::Any,
+				window: 10,
+				native: limit(None, None),
+				assets: vec![],
+			}),
+			RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: 3,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}),
+		];
+		for call in calls {
+			assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call)));
+			System::assert_last_event(
+				ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+			);
+		}
+		assert!(Proxy::proxy_budget(1, 2, ProxyType::Any).is_some());
+		assert_eq!(Proxies::<Test>::get(1).0.len(), 1);
+	});
+}
+
+#[test]
+fn removing_proxy_removes_its_budget() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 1));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		for delegate in [2, 3] {
+			assert_ok!(Proxy::set_proxy_budget(
+				RuntimeOrigin::signed(1),
+				delegate,
+				ProxyType::Any,
+				10,
+				limit(Some(1), None),
+				vec![]
+			));
+		}
+
+		// The budget applies to the remaining proxy with another delay.
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert!(Proxy::proxy_budget(1, 2, ProxyType::Any).is_some());
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 1));
+		assert!(Proxy::proxy_budget(1, 2, ProxyType::Any).is_none());
+
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert!(Proxy::proxy_budget(1, 3, ProxyType::Any).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn proxy_budget_holds_a_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(1), None),
+			vec![(1, limit(Some(1), None)), (2, limit(Some(1), None))]
+		));
+		assert_eq!(Proxy::proxy_budget(1, 2, ProxyType::Any).unwrap().deposit, 3);
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		// Replacing the budget adjusts the deposit.
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(1), None),
+			vec![]
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_ok!(Proxy::remove_proxy_budget(RuntimeOrigin::signed(1), 2, ProxyType::Any));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// The deposit must be affordable.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(5), 2, ProxyType::Any, 0));
+		assert_noop!(
+			Proxy::set_proxy_budget(
+				RuntimeOrigin::signed(5),
+				2,
+				ProxyType::Any,
+				10,
+				limit(Some(1), None),
+				vec![(1, limit(Some(1), None))]
+			),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn budget_counts_reserved_and_unreserved_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(1), None),
+			vec![]
+		));
+
+		// Reserving funds is spending them.
+		let create_pure = Box::new(RuntimeCall::Proxy(ProxyCall::create_pure {
+			proxy_type: ProxyType::Any,
+			delay: 0,
+			index: 0,
+		}));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, create_pure),
+			Error::<Test>::BudgetExceeded
+		);
+
+		// Funds unreserved by the same call do not offset spending.
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![
+				RuntimeCall::Proxy(ProxyCall::remove_proxy {
+					delegate: 3,
+					proxy_type: ProxyType::Any,
+					delay: 0,
+				}),
+				call_transfer(6, 2),
+			],
+		}));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch),
+			Error::<Test>::BudgetExceeded
+		);
+	});
+}
+
+#[test]
+fn budgeted_proxy_is_limited_to_budget_call_filter() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		let transfer_all =
+			RuntimeCall::Balances(BalancesCall::transfer_all { dest: 6, keep_alive: false });
+
+		// Without a budget, the call is only subject to the proxy type.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(transfer_all.clone())));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), 1, 5));
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(None, None),
+			vec![]
+		));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(transfer_all)));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+	});
+}
+
+#[test]
+fn proxy_returns_actual_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		let call = Box::new(call_transfer(6, 1));
+		let call_weight = call.get_dispatch_info().call_weight;
+		let proxy_weight = <() as WeightInfo>::proxy(1)
+			.saturating_add(
+				<<Test as frame_system::Config>::DbWeight as Get<RuntimeDbWeight>>::get()
+					.reads_writes(1, 1),
+			)
+			.saturating_add(call_weight);
+
+		// Looking for a budget is paid for even if there is none.
+		let post_info = Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(proxy_weight.saturating_add(Proxy::budget_read_weight()))
+		);
+		assert!(Proxy::budget_read_weight().proof_size() > 0);
+
+		// Budgeted proxies pay for checking and updating their budget.
+		assert_ok!(Proxy::set_proxy_budget(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			10,
+			limit(Some(5), None),
+			vec![(1, limit(None, None))]
+		));
+		let post_info = Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(proxy_weight.saturating_add(<() as WeightInfo>::budgeted_dispatch(1)))
+		);
+	});
+}
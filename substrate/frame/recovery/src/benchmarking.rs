@@ -124,6 +124,37 @@ fn setup_active_recovery_with_max_friends<T: Config>(
 	<ActiveRecoveries<T>>::insert(lost_account, caller, active_recovery);
 }
 
+fn insert_inheritance_config<T: Config>(
+	account: &T::AccountId,
+	heirs: Vec<T::AccountId>,
+	claims: bool,
+) {
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value());
+
+	let bounded_heirs: FriendsOf<T> = heirs.try_into().unwrap();
+
+	// Get deposit for the inheritance
+	let total_deposit = get_total_deposit::<T>(&bounded_heirs).unwrap();
+	T::Currency::reserve(&account, total_deposit).unwrap();
+
+	if claims {
+		for heir in bounded_heirs.iter() {
+			let claim = InheritanceClaim {
+				created: DEFAULT_DELAY.into(),
+				nonce: frame_system::Pallet::<T>::account_nonce(account),
+			};
+			<InheritanceClaims<T>>::insert(account, heir, claim);
+		}
+	}
+
+	let config = InheritanceConfig {
+		inactivity_period: T::MinInactivityPeriod::get(),
+		deposit: total_deposit,
+		heirs: bounded_heirs,
+	};
+	<Inheritance<T>>::insert(&account, config);
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -137,9 +168,13 @@ mod benchmarks {
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
 
 		Proxy::<T>::insert(&caller, &recovered_account);
+		// The use of an account with heirs is recorded.
+		insert_inheritance_config::<T>(&recovered_account, generate_friends::<T>(1), false);
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), recovered_account_lookup, Box::new(call))
+		_(RawOrigin::Signed(caller), recovered_account_lookup, Box::new(call));
+
+		assert!(<LastActive<T>>::contains_key(&recovered_account));
 	}
 
 	#[benchmark]
@@ -465,5 +500,79 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn edit_recovery(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		insert_recovery_config_with_max_friends::<T>(&caller);
+		let friends = generate_friends::<T>(n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), friends.clone(), n as u16);
+
+		assert_eq!(<Recoverable<T>>::get(&caller).unwrap().friends.to_vec(), friends);
+		assert_last_event::<T>(Event::RecoveryEdited { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn set_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Replace the heirs with the most claims to reset.
+		insert_inheritance_config::<T>(&caller, generate_friends::<T>(n), true);
+		let mut heirs = (0..n).map(|x| account("heir", x, SEED)).collect::<Vec<T::AccountId>>();
+		heirs.sort();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), heirs, T::MinInactivityPeriod::get());
+
+		assert_eq!(<InheritanceClaims<T>>::iter_prefix(&caller).count(), 0);
+		assert_last_event::<T>(Event::InheritanceSet { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn remove_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		insert_inheritance_config::<T>(&caller, generate_friends::<T>(n), true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!<Inheritance<T>>::contains_key(&caller));
+		assert_last_event::<T>(Event::InheritanceRemoved { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn initiate_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+
+		let heirs = add_caller_and_generate_friends::<T>(caller.clone(), n);
+		insert_inheritance_config::<T>(&account, heirs, false);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_lookup);
+
+		assert_last_event::<T>(Event::InheritanceInitiated { account, heir: caller }.into());
+	}
+
+	#[benchmark]
+	fn claim_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+
+		let heirs = add_caller_and_generate_friends::<T>(caller.clone(), n);
+		insert_inheritance_config::<T>(&account, heirs, true);
+		T::BlockNumberProvider::set_block_number(T::MinInactivityPeriod::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_lookup);
+
+		assert_last_event::<T>(Event::AccountInherited { account, heir: caller }.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -40,6 +40,9 @@
 //! * `delay_period` - The minimum number of blocks after the beginning of the recovery process that
 //!   need to pass before the account can be successfully recovered.
 //!
+//! The friends and the threshold of an existing configuration can be changed in place with
+//! `edit_recovery`, as long as no recovery process is active for the account.
+//!
 //! There is a configurable deposit that all users need to pay to create a recovery
 //! configuration. This deposit is composed of a base deposit plus a multiplier for
 //! the number of friends chosen. This deposit is returned in full when the account
@@ -73,6 +76,22 @@
 //! 10. When the recovered account becomes reaped (i.e. its free and reserved balance drops to
 //!     zero), the final recovery link is removed.
 //!
+//! ### Inheritance
+//!
+//! Independently of its recovery configuration, an account may designate `heirs` which are able
+//! to gain access to it once it has stopped being used, without any friend vouching for them:
+//! 1. The account owner calls `set_inheritance` with the list of heirs and an `inactivity_period`.
+//! 2. When the owner appears to be gone, one of the heirs calls `initiate_inheritance`.
+//! 3. If the account is not used during the following `inactivity_period` blocks, the heir is able
+//!    to call `claim_inheritance`, which allows them to call `as_recovered` on behalf of the
+//!    account.
+//!
+//! The account is used when it sends a transaction, which is told from its nonce, or when a call
+//! is dispatched on its behalf through `as_recovered`, which is recorded for accounts with heirs.
+//! Calls dispatched on behalf of the account by other pallets, such as proxy and multisig, are not
+//! recorded. Instead, an account which may be used this way, as told by [`Config::Delegated`],
+//! can't be claimed. The `inactivity_period` is at least [`Config::MinInactivityPeriod`].
+//!
 //! ### Malicious Recovery Attempts
 //!
 //! Initializing the recovery process for a recoverable account is open and
@@ -123,11 +142,19 @@
 //!
 //! * `create_recovery` - Create a recovery configuration for your account and make it recoverable.
 //! * `initiate_recovery` - Start the recovery process for a recoverable account.
+//! * `set_inheritance` - Designate the heirs of your account and the period of inactivity after
+//!   which they may claim it.
 //!
 //! #### For Friends of a Recoverable Account
 //! * `vouch_recovery` - As a `friend` of a recoverable account, vouch for a recovery attempt on the
 //!   account.
 //!
+//! #### For Heirs of an Account
+//! * `initiate_inheritance` - As an `heir` of an account, start the inactivity period after which
+//!   you may claim the account.
+//! * `claim_inheritance` - Claim access to an account which has been inactive since you initiated
+//!   the inheritance.
+//!
 //! #### For a User Who Successfully Recovered an Account
 //!
 //! * `claim_recovery` - Claim access to the account that you have successfully completed the
@@ -141,6 +168,9 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `edit_recovery` - Change the friends and the threshold of the recovery configuration of the
+//!   account.
+//! * `remove_inheritance` - Remove the heirs of the account.
 //!
 //! #### For Super Users
 //!
@@ -178,6 +208,10 @@ pub type BlockNumberFromProviderOf<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 pub type FriendsOf<T> =
 	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxFriends>;
+pub type InheritanceConfigOf<T> =
+	InheritanceConfig<BlockNumberFromProviderOf<T>, BalanceOf<T>, FriendsOf<T>>;
+pub type InheritanceClaimOf<T> =
+	InheritanceClaim<BlockNumberFromProviderOf<T>, <T as frame_system::Config>::Nonce>;
 
 /// An active recovery process.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -206,6 +240,29 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	pub threshold: u16,
 }
 
+/// Configuration for inheriting an account once it is no longer used.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceConfig<BlockNumber, Balance, Heirs> {
+	/// The minimum number of blocks the account must be inactive for after an heir initiated the
+	/// inheritance before the heir can claim it.
+	pub inactivity_period: BlockNumber,
+	/// The amount held in reserve of the `depositor`,
+	/// to be returned once this configuration is removed.
+	pub deposit: Balance,
+	/// The list of heirs which can claim the account. Always sorted.
+	pub heirs: Heirs,
+}
+
+/// A claim of an heir on an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceClaim<BlockNumber, Nonce> {
+	/// The block number when the claim was initiated.
+	pub created: BlockNumber,
+	/// The nonce of the account when the claim was initiated. The claim fails if the account has
+	/// sent any transaction since.
+	pub nonce: Nonce,
+}
+
 /// The type of deposit
 #[derive(
 	Clone,
@@ -308,6 +365,20 @@ pub mod pallet {
 		/// threshold.
 		#[pallet::constant]
 		type RecoveryDeposit: Get<BalanceOf<Self>>;
+
+		/// The minimum number of blocks an account must be inactive for before its heirs can
+		/// claim it.
+		///
+		/// This should be long enough for the owner of the account to notice an initiated
+		/// inheritance and use their account.
+		#[pallet::constant]
+		type MinInactivityPeriod: Get<BlockNumberFromProviderOf<Self>>;
+
+		/// The accounts which may be used without sending any transaction, e.g. through proxies or
+		/// multisig operations.
+		///
+		/// Their activity can't be told, so they can't be claimed by their heirs.
+		type Delegated: Contains<Self::AccountId>;
 	}
 
 	/// Events type.
@@ -330,6 +401,16 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// The recovery configuration of an account has been changed.
+		RecoveryEdited { account: T::AccountId },
+		/// The heirs of an account have been set.
+		InheritanceSet { account: T::AccountId },
+		/// The heirs of an account have been removed.
+		InheritanceRemoved { account: T::AccountId },
+		/// An heir has initiated the inheritance of an account.
+		InheritanceInitiated { account: T::AccountId, heir: T::AccountId },
+		/// An account has been inherited by an heir.
+		AccountInherited { account: T::AccountId, heir: T::AccountId },
 		/// A deposit has been updated.
 		DepositPoked {
 			who: T::AccountId,
@@ -373,6 +454,18 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// This account has no heirs
+		NoHeirs,
+		/// This account is not an heir of the account
+		NotHeir,
+		/// The heir must wait until the inactivity period has passed to claim the account
+		InactivityPeriod,
+		/// The account has been used since the inheritance was initiated
+		AccountActive,
+		/// The inactivity period is shorter than `MinInactivityPeriod`
+		InactivityPeriodTooShort,
+		/// The account may be used on behalf of its owner, e.g. through a proxy or multisig
+		AccountDelegated,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -407,6 +500,31 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// The heirs of accounts and the period of inactivity after which they may claim them.
+	#[pallet::storage]
+	pub type Inheritance<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, InheritanceConfigOf<T>>;
+
+	/// Initiated inheritances.
+	///
+	/// First account is the account to be inherited, and the second account is the heir who
+	/// initiated the inheritance.
+	#[pallet::storage]
+	pub type InheritanceClaims<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		InheritanceClaimOf<T>,
+	>;
+
+	/// The last block in which a call was dispatched on behalf of an account with heirs through
+	/// `as_recovered`.
+	#[pallet::storage]
+	pub type LastActive<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFromProviderOf<T>>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -434,6 +552,7 @@ pub mod pallet {
 			// Check `who` is allowed to make a call on behalf of `account`
 			let target = Self::proxy(&who).ok_or(Error::<T>::NotAllowed)?;
 			ensure!(target == account, Error::<T>::NotAllowed);
+			Self::note_active(&account);
 			call.dispatch(frame_system::RawOrigin::Signed(account).into())
 				.map(|_| ())
 				.map_err(|e| e.error)
@@ -494,12 +613,7 @@ pub mod pallet {
 			// Check account is not already set up for recovery
 			ensure!(!<Recoverable<T>>::contains_key(&who), Error::<T>::AlreadyRecoverable);
 			// Check user input is valid
-			ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
-			ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
-			ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
-			let bounded_friends: FriendsOf<T> =
-				friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
-			ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
+			let bounded_friends = Self::ensure_valid_friends(friends, threshold)?;
 			// Calculate total deposit required
 			let total_deposit = Self::get_recovery_config_deposit(bounded_friends.len())?;
 			// Reserve the deposit
@@ -786,6 +900,183 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No } else { Pays::Yes }.into())
 		}
+
+		/// Change the friends and the threshold of the recovery configuration of your account.
+		///
+		/// Payment: The deposit reserved for the recovery configuration is adjusted to
+		/// `ConfigDepositBase` + `FriendDepositFactor` * #_of_friends.
+		///
+		/// NOTE: The user must make sure to call `close_recovery` on all active
+		/// recovery attempts before calling this function else it will fail.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `friends`: The new list of friends. Should be ordered and contain no duplicate values.
+		/// - `threshold`: The new number of friends that must vouch for a recovery attempt.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::edit_recovery(friends.len() as u32))]
+		pub fn edit_recovery(
+			origin: OriginFor<T>,
+			friends: Vec<T::AccountId>,
+			threshold: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Vouches of former friends must not count towards a recovery.
+			let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&who);
+			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
+			let bounded_friends = Self::ensure_valid_friends(friends, threshold)?;
+			<Recoverable<T>>::try_mutate(&who, |maybe_config| -> DispatchResult {
+				let config = maybe_config.as_mut().ok_or(Error::<T>::NotRecoverable)?;
+				let new_deposit = Self::get_recovery_config_deposit(bounded_friends.len())?;
+				Self::update_deposit(&who, config.deposit, new_deposit)?;
+				config.deposit = new_deposit;
+				config.friends = bounded_friends;
+				config.threshold = threshold;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::RecoveryEdited { account: who });
+			Ok(())
+		}
+
+		/// Designate the heirs of your account, which may claim it once it has not been used for
+		/// `inactivity_period` blocks. Replaces any heirs set before and resets any inheritance
+		/// initiated by them.
+		///
+		/// Payment: `ConfigDepositBase` + `FriendDepositFactor` * #_of_heirs balance
+		/// will be reserved for storing the inheritance configuration. This deposit is returned
+		/// in full when the user calls `remove_inheritance`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `heirs`: A list of accounts which may claim your account. Should be ordered and
+		///   contain no duplicate values.
+		/// - `inactivity_period`: The number of blocks after an inheritance is initiated during
+		///   which your account must not be used for an heir to claim it. At least
+		///   `MinInactivityPeriod`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_inheritance(heirs.len() as u32))]
+		pub fn set_inheritance(
+			origin: OriginFor<T>,
+			heirs: Vec<T::AccountId>,
+			inactivity_period: BlockNumberFromProviderOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!heirs.is_empty(), Error::<T>::NotEnoughFriends);
+			let heirs: FriendsOf<T> = heirs.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+			ensure!(Self::is_sorted_and_unique(&heirs), Error::<T>::NotSorted);
+			ensure!(
+				inactivity_period >= T::MinInactivityPeriod::get(),
+				Error::<T>::InactivityPeriodTooShort
+			);
+			let new_deposit = Self::get_recovery_config_deposit(heirs.len())?;
+			let old_deposit = <Inheritance<T>>::get(&who).map_or(Zero::zero(), |c| c.deposit);
+			Self::update_deposit(&who, old_deposit, new_deposit)?;
+			let _ = <InheritanceClaims<T>>::clear_prefix(&who, T::MaxFriends::get(), None);
+			<Inheritance<T>>::insert(
+				&who,
+				InheritanceConfig { inactivity_period, deposit: new_deposit, heirs },
+			);
+
+			Self::deposit_event(Event::<T>::InheritanceSet { account: who });
+			Ok(())
+		}
+
+		/// Remove the heirs of your account, along with any inheritance initiated by them.
+		///
+		/// Payment: By calling this function the account will unreserve the deposit of its
+		/// inheritance configuration.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an account with heirs.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_inheritance(T::MaxFriends::get()))]
+		pub fn remove_inheritance(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let config = <Inheritance<T>>::take(&who).ok_or(Error::<T>::NoHeirs)?;
+			let _ = <InheritanceClaims<T>>::clear_prefix(&who, T::MaxFriends::get(), None);
+			<LastActive<T>>::remove(&who);
+
+			T::Currency::unreserve(&who, config.deposit);
+			Self::deposit_event(Event::<T>::InheritanceRemoved { account: who });
+			Ok(())
+		}
+
+		/// As an heir of an account, start the period of inactivity after which you may claim
+		/// the account. Initiating it again restarts the period.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an heir of the account.
+		///
+		/// Parameters:
+		/// - `account`: The account you want to inherit.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::initiate_inheritance(T::MaxFriends::get()))]
+		pub fn initiate_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let config = <Inheritance<T>>::get(&account).ok_or(Error::<T>::NoHeirs)?;
+			ensure!(Self::is_friend(&config.heirs, &who), Error::<T>::NotHeir);
+			let claim = InheritanceClaim {
+				created: T::BlockNumberProvider::current_block_number(),
+				nonce: frame_system::Pallet::<T>::account_nonce(&account),
+			};
+			<InheritanceClaims<T>>::insert(&account, &who, claim);
+
+			Self::deposit_event(Event::<T>::InheritanceInitiated { account, heir: who });
+			Ok(())
+		}
+
+		/// Claim access to an account which has not been used since you initiated its inheritance,
+		/// `inactivity_period` or more blocks ago.
+		///
+		/// Accounts which may be used on behalf of their owner, as told by `Config::Delegated`,
+		/// can't be claimed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an heir of the account
+		/// who has initiated its inheritance.
+		///
+		/// Parameters:
+		/// - `account`: The account you want to claim.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::claim_inheritance(T::MaxFriends::get()))]
+		pub fn claim_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let config = <Inheritance<T>>::get(&account).ok_or(Error::<T>::NoHeirs)?;
+			ensure!(Self::is_friend(&config.heirs, &who), Error::<T>::NotHeir);
+			let claim =
+				<InheritanceClaims<T>>::get(&account, &who).ok_or(Error::<T>::NotStarted)?;
+			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
+			// Make sure the inactivity period has passed
+			let claimable_block_number = claim
+				.created
+				.checked_add(&config.inactivity_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				claimable_block_number <= T::BlockNumberProvider::current_block_number(),
+				Error::<T>::InactivityPeriod
+			);
+			// Make sure the account has not been used in the meantime
+			ensure!(
+				frame_system::Pallet::<T>::account_nonce(&account) == claim.nonce &&
+					<LastActive<T>>::get(&account).map_or(true, |last| last < claim.created),
+				Error::<T>::AccountActive
+			);
+			ensure!(!T::Delegated::contains(&account), Error::<T>::AccountDelegated);
+			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
+			<InheritanceClaims<T>>::remove(&account, &who);
+			Proxy::<T>::insert(&who, &account);
+			Self::deposit_event(Event::<T>::AccountInherited { account, heir: who });
+			Ok(())
+		}
 	}
 }
 
@@ -795,6 +1086,20 @@ impl<T: Config> Pallet<T> {
 		friends.windows(2).all(|w| w[0] < w[1])
 	}
 
+	/// Check that `friends` and `threshold` make up a valid recovery configuration.
+	fn ensure_valid_friends(
+		friends: Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<FriendsOf<T>, DispatchError> {
+		ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
+		ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
+		ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
+		let bounded_friends: FriendsOf<T> =
+			friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+		ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
+		Ok(bounded_friends)
+	}
+
 	/// Check that a user is a friend in the friends list.
 	fn is_friend(friends: &Vec<T::AccountId>, friend: &T::AccountId) -> bool {
 		friends.binary_search(&friend).is_ok()
@@ -811,6 +1116,28 @@ impl<T: Config> Pallet<T> {
 			.ok_or(ArithmeticError::Overflow.into())
 	}
 
+	/// Reserve or unreserve the difference between the `old_deposit` and the `new_deposit` of
+	/// `who`.
+	fn update_deposit(
+		who: &T::AccountId,
+		old_deposit: BalanceOf<T>,
+		new_deposit: BalanceOf<T>,
+	) -> DispatchResult {
+		if new_deposit > old_deposit {
+			T::Currency::reserve(who, new_deposit.saturating_sub(old_deposit))?;
+		} else {
+			let excess = old_deposit.saturating_sub(new_deposit);
+			let remaining_unreserved = T::Currency::unreserve(who, excess);
+			if !remaining_unreserved.is_zero() {
+				defensive!(
+					"Failed to unreserve full amount. (Requested, Actual)",
+					(excess, excess.saturating_sub(remaining_unreserved))
+				);
+			}
+		}
+		Ok(())
+	}
+
 	/// Helper function to poke the deposit reserved for creating a recovery config
 	fn poke_recovery_config_deposit(who: &T::AccountId) -> Result<bool, DispatchError> {
 		<Recoverable<T>>::try_mutate(&who, |maybe_config| -> Result<bool, DispatchError> {
@@ -891,4 +1218,11 @@ impl<T: Config> Pallet<T> {
 			},
 		)
 	}
+
+	/// Record that a call is dispatched on behalf of `who`, if it has heirs.
+	fn note_active(who: &T::AccountId) {
+		if <Inheritance<T>>::contains_key(who) {
+			<LastActive<T>>::insert(who, T::BlockNumberProvider::current_block_number());
+		}
+	}
 }
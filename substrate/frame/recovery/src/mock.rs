@@ -30,6 +30,8 @@ construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Recovery: recovery,
+		Proxies: pallet_proxy,
+		Multisig: pallet_multisig,
 	}
 );
 
@@ -56,6 +58,7 @@ parameter_types! {
 	pub static RecoveryDeposit: u64 = 10;
 	// Large number of friends for benchmarking.
 	pub const MaxFriends: u32 = 128;
+	pub const MinInactivityPeriod: u64 = 5;
 }
 
 impl Config for Test {
@@ -68,6 +71,42 @@ impl Config for Test {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type MinInactivityPeriod = MinInactivityPeriod;
+	type Delegated = (pallet_proxy::HasProxies<Test>, pallet_multisig::HasMultisigOperations<Test>);
+}
+
+impl pallet_proxy::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ();
+	type ProxyDepositBase = ConstU128<1>;
+	type ProxyDepositFactor = ConstU128<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type MaxPending = ConstU32<2>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ConstU128<1>;
+	type AnnouncementDepositFactor = ConstU128<1>;
+	type BlockNumberProvider = System;
+	type BudgetAssets = ();
+	type MaxBudgetAssets = ConstU32<0>;
+	type BudgetCallFilter = Everything;
+	type BudgetDepositBase = ConstU128<1>;
+	type BudgetDepositFactor = ConstU128<1>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl pallet_multisig::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = ConstU128<1>;
+	type DepositFactor = ConstU128<1>;
+	type MaxSignatories = ConstU32<3>;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
 }
 
 pub type BalancesCall = pallet_balances::Call<Test>;
@@ -491,6 +491,256 @@ fn remove_recovery_works() {
 	});
 }
 
+#[test]
+fn edit_recovery_works() {
+	new_test_ext().execute_with(|| {
+		// Cannot edit an unrecoverable account
+		assert_noop!(
+			Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![2, 3], 2),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_eq!(Balances::reserved_balance(5), 13);
+		// Cannot edit a recovery when there are active recoveries.
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_noop!(
+			Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![2, 3], 2),
+			Error::<Test>::StillActive
+		);
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		// The new configuration must be valid
+		assert_noop!(
+			Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![2, 3], 0),
+			Error::<Test>::ZeroThreshold
+		);
+		assert_noop!(
+			Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![2, 3], 3),
+			Error::<Test>::NotEnoughFriends
+		);
+		assert_noop!(
+			Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![3, 2], 2),
+			Error::<Test>::NotSorted
+		);
+		// More friends, more deposit
+		assert_ok!(Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4, 6], 2));
+		System::assert_last_event(Event::<Test>::RecoveryEdited { account: 5 }.into());
+		assert_eq!(
+			Recovery::recovery_config(5),
+			Some(RecoveryConfig {
+				delay_period: 10,
+				deposit: 14,
+				friends: bounded_vec![2, 3, 4, 6],
+				threshold: 2,
+			})
+		);
+		assert_eq!(Balances::reserved_balance(5), 14);
+		// Fewer friends, less deposit
+		assert_ok!(Recovery::edit_recovery(RuntimeOrigin::signed(5), vec![4], 1));
+		assert_eq!(Balances::reserved_balance(5), 11);
+		// The new friends can recover the account
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_noop!(
+			Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1),
+			Error::<Test>::NotFriend
+		);
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+	});
+}
+
+#[test]
+fn inheritance_life_cycle_works() {
+	new_test_ext().execute_with(|| {
+		// Account 5 designates its heirs
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10));
+		assert_eq!(Balances::reserved_balance(5), 12);
+		// Account 1 believes account 5 is gone and initiates the inheritance
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(
+			Event::<Test>::InheritanceInitiated { account: 5, heir: 1 }.into(),
+		);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+		// Account 5 sends a transaction in the meantime
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		frame_system::Pallet::<Test>::inc_account_nonce(5);
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AccountActive
+		);
+		// Account 5 is gone for good now
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::run_to_block::<AllPalletsWithSystem>(20);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+		System::run_to_block::<AllPalletsWithSystem>(21);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(Event::<Test>::AccountInherited { account: 5, heir: 1 }.into());
+		assert_eq!(<Proxy<Test>>::get(&1), Some(5));
+		assert_eq!(<InheritanceClaims<Test>>::get(5, 1), None);
+		// Account 1 can use account 5 to remove the heirs and take all of its funds
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::remove_inheritance {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::free_balance(5), 100);
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+			dest: 1,
+			value: 100,
+		}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::free_balance(1), 200);
+	});
+}
+
+#[test]
+fn inheritance_accounts_for_as_recovered_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10));
+		// Account 3 uses account 5 without it sending a transaction
+		assert_ok!(Recovery::set_recovered(RuntimeOrigin::root(), 5, 3));
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(3), 5, call));
+		assert_eq!(<LastActive<Test>>::get(5), Some(5));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AccountActive
+		);
+		// Uses before the inheritance was initiated do not matter
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::run_to_block::<AllPalletsWithSystem>(21);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		// Only the use of accounts with heirs is recorded
+		assert_ok!(Recovery::set_recovered(RuntimeOrigin::root(), 4, 3));
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(3), 4, call));
+		assert_eq!(<LastActive<Test>>::get(4), None);
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::remove_inheritance {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(<LastActive<Test>>::get(5), None);
+	});
+}
+
+#[test]
+fn inheritance_refuses_accounts_with_proxies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10));
+		// Account 3 is able to use account 5 without it sending a transaction
+		assert_ok!(Proxies::add_proxy(RuntimeOrigin::signed(5), 3, (), 0));
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AccountDelegated
+		);
+		assert_ok!(Proxies::remove_proxies(RuntimeOrigin::signed(5)));
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+	});
+}
+
+#[test]
+fn inheritance_refuses_accounts_with_multisig_operations() {
+	new_test_ext().execute_with(|| {
+		let multi = pallet_multisig::Pallet::<Test>::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 20));
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(multi), vec![4, 5], 10));
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(4), multi));
+		// The signatories are about to use the account without it sending a transaction
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }));
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(4), multi),
+			Error::<Test>::AccountDelegated
+		);
+		let timepoint = pallet_multisig::Timepoint { height: 1, index: 0 };
+		assert_ok!(Multisig::cancel_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			timepoint,
+			blake2_256(&call.encode())
+		));
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(4), multi));
+	});
+}
+
+#[test]
+fn inheritance_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![], 10),
+			Error::<Test>::NotEnoughFriends
+		);
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![2, 1], 10),
+			Error::<Test>::NotSorted
+		);
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 4),
+			Error::<Test>::InactivityPeriodTooShort
+		);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NoHeirs
+		);
+		assert_noop!(
+			Recovery::remove_inheritance(RuntimeOrigin::signed(5)),
+			Error::<Test>::NoHeirs
+		);
+
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10));
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(3), 5),
+			Error::<Test>::NotHeir
+		);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotStarted
+		);
+		// Changing the heirs resets the initiated inheritances
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2, 3], 10));
+		assert_eq!(Balances::reserved_balance(5), 13);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotStarted
+		);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![2], 10));
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotHeir
+		);
+		// Heirs already able to act on behalf of an account cannot claim another one
+		assert_ok!(Recovery::set_recovered(RuntimeOrigin::root(), 4, 2));
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(2), 5));
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::AlreadyProxy
+		);
+
+		assert_ok!(Recovery::remove_inheritance(RuntimeOrigin::signed(5)));
+		System::assert_last_event(Event::<Test>::InheritanceRemoved { account: 5 }.into());
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(<InheritanceClaims<Test>>::iter_prefix(5).count(), 0);
+	});
+}
+
 #[test]
 fn poke_deposit_handles_unsigned_origin() {
 	new_test_ext().execute_with(|| {
@@ -80,6 +80,11 @@ pub trait WeightInfo {
 	fn remove_recovery(n: u32, ) -> Weight;
 	fn cancel_recovered() -> Weight;
 	fn poke_deposit(n: u32, ) -> Weight;
+	fn edit_recovery(n: u32, ) -> Weight;
+	fn set_inheritance(n: u32, ) -> Weight;
+	fn remove_inheritance(n: u32, ) -> Weight;
+	fn initiate_inheritance(n: u32, ) -> Weight;
+	fn claim_inheritance(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_recovery` using the Substrate node and recommended hardware.
@@ -91,13 +96,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritance` (r:1 w:0)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `530`
+		//  Measured:  `623`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_724_000 picoseconds.
-		Weight::from_parts(19_503_000, 3997)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_340_000 picoseconds.
+		Weight::from_parts(23_473_000, 3997)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -227,6 +237,101 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn edit_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `374 + n * (32 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 31_215_000 picoseconds.
+		Weight::from_parts(32_804_117, 3854)
+			// Standard Error: 9_013
+			.saturating_add(Weight::from_parts(241_836, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:1)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:9 w:9)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn set_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130 + n * (87 ±0)`
+		//  Estimated: `3814`
+		// Minimum execution time: 19_781_000 picoseconds.
+		Weight::from_parts(27_701_534, 3814)
+			// Standard Error: 67_481
+			.saturating_add(Weight::from_parts(109_811, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:1)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:9 w:9)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130 + n * (87 ±0)`
+		//  Estimated: `3814`
+		// Minimum execution time: 22_518_000 picoseconds.
+		Weight::from_parts(30_124_107, 3814)
+			// Standard Error: 95_765
+			.saturating_add(Weight::from_parts(1_787_361, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:0)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:0 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn initiate_inheritance(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `157 + n * (37 ±0)`
+		//  Estimated: `3814`
+		// Minimum execution time: 13_404_000 picoseconds.
+		Weight::from_parts(18_109_867, 3814)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:0)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:0)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3260), added: 5735, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_inheritance(n: u32, ) -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(52_144_333, 19086)
+			// Standard Error: 46_209
+			.saturating_add(Weight::from_parts(2_238_162, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -237,13 +342,18 @@ impl WeightInfo for () {
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritance` (r:1 w:0)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `530`
+		//  Measured:  `623`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_724_000 picoseconds.
-		Weight::from_parts(19_503_000, 3997)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_340_000 picoseconds.
+		Weight::from_parts(23_473_000, 3997)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -373,4 +483,99 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn edit_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `374 + n * (32 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 31_215_000 picoseconds.
+		Weight::from_parts(32_804_117, 3854)
+			// Standard Error: 9_013
+			.saturating_add(Weight::from_parts(241_836, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:1)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:9 w:9)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn set_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130 + n * (87 ±0)`
+		//  Estimated: `3814`
+		// Minimum execution time: 19_781_000 picoseconds.
+		Weight::from_parts(27_701_534, 3814)
+			// Standard Error: 67_481
+			.saturating_add(Weight::from_parts(109_811, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:1)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:9 w:9)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130 + n * (87 ±0)`
+		//  Estimated: `3814`
+		// Minimum execution time: 22_518_000 picoseconds.
+		Weight::from_parts(30_124_107, 3814)
+			// Standard Error: 95_765
+			.saturating_add(Weight::from_parts(1_787_361, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:0)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:0 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn initiate_inheritance(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `157 + n * (37 ±0)`
+		//  Estimated: `3814`
+		// Minimum execution time: 13_404_000 picoseconds.
+		Weight::from_parts(18_109_867, 3814)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritance` (r:1 w:0)
+	/// Proof: `Recovery::Inheritance` (`max_values`: None, `max_size`: Some(349), added: 2824, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:0)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:0)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3260), added: 5735, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_inheritance(n: u32, ) -> Weight {
+		// Not benchmarked: estimated from the storage accessed.
+		Weight::from_parts(52_144_333, 19086)
+			// Standard Error: 46_209
+			.saturating_add(Weight::from_parts(2_238_162, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
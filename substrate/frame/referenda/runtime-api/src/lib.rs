@@ -0,0 +1,48 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the referenda pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_referenda::{DecisionTimeline, ReferendumIndex};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api predicting the decision timeline of referenda.
+	pub trait ReferendaApi<TrackId, Tally, BlockNumber>
+		where
+			TrackId: Codec,
+			Tally: Codec,
+			BlockNumber: Codec,
+	{
+		/// The course the decision of the ongoing referendum `index` would take if its tally
+		/// stayed as it is, or `None` if it has no decision deposit yet.
+		fn referendum_timeline(
+			index: ReferendumIndex,
+			step: BlockNumber,
+		) -> Option<DecisionTimeline<BlockNumber>>;
+
+		/// The course the decision of a referendum on `track` would take if it began being
+		/// decided now with the given `tally`.
+		fn simulate_timeline(
+			track: TrackId,
+			tally: Tally,
+			step: BlockNumber,
+		) -> Option<DecisionTimeline<BlockNumber>>;
+	}
+}
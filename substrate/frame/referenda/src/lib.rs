@@ -98,10 +98,10 @@ pub use self::{
 	pallet::*,
 	types::{
 		BalanceOf, BlockNumberFor, BoundedCallOf, CallOf, ConstTrackInfo, Curve, DecidingStatus,
-		DecidingStatusOf, Deposit, InsertSorted, NegativeImbalanceOf, PalletsOriginOf,
-		ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf,
-		ScheduleAddressOf, StringLike, TallyOf, Track, TrackIdOf, TrackInfo, TrackInfoOf,
-		TracksInfo, VotesOf,
+		DecidingStatusOf, DecisionTimeline, DecisionTimelineOf, Deposit, InsertSorted,
+		NegativeImbalanceOf, PalletsOriginOf, PassingThreshold, ReferendumIndex, ReferendumInfo,
+		ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, StringLike,
+		TallyOf, Track, TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -120,6 +120,9 @@ pub use frame_support::traits::Get;
 
 const ASSEMBLY_ID: LockIdentifier = *b"assembly";
 
+/// The maximum number of passing thresholds reported in a `DecisionTimeline`.
+const MAX_TIMELINE_THRESHOLDS: u32 = 1024;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -752,6 +755,62 @@ pub mod pallet {
 			}
 		}
 	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The course the decision of the ongoing referendum `index` would take if its tally
+		/// stayed as it is.
+		///
+		/// A referendum which is not being decided yet is assumed to begin being decided as soon
+		/// as it may, regardless of the capacity of its track. `None` is returned for a
+		/// referendum which has no decision deposit yet, since it can't begin being decided
+		/// until one is placed. Passing thresholds are reported every `step` blocks.
+		pub fn referendum_timeline(
+			index: ReferendumIndex,
+			step: BlockNumberFor<T, I>,
+		) -> Option<DecisionTimelineOf<T, I>> {
+			let status = Self::ensure_ongoing(index).ok()?;
+			if status.decision_deposit.is_none() {
+				return None
+			}
+			let track = T::Tracks::info(status.track)?;
+			let deciding = status.deciding.clone().unwrap_or_else(|| {
+				let now = T::BlockNumberProvider::current_block_number();
+				let prepare_end = status.submitted.saturating_add(track.prepare_period);
+				let since = if prepare_end > now { Self::alarm_moment(prepare_end) } else { now };
+				Self::simulated_deciding(&status.tally, status.track, &track, since)
+			});
+			Some(Self::decision_timeline(
+				&status.tally,
+				status.track,
+				&track,
+				&deciding,
+				status.enactment,
+				step,
+			))
+		}
+
+		/// The course the decision of a referendum on `track` would take if it began being
+		/// decided now with the given `tally` and the tally stayed as it is.
+		///
+		/// Passing thresholds are reported every `step` blocks.
+		pub fn simulate_timeline(
+			track: TrackIdOf<T, I>,
+			tally: T::Tally,
+			step: BlockNumberFor<T, I>,
+		) -> Option<DecisionTimelineOf<T, I>> {
+			let info = T::Tracks::info(track)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			let deciding = Self::simulated_deciding(&tally, track, &info, now);
+			Some(Self::decision_timeline(
+				&tally,
+				track,
+				&info,
+				&deciding,
+				DispatchTime::After(Zero::zero()),
+				step,
+			))
+		}
+	}
 }
 
 impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
@@ -922,17 +981,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		debug_assert!(ok, "LOGIC ERROR: bake_referendum/schedule_named failed");
 	}
 
+	/// The block at which an alarm set for `when` goes off.
+	fn alarm_moment(when: BlockNumberFor<T, I>) -> BlockNumberFor<T, I> {
+		let alarm_interval = T::AlarmInterval::get().max(One::one());
+		// Alarm must go off no earlier than `when`.
+		// This rounds `when` upwards to the next multiple of `alarm_interval`.
+		(when.saturating_add(alarm_interval.saturating_sub(One::one())) / alarm_interval)
+			.saturating_mul(alarm_interval)
+	}
+
 	/// Set an alarm to dispatch `call` at block number `when`.
 	fn set_alarm(
 		call: BoundedCallOf<T, I>,
 		when: BlockNumberFor<T, I>,
 	) -> Option<(BlockNumberFor<T, I>, ScheduleAddressOf<T, I>)> {
-		let alarm_interval = T::AlarmInterval::get().max(One::one());
-		// Alarm must go off no earlier than `when`.
-		// This rounds `when` upwards to the next multiple of `alarm_interval`.
-		let when = (when.saturating_add(alarm_interval.saturating_sub(One::one())) /
-			alarm_interval)
-			.saturating_mul(alarm_interval);
+		let when = Self::alarm_moment(when);
 		let result = T::Scheduler::schedule(
 			DispatchTime::At(when),
 			None,
@@ -1273,6 +1336,100 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// The deciding status a referendum with `tally` would have if it began being decided at
+	/// `since`.
+	fn simulated_deciding(
+		tally: &T::Tally,
+		track_id: TrackIdOf<T, I>,
+		track: &TrackInfoOf<T, I>,
+		since: BlockNumberFor<T, I>,
+	) -> DecidingStatusOf<T, I> {
+		let is_passing = Self::is_passing(
+			tally,
+			Zero::zero(),
+			track.decision_period,
+			&track.min_support,
+			&track.min_approval,
+			track_id,
+		);
+		let confirming = is_passing.then(|| since.saturating_add(track.confirm_period));
+		DecidingStatus { since, confirming }
+	}
+
+	/// Determine the course of the decision described by `deciding` if `tally` stayed as it is,
+	/// reporting the passing thresholds every `step` blocks from now on.
+	fn decision_timeline(
+		tally: &T::Tally,
+		track_id: TrackIdOf<T, I>,
+		track: &TrackInfoOf<T, I>,
+		deciding: &DecidingStatusOf<T, I>,
+		enactment: DispatchTime<BlockNumberFor<T, I>>,
+		step: BlockNumberFor<T, I>,
+	) -> DecisionTimelineOf<T, I> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let period = track.decision_period;
+		let decision_end = deciding.since.saturating_add(period);
+		let confirm_since = match deciding.confirming {
+			Some(end) => Some(end.saturating_sub(track.confirm_period)),
+			None => {
+				// Confirmation begins once the alarm set for the moment the tally starts passing
+				// goes off, unless the decision period is over by then.
+				let at = Self::decision_time(deciding, tally, track_id, track).max(now);
+				let at = Self::alarm_moment(at);
+				Self::is_passing(
+					tally,
+					at.saturating_sub(deciding.since),
+					period,
+					&track.min_support,
+					&track.min_approval,
+					track_id,
+				)
+				.then_some(at)
+			},
+		};
+		let approved_at = deciding
+			.confirming
+			.or_else(|| confirm_since.map(|since| since.saturating_add(track.confirm_period)))
+			.map(|end| Self::alarm_moment(end.max(now)));
+		// Mirrors `schedule_enactment`.
+		let earliest_enactment = approved_at.map(|approved| {
+			let earliest_allowed =
+				approved.saturating_add(track.min_enactment_period.max(One::one()));
+			enactment.evaluate(approved).max(earliest_allowed)
+		});
+
+		let from = now.max(deciding.since).min(decision_end);
+		let max_points: BlockNumberFor<T, I> = MAX_TIMELINE_THRESHOLDS.into();
+		let min_step = decision_end
+			.saturating_sub(from)
+			.saturating_add(max_points.saturating_sub(One::one())) /
+			max_points;
+		let step = step.max(min_step).max(One::one());
+		let mut thresholds = Vec::new();
+		let mut at = from;
+		loop {
+			let x = Perbill::from_rational(at.saturating_sub(deciding.since).min(period), period);
+			thresholds.push(PassingThreshold {
+				at,
+				approval: track.min_approval.threshold(x),
+				support: track.min_support.threshold(x),
+			});
+			if at >= decision_end {
+				break
+			}
+			at = at.saturating_add(step).min(decision_end);
+		}
+
+		DecisionTimeline {
+			deciding_since: deciding.since,
+			decision_end,
+			confirm_since,
+			approved_at,
+			earliest_enactment,
+			thresholds,
+		}
+	}
+
 	/// Cancel the alarm in `status`, if one exists.
 	fn ensure_no_alarm(status: &mut ReferendumStatusOf<T, I>) -> bool {
 		if let Some((_, last_alarm)) = status.alarm.take() {
@@ -304,6 +304,116 @@ fn decision_time_is_correct() {
 	});
 }
 
+#[test]
+fn referendum_timeline_is_correct() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		set_tally(0, 60, 40);
+
+		let timeline = Referenda::referendum_timeline(0, 1).unwrap();
+		assert_eq!(timeline.deciding_since, 5);
+		assert_eq!(timeline.decision_end, 9);
+		assert_eq!(timeline.confirm_since, Some(9));
+		assert_eq!(timeline.approved_at, Some(11));
+		assert_eq!(timeline.earliest_enactment, Some(15));
+		assert_eq!(timeline.thresholds.len(), 5);
+		assert_eq!(
+			timeline.thresholds[1],
+			PassingThreshold {
+				at: 6,
+				approval: Perbill::from_parts(875_000_000),
+				support: Perbill::from_percent(75),
+			}
+		);
+		assert_eq!(
+			timeline.thresholds[4],
+			PassingThreshold {
+				at: 9,
+				approval: Perbill::from_percent(50),
+				support: Perbill::zero(),
+			}
+		);
+
+		// The prediction holds as long as the tally does.
+		run_to(8);
+		assert_eq!(deciding_and_failing_since(0), 5);
+		assert_eq!(
+			Referenda::referendum_timeline(0, 1),
+			Some(DecisionTimeline {
+				thresholds: timeline.thresholds[3..].to_vec(),
+				..timeline.clone()
+			})
+		);
+		run_to(9);
+		assert_eq!(confirming_until(0), 11);
+		run_to(11);
+		assert_eq!(approved_since(0), 11);
+		assert_eq!(Referenda::referendum_timeline(0, 1), None);
+		run_to(14);
+		assert_eq!(Balances::free_balance(&42), 0);
+		run_to(15);
+		assert_eq!(Balances::free_balance(&42), 1);
+	});
+}
+
+#[test]
+fn referendum_timeline_awaits_decision_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		set_tally(0, 100, 0);
+
+		// Without a decision deposit the referendum can never begin being decided.
+		run_to(6);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		assert_eq!(Referenda::referendum_timeline(0, 1), None);
+
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		let timeline = Referenda::referendum_timeline(0, 1).unwrap();
+		assert_eq!(timeline.deciding_since, 6);
+	});
+}
+
+#[test]
+fn simulate_timeline_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Referenda::simulate_timeline(3, Tally { ayes: 100, nays: 0 }, 1), None);
+
+		// Passing from the start, so confirming straight away.
+		let timeline = Referenda::simulate_timeline(0, Tally { ayes: 100, nays: 0 }, 0).unwrap();
+		assert_eq!(timeline.deciding_since, 1);
+		assert_eq!(timeline.confirm_since, Some(1));
+		assert_eq!(timeline.approved_at, Some(3));
+		assert_eq!(timeline.earliest_enactment, Some(7));
+		assert_eq!(timeline.thresholds.len(), 5);
+
+		// Never passing.
+		let timeline = Referenda::simulate_timeline(0, Tally { ayes: 0, nays: 100 }, 2).unwrap();
+		assert_eq!(timeline.decision_end, 5);
+		assert_eq!(timeline.confirm_since, None);
+		assert_eq!(timeline.approved_at, None);
+		assert_eq!(timeline.earliest_enactment, None);
+		assert_eq!(timeline.thresholds.iter().map(|t| t.at).collect::<Vec<_>>(), vec![1, 3, 5]);
+
+		// Alarms only go off every `AlarmInterval` blocks.
+		AlarmInterval::set(3);
+		let timeline = Referenda::simulate_timeline(0, Tally { ayes: 60, nays: 40 }, 1).unwrap();
+		assert_eq!(timeline.confirm_since, Some(6));
+		assert_eq!(timeline.approved_at, Some(9));
+		assert_eq!(timeline.earliest_enactment, Some(13));
+	});
+}
+
 #[test]
 fn auto_timeout_should_happen_with_nothing_but_submit() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -66,6 +66,7 @@ pub type ReferendumStatusOf<T, I> = ReferendumStatus<
 	ScheduleAddressOf<T, I>,
 >;
 pub type DecidingStatusOf<T, I> = DecidingStatus<BlockNumberFor<T, I>>;
+pub type DecisionTimelineOf<T, I> = DecisionTimeline<BlockNumberFor<T, I>>;
 pub type TrackInfoOf<T, I = ()> = TrackInfo<BalanceOf<T, I>, BlockNumberFor<T, I>>;
 pub type TrackIdOf<T, I> =
 	<<T as Config<I>>::Tracks as TracksInfo<BalanceOf<T, I>, BlockNumberFor<T, I>>>::Id;
@@ -120,6 +121,36 @@ pub struct DecidingStatus<BlockNumber> {
 	pub confirming: Option<BlockNumber>,
 }
 
+/// The approval and support a tally needs for a referendum to be passing at a given moment.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PassingThreshold<BlockNumber> {
+	/// The moment the threshold applies to.
+	pub at: BlockNumber,
+	/// The approval needed at `at`.
+	pub approval: Perbill,
+	/// The support needed at `at`.
+	pub support: Perbill,
+}
+
+/// The course a decision would take if the tally of the referendum stayed as it is.
+///
+/// All moments account for the quantization of the referendum alarms by `AlarmInterval`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DecisionTimeline<BlockNumber> {
+	/// When the referendum began, or would at the earliest begin, being decided.
+	pub deciding_since: BlockNumber,
+	/// When the decision period ends. A referendum which is not confirming by then is rejected.
+	pub decision_end: BlockNumber,
+	/// When the referendum would enter the confirmation stage, if it ever does.
+	pub confirm_since: Option<BlockNumber>,
+	/// When the referendum would be approved, if it ever is.
+	pub approved_at: Option<BlockNumber>,
+	/// The earliest moment the proposal could be enacted, if the referendum is approved.
+	pub earliest_enactment: Option<BlockNumber>,
+	/// The thresholds the tally needs to meet from now until the end of the decision period.
+	pub thresholds: Vec<PassingThreshold<BlockNumber>>,
+}
+
 #[derive(
 	Encode,
 	Decode,
@@ -62,6 +62,15 @@ fn fill_schedule<T: Config>(when: BlockNumberFor<T>, n: u32) -> Result<(), &'sta
 	Ok(())
 }
 
+/// Turn the periodic task at `address` into a one-off task, so that it can be made recurring.
+fn clear_periodic<T: Config>((when, index): TaskAddress<BlockNumberFor<T>>) {
+	Agenda::<T>::mutate(when, |agenda| {
+		if let Some(Some(task)) = agenda.get_mut(index as usize) {
+			task.maybe_periodic = None;
+		}
+	});
+}
+
 fn u32_to_name(i: u32) -> TaskName {
 	i.using_encoded(blake2_256)
 }
@@ -506,6 +515,104 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_recurrence() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		clear_periodic::<T>(address);
+		let period = RecurrencePeriod::Millis(86_400_000);
+		let end = Some(when.saturating_add(1_000u32.into()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, address, period, end);
+
+		assert_eq!(
+			Recurrences::<T>::get(address),
+			Some(Recurrence { period, end, next_due: None, skip: 0 })
+		);
+		assert_last_event::<T>(
+			Event::RecurrenceSet { task: address, id: None, period, end }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_recurrence_named() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		clear_periodic::<T>(address);
+		let period = RecurrencePeriod::Millis(86_400_000);
+		let end = Some(when.saturating_add(1_000u32.into()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, period, end);
+
+		assert_eq!(
+			Recurrences::<T>::get(address),
+			Some(Recurrence { period, end, next_due: None, skip: 0 })
+		);
+		assert_last_event::<T>(
+			Event::RecurrenceSet { task: address, id: Some(name), period, end }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn skip_recurring() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		clear_periodic::<T>(address);
+		let period = RecurrencePeriod::Blocks(BlockNumberFor::<T>::one());
+		assert!(Pallet::<T>::set_recurrence(RawOrigin::Root.into(), address, period, None).is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, address, 10);
+
+		assert_eq!(Recurrences::<T>::get(address).map(|r| r.skip), Some(10));
+		assert_last_event::<T>(
+			Event::RecurringSkipSet { task: address, id: None, runs: 10 }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn skip_recurring_named() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		clear_periodic::<T>(address);
+		let period = RecurrencePeriod::Blocks(BlockNumberFor::<T>::one());
+		assert!(Pallet::<T>::set_recurrence(RawOrigin::Root.into(), address, period, None).is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, 10);
+
+		assert_eq!(Recurrences::<T>::get(address).map(|r| r.skip), Some(10));
+		assert_last_event::<T>(
+			Event::RecurringSkipSet { task: address, id: Some(name), runs: 10 }.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::new_test_ext(),
@@ -33,6 +33,12 @@
 //! number or at a specified period. These scheduled runtime calls may be named or anonymous and may
 //! be canceled.
 //!
+//! A scheduled task can also be made recurring with `set_recurrence`. A recurring task runs every
+//! given number of blocks or every given amount of [`Config::UnixTime`] time, either forever or
+//! until an end block. Upcoming runs can be skipped with `skip_recurring` without cancelling the
+//! task, and the [`Pallet::upcoming_executions`] view function lists when the next runs are
+//! expected.
+//!
 //! __NOTE:__ Instead of using the filter contained in the origin to call `fn schedule`, scheduled
 //! runtime calls will be dispatched with the default filter for the origin: namely
 //! `frame_system::Config::BaseCallFilter` for all origin types (except root which will get no
@@ -87,7 +93,7 @@ pub mod weights;
 
 extern crate alloc;
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::{borrow::Borrow, cmp::Ordering, marker::PhantomData};
 use frame_support::{
@@ -96,7 +102,7 @@ use frame_support::{
 	traits::{
 		schedule::{self, DispatchTime, MaybeHashed},
 		Bounded, CallerTrait, EnsureOrigin, Get, IsType, OriginTrait, PalletInfoAccess,
-		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage, UnixTime,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -104,7 +110,9 @@ use frame_system::{self as system};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{BadOrigin, BlockNumberProvider, Dispatchable, One, Saturating, Zero},
+	traits::{
+		BadOrigin, BlockNumberProvider, Dispatchable, One, Saturating, UniqueSaturatedInto, Zero,
+	},
 	BoundedVec, DispatchError, RuntimeDebug,
 };
 
@@ -147,6 +155,55 @@ pub struct RetryConfig<Period> {
 	period: Period,
 }
 
+/// The maximum number of runs returned by [`Pallet::upcoming_executions`].
+pub const MAX_UPCOMING_EXECUTIONS: u32 = 256;
+
+/// How often a recurring task runs.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum RecurrencePeriod<BlockNumber> {
+	/// Every given number of blocks after the previous run.
+	Blocks(BlockNumber),
+	/// Every given number of milliseconds of [`Config::UnixTime`] time, counted from the time
+	/// the first run was due, so that the runs do not drift.
+	Millis(u64),
+}
+
+/// The recurrence of a task along with its current state.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct Recurrence<BlockNumber> {
+	/// The period between two runs.
+	period: RecurrencePeriod<BlockNumber>,
+	/// The last block at which the task may run, or `None` if it recurs forever.
+	end: Option<BlockNumber>,
+	/// For a time-based period, the time in milliseconds at which the scheduled run is due. `None`
+	/// until the first run, which is due at the block it is scheduled for.
+	next_due: Option<u64>,
+	/// The number of upcoming runs that will be skipped.
+	skip: u32,
+}
+
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
 struct ScheduledV1<Call, BlockNumber> {
@@ -333,6 +390,20 @@ pub mod pallet {
 		/// swap block number providers on the fly, then please at least ensure that you do not run
 		/// any pallet migration in the same runtime upgrade.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The source of time for recurring tasks with a time-based period.
+		///
+		/// Only read in `on_finalize`, once the time of the block is set.
+		type UnixTime: UnixTime;
+
+		/// The expected time in milliseconds between two blocks of the
+		/// [`Config::BlockNumberProvider`].
+		///
+		/// Used to estimate the time of a block from the time recorded at the end of the last one,
+		/// and the block at which a recurring task with a time-based period is due. Tasks woken up
+		/// too early are postponed until their time has come.
+		#[pallet::constant]
+		type ExpectedBlockTime: Get<u64>;
 	}
 
 	/// Block number at which the agenda began incomplete execution.
@@ -359,6 +430,29 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Recurrence configurations for items to be executed, indexed by task address.
+	#[pallet::storage]
+	pub type Recurrences<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<BlockNumberFor<T>>,
+		Recurrence<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The block number of [`Config::BlockNumberProvider`] and the time in milliseconds of
+	/// [`Config::UnixTime`] recorded at the end of the last block.
+	///
+	/// Time is not set yet when agendas are serviced, so the time of a block is estimated from it.
+	#[pallet::storage]
+	pub type LastBlockTime<T: Config> = StorageValue<_, (BlockNumberFor<T>, u64)>;
+
+	/// The number of recurrences with a time-based period.
+	///
+	/// The time is only recorded at the end of a block while there are any.
+	#[pallet::storage]
+	pub type TimedRecurrenceCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Lookup from a name to the block number and index of the task.
 	///
 	/// For v3 -> v4 the previously unbounded identities are Blake2-256 hashed to form the v4
@@ -401,6 +495,19 @@ pub mod pallet {
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// Agenda is incomplete from `when`.
 		AgendaIncomplete { when: BlockNumberFor<T> },
+		/// Set a recurrence for some task.
+		RecurrenceSet {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+			period: RecurrencePeriod<BlockNumberFor<T>>,
+			end: Option<BlockNumberFor<T>>,
+		},
+		/// Upcoming runs of a recurring task will be skipped.
+		RecurringSkipSet { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName>, runs: u32 },
+		/// A run of a recurring task was skipped.
+		RecurringSkipped { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// A recurring task reached its end and will not run again.
+		RecurrenceEnded { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 	}
 
 	#[pallet::error]
@@ -415,6 +522,12 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// The recurrence has a zero period or ends before the task is scheduled.
+		InvalidRecurrence,
+		/// Attempt to make a task recurring which is already periodic.
+		Periodic,
+		/// Attempt to skip runs of a task which is not recurring.
+		NotRecurring,
 	}
 
 	#[pallet::hooks]
@@ -424,7 +537,19 @@ pub mod pallet {
 			let now = T::BlockNumberProvider::current_block_number();
 			let mut weight_counter = WeightMeter::with_limit(T::MaximumWeight::get());
 			Self::service_agendas(&mut weight_counter, now, u32::MAX);
-			weight_counter.consumed()
+			// Account for checking for time-based recurrences in `on_finalize`, then reading and
+			// recording the time.
+			weight_counter.consumed().saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		}
+
+		/// Record the time of the block, once it has been set, if any task recurs based on time.
+		fn on_finalize(_now: SystemBlockNumberFor<T>) {
+			if TimedRecurrenceCount::<T>::get().is_zero() {
+				return
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			let millis: u64 = T::UnixTime::now().as_millis().unique_saturated_into();
+			LastBlockTime::<T>::put((now, millis));
 		}
 
 		#[cfg(feature = "std")]
@@ -667,6 +792,111 @@ pub mod pallet {
 			Self::deposit_event(Event::RetryCancelled { task, id: Some(id) });
 			Ok(())
 		}
+
+		/// Make a task recurring, so that after each run it is scheduled again `period` later, for
+		/// as long as the next run would happen no later than the `end` block, if any.
+		///
+		/// Replaces any recurrence previously set for the task. Periodic tasks, as scheduled with
+		/// `maybe_periodic`, cannot be made recurring.
+		///
+		/// Recurring tasks are still subject to weight metering and agenda space, same as a
+		/// regular task.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_recurrence())]
+		pub fn set_recurrence(
+			origin: OriginFor<T>,
+			task: TaskAddress<BlockNumberFor<T>>,
+			period: RecurrencePeriod<BlockNumberFor<T>>,
+			end: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_recurrence(origin.caller(), task, period, end)?;
+			Self::deposit_event(Event::RecurrenceSet { task, id: None, period, end });
+			Ok(())
+		}
+
+		/// Make a named task recurring, so that after each run it is scheduled again `period`
+		/// later, for as long as the next run would happen no later than the `end` block, if any.
+		///
+		/// Replaces any recurrence previously set for the task. Periodic tasks, as scheduled with
+		/// `maybe_periodic`, cannot be made recurring.
+		///
+		/// Recurring tasks are still subject to weight metering and agenda space, same as a
+		/// regular task.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_recurrence_named())]
+		pub fn set_recurrence_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			period: RecurrencePeriod<BlockNumberFor<T>>,
+			end: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_recurrence(origin.caller(), task, period, end)?;
+			Self::deposit_event(Event::RecurrenceSet { task, id: Some(id), period, end });
+			Ok(())
+		}
+
+		/// Skip the next `runs` runs of a recurring task, without cancelling it.
+		///
+		/// Replaces the number of runs to skip previously set for the task, so `0` resumes it.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::skip_recurring())]
+		pub fn skip_recurring(
+			origin: OriginFor<T>,
+			task: TaskAddress<BlockNumberFor<T>>,
+			runs: u32,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_skip_recurring(origin.caller(), task, runs)?;
+			Self::deposit_event(Event::RecurringSkipSet { task, id: None, runs });
+			Ok(())
+		}
+
+		/// Skip the next `runs` runs of a named recurring task, without cancelling it.
+		///
+		/// Replaces the number of runs to skip previously set for the task, so `0` resumes it.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::skip_recurring_named())]
+		pub fn skip_recurring_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			runs: u32,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_skip_recurring(origin.caller(), task, runs)?;
+			Self::deposit_event(Event::RecurringSkipSet { task, id: Some(id), runs });
+			Ok(())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The blocks at which the next `count` runs of the task at `task` are expected, skipped
+		/// runs excluded. At most [`MAX_UPCOMING_EXECUTIONS`] runs are returned.
+		///
+		/// The blocks of runs with a time-based period are estimated with
+		/// [`Config::ExpectedBlockTime`].
+		pub fn upcoming_executions(
+			task: TaskAddress<BlockNumberFor<T>>,
+			count: u32,
+		) -> Vec<BlockNumberFor<T>> {
+			Self::project_executions(task, count)
+		}
+
+		/// The blocks at which the next `count` runs of the named task `id` are expected, skipped
+		/// runs excluded.
+		pub fn upcoming_executions_named(id: TaskName, count: u32) -> Vec<BlockNumberFor<T>> {
+			Lookup::<T>::get(id)
+				.map(|task| Self::project_executions(task, count))
+				.unwrap_or_default()
+		}
 	}
 }
 
@@ -1076,6 +1306,7 @@ impl<T: Config> Pallet<T> {
 				Lookup::<T>::remove(id);
 			}
 			Retries::<T>::remove((when, index));
+			Self::take_recurrence((when, index));
 			Self::cleanup_agenda(when);
 			Self::deposit_event(Event::Canceled { when, index });
 			Ok(())
@@ -1102,7 +1333,7 @@ impl<T: Config> Pallet<T> {
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
 
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		Self::place_task_moving_recurrence((when, index), new_time, task)
 	}
 
 	fn do_schedule_named(
@@ -1157,6 +1388,7 @@ impl<T: Config> Pallet<T> {
 							Retries::<T>::remove((when, index));
 							T::Preimages::drop(&s.call);
 						}
+						Self::take_recurrence((when, index));
 						*s = None;
 					}
 					Ok(())
@@ -1189,7 +1421,7 @@ impl<T: Config> Pallet<T> {
 		})?;
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		Self::place_task_moving_recurrence((when, index), new_time, task)
 	}
 
 	fn do_cancel_retry(
@@ -1205,6 +1437,155 @@ impl<T: Config> Pallet<T> {
 		Retries::<T>::remove((when, index));
 		Ok(())
 	}
+
+	/// Place `task`, previously at `old`, at `when`, keeping its recurrence.
+	fn place_task_moving_recurrence(
+		old: TaskAddress<BlockNumberFor<T>>,
+		when: BlockNumberFor<T>,
+		task: ScheduledOf<T>,
+	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
+		let address = Self::place_task(when, task).map_err(|x| x.0)?;
+		if let Some(recurrence) = Self::take_recurrence(old) {
+			Self::put_recurrence(address, recurrence);
+		}
+		Ok(address)
+	}
+
+	fn do_set_recurrence(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		period: RecurrencePeriod<BlockNumberFor<T>>,
+		end: Option<BlockNumberFor<T>>,
+	) -> Result<(), DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		ensure!(scheduled.maybe_periodic.is_none(), Error::<T>::Periodic);
+		let zero_period = match period {
+			RecurrencePeriod::Blocks(blocks) => blocks.is_zero(),
+			RecurrencePeriod::Millis(millis) => millis.is_zero(),
+		};
+		ensure!(!zero_period && end.map_or(true, |end| end >= when), Error::<T>::InvalidRecurrence);
+		Self::take_recurrence((when, index));
+		Self::put_recurrence((when, index), Recurrence { period, end, next_due: None, skip: 0 });
+		Ok(())
+	}
+
+	/// Remove and return the recurrence of the task at `address`, if any.
+	fn take_recurrence(
+		address: TaskAddress<BlockNumberFor<T>>,
+	) -> Option<Recurrence<BlockNumberFor<T>>> {
+		let maybe_recurrence = Recurrences::<T>::take(address);
+		if matches!(maybe_recurrence, Some(Recurrence { period: RecurrencePeriod::Millis(_), .. }))
+		{
+			TimedRecurrenceCount::<T>::mutate(|count| count.saturating_dec());
+		}
+		maybe_recurrence
+	}
+
+	/// Set the recurrence of the task at `address`, which must not have one already.
+	fn put_recurrence(
+		address: TaskAddress<BlockNumberFor<T>>,
+		recurrence: Recurrence<BlockNumberFor<T>>,
+	) {
+		if matches!(recurrence.period, RecurrencePeriod::Millis(_)) {
+			TimedRecurrenceCount::<T>::mutate(|count| count.saturating_inc());
+		}
+		Recurrences::<T>::insert(address, recurrence);
+	}
+
+	fn do_skip_recurring(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		runs: u32,
+	) -> Result<(), DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		Recurrences::<T>::try_mutate((when, index), |maybe_recurrence| {
+			let recurrence = maybe_recurrence.as_mut().ok_or(Error::<T>::NotRecurring)?;
+			recurrence.skip = runs;
+			Ok(())
+		})
+	}
+
+	/// The estimated time in milliseconds at block `now`, from the time recorded at the end of
+	/// the last block, or `None` if no time was recorded yet.
+	fn now_millis(now: BlockNumberFor<T>) -> Option<u64> {
+		LastBlockTime::<T>::get().map(|(block, millis)| {
+			let blocks: u64 = now.saturating_sub(block).unique_saturated_into();
+			millis.saturating_add(blocks.saturating_mul(T::ExpectedBlockTime::get()))
+		})
+	}
+
+	/// The estimated block at which the time `due` is reached, given that it is `now_millis` at
+	/// block `now`. Always after `now`.
+	fn estimate_block(now: BlockNumberFor<T>, now_millis: u64, due: u64) -> BlockNumberFor<T> {
+		let block_time = T::ExpectedBlockTime::get().max(1);
+		let blocks = due.saturating_sub(now_millis).div_ceil(block_time).max(1);
+		now.saturating_add(blocks.unique_saturated_into())
+	}
+
+	/// The blocks at which the next `count` runs of the task at `task` are expected, at most
+	/// [`MAX_UPCOMING_EXECUTIONS`].
+	fn project_executions(
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		count: u32,
+	) -> Vec<BlockNumberFor<T>> {
+		let count = count.min(MAX_UPCOMING_EXECUTIONS);
+		let agenda = Agenda::<T>::get(when);
+		let Some(scheduled) = agenda.get(index as usize).and_then(Option::as_ref) else {
+			return Vec::new()
+		};
+		let Some(mut recurrence) = Recurrences::<T>::get((when, index)) else {
+			return match scheduled.maybe_periodic {
+				Some((period, remaining)) => (0..count.min(remaining.saturating_add(1)))
+					.map(|i| when.saturating_add(period.saturating_mul(i.into())))
+					.collect(),
+				None if count > 0 => vec![when],
+				None => Vec::new(),
+			}
+		};
+
+		let now = T::BlockNumberProvider::current_block_number();
+		// Without a recorded time, assume the task is on time.
+		let now_millis = Self::now_millis(now).or(recurrence.next_due).unwrap_or_default();
+		let mut upcoming = Vec::new();
+		let mut at = when;
+		while (upcoming.len() as u32) < count && recurrence.end.map_or(true, |end| at <= end) {
+			if recurrence.skip > 0 {
+				recurrence.skip -= 1;
+			} else {
+				upcoming.push(at);
+			}
+			let next = match recurrence.period {
+				RecurrencePeriod::Blocks(blocks) => at.saturating_add(blocks),
+				RecurrencePeriod::Millis(millis) => {
+					// Assume each run happens on time.
+					let due = recurrence.next_due.unwrap_or_else(|| {
+						let blocks: u64 = at.saturating_sub(now).unique_saturated_into();
+						now_millis
+							.saturating_add(blocks.saturating_mul(T::ExpectedBlockTime::get()))
+					});
+					let next_due = due.saturating_add(millis);
+					recurrence.next_due = Some(next_due);
+					Self::estimate_block(now, now_millis, next_due)
+						.max(at.saturating_add(One::one()))
+				},
+			};
+			if next <= at {
+				break
+			}
+			at = next;
+		}
+		upcoming
+	}
 }
 
 enum ServiceTaskError {
@@ -1344,6 +1725,8 @@ impl<T: Config> Pallet<T> {
 				// It was not available when we needed it, so we don't need to have requested it
 				// anymore.
 				T::Preimages::drop(&task.call);
+				// The task will not run again, so neither will its recurrence.
+				Self::take_recurrence((when, agenda_index));
 
 				// We don't know why `peek` failed, thus we most account here for the "full weight".
 				let _ = weight.try_consume(T::WeightInfo::service_task(
@@ -1362,8 +1745,47 @@ impl<T: Config> Pallet<T> {
 			task.maybe_periodic.is_some(),
 		));
 
+		let mut maybe_recurrence = Self::take_recurrence((when, agenda_index));
+		let mut now_millis = 0;
+		if let Some(ref mut recurrence) = maybe_recurrence {
+			// Account for rescheduling the task, as for a periodic one, and for keeping count of
+			// time-based recurrences.
+			let _ = weight.try_consume(
+				T::WeightInfo::service_task_periodic()
+					.saturating_sub(T::WeightInfo::service_task_base())
+					.saturating_add(T::DbWeight::get().reads_writes(2, 2)),
+			);
+
+			// Without a recorded time, assume the task is on time.
+			now_millis = Self::now_millis(now).or(recurrence.next_due).unwrap_or_default();
+			let early = matches!(recurrence.next_due, Some(due) if due > now_millis);
+			if early || recurrence.skip > 0 {
+				let wake = if early {
+					let due = recurrence.next_due.unwrap_or(now_millis);
+					Self::estimate_block(now, now_millis, due)
+				} else {
+					recurrence.skip -= 1;
+					Self::deposit_event(Event::RecurringSkipped {
+						task: (when, agenda_index),
+						id: task.maybe_id,
+					});
+					Self::next_run(now, now_millis, recurrence)
+				};
+				let maybe_retry_config = Retries::<T>::take((when, agenda_index));
+				Self::place_recurring(
+					(when, agenda_index),
+					wake,
+					task,
+					*recurrence,
+					maybe_retry_config,
+				);
+				return Ok(())
+			}
+		}
+
 		match Self::execute_dispatch(weight, task.origin.clone(), call) {
 			Err(()) if is_first => {
+				// The task will not run again, so its recurrence taken above is dropped too.
 				T::Preimages::drop(&task.call);
 				Self::deposit_event(Event::PermanentlyOverweight {
 					task: (when, agenda_index),
@@ -1371,7 +1793,13 @@ impl<T: Config> Pallet<T> {
 				});
 				Err((Unavailable, Some(task)))
 			},
-			Err(()) => Err((Overweight, Some(task))),
+			Err(()) => {
+				if let Some(recurrence) = maybe_recurrence {
+					// The task stays where it is, to be serviced again later.
+					Self::put_recurrence((when, agenda_index), recurrence);
+				}
+				Err((Overweight, Some(task)))
+			},
 			Ok(result) => {
 				let failed = result.is_err();
 				let maybe_retry_config = Retries::<T>::take((when, agenda_index));
@@ -1388,7 +1816,16 @@ impl<T: Config> Pallet<T> {
 					_ => {},
 				}
 
-				if let &Some((period, count)) = &task.maybe_periodic {
+				if let Some(mut recurrence) = maybe_recurrence {
+					let wake = Self::next_run(now, now_millis, &mut recurrence);
+					Self::place_recurring(
+						(when, agenda_index),
+						wake,
+						task,
+						recurrence,
+						maybe_retry_config,
+					);
+				} else if let &Some((period, count)) = &task.maybe_periodic {
 					if count > 1 {
 						task.maybe_periodic = Some((period, count - 1));
 					} else {
@@ -1418,6 +1855,57 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Return the block of the run following the one due now, updating the due time of a
+	/// time-based `recurrence`.
+	fn next_run(
+		now: BlockNumberFor<T>,
+		now_millis: u64,
+		recurrence: &mut Recurrence<BlockNumberFor<T>>,
+	) -> BlockNumberFor<T> {
+		match recurrence.period {
+			RecurrencePeriod::Blocks(blocks) => now.saturating_add(blocks),
+			RecurrencePeriod::Millis(millis) => {
+				let due = recurrence.next_due.unwrap_or(now_millis);
+				let mut next_due = due.saturating_add(millis);
+				if next_due <= now_millis && !millis.is_zero() {
+					// Runs missed while the chain was stalled are not caught up with.
+					let missed = (now_millis - next_due) / millis + 1;
+					next_due = next_due.saturating_add(missed.saturating_mul(millis));
+				}
+				recurrence.next_due = Some(next_due);
+				Self::estimate_block(now, now_millis, next_due)
+			},
+		}
+	}
+
+	/// Place the recurring `task`, previously at `old`, at `wake` unless that is past the end of
+	/// its `recurrence`.
+	fn place_recurring(
+		old: TaskAddress<BlockNumberFor<T>>,
+		wake: BlockNumberFor<T>,
+		task: ScheduledOf<T>,
+		recurrence: Recurrence<BlockNumberFor<T>>,
+		maybe_retry_config: Option<RetryConfig<BlockNumberFor<T>>>,
+	) {
+		if recurrence.end.is_some_and(|end| wake > end) {
+			T::Preimages::drop(&task.call);
+			Self::deposit_event(Event::RecurrenceEnded { task: old, id: task.maybe_id });
+			return
+		}
+		match Self::place_task(wake, task) {
+			Ok(new_address) => {
+				Self::put_recurrence(new_address, recurrence);
+				if let Some(retry_config) = maybe_retry_config {
+					Retries::<T>::insert(new_address, retry_config);
+				}
+			},
+			Err((_, task)) => {
+				T::Preimages::drop(&task.call);
+				Self::deposit_event(Event::PeriodicFailed { task: old, id: task.maybe_id });
+			},
+		}
+	}
+
 	/// Make a dispatch to the given `call` from the given `origin`, ensuring that the `weight`
 	/// counter does not exceed its limit and that it is counted accurately (e.g. accounted using
 	/// post info if available).
@@ -22,7 +22,7 @@ use super::*;
 use crate as scheduler;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU32, Contains, EitherOfDiverse, EqualPrivilegeOnly},
+	traits::{ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_runtime::{BuildStorage, Perbill};
@@ -211,10 +211,33 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_retry_named() -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn set_recurrence() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_recurrence_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn skip_recurring() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn skip_recurring_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
 }
 parameter_types! {
 	pub storage MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub static TimeDrift: u64 = 0;
+}
+
+pub const BLOCK_TIME: u64 = 6_000;
+
+/// Block time of [`BLOCK_TIME`] milliseconds, plus an adjustable [`TimeDrift`].
+pub struct MockUnixTime;
+impl UnixTime for MockUnixTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(System::block_number() * BLOCK_TIME + TimeDrift::get())
+	}
 }
 
 impl Config for Test {
@@ -229,6 +252,8 @@ impl Config for Test {
 	type WeightInfo = TestWeightInfo;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Self>;
+	type UnixTime = MockUnixTime;
+	type ExpectedBlockTime = ConstU64<BLOCK_TIME>;
 }
 
 pub type LoggerCall = logger::Call<Test>;
@@ -3194,3 +3194,318 @@ fn not_permanently_overweight_when_task_from_not_first_agenda() {
 		assert_eq!(IncompleteSince::<Test>::get(), Some(System::block_number() + 1));
 	});
 }
+
+#[test]
+fn recurring_block_period_works_until_end() {
+	new_test_ext().execute_with(|| {
+		System::run_to_block::<AllPalletsWithSystem>(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		// every 3 blocks, up to block 10
+		assert_ok!(Scheduler::set_recurrence(
+			root().into(),
+			(4, 0),
+			RecurrencePeriod::Blocks(3),
+			Some(10)
+		));
+		System::assert_last_event(
+			Event::RecurrenceSet {
+				task: (4, 0),
+				id: None,
+				period: RecurrencePeriod::Blocks(3),
+				end: Some(10),
+			}
+			.into(),
+		);
+		assert_eq!(Scheduler::upcoming_executions((4, 0), 5), vec![4, 7, 10]);
+
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(Recurrences::<Test>::get((4, 0)).is_none());
+		assert!(Recurrences::<Test>::get((7, 0)).is_some());
+		assert_eq!(Scheduler::upcoming_executions((7, 0), 5), vec![7, 10]);
+		System::run_to_block::<AllPalletsWithSystem>(7);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32), (root(), 42u32)]);
+		System::assert_last_event(Event::RecurrenceEnded { task: (10, 0), id: None }.into());
+		assert_eq!(Recurrences::<Test>::iter().count(), 0);
+		assert!(Agenda::<Test>::iter().all(|(_, agenda)| agenda.iter().all(|t| t.is_none())));
+		System::run_to_block::<AllPalletsWithSystem>(20);
+		assert_eq!(logger::log().len(), 3);
+	});
+}
+
+#[test]
+fn recurring_time_period_follows_unix_time() {
+	new_test_ext().execute_with(|| {
+		// the clock runs one block ahead at first
+		TimeDrift::set(BLOCK_TIME);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(2),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		// every 3 blocks worth of time, forever
+		assert_ok!(Scheduler::set_recurrence_named(
+			root().into(),
+			[1u8; 32],
+			RecurrencePeriod::Millis(3 * BLOCK_TIME),
+			None
+		));
+		assert_eq!(Scheduler::upcoming_executions_named([1u8; 32], 3), vec![2, 5, 8]);
+
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((5, 0)));
+		assert_eq!(Recurrences::<Test>::get((5, 0)).and_then(|r| r.next_due), Some(6 * BLOCK_TIME));
+
+		// the clock falls back in line with the blocks, so the run at block 5 is early
+		TimeDrift::set(0);
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		assert_eq!(logger::log().len(), 1);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((6, 0)));
+		System::run_to_block::<AllPalletsWithSystem>(6);
+		assert_eq!(logger::log().len(), 2);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((9, 0)));
+		assert_eq!(Scheduler::upcoming_executions_named([1u8; 32], 3), vec![9, 12, 15]);
+
+		// cancelling removes the recurrence
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		assert_eq!(Recurrences::<Test>::iter().count(), 0);
+		assert!(Scheduler::upcoming_executions_named([1u8; 32], 3).is_empty());
+	});
+}
+
+#[test]
+fn time_is_only_recorded_for_timed_recurrences() {
+	new_test_ext().execute_with(|| {
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert_eq!(LastBlockTime::<Test>::get(), None);
+
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(10),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		assert_ok!(Scheduler::set_recurrence_named(
+			root().into(),
+			[1u8; 32],
+			RecurrencePeriod::Blocks(3),
+			None
+		));
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert_eq!(TimedRecurrenceCount::<Test>::get(), 0);
+		assert_eq!(LastBlockTime::<Test>::get(), None);
+
+		// replacing the recurrence by a time-based one
+		assert_ok!(Scheduler::set_recurrence_named(
+			root().into(),
+			[1u8; 32],
+			RecurrencePeriod::Millis(3 * BLOCK_TIME),
+			None
+		));
+		assert_eq!(TimedRecurrenceCount::<Test>::get(), 1);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(LastBlockTime::<Test>::get().map(|(block, _)| block), Some(3));
+
+		// the count follows the task as it recurs
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_eq!(logger::log().len(), 1);
+		assert_eq!(TimedRecurrenceCount::<Test>::get(), 1);
+		assert_eq!(LastBlockTime::<Test>::get().map(|(block, _)| block), Some(9));
+
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		assert_eq!(TimedRecurrenceCount::<Test>::get(), 0);
+		System::run_to_block::<AllPalletsWithSystem>(12);
+		assert_eq!(LastBlockTime::<Test>::get().map(|(block, _)| block), Some(9));
+	});
+}
+
+#[test]
+fn skip_recurring_works() {
+	new_test_ext().execute_with(|| {
+		System::run_to_block::<AllPalletsWithSystem>(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		assert_noop!(
+			Scheduler::skip_recurring(root().into(), (4, 0), 1),
+			Error::<Test>::NotRecurring
+		);
+		assert_ok!(Scheduler::set_recurrence(
+			root().into(),
+			(4, 0),
+			RecurrencePeriod::Blocks(2),
+			None
+		));
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
+		assert_ok!(Scheduler::skip_recurring(root().into(), (4, 0), 2));
+		System::assert_last_event(
+			Event::RecurringSkipSet { task: (4, 0), id: None, runs: 2 }.into(),
+		);
+		assert_eq!(Scheduler::upcoming_executions((4, 0), 3), vec![8, 10, 12]);
+
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		System::assert_has_event(Event::RecurringSkipped { task: (4, 0), id: None }.into());
+		assert!(logger::log().is_empty());
+		// the retry configuration moves along with the task
+		assert!(Retries::<Test>::get((6, 0)).is_some());
+		System::run_to_block::<AllPalletsWithSystem>(6);
+		assert!(logger::log().is_empty());
+		System::run_to_block::<AllPalletsWithSystem>(8);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+
+		// skip the next run and then resume
+		assert_ok!(Scheduler::skip_recurring(root().into(), (10, 0), 1));
+		assert_ok!(Scheduler::skip_recurring(root().into(), (10, 0), 0));
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_eq!(logger::log().len(), 2);
+		assert!(Retries::<Test>::get((12, 0)).is_some());
+		assert!(Recurrences::<Test>::get((12, 0)).is_some());
+	});
+}
+
+#[test]
+fn set_recurrence_checks_task() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap()
+		));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			101.into(),
+			Preimage::bound(call.clone()).unwrap()
+		));
+		let period = RecurrencePeriod::Blocks(3);
+
+		assert_noop!(
+			Scheduler::set_recurrence(root().into(), (4, 3), period, None),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(
+			Scheduler::set_recurrence_named(root().into(), [1u8; 32], period, None),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(
+			Scheduler::set_recurrence(root().into(), (4, 0), period, None),
+			Error::<Test>::Periodic
+		);
+		assert_noop!(Scheduler::set_recurrence(root().into(), (4, 1), period, None), BadOrigin);
+		assert_noop!(
+			Scheduler::set_recurrence(RuntimeOrigin::signed(101), (4, 1), period, None),
+			BadOrigin
+		);
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		assert_noop!(
+			Scheduler::set_recurrence(root().into(), (4, 2), RecurrencePeriod::Blocks(0), None),
+			Error::<Test>::InvalidRecurrence
+		);
+		assert_noop!(
+			Scheduler::set_recurrence(root().into(), (4, 2), RecurrencePeriod::Millis(0), None),
+			Error::<Test>::InvalidRecurrence
+		);
+		assert_noop!(
+			Scheduler::set_recurrence(root().into(), (4, 2), period, Some(3)),
+			Error::<Test>::InvalidRecurrence
+		);
+		assert_ok!(Scheduler::set_recurrence(root().into(), (4, 2), period, Some(4)));
+		assert_eq!(Scheduler::upcoming_executions((4, 2), 3), vec![4]);
+		// periodic tasks are projected from their period
+		assert_eq!(Scheduler::upcoming_executions((4, 0), 10), vec![4, 7, 10]);
+	});
+}
+
+#[test]
+fn recurrence_of_unavailable_call_is_removed() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// The preimage is never noted.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Bounded::Lookup { hash, len },
+		));
+		assert_ok!(Scheduler::set_recurrence(
+			root().into(),
+			(4, 0),
+			RecurrencePeriod::Blocks(3),
+			None
+		));
+
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert!(logger::log().is_empty());
+		System::assert_has_event(Event::CallUnavailable { task: (4, 0), id: None }.into());
+		assert_eq!(Recurrences::<Test>::iter().count(), 0);
+		assert!(Agenda::<Test>::iter().all(|(when, _)| when == 4));
+	});
+}
+
+#[test]
+fn recurrence_of_permanently_overweight_call_is_removed() {
+	new_test_ext().execute_with(|| {
+		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_recurrence(
+			root().into(),
+			(4, 0),
+			RecurrencePeriod::Blocks(3),
+			None
+		));
+
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert!(logger::log().is_empty());
+		System::assert_has_event(Event::PermanentlyOverweight { task: (4, 0), id: None }.into());
+		assert_eq!(Recurrences::<Test>::iter().count(), 0);
+		assert!(Agenda::<Test>::iter().all(|(when, _)| when == 4));
+	});
+}
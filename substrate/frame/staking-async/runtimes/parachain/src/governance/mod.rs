@@ -40,7 +40,8 @@ use xcm::latest::{
 mod origins;
 pub use origins::{
 	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin,
-	ReferendumCanceller, ReferendumKiller, Spender, StakingAdmin, Treasurer, WhitelistedCaller,
+	ReferendumCanceller, ReferendumKiller, Spender, SpenderTrack, StakingAdmin, Treasurer,
+	WhitelistedCaller,
 };
 mod tracks;
 pub use tracks::TracksInfo;
@@ -56,6 +57,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegates = ConstU32<16>;
 	type MaxTurnout =
 		frame_support::traits::tokens::currency::ActiveIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
@@ -169,6 +171,10 @@ impl pallet_treasury::Config for Runtime {
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
+	// The referenda track of the spend origin.
+	type BudgetHolder = u16;
+	type BudgetOrigin = SpenderTrack;
+	type ManageBudgetOrigin = EnsureRoot<AccountId>;
 }
 impl pallet_asset_rate::Config for Runtime {
 	type WeightInfo = weights::pallet_asset_rate::WeightInfo<Runtime>;
@@ -201,6 +207,8 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ConstU64<{ RELAY_CHAIN_SLOT_DURATION_MILLIS as u64 }>;
 }
 
 parameter_types! {
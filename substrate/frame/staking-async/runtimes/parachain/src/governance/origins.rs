@@ -189,6 +189,18 @@ pub mod pallet_custom_origins {
 		}
 	}
 
+	// The referenda track of each spending origin, holding the treasury budgets of the origin.
+	decl_ensure! {
+		pub type SpenderTrack: EnsureOrigin<Success = u16> {
+			SmallTipper = 30,
+			BigTipper = 31,
+			SmallSpender = 32,
+			MediumSpender = 33,
+			BigSpender = 34,
+			Treasurer = 11,
+		}
+	}
+
 	decl_ensure! {
 		pub type EnsureFellowship: EnsureOrigin<Success = u16> {
 			Fellowship1Dan = 1,
@@ -475,6 +475,9 @@ parameter_types! {
 	pub const MinVestedTransfer: Balance = 100 * CENTS;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const VestingPalletId: PalletId = PalletId(*b"py/vestg");
+	// One storage item; key size 60, value size 436.
+	pub const ReleaseScheduleDeposit: Balance = deposit(1, 496);
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -486,6 +489,13 @@ impl pallet_vesting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type Assets = ();
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = ConstU32<16>;
+	type ReleaseScheduleDeposit = ReleaseScheduleDeposit;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 parameter_types! {
@@ -619,6 +629,10 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	// One storage item; key size 81, value size 75.
+	pub const BudgetDepositBase: Balance = deposit(1, 156);
+	// Additional storage item size of 54 bytes.
+	pub const BudgetDepositFactor: Balance = deposit(0, 54);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -789,6 +803,13 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	// TODO add migration.
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
+	type BudgetAssets = ();
+	type MaxBudgetAssets = ConstU32<0>;
+	type BudgetCallFilter = frame_support::traits::Everything;
+	type BudgetDepositBase = BudgetDepositBase;
+	type BudgetDepositFactor = BudgetDepositFactor;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -1540,6 +1561,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<
+		Block,
+		pallet_referenda::TrackIdOf<Runtime, ()>,
+		pallet_referenda::TallyOf<Runtime, ()>,
+		pallet_referenda::BlockNumberFor<Runtime, ()>,
+	> for Runtime {
+		fn referendum_timeline(
+			index: pallet_referenda::ReferendumIndex,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::referendum_timeline(index, step)
+		}
+
+		fn simulate_timeline(
+			track: pallet_referenda::TrackIdOf<Runtime, ()>,
+			tally: pallet_referenda::TallyOf<Runtime, ()>,
+			step: pallet_referenda::BlockNumberFor<Runtime, ()>,
+		) -> Option<pallet_referenda::DecisionTimelineOf<Runtime, ()>> {
+			Referenda::simulate_timeline(track, tally, step)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
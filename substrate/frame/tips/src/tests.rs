@@ -122,6 +122,9 @@ impl pallet_treasury::Config for Test {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type BudgetHolder = ();
+	type BudgetOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ManageBudgetOrigin = frame_system::EnsureRoot<u128>;
 }
 
 impl pallet_treasury::Config<Instance1> for Test {
@@ -145,6 +148,9 @@ impl pallet_treasury::Config<Instance1> for Test {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type BudgetHolder = ();
+	type BudgetOrigin = frame_support::traits::NeverEnsureOrigin<()>;
+	type ManageBudgetOrigin = frame_system::EnsureRoot<u128>;
 }
 
 parameter_types! {
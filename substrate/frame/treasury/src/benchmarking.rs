@@ -110,6 +110,33 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Create a budget holder from the successful origin of `BudgetOrigin`, if it has one.
+fn create_budget_holder<T: Config<I>, I: 'static>() -> Result<T::BudgetHolder, BenchmarkError> {
+	let origin =
+		T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	T::BudgetOrigin::try_origin(origin).map_err(|_| BenchmarkError::Weightless)
+}
+
+// Give the budget holder of `origin`, if it has one, a budget of `amount` of `asset_kind`, so that
+// its spends are charged against it.
+fn set_budget_of<T: Config<I>, I: 'static>(
+	origin: &T::RuntimeOrigin,
+	asset_kind: &T::AssetKind,
+	amount: AssetBalanceOf<T, I>,
+) -> Result<(), BenchmarkError> {
+	if let Ok(holder) = T::BudgetOrigin::try_origin(origin.clone()) {
+		Treasury::<T, _>::set_budget(
+			T::ManageBudgetOrigin::try_successful_origin()
+				.map_err(|_| BenchmarkError::Weightless)?,
+			holder,
+			Box::new(asset_kind.clone()),
+			amount,
+			BudgetRollover::Full,
+		)?;
+	}
+	Ok(())
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -122,6 +149,16 @@ mod benchmarks {
 		let origin =
 			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let beneficiary = T::Lookup::lookup(beneficiary_lookup.clone()).unwrap();
+		// Charge the spend against a budget if the spend origin has one.
+		if let Ok(holder) = T::BudgetOrigin::try_origin(origin.clone()) {
+			Treasury::<T, _>::set_local_budget(
+				T::ManageBudgetOrigin::try_successful_origin()
+					.map_err(|_| BenchmarkError::Weightless)?,
+				holder,
+				value,
+				BudgetRollover::Full,
+			)?;
+		}
 
 		#[extrinsic_call]
 		_(origin as T::RuntimeOrigin, value, beneficiary_lookup);
@@ -188,6 +225,8 @@ mod benchmarks {
 		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
 			create_spend_arguments::<T, _>(SEED);
 		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		// Charge the spend against a budget if the spend origin has one.
+		set_budget_of::<T, _>(&origin, &asset_kind, amount)?;
 
 		#[extrinsic_call]
 		_(
@@ -273,6 +312,7 @@ mod benchmarks {
 		let caller: T::AccountId = account("caller", 0, SEED);
 
 		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			set_budget_of::<T, _>(&origin, &asset_kind, amount)?;
 			Treasury::<T, _>::spend(
 				origin,
 				Box::new(asset_kind),
@@ -318,6 +358,8 @@ mod benchmarks {
 		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
 		T::BalanceConverter::ensure_successful(asset_kind.clone());
 		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			// Voiding the spend refunds the budget it was charged against.
+			set_budget_of::<T, _>(&origin, &asset_kind, amount)?;
 			Treasury::<T, _>::spend(
 				origin,
 				Box::new(asset_kind.clone()),
@@ -350,6 +392,87 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_budget() -> Result<(), BenchmarkError> {
+		let origin = T::ManageBudgetOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let holder = create_budget_holder::<T, _>()?;
+		let (asset_kind, amount, _, _) = create_spend_arguments::<T, _>(SEED);
+		let rollover = BudgetRollover::Capped(amount);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			holder.clone(),
+			Box::new(asset_kind.clone()),
+			amount,
+			rollover,
+		);
+
+		assert_last_event::<T, I>(
+			Event::BudgetSet { holder, asset_kind, allocation: amount, rollover }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_budget() -> Result<(), BenchmarkError> {
+		let origin = T::ManageBudgetOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let holder = create_budget_holder::<T, _>()?;
+		let (asset_kind, amount, _, _) = create_spend_arguments::<T, _>(SEED);
+		Treasury::<T, _>::set_budget(
+			origin.clone(),
+			holder.clone(),
+			Box::new(asset_kind.clone()),
+			amount,
+			BudgetRollover::Full,
+		)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, holder.clone(), Box::new(asset_kind.clone()));
+
+		assert_last_event::<T, I>(Event::BudgetRemoved { holder, asset_kind }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_local_budget() -> Result<(), BenchmarkError> {
+		let origin = T::ManageBudgetOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let holder = create_budget_holder::<T, _>()?;
+		let (_, value, _) = setup_proposal::<T, _>(SEED);
+		let rollover = BudgetRollover::Capped(value);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, holder.clone(), value, rollover);
+
+		assert_last_event::<T, I>(
+			Event::LocalBudgetSet { holder, allocation: value, rollover }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_local_budget() -> Result<(), BenchmarkError> {
+		let origin = T::ManageBudgetOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let holder = create_budget_holder::<T, _>()?;
+		let (_, value, _) = setup_proposal::<T, _>(SEED);
+		Treasury::<T, _>::set_local_budget(
+			origin.clone(),
+			holder.clone(),
+			value,
+			BudgetRollover::Full,
+		)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, holder.clone());
+
+		assert_last_event::<T, I>(Event::LocalBudgetRemoved { holder }.into());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -69,6 +69,17 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! The `spend`s of an origin can additionally be capped per spend period and asset kind with
+//! budgets. A [`pallet::Config::BudgetOrigin`] maps origins, e.g. those of governance tracks, to
+//! budget holders, and the [`pallet::Config::ManageBudgetOrigin`] sets the budgets of each holder
+//! with the `set_budget` dispatchable. Every [`pallet::Config::SpendPeriod`] a budget is
+//! replenished with its allocation, and what is left unspent carries over according to its
+//! [`BudgetRollover`] rule. A spend which is voided or expires without being paid is refunded to
+//! the budget it was charged against. The `spend_local`s of an origin are likewise capped by its
+//! budget in the native currency, set with the `set_local_budget` dispatchable. The
+//! `budget_status`, `budgets_of` and `local_budget_status` view functions report the allocated,
+//! spent and remaining amounts of budgets in the current spend period.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -87,11 +98,11 @@ extern crate alloc;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use alloc::{boxed::Box, collections::btree_map::BTreeMap};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec::Vec};
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, One,
+		Saturating, StaticLookup, UniqueSaturatedInto, Zero,
 	},
 	PerThing, Permill, RuntimeDebug,
 };
@@ -124,6 +135,8 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 type BeneficiaryLookupOf<T, I> = <<T as Config<I>>::BeneficiaryLookup as StaticLookup>::Source;
 pub type BlockNumberFor<T, I = ()> =
 	<<T as Config<I>>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
+pub type BudgetOf<T, I = ()> = Budget<AssetBalanceOf<T, I>, BlockNumberFor<T, I>>;
+pub type LocalBudgetOf<T, I = ()> = Budget<BalanceOf<T, I>, BlockNumberFor<T, I>>;
 
 /// A trait to allow the Treasury Pallet to spend it's funds for other purposes.
 /// There is an expectation that the implementer of this trait will correctly manage
@@ -226,6 +239,135 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// How the unspent part of a budget carries over into the next spend period.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub enum BudgetRollover<Balance> {
+	/// Nothing carries over; every spend period starts with just the allocation.
+	Reset,
+	/// Everything unspent carries over.
+	Full,
+	/// Unspent funds carry over, up to the given amount.
+	Capped(Balance),
+}
+
+/// A budget of some asset kind, capping the spends of a budget holder per spend period.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct Budget<Balance, BlockNumber> {
+	/// The amount allocated every spend period.
+	pub allocation: Balance,
+	/// How the unspent part of the budget carries over into the next spend period.
+	pub rollover: BudgetRollover<Balance>,
+	/// The amount carried over into the spend period starting at `period`.
+	pub carried: Balance,
+	/// The amount spent in the spend period starting at `period`.
+	pub spent: Balance,
+	/// The first block of the spend period `carried` and `spent` refer to.
+	pub period: BlockNumber,
+}
+
+impl<Balance, BlockNumber> Budget<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The amount which can be spent in the spend period, rollover included.
+	pub fn allocated(&self) -> Balance {
+		self.allocation.saturating_add(self.carried)
+	}
+
+	/// The amount which can still be spent in the spend period.
+	pub fn remaining(&self) -> Balance {
+		self.allocated().saturating_sub(self.spent)
+	}
+
+	/// Move the budget forward to the spend period starting at `period`, applying the rollover
+	/// rule for every spend period of length `spend_period` that has passed.
+	pub fn roll(&mut self, period: BlockNumber, spend_period: BlockNumber) {
+		if period <= self.period {
+			return
+		}
+		let periods_passed =
+			((period - self.period) / spend_period.max(One::one())).max(One::one());
+		// Periods passed without any spend carry over their full allocation.
+		let idle_periods: u32 = periods_passed.saturating_sub(One::one()).unique_saturated_into();
+		let unspent = self
+			.remaining()
+			.saturating_add(self.allocation.saturating_mul(Balance::from(idle_periods)));
+		self.carried = match self.rollover {
+			BudgetRollover::Reset => Zero::zero(),
+			BudgetRollover::Full => unspent,
+			BudgetRollover::Capped(cap) => unspent.min(cap),
+		};
+		self.spent = Zero::zero();
+		self.period = period;
+	}
+
+	/// Give back `amount` charged in the spend period starting at `charged_in`, returning the
+	/// amount given back. Refunds of spends charged in an earlier spend period are carried over
+	/// into the current one as far as the rollover rule allows, as if they had not been spent.
+	pub fn refund(&mut self, amount: Balance, charged_in: BlockNumber) -> Balance {
+		if charged_in == self.period {
+			let refunded = amount.min(self.spent);
+			self.spent = self.spent.saturating_sub(refunded);
+			return refunded
+		}
+		let carried = match self.rollover {
+			BudgetRollover::Reset => self.carried,
+			BudgetRollover::Full => self.carried.saturating_add(amount),
+			BudgetRollover::Capped(cap) =>
+				self.carried.saturating_add(amount).min(cap.max(self.carried)),
+		};
+		let refunded = carried.saturating_sub(self.carried);
+		self.carried = carried;
+		refunded
+	}
+}
+
+/// The state of a budget in the current spend period.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct BudgetReport<Balance> {
+	/// The amount which can be spent in the spend period, rollover included.
+	pub allocated: Balance,
+	/// The amount spent in the spend period.
+	pub spent: Balance,
+	/// The amount which can still be spent in the spend period.
+	pub remaining: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -318,6 +460,16 @@ pub mod pallet {
 
 		/// Provider for the block number. Normally this is the `frame_system` pallet.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Type identifying the holder of a set of budgets, e.g. a governance track.
+		type BudgetHolder: Parameter + MaxEncodedLen;
+
+		/// The origin whose `spend`s count against the budgets of the returned budget holder.
+		/// Spends of origins which it does not accept are not subject to any budget.
+		type BudgetOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::BudgetHolder>;
+
+		/// The origin required for setting and removing budgets.
+		type ManageBudgetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	#[pallet::extra_constants]
@@ -386,6 +538,35 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
 
+	/// The budgets of each budget holder, per asset kind. Spends of an asset kind without a
+	/// budget are only limited by the [`Config::SpendOrigin`].
+	#[pallet::storage]
+	pub type Budgets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::BudgetHolder,
+		Blake2_128Concat,
+		T::AssetKind,
+		BudgetOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The budgets of each budget holder in the native currency, capping their `spend_local`s.
+	#[pallet::storage]
+	pub type LocalBudgets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::BudgetHolder, LocalBudgetOf<T, I>, OptionQuery>;
+
+	/// The budget holder and spend period each pending spend was charged to, so that the budget
+	/// can be refunded if the spend is voided or expires.
+	#[pallet::storage]
+	pub type SpendBudgets<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		(T::BudgetHolder, BlockNumberFor<T, I>),
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -444,6 +625,42 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// The budget of a budget holder for an asset kind was set.
+		BudgetSet {
+			holder: T::BudgetHolder,
+			asset_kind: T::AssetKind,
+			allocation: AssetBalanceOf<T, I>,
+			rollover: BudgetRollover<AssetBalanceOf<T, I>>,
+		},
+		/// The budget of a budget holder for an asset kind was removed.
+		BudgetRemoved { holder: T::BudgetHolder, asset_kind: T::AssetKind },
+		/// An approved spend was charged against a budget.
+		BudgetSpent {
+			holder: T::BudgetHolder,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			remaining: AssetBalanceOf<T, I>,
+		},
+		/// A voided or expired spend was refunded to the budget it was charged against.
+		BudgetRefunded {
+			holder: T::BudgetHolder,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+		},
+		/// The budget of a budget holder in the native currency was set.
+		LocalBudgetSet {
+			holder: T::BudgetHolder,
+			allocation: BalanceOf<T, I>,
+			rollover: BudgetRollover<BalanceOf<T, I>>,
+		},
+		/// The budget of a budget holder in the native currency was removed.
+		LocalBudgetRemoved { holder: T::BudgetHolder },
+		/// An approved local spend was charged against a budget in the native currency.
+		LocalBudgetSpent {
+			holder: T::BudgetHolder,
+			amount: BalanceOf<T, I>,
+			remaining: BalanceOf<T, I>,
+		},
 	}
 
 	/// Error for the treasury pallet.
@@ -472,6 +689,14 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// The budget of the spend origin for the asset kind has been spent in full for the
+		/// current spend period.
+		BudgetExhausted,
+		/// The amount to be spent exceeds what is left of the budget of the spend origin for the
+		/// asset kind in the current spend period.
+		InsufficientBudget,
+		/// There is no budget for the budget holder and asset kind.
+		NoBudget,
 	}
 
 	#[pallet::hooks]
@@ -538,6 +763,10 @@ pub mod pallet {
 		/// NOTE: For record-keeping purposes, the proposer is deemed to be equivalent to the
 		/// beneficiary.
 		///
+		/// If the origin is also a [`Config::BudgetOrigin`], the spend is charged against the
+		/// native budget of its budget holder in the current spend period, if there is one, and
+		/// fails if it exceeds what is left of that budget.
+		///
 		/// ### Parameters
 		/// - `amount`: The amount to be transferred from the treasury to the `beneficiary`.
 		/// - `beneficiary`: The destination account for the transfer.
@@ -545,6 +774,7 @@ pub mod pallet {
 		/// ## Events
 		///
 		/// Emits [`Event::SpendApproved`] if successful.
+		/// Emits [`Event::LocalBudgetSpent`] if the spend was charged against a budget.
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::spend_local())]
 		#[deprecated(
@@ -556,6 +786,7 @@ pub mod pallet {
 			#[pallet::compact] amount: BalanceOf<T, I>,
 			beneficiary: AccountIdLookupOf<T>,
 		) -> DispatchResult {
+			let budget_holder = T::BudgetOrigin::try_origin(origin.clone()).ok();
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
 			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
 
@@ -579,6 +810,10 @@ pub mod pallet {
 			})
 			.unwrap_or(Ok(()))?;
 
+			if let Some(holder) = budget_holder {
+				Self::charge_local_budget(holder, amount)?;
+			}
+
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 			#[allow(deprecated)]
 			let proposal_index = ProposalCount::<T, I>::get();
@@ -669,9 +904,14 @@ pub mod pallet {
 		///   [`Config::PayoutPeriod`]. If `None`, the spend can be claimed immediately after
 		///   approval.
 		///
+		/// If the origin is also a [`Config::BudgetOrigin`], the spend is charged against the
+		/// budget of its budget holder for `asset_kind` in the current spend period, if there is
+		/// one, and fails if it exceeds what is left of that budget.
+		///
 		/// ## Events
 		///
 		/// Emits [`Event::AssetSpendApproved`] if successful.
+		/// Emits [`Event::BudgetSpent`] if the spend was charged against a budget.
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::spend())]
 		pub fn spend(
@@ -681,6 +921,7 @@ pub mod pallet {
 			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
 			valid_from: Option<BlockNumberFor<T, I>>,
 		) -> DispatchResult {
+			let budget_holder = T::BudgetOrigin::try_origin(origin.clone()).ok();
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
 			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
 
@@ -714,6 +955,10 @@ pub mod pallet {
 			.unwrap_or(Ok(()))?;
 
 			let index = SpendCount::<T, I>::get();
+			if let Some(holder) = budget_holder {
+				Self::charge_budget(index, holder, &asset_kind, amount)?;
+			}
+
 			Spends::<T, I>::insert(
 				index,
 				SpendStatus {
@@ -792,7 +1037,8 @@ pub mod pallet {
 		///
 		/// The status check is a prerequisite for retrying a failed payout.
 		/// If a spend has either succeeded or expired, it is removed from the storage by this
-		/// function. In such instances, transaction fees are refunded.
+		/// function. In such instances, transaction fees are refunded. An expired spend is also
+		/// refunded to the budget it was charged against, if any.
 		///
 		/// ### Parameters
 		/// - `index`: The spend index.
@@ -801,6 +1047,7 @@ pub mod pallet {
 		///
 		/// Emits [`Event::PaymentFailed`] if the spend payout has failed.
 		/// Emits [`Event::SpendProcessed`] if the spend payout has succeed.
+		/// Emits [`Event::BudgetRefunded`] if an expired spend was refunded to its budget.
 		#[pallet::call_index(7)]
 		#[pallet::weight(T::WeightInfo::check_status())]
 		pub fn check_status(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
@@ -814,6 +1061,7 @@ pub mod pallet {
 			if now > spend.expire_at && !matches!(spend.status, State::Attempted { .. }) {
 				// spend has expired and no further status update is expected.
 				Spends::<T, I>::remove(index);
+				Self::refund_budget(index, spend.asset_kind, spend.amount);
 				Self::deposit_event(Event::<T, I>::SpendProcessed { index });
 				return Ok(Pays::No.into())
 			}
@@ -831,6 +1079,7 @@ pub mod pallet {
 				},
 				Status::Success | Status::Unknown => {
 					Spends::<T, I>::remove(index);
+					SpendBudgets::<T, I>::remove(index);
 					Self::deposit_event(Event::<T, I>::SpendProcessed { index });
 					return Ok(Pays::No.into())
 				},
@@ -847,7 +1096,8 @@ pub mod pallet {
 		///
 		/// ## Details
 		///
-		/// A spend void is only possible if the payout has not been attempted yet.
+		/// A spend void is only possible if the payout has not been attempted yet. The spend is
+		/// refunded to the budget it was charged against, if any.
 		///
 		/// ### Parameters
 		/// - `index`: The spend index.
@@ -855,6 +1105,7 @@ pub mod pallet {
 		/// ## Events
 		///
 		/// Emits [`Event::AssetSpendVoided`] if successful.
+		/// Emits [`Event::BudgetRefunded`] if the spend was refunded to its budget.
 		#[pallet::call_index(8)]
 		#[pallet::weight(T::WeightInfo::void_spend())]
 		pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
@@ -866,9 +1117,207 @@ pub mod pallet {
 			);
 
 			Spends::<T, I>::remove(index);
+			Self::refund_budget(index, spend.asset_kind, spend.amount);
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Set the budget of a budget holder for an asset kind.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::ManageBudgetOrigin`].
+		///
+		/// ## Details
+		///
+		/// The `spend`s of the [`Config::BudgetOrigin`] of `holder` in `asset_kind` are capped
+		/// at `allocation` per [`Config::SpendPeriod`], plus whatever carries over from previous
+		/// spend periods according to `rollover`. Updating an existing budget keeps what has been
+		/// spent and carried over in the current spend period.
+		///
+		/// ### Parameters
+		/// - `holder`: The budget holder.
+		/// - `asset_kind`: The asset kind the budget is in.
+		/// - `allocation`: The amount allocated every spend period.
+		/// - `rollover`: How the unspent part of the budget carries over into the next spend
+		///   period.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::BudgetSet`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_budget())]
+		pub fn set_budget(
+			origin: OriginFor<T>,
+			holder: T::BudgetHolder,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] allocation: AssetBalanceOf<T, I>,
+			rollover: BudgetRollover<AssetBalanceOf<T, I>>,
+		) -> DispatchResult {
+			T::ManageBudgetOrigin::ensure_origin(origin)?;
+
+			let period = Self::current_spend_period();
+			Budgets::<T, I>::mutate(&holder, &*asset_kind, |maybe_budget| {
+				let budget = maybe_budget.get_or_insert_with(|| Budget {
+					allocation,
+					rollover,
+					carried: Zero::zero(),
+					spent: Zero::zero(),
+					period,
+				});
+				budget.roll(period, T::SpendPeriod::get());
+				budget.allocation = allocation;
+				budget.rollover = rollover;
+			});
+
+			Self::deposit_event(Event::<T, I>::BudgetSet {
+				holder,
+				asset_kind: *asset_kind,
+				allocation,
+				rollover,
+			});
+			Ok(())
+		}
+
+		/// Remove the budget of a budget holder for an asset kind.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::ManageBudgetOrigin`].
+		///
+		/// ## Details
+		///
+		/// The `spend`s of the budget holder in `asset_kind` are no longer capped per spend
+		/// period.
+		///
+		/// ### Parameters
+		/// - `holder`: The budget holder.
+		/// - `asset_kind`: The asset kind the budget is in.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::BudgetRemoved`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::remove_budget())]
+		pub fn remove_budget(
+			origin: OriginFor<T>,
+			holder: T::BudgetHolder,
+			asset_kind: Box<T::AssetKind>,
+		) -> DispatchResult {
+			T::ManageBudgetOrigin::ensure_origin(origin)?;
+			Budgets::<T, I>::take(&holder, &*asset_kind).ok_or(Error::<T, I>::NoBudget)?;
+
+			Self::deposit_event(Event::<T, I>::BudgetRemoved { holder, asset_kind: *asset_kind });
+			Ok(())
+		}
+
+		/// Set the budget of a budget holder in the native currency.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::ManageBudgetOrigin`].
+		///
+		/// ## Details
+		///
+		/// The `spend_local`s of the [`Config::BudgetOrigin`] of `holder` are capped at
+		/// `allocation` per [`Config::SpendPeriod`], plus whatever carries over from previous
+		/// spend periods according to `rollover`. Updating an existing budget keeps what has been
+		/// spent and carried over in the current spend period.
+		///
+		/// ### Parameters
+		/// - `holder`: The budget holder.
+		/// - `allocation`: The amount allocated every spend period.
+		/// - `rollover`: How the unspent part of the budget carries over into the next spend
+		///   period.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::LocalBudgetSet`] if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_local_budget())]
+		pub fn set_local_budget(
+			origin: OriginFor<T>,
+			holder: T::BudgetHolder,
+			#[pallet::compact] allocation: BalanceOf<T, I>,
+			rollover: BudgetRollover<BalanceOf<T, I>>,
+		) -> DispatchResult {
+			T::ManageBudgetOrigin::ensure_origin(origin)?;
+
+			let period = Self::current_spend_period();
+			LocalBudgets::<T, I>::mutate(&holder, |maybe_budget| {
+				let budget = maybe_budget.get_or_insert_with(|| Budget {
+					allocation,
+					rollover,
+					carried: Zero::zero(),
+					spent: Zero::zero(),
+					period,
+				});
+				budget.roll(period, T::SpendPeriod::get());
+				budget.allocation = allocation;
+				budget.rollover = rollover;
+			});
+
+			Self::deposit_event(Event::<T, I>::LocalBudgetSet { holder, allocation, rollover });
+			Ok(())
+		}
+
+		/// Remove the budget of a budget holder in the native currency.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::ManageBudgetOrigin`].
+		///
+		/// ## Details
+		///
+		/// The `spend_local`s of the budget holder are no longer capped per spend period.
+		///
+		/// ### Parameters
+		/// - `holder`: The budget holder.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::LocalBudgetRemoved`] if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_local_budget())]
+		pub fn remove_local_budget(
+			origin: OriginFor<T>,
+			holder: T::BudgetHolder,
+		) -> DispatchResult {
+			T::ManageBudgetOrigin::ensure_origin(origin)?;
+			LocalBudgets::<T, I>::take(&holder).ok_or(Error::<T, I>::NoBudget)?;
+
+			Self::deposit_event(Event::<T, I>::LocalBudgetRemoved { holder });
+			Ok(())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The state of the budget of `holder` for `asset_kind` in the current spend period, if
+		/// there is one.
+		pub fn budget_status(
+			holder: T::BudgetHolder,
+			asset_kind: T::AssetKind,
+		) -> Option<BudgetReport<AssetBalanceOf<T, I>>> {
+			Budgets::<T, I>::get(holder, asset_kind).map(Self::budget_report)
+		}
+
+		/// The state of all budgets of `holder` in the current spend period.
+		pub fn budgets_of(
+			holder: T::BudgetHolder,
+		) -> Vec<(T::AssetKind, BudgetReport<AssetBalanceOf<T, I>>)> {
+			Budgets::<T, I>::iter_prefix(holder)
+				.map(|(asset_kind, budget)| (asset_kind, Self::budget_report(budget)))
+				.collect()
+		}
+
+		/// The state of the budget of `holder` in the native currency in the current spend
+		/// period, if there is one.
+		pub fn local_budget_status(
+			holder: T::BudgetHolder,
+		) -> Option<BudgetReport<BalanceOf<T, I>>> {
+			LocalBudgets::<T, I>::get(holder).map(Self::budget_report)
+		}
 	}
 }
 
@@ -902,6 +1351,87 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		last_spend_period
 	}
 
+	/// The first block of the current spend period.
+	fn current_spend_period() -> BlockNumberFor<T, I> {
+		LastSpendPeriod::<T, I>::get().unwrap_or_else(|| {
+			let block_number = T::BlockNumberProvider::current_block_number();
+			let spend_period = T::SpendPeriod::get().max(BlockNumberFor::<T, I>::one());
+			block_number.saturating_sub(block_number % spend_period)
+		})
+	}
+
+	/// Charge the spend `index` of `amount` of `asset_kind` against the budget of `holder`, if
+	/// there is one.
+	fn charge_budget(
+		index: SpendIndex,
+		holder: T::BudgetHolder,
+		asset_kind: &T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+	) -> DispatchResult {
+		let Some(mut budget) = Budgets::<T, I>::get(&holder, asset_kind) else { return Ok(()) };
+		let remaining = Self::charge(&mut budget, amount)?;
+		SpendBudgets::<T, I>::insert(index, (holder.clone(), budget.period));
+		Budgets::<T, I>::insert(&holder, asset_kind, budget);
+
+		Self::deposit_event(Event::BudgetSpent {
+			holder,
+			asset_kind: asset_kind.clone(),
+			amount,
+			remaining,
+		});
+		Ok(())
+	}
+
+	/// Charge a local spend of `amount` against the native budget of `holder`, if there is one.
+	fn charge_local_budget(holder: T::BudgetHolder, amount: BalanceOf<T, I>) -> DispatchResult {
+		let Some(mut budget) = LocalBudgets::<T, I>::get(&holder) else { return Ok(()) };
+		let remaining = Self::charge(&mut budget, amount)?;
+		LocalBudgets::<T, I>::insert(&holder, budget);
+
+		Self::deposit_event(Event::LocalBudgetSpent { holder, amount, remaining });
+		Ok(())
+	}
+
+	/// Charge `amount` against `budget` in the current spend period, returning what is left.
+	fn charge<Balance: AtLeast32BitUnsigned + Copy>(
+		budget: &mut Budget<Balance, BlockNumberFor<T, I>>,
+		amount: Balance,
+	) -> Result<Balance, Error<T, I>> {
+		budget.roll(Self::current_spend_period(), T::SpendPeriod::get());
+
+		let remaining = budget.remaining();
+		ensure!(!remaining.is_zero(), Error::<T, I>::BudgetExhausted);
+		ensure!(amount <= remaining, Error::<T, I>::InsufficientBudget);
+		budget.spent.saturating_accrue(amount);
+		Ok(remaining.saturating_sub(amount))
+	}
+
+	/// Refund the spend `index` of `amount` of `asset_kind` to the budget it was charged
+	/// against, if any.
+	fn refund_budget(index: SpendIndex, asset_kind: T::AssetKind, amount: AssetBalanceOf<T, I>) {
+		let Some((holder, charged_in)) = SpendBudgets::<T, I>::take(index) else { return };
+		let refunded = Budgets::<T, I>::mutate(&holder, &asset_kind, |maybe_budget| {
+			let Some(budget) = maybe_budget else { return Zero::zero() };
+			budget.roll(Self::current_spend_period(), T::SpendPeriod::get());
+			budget.refund(amount, charged_in)
+		});
+		if !refunded.is_zero() {
+			Self::deposit_event(Event::BudgetRefunded { holder, asset_kind, amount: refunded });
+		}
+	}
+
+	/// The state of `budget` in the current spend period.
+	fn budget_report<Balance: AtLeast32BitUnsigned + Copy>(
+		mut budget: Budget<Balance, BlockNumberFor<T, I>>,
+	) -> BudgetReport<Balance> {
+		budget.roll(Self::current_spend_period(), T::SpendPeriod::get());
+		BudgetReport {
+			allocated: budget.allocated(),
+			spent: budget.spent,
+			remaining: budget.remaining(),
+		}
+	}
+
 	/// Public function to proposal_count storage.
 	#[deprecated(
 		note = "This function will be removed by May 2025. Configure pallet to use PayFromAccount for Paymaster type instead"
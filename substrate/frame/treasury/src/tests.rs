@@ -167,6 +167,24 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestSpendOrigin {
 	}
 }
 
+pub struct TestBudgetOrigin;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestBudgetOrigin {
+	type Success = u32;
+	fn try_origin(outer: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		Result::<frame_system::RawOrigin<_>, RuntimeOrigin>::from(outer.clone()).and_then(|o| {
+			match o {
+				frame_system::RawOrigin::Signed(13) => Ok(1),
+				frame_system::RawOrigin::Signed(14) => Ok(2),
+				_ => Err(outer),
+			}
+		})
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(frame_system::RawOrigin::Signed(14).into())
+	}
+}
+
 pub struct MulBy<N>(PhantomData<N>);
 impl<N: Get<u64>> ConversionFromAssetBalance<u64, u32, u64> for MulBy<N> {
 	type Error = ();
@@ -198,6 +216,9 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type BudgetHolder = u32;
+	type BudgetOrigin = TestBudgetOrigin;
+	type ManageBudgetOrigin = frame_system::EnsureRoot<u128>;
 }
 
 pub struct ExtBuilder {}
@@ -1046,3 +1067,298 @@ fn multiple_spend_periods_work() {
 		assert_eq!(LastSpendPeriod::<Test>::get(), Some(8));
 	});
 }
+
+#[test]
+fn set_and_remove_budget_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Treasury::set_budget(
+				RuntimeOrigin::signed(14),
+				2,
+				Box::new(1),
+				100,
+				BudgetRollover::Reset
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Treasury::remove_budget(RuntimeOrigin::root(), 2, Box::new(1)),
+			Error::<Test>::NoBudget
+		);
+
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			2,
+			Box::new(1),
+			100,
+			BudgetRollover::Reset
+		));
+		System::assert_last_event(
+			Event::<Test, _>::BudgetSet {
+				holder: 2,
+				asset_kind: 1,
+				allocation: 100,
+				rollover: BudgetRollover::Reset,
+			}
+			.into(),
+		);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 40, Box::new(6), None));
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 100, spent: 40, remaining: 60 })
+		);
+
+		// Updating the allocation keeps what has been spent in the spend period.
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			2,
+			Box::new(1),
+			50,
+			BudgetRollover::Full
+		));
+		assert_eq!(
+			Treasury::budgets_of(2),
+			vec![(1, BudgetReport { allocated: 50, spent: 40, remaining: 10 })]
+		);
+
+		assert_noop!(Treasury::remove_budget(RuntimeOrigin::signed(14), 2, Box::new(1)), BadOrigin);
+		assert_ok!(Treasury::remove_budget(RuntimeOrigin::root(), 2, Box::new(1)));
+		System::assert_last_event(
+			Event::<Test, _>::BudgetRemoved { holder: 2, asset_kind: 1 }.into(),
+		);
+		assert_eq!(Treasury::budget_status(2, 1), None);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 200, Box::new(6), None));
+	});
+}
+
+#[test]
+fn budget_caps_spends_per_spend_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			2,
+			Box::new(1),
+			30,
+			BudgetRollover::Reset
+		));
+
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 20, Box::new(6), None));
+		System::assert_last_event(
+			Event::<Test, _>::AssetSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 20,
+				beneficiary: 6,
+				valid_from: 1,
+				expire_at: 6,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::<Test, _>::BudgetSpent { holder: 2, asset_kind: 1, amount: 20, remaining: 10 }
+				.into(),
+		);
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 11, Box::new(6), None),
+			Error::<Test>::InsufficientBudget
+		);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 10, Box::new(6), None));
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 1, Box::new(6), None),
+			Error::<Test>::BudgetExhausted
+		);
+
+		// Other asset kinds, budget holders and origins without a budget holder are not capped.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(2), 50, Box::new(6), None));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(13), Box::new(1), 10, Box::new(6), None));
+		assert_ok!(Treasury::spend(RuntimeOrigin::root(), Box::new(1), 50, Box::new(6), None));
+
+		// The budget is replenished in the next spend period.
+		go_to_block(2);
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 30, spent: 0, remaining: 30 })
+		);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 30, Box::new(6), None));
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 1, Box::new(6), None),
+			Error::<Test>::BudgetExhausted
+		);
+	});
+}
+
+#[test]
+fn budget_rollover_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			2,
+			Box::new(1),
+			10,
+			BudgetRollover::Full
+		));
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			1,
+			Box::new(1),
+			10,
+			BudgetRollover::Capped(4)
+		));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(13), Box::new(1), 3, Box::new(6), None));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 3, Box::new(6), None));
+
+		go_to_block(2);
+		assert_eq!(
+			Treasury::budget_status(1, 1),
+			Some(BudgetReport { allocated: 14, spent: 0, remaining: 14 })
+		);
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 17, spent: 0, remaining: 17 })
+		);
+
+		// Spend periods without any spend carry over their full allocation.
+		go_to_block(6);
+		assert_eq!(
+			Treasury::budget_status(1, 1),
+			Some(BudgetReport { allocated: 14, spent: 0, remaining: 14 })
+		);
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 37, spent: 0, remaining: 37 })
+		);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 37, Box::new(6), None));
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(13), Box::new(1), 15, Box::new(6), None),
+			Error::<Test>::InsufficientBudget
+		);
+	});
+}
+
+#[test]
+fn budget_is_refunded_when_spend_is_voided_or_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			2,
+			Box::new(1),
+			30,
+			BudgetRollover::Reset
+		));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 20, Box::new(6), None));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 10, Box::new(6), None));
+		assert_eq!(SpendBudgets::<Test>::get(0), Some((2, 0)));
+
+		// Voiding a spend refunds it within the spend period it was charged in.
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		System::assert_has_event(
+			Event::<Test, _>::BudgetRefunded { holder: 2, asset_kind: 1, amount: 20 }.into(),
+		);
+		assert_eq!(SpendBudgets::<Test>::get(0), None);
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 30, spent: 10, remaining: 20 })
+		);
+
+		// A paid spend is not refunded.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 5, Box::new(6), None));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 2));
+		set_status(get_payment_id(2).expect("no payment attempt"), PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 2));
+		assert_eq!(SpendBudgets::<Test>::get(2), None);
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 30, spent: 15, remaining: 15 })
+		);
+
+		// A spend expiring in a later spend period is subject to the rollover rule, which
+		// carries nothing over.
+		go_to_block(7);
+		System::reset_events();
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 1));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Treasury(Event::BudgetRefunded { .. })
+		)));
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 30, spent: 0, remaining: 30 })
+		);
+		assert_eq!(SpendBudgets::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn refund_from_earlier_spend_period_follows_rollover() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			2,
+			Box::new(1),
+			30,
+			BudgetRollover::Capped(70)
+		));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(14), Box::new(1), 30, Box::new(6), None));
+
+		// Two idle spend periods carry over 60, so only 10 of the expired spend fits in the cap.
+		go_to_block(7);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(
+			Event::<Test, _>::BudgetRefunded { holder: 2, asset_kind: 1, amount: 10 }.into(),
+		);
+		assert_eq!(
+			Treasury::budget_status(2, 1),
+			Some(BudgetReport { allocated: 100, spent: 0, remaining: 100 })
+		);
+	});
+}
+
+#[test]
+fn local_budget_caps_local_spends() {
+	#[allow(deprecated)]
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Treasury::set_local_budget(RuntimeOrigin::signed(14), 2, 30, BudgetRollover::Reset),
+			BadOrigin
+		);
+		assert_ok!(Treasury::set_local_budget(RuntimeOrigin::root(), 2, 30, BudgetRollover::Reset));
+		System::assert_last_event(
+			Event::<Test, _>::LocalBudgetSet {
+				holder: 2,
+				allocation: 30,
+				rollover: BudgetRollover::Reset,
+			}
+			.into(),
+		);
+
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 20, 6));
+		System::assert_has_event(
+			Event::<Test, _>::LocalBudgetSpent { holder: 2, amount: 20, remaining: 10 }.into(),
+		);
+		assert_noop!(
+			Treasury::spend_local(RuntimeOrigin::signed(14), 11, 6),
+			Error::<Test>::InsufficientBudget
+		);
+		assert_eq!(
+			Treasury::local_budget_status(2),
+			Some(BudgetReport { allocated: 30, spent: 20, remaining: 10 })
+		);
+		// Budgets of asset kinds do not apply to local spends.
+		assert_ok!(Treasury::set_budget(
+			RuntimeOrigin::root(),
+			1,
+			Box::new(1),
+			0,
+			BudgetRollover::Reset
+		));
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(13), 10, 6));
+
+		assert_ok!(Treasury::remove_local_budget(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::<Test, _>::LocalBudgetRemoved { holder: 2 }.into());
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 11, 6));
+		assert_noop!(
+			Treasury::remove_local_budget(RuntimeOrigin::root(), 2),
+			Error::<Test>::NoBudget
+		);
+	});
+}
@@ -115,6 +115,26 @@ mod benchmark {
 		_(RawOrigin::Signed(caller), main_call, fallback_call);
 	}
 
+	#[benchmark]
+	fn batch_with_results(c: Linear<0, 1000>) {
+		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let calls = vec![call.clone(); c as usize];
+		let caller = whitelisted_caller();
+		let event_count = frame_system::Pallet::<T>::event_count();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), calls);
+
+		let result = CallResult {
+			result: Ok(()),
+			actual_weight: call.get_dispatch_info().call_weight,
+			events: (event_count, event_count),
+		};
+		assert_last_event::<T>(
+			Event::BatchCompletedWithResults { results: vec![result; c as usize] }.into(),
+		);
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		tests::new_test_ext(),
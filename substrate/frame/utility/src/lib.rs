@@ -45,6 +45,8 @@
 //!
 //! #### For batch dispatch
 //! * `batch` - Dispatch multiple calls from the sender's origin.
+//! * `batch_with_results` - Dispatch multiple calls from the sender's origin and report the result,
+//!   actual weight and events of each of them.
 //!
 //! #### For pseudonymal dispatch
 //! * `as_derivative` - Dispatch a call from a derivative signed origin.
@@ -59,18 +61,24 @@ pub mod weights;
 extern crate alloc;
 
 use alloc::{boxed::Box, vec::Vec};
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{
 	dispatch::{
 		extract_actual_weight,
 		DispatchClass::{Normal, Operational},
 		GetDispatchInfo, PostDispatchInfo,
 	},
+	storage::with_storage_layer,
 	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
+	weights::Weight,
 };
+use scale_info::TypeInfo;
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{BadOrigin, Dispatchable, TrailingZeroInput};
+use sp_runtime::{
+	traits::{BadOrigin, Dispatchable, TrailingZeroInput},
+	RuntimeDebug,
+};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
@@ -129,6 +137,8 @@ pub mod pallet {
 		IfElseMainSuccess,
 		/// The fallback call was dispatched.
 		IfElseFallbackCalled { main_error: DispatchError },
+		/// Batch of dispatches completed, with the outcome of every single item.
+		BatchCompletedWithResults { results: Vec<CallResult> },
 	}
 
 	// Align the call size to 1KB. As we are currently compiling the runtime for native/wasm
@@ -583,6 +593,83 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Send a batch of dispatch calls atomically and report the outcome of each of them.
+		/// Like `batch_all`, the batch is interrupted by the first failing call.
+		///
+		/// May be called from any origin except `None`.
+		///
+		/// - `calls`: The calls to be dispatched from the same origin. The number of call must not
+		///   exceed the constant: `batched_calls_limit` (available in constant metadata).
+		///
+		/// If origin is root then the calls are dispatched without checking origin filter. (This
+		/// includes bypassing `frame_system::Config::BaseCallFilter`).
+		///
+		/// A failing call reverts the changes and events of all the calls of the batch, while the
+		/// batch itself still succeeds so that the outcome can be reported.
+		///
+		/// ## Complexity
+		/// - O(C) where C is the number of calls to be batched.
+		///
+		/// A single `BatchCompletedWithResults` event is deposited once the batch is done,
+		/// carrying for each dispatched call its result, its actual weight and the range of indices
+		/// of the events it deposited. If a call failed, it is the last one reported and all the
+		/// ranges are empty, as the events were reverted. Only the actual weight of the dispatched
+		/// calls is charged.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(&calls);
+			let dispatch_weight = dispatch_weight.saturating_add(T::WeightInfo::batch_with_results(calls.len() as u32));
+			(dispatch_weight, dispatch_class)
+		})]
+		pub fn batch_with_results(
+			origin: OriginFor<T>,
+			calls: Vec<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			// Do not allow the `None` origin.
+			if ensure_none(origin.clone()).is_ok() {
+				return Err(BadOrigin.into())
+			}
+
+			let is_root = ensure_root(origin.clone()).is_ok();
+			let calls_len = calls.len();
+			ensure!(calls_len <= Self::batched_calls_limit() as usize, Error::<T>::TooManyCalls);
+
+			// Track the actual weight of each of the batch calls.
+			let mut weight = Weight::zero();
+			let mut results = Vec::with_capacity(calls_len);
+			let completed = with_storage_layer(|| -> DispatchResult {
+				for call in calls.into_iter() {
+					let info = call.get_dispatch_info();
+					let first_event = frame_system::Pallet::<T>::event_count();
+					// If origin is root, don't apply any dispatch filters; root can call anything.
+					let result = if is_root {
+						call.dispatch_bypass_filter(origin.clone())
+					} else {
+						call.dispatch(origin.clone())
+					};
+					let actual_weight = extract_actual_weight(&result, &info);
+					weight = weight.saturating_add(actual_weight);
+					let result = result.map(|_| ()).map_err(|e| e.error);
+					results.push(CallResult {
+						result: result.clone(),
+						actual_weight,
+						events: (first_event, frame_system::Pallet::<T>::event_count()),
+					});
+					result?;
+				}
+				Ok(())
+			})
+			.is_ok();
+			if !completed {
+				// The events of all the calls were reverted together with their changes.
+				let event_count = frame_system::Pallet::<T>::event_count();
+				results.iter_mut().for_each(|r| r.events = (event_count, event_count));
+			}
+			let base_weight = T::WeightInfo::batch_with_results(results.len() as u32);
+			Self::deposit_event(Event::BatchCompletedWithResults { results });
+			Ok(Some(base_weight.saturating_add(weight)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -607,6 +694,18 @@ pub mod pallet {
 	}
 }
 
+/// The outcome of a single call dispatched by [`Pallet::batch_with_results`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, DecodeWithMemTracking, RuntimeDebug, TypeInfo)]
+pub struct CallResult {
+	/// The result of the call.
+	pub result: sp_runtime::DispatchResult,
+	/// The weight actually consumed by the call, after any refund.
+	pub actual_weight: Weight,
+	/// The range `[start, end)` of the indices in `frame_system::Events` of the events deposited
+	/// by the call.
+	pub events: (u32, u32),
+}
+
 /// A pallet identifier. These are per pallet and should be stored in a registry somewhere.
 #[derive(Clone, Copy, Eq, PartialEq, Encode, Decode)]
 struct IndexedUtilityPalletId(u16);
@@ -749,6 +749,146 @@ fn force_batch_works() {
 	});
 }
 
+#[test]
+fn batch_with_results_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		let event_count = System::event_count();
+		assert_ok!(Utility::batch_with_results(
+			RuntimeOrigin::signed(1),
+			vec![
+				call_transfer(2, 5),
+				call_foobar(false, Weight::from_parts(50, 0), Some(Weight::from_parts(20, 0))),
+				call_transfer(2, 5),
+			]
+		));
+		assert_eq!(Balances::free_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), 20);
+
+		let transfer_weight = call_transfer(2, 5).get_dispatch_info().call_weight;
+		let utility::Event::BatchCompletedWithResults { results } = utility_events().pop().unwrap()
+		else {
+			panic!("expected a `BatchCompletedWithResults` event");
+		};
+		assert_eq!(results.len(), 3);
+		// The first transfer deposits events.
+		assert_eq!(results[0].result, Ok(()));
+		assert_eq!(results[0].actual_weight, transfer_weight);
+		assert_eq!(results[0].events.0, event_count);
+		assert!(results[0].events.1 > event_count);
+		// The successful call is refunded.
+		assert_eq!(results[1].result, Ok(()));
+		assert_eq!(results[1].actual_weight, Weight::from_parts(20, 0));
+		assert_eq!(results[1].events, (results[0].events.1, results[0].events.1));
+		assert_eq!(results[2].result, Ok(()));
+		assert_eq!(results[2].events.0, results[1].events.1);
+
+		// The events ranges point to the events deposited by each call.
+		let events = System::events();
+		let (start, end) = results[2].events;
+		assert!(events[start as usize..end as usize]
+			.iter()
+			.any(|r| r.event ==
+				pallet_balances::Event::Transfer { from: 1, to: 2, amount: 5 }.into()));
+		assert_eq!(events.len() as u32, end + 1);
+	});
+}
+
+#[test]
+fn batch_with_results_reverts_all_calls_on_failure() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		let event_count = System::event_count();
+		assert_ok!(Utility::batch_with_results(
+			RuntimeOrigin::signed(1),
+			vec![
+				call_transfer(2, 5),
+				call_foobar(true, Weight::from_parts(75, 0), Some(Weight::from_parts(25, 0))),
+				call_transfer(2, 5),
+			]
+		));
+		// The first transfer was reverted, and the last one was not dispatched.
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+
+		let transfer_weight = call_transfer(2, 5).get_dispatch_info().call_weight;
+		let utility::Event::BatchCompletedWithResults { results } = utility_events().pop().unwrap()
+		else {
+			panic!("expected a `BatchCompletedWithResults` event");
+		};
+		assert_eq!(
+			results,
+			vec![
+				CallResult {
+					result: Ok(()),
+					actual_weight: transfer_weight,
+					events: (event_count, event_count),
+				},
+				CallResult {
+					result: Err(DispatchError::Other("")),
+					actual_weight: Weight::from_parts(25, 0),
+					events: (event_count, event_count),
+				},
+			]
+		);
+		// Only the event of the batch itself is left.
+		assert_eq!(System::event_count(), event_count + 1);
+	});
+}
+
+#[test]
+fn batch_with_results_weight_calculation() {
+	new_test_ext().execute_with(|| {
+		let good_call =
+			call_foobar(false, Weight::from_parts(10, 0), Some(Weight::from_parts(5, 0)));
+		let bad_call = call_foobar(true, Weight::from_parts(10, 0), Some(Weight::from_parts(1, 0)));
+		let batch_call = RuntimeCall::Utility(crate::Call::batch_with_results {
+			calls: vec![good_call.clone(), bad_call, good_call],
+		});
+		let info = batch_call.get_dispatch_info();
+		assert_eq!(
+			info.call_weight,
+			<Test as Config>::WeightInfo::batch_with_results(3) + Weight::from_parts(30, 0)
+		);
+		// The batch stops at the failing call.
+		let result = batch_call.dispatch(RuntimeOrigin::signed(1));
+		assert_eq!(
+			extract_actual_weight(&result, &info),
+			<Test as Config>::WeightInfo::batch_with_results(2) + Weight::from_parts(6, 0)
+		);
+	});
+}
+
+#[test]
+fn batch_with_results_respects_filters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Utility::batch_with_results(RuntimeOrigin::none(), vec![]), BadOrigin);
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert_ok!(Utility::batch_with_results(RuntimeOrigin::signed(1), vec![remark]));
+		// Filtered call is reported as failed.
+		let filtered = RuntimeCall::Balances(pallet_balances::Call::force_transfer {
+			source: 1,
+			dest: 2,
+			value: 1,
+		});
+		assert_ok!(Utility::batch_with_results(RuntimeOrigin::signed(1), vec![filtered.clone()]));
+		let utility::Event::BatchCompletedWithResults { results } = utility_events().pop().unwrap()
+		else {
+			panic!("expected a `BatchCompletedWithResults` event");
+		};
+		assert_eq!(results[0].result, Err(frame_system::Error::<Test>::CallFiltered.into()));
+		// Root bypasses the filters.
+		assert_ok!(Utility::batch_with_results(RuntimeOrigin::root(), vec![filtered]));
+		let utility::Event::BatchCompletedWithResults { results } = utility_events().pop().unwrap()
+		else {
+			panic!("expected a `BatchCompletedWithResults` event");
+		};
+		assert_eq!(results[0].result, Ok(()));
+	});
+}
+
 #[test]
 fn none_origin_does_not_work() {
 	new_test_ext().execute_with(|| {
@@ -69,6 +69,47 @@ fn add_vesting_schedules<T: Config>(
 	Ok(total_locked)
 }
 
+// The worst case release curve: as many milestones as possible, each vesting `MinVestedTransfer`
+// from block 1 on.
+fn worst_release_curve<T: Config>() -> (ReleaseCurveOf<T>, BalanceOf<T>) {
+	let min_transfer = T::MinVestedTransfer::get();
+	let milestones = T::MaxMilestones::get();
+	if milestones == 0 {
+		let curve =
+			ReleaseCurve::Linear { start: 1_u32.into(), cliff: 1_u32.into(), end: 3_u32.into() };
+		return (curve, min_transfer.saturating_mul(2_u32.into()))
+	}
+	let curve = ReleaseCurve::Milestones(BoundedVec::truncate_from(
+		(1..=milestones).map(|i| (i.into(), min_transfer)).collect(),
+	));
+	(curve, min_transfer.saturating_mul(milestones.into()))
+}
+
+// Place funds under a release schedule for `target`, revocable in favour of `beneficiary`.
+fn add_release_schedule<T: Config>(
+	target: &T::AccountId,
+	beneficiary: &T::AccountId,
+) -> Result<(), BenchmarkError> {
+	let source: T::AccountId = account("source", 0, SEED);
+	T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value() / 2_u32.into());
+	let (curve, total) = worst_release_curve::<T>();
+	T::BlockNumberProvider::set_block_number(BlockNumberFor::<T>::zero());
+	Pallet::<T>::create_release_schedule(
+		RawOrigin::Signed(source).into(),
+		T::Lookup::unlookup(target.clone()),
+		VestingAsset::Native,
+		total,
+		curve,
+		Some(T::Lookup::unlookup(beneficiary.clone())),
+	)?;
+	Ok(())
+}
+
+// A block at which the release schedules of `add_release_schedule` are partly vested.
+fn partly_vested_block<T: Config>() -> BlockNumberFor<T> {
+	(T::MaxMilestones::get() / 2).max(1).into()
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -435,6 +476,64 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn create_release_schedule() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2_u32.into());
+		let target: T::AccountId = account("target", 0, SEED);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let (curve, total) = worst_release_curve::<T>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(target.clone()),
+			VestingAsset::Native,
+			total,
+			curve,
+			Some(T::Lookup::unlookup(beneficiary)),
+		);
+
+		assert!(ReleaseSchedules::<T>::contains_key(&target, 0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_vested() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		add_release_schedule::<T>(&target, &beneficiary)?;
+		// Completing the last schedule also releases its deposit and returns the minimum balance.
+		T::BlockNumberProvider::set_block_number(BlockNumberFor::<T>::max_value());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(target.clone()), 0);
+
+		assert!(!ReleaseSchedules::<T>::contains_key(&target, 0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_release_schedule() -> Result<(), BenchmarkError> {
+		let origin =
+			T::RevokeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let target: T::AccountId = account("target", 0, SEED);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		add_release_schedule::<T>(&target, &beneficiary)?;
+		T::BlockNumberProvider::set_block_number(partly_vested_block::<T>());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(target.clone()), 0);
+
+		assert!(!ReleaseSchedules::<T>::contains_key(&target, 0));
+		assert!(!T::Currency::free_balance(&beneficiary).is_zero());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::ExtBuilder::default().existential_deposit(256).build(),
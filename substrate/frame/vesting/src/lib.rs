@@ -42,6 +42,20 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `create_release_schedule` - Place funds of the native currency or of another asset under a
+//!   release schedule with a cliff or milestones, optionally revocable.
+//! - `release_vested` - Pay out the funds of a release schedule vested so far.
+//! - `revoke_release_schedule` - Revoke a release schedule, returning the unvested remainder.
+//!
+//! ### Release Schedules
+//!
+//! Besides the linear schedules locking funds in place, funds can be placed under release
+//! schedules. These are held by the pallet account and paid out to the beneficiary as they vest,
+//! which allows vesting any asset of [`Config::Assets`] and not only the native currency. Funds
+//! vest either linearly after a cliff, or in steps at given milestones. A release schedule created
+//! with a revocation beneficiary can be revoked by [`Config::RevokeOrigin`], in which case the
+//! vested funds are paid out to the beneficiary and the unvested remainder to the revocation
+//! beneficiary.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -49,6 +63,7 @@ mod benchmarking;
 
 #[cfg(test)]
 mod mock;
+mod release_schedule;
 #[cfg(test)]
 mod tests;
 mod vesting_info;
@@ -64,24 +79,28 @@ use core::{fmt::Debug, marker::PhantomData};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	storage::bounded_vec::BoundedVec,
+	storage::{bounded_vec::BoundedVec, with_storage_layer},
 	traits::{
-		Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, VestedTransfer,
-		VestingSchedule, WithdrawReasons,
+		fungible::{self, MutateHold},
+		tokens::Precision,
+		Currency, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier, LockableCurrency,
+		VestedTransfer, VestingSchedule, WithdrawReasons,
 	},
 	weights::Weight,
+	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		AtLeast32BitUnsigned, BlockNumberProvider, Bounded, Convert, MaybeSerializeDeserialize,
-		One, Saturating, StaticLookup, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, Bounded, Convert,
+		MaybeSerializeDeserialize, One, Saturating, StaticLookup, Zero,
 	},
-	DispatchError, RuntimeDebug,
+	ArithmeticError, DispatchError, RuntimeDebug,
 };
 
 pub use pallet::*;
+pub use release_schedule::*;
 pub use vesting_info::*;
 pub use weights::WeightInfo;
 
@@ -206,6 +225,39 @@ pub mod pallet {
 
 		/// Maximum number of vesting schedules an account may have at a given moment.
 		const MAX_VESTING_SCHEDULES: u32;
+
+		/// The assets, besides the native currency, which can be placed under a release schedule.
+		type Assets: VestingAssets<Self::AccountId, BalanceOf<Self>>;
+
+		/// The origin which may revoke release schedules created as revocable.
+		type RevokeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The pallet id, used to derive the account holding the funds under release schedules.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of milestones of a release schedule.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
+		/// The amount of the native currency held from the creator of each release schedule, and
+		/// released once the schedule is completed or revoked.
+		#[pallet::constant]
+		type ReleaseScheduleDeposit: Get<BalanceOf<Self>>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The native currency, holding the deposits of release schedules.
+		type DepositCurrency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ fungible::Inspect<Self::AccountId, Balance = BalanceOf<Self>>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as deposit for a release schedule.
+		ReleaseScheduleDeposit,
 	}
 
 	#[pallet::extra_constants]
@@ -232,6 +284,34 @@ pub mod pallet {
 		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
 	>;
 
+	/// The identifier of the next release schedule.
+	#[pallet::storage]
+	pub type NextReleaseScheduleId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The release schedules of each beneficiary, by identifier.
+	#[pallet::storage]
+	pub type ReleaseSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		ReleaseScheduleOf<T>,
+	>;
+
+	/// The account which paid for the minimum balance of each asset held by the pallet account,
+	/// along with that amount and the number of release schedules of the asset.
+	///
+	/// The minimum balance is returned to the account once the last of these schedules ends. If
+	/// that fails, it is kept with no schedule left, to be returned when the next one ends.
+	#[pallet::storage]
+	pub type ExistentialDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VestingAsset<AssetKindOf<T>>,
+		(T::AccountId, BalanceOf<T>, u32),
+	>;
+
 	/// Storage version of the pallet.
 	///
 	/// New networks start with latest version, as determined by the genesis build.
@@ -293,6 +373,32 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A release schedule has been created.
+		ReleaseScheduleCreated {
+			account: T::AccountId,
+			id: u32,
+			asset: VestingAsset<AssetKindOf<T>>,
+			total: BalanceOf<T>,
+		},
+		/// Vested funds of a release schedule have been paid out.
+		Released { account: T::AccountId, id: u32, amount: BalanceOf<T> },
+		/// A release schedule has been fully paid out.
+		ReleaseScheduleCompleted { account: T::AccountId, id: u32 },
+		/// A release schedule has been revoked. The vested funds not yet released have been paid
+		/// out to the account, and the unvested remainder to the revocation beneficiary.
+		ReleaseScheduleRevoked {
+			account: T::AccountId,
+			id: u32,
+			released: BalanceOf<T>,
+			returned: BalanceOf<T>,
+		},
+		/// The minimum balance of an asset could not be returned to the account which paid for it
+		/// once the last release schedule of the asset ended. It is kept for the next schedules.
+		ExistentialDepositNotRefunded {
+			asset: VestingAsset<AssetKindOf<T>>,
+			funder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Error for the vesting pallet.
@@ -309,6 +415,12 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// No release schedule exists with the given identifier.
+		UnknownReleaseSchedule,
+		/// The release schedule was not created as revocable.
+		NotRevocable,
+		/// No identifier is left for a new release schedule.
+		NoAvailableReleaseScheduleId,
 	}
 
 	#[pallet::call]
@@ -488,10 +600,277 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Place funds of the sender under a release schedule for `target`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the funds as they vest.
+		/// - `asset`: The asset to place under the schedule.
+		/// - `total`: The amount to place under the schedule. Must be at least `MinVestedTransfer`
+		///   for the native currency, or the minimum set by `Assets` for other assets.
+		/// - `curve`: How `total` vests over time.
+		/// - `revocation_beneficiary`: The account receiving the unvested remainder if the schedule
+		///   is revoked by `RevokeOrigin`, or `None` to make the schedule irrevocable.
+		///
+		/// The funds are held by the pallet account until released with `release_vested`. If no
+		/// other release schedule of `asset` exists, the minimum balance of `asset` is also
+		/// transferred to keep the account alive, and returned to the sender once the last
+		/// schedule of `asset` is completed or revoked.
+		///
+		/// `ReleaseScheduleDeposit` of the native currency is held from the sender, and released
+		/// once the schedule is completed or revoked.
+		///
+		/// Emits `ReleaseScheduleCreated`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_release_schedule())]
+		pub fn create_release_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			asset: VestingAsset<AssetKindOf<T>>,
+			#[pallet::compact] total: BalanceOf<T>,
+			curve: ReleaseCurveOf<T>,
+			revocation_beneficiary: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let revocation_beneficiary =
+				revocation_beneficiary.map(T::Lookup::lookup).transpose()?;
+
+			let min_vested_transfer = match &asset {
+				VestingAsset::Native => T::MinVestedTransfer::get(),
+				VestingAsset::Asset(asset) => T::Assets::min_vested_transfer(asset),
+			};
+			ensure!(total >= min_vested_transfer, Error::<T>::AmountLow);
+			let deposit = T::ReleaseScheduleDeposit::get();
+			let schedule = ReleaseSchedule::new(
+				source.clone(),
+				deposit,
+				asset,
+				total,
+				curve,
+				revocation_beneficiary,
+			);
+			ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+
+			let id = NextReleaseScheduleId::<T>::try_mutate(|next| {
+				let id = *next;
+				*next = next.checked_add(1).ok_or(Error::<T>::NoAvailableReleaseScheduleId)?;
+				Ok::<_, DispatchError>(id)
+			})?;
+
+			if !deposit.is_zero() {
+				T::DepositCurrency::hold(
+					&HoldReason::ReleaseScheduleDeposit.into(),
+					&source,
+					deposit,
+				)?;
+			}
+			Self::add_schedule_of(schedule.asset(), &source)?;
+			Self::transfer_asset(schedule.asset(), &source, &Self::account_id(), total)?;
+			Self::deposit_event(Event::<T>::ReleaseScheduleCreated {
+				account: target.clone(),
+				id,
+				asset: schedule.asset().clone(),
+				total,
+			});
+			ReleaseSchedules::<T>::insert(&target, id, schedule);
+
+			Ok(())
+		}
+
+		/// Pay out the funds of a release schedule of `target` vested so far.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The beneficiary of the release schedule.
+		/// - `id`: The identifier of the release schedule.
+		///
+		/// Emits `Released` if any funds were paid out, followed by `ReleaseScheduleCompleted`
+		/// once everything has been paid out.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::release_vested())]
+		pub fn release_vested(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			id: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let mut schedule = ReleaseSchedules::<T>::get(&target, id)
+				.ok_or(Error::<T>::UnknownReleaseSchedule)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let amount = schedule.releasable_at::<T::BlockNumberToBalance>(now);
+			if !amount.is_zero() {
+				Self::transfer_asset(schedule.asset(), &Self::account_id(), &target, amount)?;
+				schedule.note_released(amount);
+				Self::deposit_event(Event::<T>::Released { account: target.clone(), id, amount });
+			}
+
+			if schedule.is_complete() {
+				ReleaseSchedules::<T>::remove(&target, id);
+				Self::end_schedule(&schedule)?;
+				Self::deposit_event(Event::<T>::ReleaseScheduleCompleted { account: target, id });
+			} else {
+				ReleaseSchedules::<T>::insert(&target, id, schedule);
+			}
+
+			Ok(())
+		}
+
+		/// Revoke a release schedule of `target`.
+		///
+		/// The dispatch origin for this call must be `RevokeOrigin`, and the schedule must have
+		/// been created with a revocation beneficiary.
+		///
+		/// - `target`: The beneficiary of the release schedule.
+		/// - `id`: The identifier of the release schedule.
+		///
+		/// The funds vested so far and not yet released are paid out to `target`, and the
+		/// unvested remainder to the revocation beneficiary of the schedule.
+		///
+		/// Emits `ReleaseScheduleRevoked`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::revoke_release_schedule())]
+		pub fn revoke_release_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			id: u32,
+		) -> DispatchResult {
+			T::RevokeOrigin::ensure_origin(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let schedule = ReleaseSchedules::<T>::take(&target, id)
+				.ok_or(Error::<T>::UnknownReleaseSchedule)?;
+			let revocation_beneficiary =
+				schedule.revocation_beneficiary().ok_or(Error::<T>::NotRevocable)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let released = schedule.releasable_at::<T::BlockNumberToBalance>(now);
+			let returned =
+				schedule.total().saturating_sub(schedule.released()).saturating_sub(released);
+			let pallet_account = Self::account_id();
+			if !released.is_zero() {
+				Self::transfer_asset(schedule.asset(), &pallet_account, &target, released)?;
+			}
+			if !returned.is_zero() {
+				Self::transfer_asset(
+					schedule.asset(),
+					&pallet_account,
+					revocation_beneficiary,
+					returned,
+				)?;
+			}
+			Self::end_schedule(&schedule)?;
+
+			Self::deposit_event(Event::<T>::ReleaseScheduleRevoked {
+				account: target,
+				id,
+				released,
+				returned,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The account holding the funds under release schedules.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The amount of the release schedule `id` of `who` which can be released now.
+	pub fn releasable(who: &T::AccountId, id: u32) -> Option<BalanceOf<T>> {
+		let now = T::BlockNumberProvider::current_block_number();
+		ReleaseSchedules::<T>::get(who, id)
+			.map(|schedule| schedule.releasable_at::<T::BlockNumberToBalance>(now))
+	}
+
+	// The minimum balance of `asset` an account must hold to exist.
+	fn minimum_balance_of(asset: &VestingAsset<AssetKindOf<T>>) -> BalanceOf<T> {
+		match asset {
+			VestingAsset::Native => T::Currency::minimum_balance(),
+			VestingAsset::Asset(asset) => T::Assets::minimum_balance(asset),
+		}
+	}
+
+	// Account for a new release schedule of `asset` created by `source`. For the only schedule of
+	// `asset`, the minimum balance of `asset` is transferred from `source` to the pallet account.
+	fn add_schedule_of(
+		asset: &VestingAsset<AssetKindOf<T>>,
+		source: &T::AccountId,
+	) -> DispatchResult {
+		ExistentialDeposits::<T>::try_mutate(asset, |maybe_deposit| {
+			match maybe_deposit {
+				Some((_, _, schedules)) => {
+					*schedules = schedules.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				},
+				None => {
+					let minimum_balance = Self::minimum_balance_of(asset);
+					Self::transfer_asset(asset, source, &Self::account_id(), minimum_balance)?;
+					*maybe_deposit = Some((source.clone(), minimum_balance, 1));
+				},
+			}
+			Ok(())
+		})
+	}
+
+	// Release the deposit of a release schedule which has ended. If it was the last schedule of
+	// its asset, try to return the minimum balance of the asset to the account which paid for it.
+	// This is best-effort, so that it never prevents releasing the schedule.
+	fn end_schedule(schedule: &ReleaseScheduleOf<T>) -> DispatchResult {
+		if !schedule.deposit().is_zero() {
+			T::DepositCurrency::release(
+				&HoldReason::ReleaseScheduleDeposit.into(),
+				schedule.creator(),
+				schedule.deposit(),
+				Precision::BestEffort,
+			)?;
+		}
+
+		let asset = schedule.asset();
+		let Some((funder, minimum_balance, schedules)) = ExistentialDeposits::<T>::get(asset)
+		else {
+			return Ok(())
+		};
+		if schedules > 1 {
+			ExistentialDeposits::<T>::insert(asset, (funder, minimum_balance, schedules - 1));
+			return Ok(())
+		}
+		let refunded = minimum_balance.is_zero() ||
+			with_storage_layer(|| {
+				Self::transfer_asset(asset, &Self::account_id(), &funder, minimum_balance)
+			})
+			.is_ok();
+		if refunded {
+			ExistentialDeposits::<T>::remove(asset);
+		} else {
+			ExistentialDeposits::<T>::insert(asset, (funder.clone(), minimum_balance, 0));
+			Self::deposit_event(Event::ExistentialDepositNotRefunded {
+				asset: asset.clone(),
+				funder,
+				amount: minimum_balance,
+			});
+		}
+		Ok(())
+	}
+
+	// Transfer `amount` of `asset` from `source` to `dest`.
+	fn transfer_asset(
+		asset: &VestingAsset<AssetKindOf<T>>,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			VestingAsset::Native =>
+				T::Currency::transfer(source, dest, amount, ExistenceRequirement::AllowDeath),
+			VestingAsset::Asset(asset) => T::Assets::transfer(asset, source, dest, amount),
+		}
+	}
+
 	// Public function for accessing vesting storage
 	pub fn vesting(
 		account: T::AccountId,
@@ -15,8 +15,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use frame_support::{derive_impl, parameter_types, traits::WithdrawReasons};
-use sp_runtime::{traits::Identity, BuildStorage};
+use alloc::collections::btree_map::BTreeMap;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, WithdrawReasons},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::Identity, BuildStorage, TokenError};
 
 use super::*;
 use crate as pallet_vesting;
@@ -48,6 +53,46 @@ parameter_types! {
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
 	pub static ExistentialDeposit: u64 = 1;
+	pub const VestingPalletId: PalletId = PalletId(*b"py/vestg");
+	pub static AssetBalances: BTreeMap<(u32, u64), u64> = BTreeMap::new();
+	pub static BlockedAccounts: Vec<u64> = Vec::new();
+	pub static ReleaseScheduleDeposit: u64 = 0;
+}
+
+/// Assets whose balances are kept in [`AssetBalances`].
+pub struct MockAssets;
+impl MockAssets {
+	pub fn balance_of(asset: u32, who: u64) -> u64 {
+		AssetBalances::get().get(&(asset, who)).copied().unwrap_or_default()
+	}
+
+	pub fn set_balance(asset: u32, who: u64, amount: u64) {
+		AssetBalances::mutate(|balances| balances.insert((asset, who), amount));
+	}
+}
+impl VestingAssets<u64, u64> for MockAssets {
+	type AssetKind = u32;
+
+	fn balance(asset: &u32, who: &u64) -> u64 {
+		Self::balance_of(*asset, *who)
+	}
+
+	fn minimum_balance(_: &u32) -> u64 {
+		1
+	}
+
+	fn min_vested_transfer(_: &u32) -> u64 {
+		10
+	}
+
+	fn transfer(asset: &u32, source: &u64, dest: &u64, amount: u64) -> DispatchResult {
+		let source_balance = Self::balance_of(*asset, *source);
+		ensure!(source_balance >= amount, TokenError::FundsUnavailable);
+		ensure!(!BlockedAccounts::get().contains(dest), TokenError::Blocked);
+		Self::set_balance(*asset, *source, source_balance - amount);
+		Self::set_balance(*asset, *dest, Self::balance_of(*asset, *dest) + amount);
+		Ok(())
+	}
 }
 impl Config for Test {
 	type BlockNumberToBalance = Identity;
@@ -58,6 +103,13 @@ impl Config for Test {
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type Assets = MockAssets;
+	type RevokeOrigin = EnsureRoot<u64>;
+	type PalletId = VestingPalletId;
+	type MaxMilestones = ConstU32<4>;
+	type ReleaseScheduleDeposit = ReleaseScheduleDeposit;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DepositCurrency = Balances;
 }
 
 pub struct ExtBuilder {
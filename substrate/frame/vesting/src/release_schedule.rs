@@ -0,0 +1,360 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Release schedules with cliffs and milestones, for the native currency or other assets.
+
+use super::*;
+use frame_support::{
+	dispatch::Parameter,
+	traits::tokens::{fungibles, Preservation},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, traits::UniqueSaturatedInto, Rounding,
+	TokenError,
+};
+
+/// The assets, besides the native currency, which can be placed under a release schedule.
+pub trait VestingAssets<AccountId, Balance> {
+	/// The kind of asset.
+	type AssetKind: Parameter + MaxEncodedLen;
+
+	/// The balance of `asset` of `who`.
+	fn balance(asset: &Self::AssetKind, who: &AccountId) -> Balance;
+
+	/// The minimum balance of `asset` an account must hold to exist.
+	fn minimum_balance(asset: &Self::AssetKind) -> Balance;
+
+	/// The minimum amount of `asset` which can be placed under a release schedule.
+	fn min_vested_transfer(asset: &Self::AssetKind) -> Balance;
+
+	/// Transfer `amount` of `asset` from `source` to `dest`.
+	fn transfer(
+		asset: &Self::AssetKind,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+/// No assets besides the native currency.
+impl<AccountId, Balance: Zero> VestingAssets<AccountId, Balance> for () {
+	type AssetKind = ();
+
+	fn balance(_: &(), _: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn minimum_balance(_: &()) -> Balance {
+		Zero::zero()
+	}
+
+	fn min_vested_transfer(_: &()) -> Balance {
+		Zero::zero()
+	}
+
+	fn transfer(_: &(), _: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Err(TokenError::UnknownAsset.into())
+	}
+}
+
+/// The assets of a [`fungibles`] implementation.
+///
+/// The minimum amount of an asset which can be placed under a release schedule is its minimum
+/// balance multiplied by `MinVestedTransferFactor`.
+pub struct FungiblesVestingAssets<F, MinVestedTransferFactor>(
+	PhantomData<(F, MinVestedTransferFactor)>,
+);
+impl<AccountId, Balance, F, MinVestedTransferFactor> VestingAssets<AccountId, Balance>
+	for FungiblesVestingAssets<F, MinVestedTransferFactor>
+where
+	F: fungibles::Mutate<AccountId, Balance = Balance>,
+	MinVestedTransferFactor: Get<u32>,
+	F::AssetId: Parameter + MaxEncodedLen,
+	AccountId: Eq,
+	Balance: frame_support::traits::tokens::Balance,
+{
+	type AssetKind = F::AssetId;
+
+	fn balance(asset: &F::AssetId, who: &AccountId) -> Balance {
+		F::balance(asset.clone(), who)
+	}
+
+	fn minimum_balance(asset: &F::AssetId) -> Balance {
+		F::minimum_balance(asset.clone())
+	}
+
+	fn min_vested_transfer(asset: &F::AssetId) -> Balance {
+		F::minimum_balance(asset.clone()).saturating_mul(MinVestedTransferFactor::get().into())
+	}
+
+	fn transfer(
+		asset: &F::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		F::transfer(asset.clone(), source, dest, amount, Preservation::Expendable).map(|_| ())
+	}
+}
+
+/// The kind of asset of [`Config::Assets`].
+pub type AssetKindOf<T> = <<T as Config>::Assets as VestingAssets<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+>>::AssetKind;
+
+/// The [`ReleaseCurve`] of a release schedule of the pallet.
+pub type ReleaseCurveOf<T> =
+	ReleaseCurve<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::MaxMilestones>;
+
+/// The [`ReleaseSchedule`] stored by the pallet in [`ReleaseSchedules`].
+pub type ReleaseScheduleOf<T> = ReleaseSchedule<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+	AssetKindOf<T>,
+	<T as Config>::MaxMilestones,
+>;
+
+/// The asset placed under a release schedule.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum VestingAsset<AssetKind> {
+	/// The native currency, [`Config::Currency`].
+	Native,
+	/// An asset of [`Config::Assets`].
+	Asset(AssetKind),
+}
+
+/// How the total amount of a release schedule vests over time.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxMilestones))]
+pub enum ReleaseCurve<
+	Balance: Clone + PartialEq + Eq + Debug,
+	BlockNumber: Clone + PartialEq + Eq + Debug,
+	MaxMilestones: Get<u32>,
+> {
+	/// Vests linearly from `start` to `end`, but nothing can be released before `cliff`, at
+	/// which point everything vested since `start` can be released at once.
+	Linear { start: BlockNumber, cliff: BlockNumber, end: BlockNumber },
+	/// Vests the given amounts at the given blocks, in increasing block order. The amounts add up
+	/// to the total of the schedule.
+	Milestones(BoundedVec<(BlockNumber, Balance), MaxMilestones>),
+}
+
+impl<Balance, BlockNumber, MaxMilestones> ReleaseCurve<Balance, BlockNumber, MaxMilestones>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Debug,
+	MaxMilestones: Get<u32>,
+{
+	/// Whether the curve is well formed and vests exactly `total`.
+	pub fn is_valid_for(&self, total: Balance) -> bool {
+		match self {
+			Self::Linear { start, cliff, end } => start <= cliff && cliff <= end,
+			Self::Milestones(milestones) => {
+				let ordered = milestones.windows(2).all(|pair| pair[0].0 < pair[1].0);
+				let positive = milestones.iter().all(|(_, amount)| !amount.is_zero());
+				let sum = milestones
+					.iter()
+					.try_fold(Balance::zero(), |sum, (_, amount)| sum.checked_add(amount));
+				!milestones.is_empty() && ordered && positive && sum == Some(total)
+			},
+		}
+	}
+
+	/// The amount out of `total` vested at block `n`.
+	pub fn vested_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		total: Balance,
+		n: BlockNumber,
+	) -> Balance {
+		match self {
+			Self::Linear { start, cliff, end } =>
+				if n < *cliff {
+					Zero::zero()
+				} else if n >= *end {
+					total
+				} else {
+					let elapsed = BlockNumberToBalance::convert(n.saturating_sub(*start));
+					let duration = BlockNumberToBalance::convert(end.saturating_sub(*start));
+					multiply_by_rational_with_rounding(
+						total.unique_saturated_into(),
+						elapsed.unique_saturated_into(),
+						duration.unique_saturated_into(),
+						Rounding::Down,
+					)
+					.map(UniqueSaturatedInto::unique_saturated_into)
+					.unwrap_or(total)
+					.min(total)
+				},
+			Self::Milestones(milestones) => milestones
+				.iter()
+				.take_while(|(at, _)| *at <= n)
+				.fold(Zero::zero(), |vested: Balance, (_, amount)| vested.saturating_add(*amount)),
+		}
+	}
+}
+
+/// A release schedule, holding an amount of some asset on behalf of a beneficiary until it is
+/// vested.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxMilestones))]
+pub struct ReleaseSchedule<
+	AccountId: Clone + PartialEq + Eq + Debug,
+	Balance: Clone + PartialEq + Eq + Debug,
+	BlockNumber: Clone + PartialEq + Eq + Debug,
+	AssetKind: Clone + PartialEq + Eq + Debug,
+	MaxMilestones: Get<u32>,
+> {
+	/// The account which created the schedule and receives its deposit back.
+	creator: AccountId,
+	/// The amount of the native currency held from `creator` while the schedule exists.
+	deposit: Balance,
+	/// The asset held by the schedule.
+	asset: VestingAsset<AssetKind>,
+	/// The amount placed under the schedule.
+	total: Balance,
+	/// The amount already released to the beneficiary.
+	released: Balance,
+	/// How `total` vests over time.
+	curve: ReleaseCurve<Balance, BlockNumber, MaxMilestones>,
+	/// The account receiving the unvested remainder if the schedule is revoked, or `None` if the
+	/// schedule cannot be revoked.
+	revocation_beneficiary: Option<AccountId>,
+}
+
+impl<AccountId, Balance, BlockNumber, AssetKind, MaxMilestones>
+	ReleaseSchedule<AccountId, Balance, BlockNumber, AssetKind, MaxMilestones>
+where
+	AccountId: Clone + PartialEq + Eq + Debug,
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Debug,
+	AssetKind: Clone + PartialEq + Eq + Debug,
+	MaxMilestones: Get<u32>,
+{
+	/// Instantiate a new `ReleaseSchedule` of which nothing is released yet.
+	pub fn new(
+		creator: AccountId,
+		deposit: Balance,
+		asset: VestingAsset<AssetKind>,
+		total: Balance,
+		curve: ReleaseCurve<Balance, BlockNumber, MaxMilestones>,
+		revocation_beneficiary: Option<AccountId>,
+	) -> Self {
+		Self {
+			creator,
+			deposit,
+			asset,
+			total,
+			released: Zero::zero(),
+			curve,
+			revocation_beneficiary,
+		}
+	}
+
+	/// Validate the parameters of the schedule. Note that this does not check against the
+	/// minimum amount which can be placed under a schedule.
+	pub fn is_valid(&self) -> bool {
+		!self.total.is_zero() && self.released <= self.total && self.curve.is_valid_for(self.total)
+	}
+
+	/// The account which created the schedule.
+	pub fn creator(&self) -> &AccountId {
+		&self.creator
+	}
+
+	/// The amount of the native currency held from the creator while the schedule exists.
+	pub fn deposit(&self) -> Balance {
+		self.deposit
+	}
+
+	/// The asset held by the schedule.
+	pub fn asset(&self) -> &VestingAsset<AssetKind> {
+		&self.asset
+	}
+
+	/// The amount placed under the schedule.
+	pub fn total(&self) -> Balance {
+		self.total
+	}
+
+	/// The amount already released to the beneficiary.
+	pub fn released(&self) -> Balance {
+		self.released
+	}
+
+	/// How the total amount vests over time.
+	pub fn curve(&self) -> &ReleaseCurve<Balance, BlockNumber, MaxMilestones> {
+		&self.curve
+	}
+
+	/// The account receiving the unvested remainder if the schedule is revoked.
+	pub fn revocation_beneficiary(&self) -> Option<&AccountId> {
+		self.revocation_beneficiary.as_ref()
+	}
+
+	/// The amount vested at block `n` which has not been released yet.
+	pub fn releasable_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		n: BlockNumber,
+	) -> Balance {
+		self.curve
+			.vested_at::<BlockNumberToBalance>(self.total, n)
+			.saturating_sub(self.released)
+	}
+
+	/// Record the release of `amount`.
+	pub(crate) fn note_released(&mut self, amount: Balance) {
+		self.released = self.released.saturating_add(amount);
+	}
+
+	/// Whether everything has been released.
+	pub fn is_complete(&self) -> bool {
+		self.released >= self.total
+	}
+}
@@ -24,7 +24,10 @@ use sp_runtime::{
 };
 
 use super::{Vesting as VestingStorage, *};
-use crate::mock::{vesting_events_since_last_call, Balances, ExtBuilder, System, Test, Vesting};
+use crate::mock::{
+	vesting_events_since_last_call, Balances, BlockedAccounts, ExtBuilder, MockAssets,
+	ReleaseScheduleDeposit, RuntimeOrigin, System, Test, Vesting,
+};
 
 /// A default existential deposit.
 const ED: u64 = 256;
@@ -1262,3 +1265,363 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn release_schedule_with_cliff_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let pallet_account = Vesting::account_id();
+		// Vests from block 10 to 30, with nothing released before block 20.
+		let curve = ReleaseCurve::Linear { start: 10, cliff: 20, end: 30 };
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Native,
+			ED * 8,
+			curve.clone(),
+			None
+		));
+		assert_eq!(
+			vesting_events_since_last_call(),
+			vec![Event::ReleaseScheduleCreated {
+				account: 5,
+				id: 0,
+				asset: VestingAsset::Native,
+				total: ED * 8
+			}]
+		);
+		// The pallet account is kept alive with the existential deposit.
+		assert_eq!(Balances::free_balance(&3), ED * 21);
+		assert_eq!(Balances::free_balance(&pallet_account), ED * 9);
+		assert_eq!(
+			ReleaseSchedules::<Test>::get(5, 0),
+			Some(ReleaseSchedule::new(3, 0, VestingAsset::Native, ED * 8, curve, None))
+		);
+
+		// Nothing vested before the cliff.
+		System::set_block_number(19);
+		assert_eq!(Vesting::releasable(&5, 0), Some(0));
+		assert_ok!(Vesting::release_vested(Some(1).into(), 5, 0));
+		assert_eq!(Balances::free_balance(&5), 0);
+		assert!(vesting_events_since_last_call().is_empty());
+
+		// Half of it vested at the cliff.
+		System::set_block_number(20);
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 0));
+		assert_eq!(Balances::free_balance(&5), ED * 4);
+		assert_eq!(
+			vesting_events_since_last_call(),
+			vec![Event::Released { account: 5, id: 0, amount: ED * 4 }]
+		);
+
+		System::set_block_number(25);
+		assert_eq!(Vesting::releasable(&5, 0), Some(ED * 2));
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 0));
+		assert_eq!(Balances::free_balance(&5), ED * 6);
+
+		// Everything vested at the end.
+		System::set_block_number(40);
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 0));
+		assert_eq!(Balances::free_balance(&5), ED * 8);
+		assert_eq!(
+			vesting_events_since_last_call(),
+			vec![
+				Event::Released { account: 5, id: 0, amount: ED * 2 },
+				Event::Released { account: 5, id: 0, amount: ED * 2 },
+				Event::ReleaseScheduleCompleted { account: 5, id: 0 },
+			]
+		);
+		assert!(!ReleaseSchedules::<Test>::contains_key(5, 0));
+		// The existential deposit is returned along with the last schedule.
+		assert_eq!(Balances::free_balance(&pallet_account), 0);
+		assert_eq!(Balances::free_balance(&3), ED * 22);
+		assert!(!ExistentialDeposits::<Test>::contains_key(VestingAsset::Native));
+		assert_noop!(
+			Vesting::release_vested(Some(5).into(), 5, 0),
+			Error::<Test>::UnknownReleaseSchedule
+		);
+	});
+}
+
+#[test]
+fn release_schedule_with_milestones_works_for_assets() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let pallet_account = Vesting::account_id();
+		MockAssets::set_balance(7, 3, 1_000);
+		let curve = ReleaseCurve::Milestones(BoundedVec::truncate_from(vec![
+			(5, 100),
+			(10, 200),
+			(20, 300),
+		]));
+		// Assets are subject to the minimum of `Assets` rather than `MinVestedTransfer`.
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Asset(7),
+			600,
+			curve,
+			None
+		));
+		assert_eq!(MockAssets::balance_of(7, 3), 399);
+		assert_eq!(MockAssets::balance_of(7, pallet_account), 601);
+		// Native funds are untouched.
+		assert_eq!(Balances::free_balance(&3), ED * 30);
+
+		System::set_block_number(4);
+		assert_eq!(Vesting::releasable(&5, 0), Some(0));
+		System::set_block_number(12);
+		assert_ok!(Vesting::release_vested(Some(1).into(), 5, 0));
+		assert_eq!(MockAssets::balance_of(7, 5), 300);
+		System::set_block_number(20);
+		assert_ok!(Vesting::release_vested(Some(1).into(), 5, 0));
+		assert_eq!(MockAssets::balance_of(7, 5), 600);
+		assert_eq!(MockAssets::balance_of(7, pallet_account), 0);
+		assert_eq!(MockAssets::balance_of(7, 3), 400);
+		assert!(!ReleaseSchedules::<Test>::contains_key(5, 0));
+
+		// The next schedule pays for the minimum balance again.
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Asset(7),
+			300,
+			ReleaseCurve::Linear { start: 20, cliff: 20, end: 30 },
+			None
+		));
+		assert_eq!(MockAssets::balance_of(7, 3), 99);
+		assert_eq!(MockAssets::balance_of(7, pallet_account), 301);
+		assert!(ReleaseSchedules::<Test>::contains_key(5, 1));
+		assert_eq!(ExistentialDeposits::<Test>::get(VestingAsset::Asset(7)), Some((3, 1, 1)));
+	});
+}
+
+#[test]
+fn existential_deposit_refund_does_not_block_release() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let pallet_account = Vesting::account_id();
+		MockAssets::set_balance(7, 3, 100);
+		MockAssets::set_balance(7, 4, 100);
+		let curve = ReleaseCurve::Linear { start: 10, cliff: 10, end: 20 };
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Asset(7),
+			50,
+			curve.clone(),
+			None
+		));
+		assert_eq!(MockAssets::balance_of(7, 3), 49);
+
+		// The funder of the minimum balance can't receive the asset anymore.
+		BlockedAccounts::set(vec![3]);
+		System::set_block_number(20);
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 0));
+		assert_eq!(MockAssets::balance_of(7, 5), 50);
+		assert!(!ReleaseSchedules::<Test>::contains_key(5, 0));
+		System::assert_has_event(
+			Event::ExistentialDepositNotRefunded {
+				asset: VestingAsset::Asset(7),
+				funder: 3,
+				amount: 1,
+			}
+			.into(),
+		);
+		assert_eq!(MockAssets::balance_of(7, pallet_account), 1);
+		assert_eq!(ExistentialDeposits::<Test>::get(VestingAsset::Asset(7)), Some((3, 1, 0)));
+
+		// The minimum balance kept serves the next schedule, and is returned once it ends.
+		BlockedAccounts::set(vec![]);
+		assert_ok!(Vesting::create_release_schedule(
+			Some(4).into(),
+			5,
+			VestingAsset::Asset(7),
+			50,
+			ReleaseCurve::Linear { start: 20, cliff: 20, end: 30 },
+			None
+		));
+		assert_eq!(MockAssets::balance_of(7, 4), 50);
+		assert_eq!(ExistentialDeposits::<Test>::get(VestingAsset::Asset(7)), Some((3, 1, 1)));
+		System::set_block_number(30);
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 1));
+		assert_eq!(MockAssets::balance_of(7, 5), 100);
+		assert_eq!(MockAssets::balance_of(7, 3), 50);
+		assert_eq!(MockAssets::balance_of(7, pallet_account), 0);
+		assert!(!ExistentialDeposits::<Test>::contains_key(VestingAsset::Asset(7)));
+	});
+}
+
+#[test]
+fn revoke_release_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let curve = ReleaseCurve::Linear { start: 10, cliff: 10, end: 20 };
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Native,
+			ED * 10,
+			curve.clone(),
+			Some(4)
+		));
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Native,
+			ED * 10,
+			curve,
+			None
+		));
+		vesting_events_since_last_call();
+
+		System::set_block_number(12);
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 0));
+		assert_eq!(Balances::free_balance(&5), ED * 2);
+
+		System::set_block_number(14);
+		assert_noop!(Vesting::revoke_release_schedule(Some(4).into(), 5, 0), BadOrigin);
+		assert_noop!(
+			Vesting::revoke_release_schedule(RuntimeOrigin::root(), 5, 1),
+			Error::<Test>::NotRevocable
+		);
+		assert_noop!(
+			Vesting::revoke_release_schedule(RuntimeOrigin::root(), 5, 2),
+			Error::<Test>::UnknownReleaseSchedule
+		);
+		assert_ok!(Vesting::revoke_release_schedule(RuntimeOrigin::root(), 5, 0));
+		// What vested so far goes to the beneficiary, the rest back to account 4.
+		assert_eq!(Balances::free_balance(&5), ED * 4);
+		assert_eq!(Balances::free_balance(&4), ED * 46);
+		assert_eq!(
+			vesting_events_since_last_call(),
+			vec![
+				Event::Released { account: 5, id: 0, amount: ED * 2 },
+				Event::ReleaseScheduleRevoked {
+					account: 5,
+					id: 0,
+					released: ED * 2,
+					returned: ED * 6
+				},
+			]
+		);
+		assert!(!ReleaseSchedules::<Test>::contains_key(5, 0));
+		assert!(ReleaseSchedules::<Test>::contains_key(5, 1));
+	});
+}
+
+#[test]
+fn create_release_schedule_checks_params() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let create = |asset, total, curve| {
+			Vesting::create_release_schedule(Some(3).into(), 5, asset, total, curve, None)
+		};
+		let milestones = |milestones: Vec<(u64, u64)>| {
+			ReleaseCurve::Milestones(BoundedVec::truncate_from(milestones))
+		};
+		let linear = ReleaseCurve::Linear { start: 10, cliff: 20, end: 30 };
+
+		assert_noop!(create(VestingAsset::Native, ED, linear.clone()), Error::<Test>::AmountLow);
+		assert_noop!(create(VestingAsset::Asset(7), 9, linear.clone()), Error::<Test>::AmountLow);
+		assert_noop!(
+			create(
+				VestingAsset::Native,
+				ED * 2,
+				ReleaseCurve::Linear { start: 10, cliff: 5, end: 30 }
+			),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			create(
+				VestingAsset::Native,
+				ED * 2,
+				ReleaseCurve::Linear { start: 10, cliff: 40, end: 30 }
+			),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			create(VestingAsset::Native, ED * 2, milestones(vec![])),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			create(VestingAsset::Native, ED * 2, milestones(vec![(20, ED), (10, ED)])),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			create(VestingAsset::Native, ED * 2, milestones(vec![(10, ED * 2), (20, 0)])),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			create(VestingAsset::Native, ED * 2, milestones(vec![(10, ED), (20, ED * 2)])),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			create(VestingAsset::Asset(7), 100, linear.clone()),
+			TokenError::FundsUnavailable
+		);
+		assert_noop!(
+			create(VestingAsset::Native, ED * 9999, linear.clone()),
+			TokenError::FundsUnavailable
+		);
+		assert_ok!(create(VestingAsset::Native, ED * 2, milestones(vec![(10, ED), (20, ED)])));
+
+		NextReleaseScheduleId::<Test>::put(u32::MAX);
+		assert_noop!(
+			create(VestingAsset::Native, ED * 2, linear),
+			Error::<Test>::NoAvailableReleaseScheduleId
+		);
+	});
+}
+
+#[test]
+fn release_schedule_deposit_is_returned() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		ReleaseScheduleDeposit::set(ED * 2);
+		let pallet_account = Vesting::account_id();
+		let held = |who| {
+			<Balances as fungible::InspectHold<_>>::balance_on_hold(
+				&HoldReason::ReleaseScheduleDeposit.into(),
+				&who,
+			)
+		};
+		MockAssets::set_balance(7, 3, 1_000);
+		let curve = ReleaseCurve::Linear { start: 10, cliff: 10, end: 20 };
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Asset(7),
+			100,
+			curve.clone(),
+			Some(4)
+		));
+		// The deposit is held from the creator.
+		assert_eq!(held(3), ED * 2);
+		assert_eq!(Balances::free_balance(&3), ED * 28);
+		assert_eq!(Balances::free_balance(&pallet_account), 0);
+		assert_eq!(MockAssets::balance_of(7, 3), 899);
+		assert_ok!(Vesting::create_release_schedule(
+			Some(3).into(),
+			5,
+			VestingAsset::Native,
+			ED * 10,
+			curve,
+			None
+		));
+		assert_eq!(held(3), ED * 4);
+		assert_eq!(Balances::free_balance(&3), ED * 15);
+		assert_eq!(Balances::free_balance(&pallet_account), ED * 11);
+
+		// Released on revocation, along with the minimum balance of the asset.
+		System::set_block_number(15);
+		assert_ok!(Vesting::revoke_release_schedule(RuntimeOrigin::root(), 5, 0));
+		assert_eq!(held(3), ED * 2);
+		assert_eq!(Balances::free_balance(&3), ED * 17);
+		assert_eq!(MockAssets::balance_of(7, 3), 900);
+		assert_eq!(MockAssets::balance_of(7, pallet_account), 0);
+
+		// And on completion.
+		System::set_block_number(20);
+		assert_ok!(Vesting::release_vested(Some(5).into(), 5, 1));
+		assert!(!ReleaseSchedules::<Test>::contains_key(5, 1));
+		assert_eq!(held(3), 0);
+		assert_eq!(Balances::free_balance(&3), ED * 20);
+		assert_eq!(Balances::free_balance(&5), ED * 10);
+		assert_eq!(Balances::free_balance(&pallet_account), 0);
+	});
+}
@@ -22,7 +22,7 @@ use scale_info::TypeInfo;
 use sp_application_crypto::RuntimeAppPublic;
 use sp_runtime::traits::Hash;
 
-use crate::{BeefyAuthorityId, Payload, ValidatorSet, ValidatorSetId};
+use crate::{AuthorityIdBound, BeefyAuthorityId, Payload, ValidatorSet, ValidatorSetId};
 
 /// A commitment signature, accompanied by the id of the validator that it belongs to.
 #[derive(Debug)]
@@ -180,11 +180,157 @@ impl<TBlockNumber, TSignature> SignedCommitment<TBlockNumber, TSignature> {
 	}
 }
 
+impl<TBlockNumber: Clone, TSignature> SignedCommitment<TBlockNumber, TSignature> {
+	/// Aggregate the commitment signatures into an [`AggregatedSignedCommitment`].
+	///
+	/// Returns `None` if there are no signatures or if `TAuthorityId` does not support
+	/// signature aggregation. The signatures are expected to have been verified already.
+	pub fn aggregate<TAuthorityId>(&self) -> Option<AggregatedSignedCommitment<TBlockNumber>>
+	where
+		TAuthorityId: AuthorityIdBound<BoundedSignature = TSignature>,
+	{
+		let signatures: Vec<&TSignature> = self.signatures.iter().flatten().collect();
+		if signatures.is_empty() {
+			return None
+		}
+		let signature = TAuthorityId::aggregate_signatures(&signatures)?;
+
+		Some(AggregatedSignedCommitment {
+			commitment: self.commitment.clone(),
+			signed_by: self.signatures.iter().map(|x| x.is_some()).collect(),
+			signature,
+		})
+	}
+}
+
+/// A commitment with a single signature aggregated from the signatures of the validators that
+/// signed it.
+///
+/// This is the compact counterpart of [`SignedCommitment`] for crypto schemes supporting
+/// signature aggregation (see [`AuthorityIdBound::aggregate_signatures`]). Verifying it costs a
+/// single signature check regardless of the number of signers.
+#[derive(Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct AggregatedSignedCommitment<TBlockNumber> {
+	/// The commitment the signature is for.
+	pub commitment: Commitment<TBlockNumber>,
+	/// Which validators contributed to the aggregated signature.
+	///
+	/// The length of this `Vec` must match number of validators in the current set (see
+	/// [Commitment::validator_set_id]).
+	pub signed_by: Vec<bool>,
+	/// The aggregated signature of the validators marked in `signed_by`.
+	pub signature: Vec<u8>,
+}
+
+impl<TBlockNumber: core::fmt::Debug> core::fmt::Display
+	for AggregatedSignedCommitment<TBlockNumber>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(
+			f,
+			"AggregatedSignedCommitment(commitment: {:?}, signatures_count: {})",
+			self.commitment,
+			self.signature_count()
+		)
+	}
+}
+
+impl<TBlockNumber> AggregatedSignedCommitment<TBlockNumber> {
+	/// Return the number of validators that contributed to the signature.
+	pub fn signature_count(&self) -> usize {
+		self.signed_by.iter().filter(|x| **x).count()
+	}
+
+	/// Verify the aggregated signature against the validator set that was active at the block
+	/// where the commitment was generated.
+	///
+	/// `proofs_of_possession` holds the proof of possession of each validator key, in the order
+	/// of the validator set. See [`AuthorityIdBound::verify_aggregated_signature`].
+	///
+	/// Returns the validators that contributed to the signature if it can be verified,
+	/// otherwise the number of signature checks performed.
+	pub fn verify_signature<'a, TAuthorityId>(
+		&self,
+		target_number: TBlockNumber,
+		validator_set: &'a ValidatorSet<TAuthorityId>,
+		proofs_of_possession: &[Vec<u8>],
+	) -> Result<Vec<&'a TAuthorityId>, u32>
+	where
+		TBlockNumber: Encode + PartialEq,
+		TAuthorityId: AuthorityIdBound,
+	{
+		if self.signed_by.len() != validator_set.len() ||
+			proofs_of_possession.len() != validator_set.len() ||
+			self.commitment.validator_set_id != validator_set.id() ||
+			self.commitment.block_number != target_number
+		{
+			return Err(0)
+		}
+
+		let signers: Vec<_> = validator_set
+			.validators()
+			.iter()
+			.zip(proofs_of_possession.iter())
+			.zip(self.signed_by.iter())
+			.filter_map(|((id, proof), signed)| signed.then_some((id, &proof[..])))
+			.collect();
+		if signers.is_empty() {
+			return Err(0)
+		}
+
+		match TAuthorityId::verify_aggregated_signature(
+			&signers,
+			&self.signature,
+			&self.commitment.encode(),
+		) {
+			true => Ok(signers.into_iter().map(|(id, _)| id).collect()),
+			false => Err(1),
+		}
+	}
+}
+
 /// Type to be used to denote placement of signatures
 type BitField = Vec<u8>;
 /// Compress 8 bit values into a single u8 Byte
 const CONTAINER_BIT_SIZE: usize = 8;
 
+/// Packs one bit per validator into a [`BitField`], most significant bit first.
+fn pack_bits(bits: impl Iterator<Item = bool>) -> BitField {
+	let mut bits: Vec<u8> = bits.map(|x| if x { 1 } else { 0 }).collect();
+	// Resize with excess bits for placement purposes
+	let excess_bits_len = CONTAINER_BIT_SIZE - (bits.len() % CONTAINER_BIT_SIZE);
+	bits.resize(bits.len() + excess_bits_len, 0);
+
+	let mut bitfield: BitField = vec![];
+	let chunks = bits.chunks(CONTAINER_BIT_SIZE);
+	for chunk in chunks {
+		let mut iter = chunk.iter().copied();
+		let mut v = iter.next().unwrap() as u8;
+
+		for bit in iter {
+			v <<= 1;
+			v |= bit as u8;
+		}
+
+		bitfield.push(v);
+	}
+	bitfield
+}
+
+/// Unpacks the first `len` bits of a [`BitField`] created by [`pack_bits`].
+fn unpack_bits(bitfield: &[u8], len: u32) -> Vec<bool> {
+	let mut bits: Vec<bool> = vec![];
+
+	for block in bitfield {
+		for bit in 0..CONTAINER_BIT_SIZE {
+			bits.push((block >> (CONTAINER_BIT_SIZE - bit - 1)) & 1 == 1);
+		}
+	}
+
+	bits.truncate(len as usize);
+	bits
+}
+
 /// Compressed representation of [`SignedCommitment`], used for encoding efficiency.
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
 struct CompactSignedCommitment<TBlockNumber, TSignature> {
@@ -219,29 +365,7 @@ impl<'a, TBlockNumber: Clone, TSignature> CompactSignedCommitment<TBlockNumber,
 
 		let signatures_compact: Vec<&'a TSignature> =
 			signatures.iter().filter_map(|x| x.as_ref()).collect();
-		let bits = {
-			let mut bits: Vec<u8> =
-				signatures.iter().map(|x| if x.is_some() { 1 } else { 0 }).collect();
-			// Resize with excess bits for placement purposes
-			let excess_bits_len =
-				CONTAINER_BIT_SIZE - (validator_set_len as usize % CONTAINER_BIT_SIZE);
-			bits.resize(bits.len() + excess_bits_len, 0);
-			bits
-		};
-
-		let mut signatures_from: BitField = vec![];
-		let chunks = bits.chunks(CONTAINER_BIT_SIZE);
-		for chunk in chunks {
-			let mut iter = chunk.iter().copied();
-			let mut v = iter.next().unwrap() as u8;
-
-			for bit in iter {
-				v <<= 1;
-				v |= bit as u8;
-			}
-
-			signatures_from.push(v);
-		}
+		let signatures_from = pack_bits(signatures.iter().map(|x| x.is_some()));
 
 		Self {
 			commitment: commitment.clone(),
@@ -261,26 +385,55 @@ impl<'a, TBlockNumber: Clone, TSignature> CompactSignedCommitment<TBlockNumber,
 			validator_set_len,
 			signatures_compact,
 		} = temporary_signatures;
-		let mut bits: Vec<u8> = vec![];
-
-		for block in signatures_from {
-			for bit in 0..CONTAINER_BIT_SIZE {
-				bits.push((block >> (CONTAINER_BIT_SIZE - bit - 1)) & 1);
-			}
-		}
-
-		bits.truncate(validator_set_len as usize);
-
 		let mut next_signature = signatures_compact.into_iter();
-		let signatures: Vec<Option<TSignature>> = bits
-			.iter()
-			.map(|&x| if x == 1 { next_signature.next() } else { None })
+		let signatures: Vec<Option<TSignature>> = unpack_bits(&signatures_from, validator_set_len)
+			.into_iter()
+			.map(|x| if x { next_signature.next() } else { None })
 			.collect();
 
 		SignedCommitment { commitment, signatures }
 	}
 }
 
+/// Compressed representation of [`AggregatedSignedCommitment`], used for encoding efficiency.
+#[derive(Encode, Decode)]
+struct CompactAggregatedSignedCommitment<TBlockNumber> {
+	/// The commitment, unchanged compared to regular [`AggregatedSignedCommitment`].
+	commitment: Commitment<TBlockNumber>,
+	/// The `signed_by` flags packed the same way as `CompactSignedCommitment::signatures_from`.
+	signatures_from: BitField,
+	/// Number of validators in the Validator Set and hence number of significant bits in the
+	/// [`signatures_from`] collection.
+	validator_set_len: u32,
+	/// The aggregated signature.
+	signature: Vec<u8>,
+}
+
+impl<TBlockNumber: Encode> Encode for AggregatedSignedCommitment<TBlockNumber> {
+	fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+		// Same layout as `CompactAggregatedSignedCommitment`, without cloning the fields.
+		let signatures_from = pack_bits(self.signed_by.iter().copied());
+		let validator_set_len = self.signed_by.len() as u32;
+		(&self.commitment, signatures_from, validator_set_len, &self.signature).using_encoded(f)
+	}
+}
+
+impl<TBlockNumber: Decode> Decode for AggregatedSignedCommitment<TBlockNumber> {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+		let CompactAggregatedSignedCommitment {
+			commitment,
+			signatures_from,
+			validator_set_len,
+			signature,
+		} = CompactAggregatedSignedCommitment::decode(input)?;
+		let signed_by = unpack_bits(&signatures_from, validator_set_len);
+		if signed_by.len() != validator_set_len as usize {
+			return Err("Signer bitfield is shorter than the validator set".into())
+		}
+		Ok(Self { commitment, signed_by, signature })
+	}
+}
+
 impl<TBlockNumber, TSignature> Encode for SignedCommitment<TBlockNumber, TSignature>
 where
 	TBlockNumber: Encode + Clone,
@@ -315,12 +468,27 @@ pub enum VersionedFinalityProof<N, S> {
 	#[codec(index = 1)]
 	/// Current active version
 	V1(SignedCommitment<N, S>),
+	#[codec(index = 2)]
+	/// Version carrying a single aggregated signature, produced when the authority crypto
+	/// supports signature aggregation.
+	V2(AggregatedSignedCommitment<N>),
+}
+
+impl<N, S> VersionedFinalityProof<N, S> {
+	/// Return the commitment this finality proof is for.
+	pub fn commitment(&self) -> &Commitment<N> {
+		match self {
+			VersionedFinalityProof::V1(sc) => &sc.commitment,
+			VersionedFinalityProof::V2(asc) => &asc.commitment,
+		}
+	}
 }
 
 impl<N: core::fmt::Debug, S> core::fmt::Display for VersionedFinalityProof<N, S> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			VersionedFinalityProof::V1(sc) => write!(f, "VersionedFinalityProof::V1({})", sc),
+			VersionedFinalityProof::V2(asc) => write!(f, "VersionedFinalityProof::V2({})", asc),
 		}
 	}
 }
@@ -331,6 +499,12 @@ impl<N, S> From<SignedCommitment<N, S>> for VersionedFinalityProof<N, S> {
 	}
 }
 
+impl<N, S> From<AggregatedSignedCommitment<N>> for VersionedFinalityProof<N, S> {
+	fn from(commitment: AggregatedSignedCommitment<N>) -> Self {
+		VersionedFinalityProof::V2(commitment)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -561,6 +735,101 @@ mod tests {
 		assert_eq!(decoded, Ok(versioned));
 	}
 
+	#[test]
+	fn aggregated_signed_commitment_encode_decode() {
+		let payload =
+			Payload::from_single_entry(known_payloads::MMR_ROOT_ID, "Hello World!".encode());
+		let commitment: TestCommitment =
+			Commitment { payload, block_number: 5, validator_set_id: 0 };
+
+		let aggregated = AggregatedSignedCommitment {
+			commitment,
+			signed_by: (0..10).map(|x| x % 3 != 0).collect(),
+			signature: vec![42; 48],
+		};
+		assert_eq!(aggregated.signature_count(), 6);
+
+		let versioned = TestVersionedFinalityProof::V2(aggregated.clone());
+		let encoded = codec::Encode::encode(&versioned);
+
+		assert_eq!(2, encoded[0]);
+		assert_eq!(encoded[1..], codec::Encode::encode(&aggregated));
+
+		let decoded = TestVersionedFinalityProof::decode(&mut &*encoded);
+
+		assert_eq!(decoded, Ok(versioned));
+	}
+
+	#[test]
+	fn ecdsa_signatures_are_not_aggregated() {
+		let commitment: TestCommitment = Commitment {
+			payload: Payload::from_single_entry(known_payloads::MMR_ROOT_ID, vec![]),
+			block_number: 5,
+			validator_set_id: 0,
+		};
+		let sigs = mock_ecdsa_signatures();
+		let signed = SignedCommitment { commitment, signatures: vec![Some(sigs.0), None] };
+
+		assert_eq!(signed.aggregate::<crate::ecdsa_crypto::AuthorityId>(), None);
+	}
+
+	#[test]
+	#[cfg(feature = "bls-experimental")]
+	fn ecdsa_n_bls_signatures_aggregate_and_verify() {
+		use crate::{ecdsa_bls_crypto, test_utils::Keyring};
+		use sp_core::{crypto::ByteArray, proof_of_possession::ProofOfPossessionGenerator};
+
+		let keys = [Keyring::Alice, Keyring::Bob, Keyring::Charlie, Keyring::Dave];
+		let validator_set = ValidatorSet::<ecdsa_bls_crypto::AuthorityId>::new(
+			keys.iter().map(|key| key.public()),
+			0,
+		)
+		.unwrap();
+		let commitment: TestCommitment = Commitment {
+			payload: Payload::from_single_entry(known_payloads::MMR_ROOT_ID, vec![]),
+			block_number: 5,
+			validator_set_id: 0,
+		};
+		let message = commitment.encode();
+		let signed = SignedCommitment {
+			commitment,
+			signatures: keys
+				.iter()
+				.map(|key| (*key != Keyring::Bob).then(|| key.sign(&message)))
+				.collect(),
+		};
+
+		let proofs: Vec<Vec<u8>> = keys
+			.iter()
+			.map(|key| {
+				let mut bls_pair =
+					sp_core::bls381::Pair::from_string(&key.to_seed(), None).unwrap();
+				bls_pair.generate_proof_of_possession().to_raw_vec()
+			})
+			.collect();
+
+		let aggregated = signed.aggregate::<ecdsa_bls_crypto::AuthorityId>().unwrap();
+		assert_eq!(aggregated.signed_by, vec![true, false, true, true]);
+
+		// verifies against the signers
+		let signers = aggregated.verify_signature(5, &validator_set, &proofs).unwrap();
+		assert_eq!(signers, vec![&keys[0].public(), &keys[2].public(), &keys[3].public()]);
+
+		// wrong block number
+		assert_eq!(aggregated.verify_signature(6, &validator_set, &proofs), Err(0));
+
+		// wrong signers
+		let mut bad = aggregated.clone();
+		bad.signed_by = vec![true, true, true, false];
+		assert_eq!(bad.verify_signature(5, &validator_set, &proofs), Err(1));
+
+		// missing or invalid proofs of possession
+		assert_eq!(aggregated.verify_signature(5, &validator_set, &proofs[1..]), Err(0));
+		let mut bad_proofs = proofs.clone();
+		bad_proofs.swap(0, 2);
+		assert_eq!(aggregated.verify_signature(5, &validator_set, &bad_proofs), Err(1));
+	}
+
 	#[test]
 	fn large_signed_commitment_encode_decode() {
 		// given
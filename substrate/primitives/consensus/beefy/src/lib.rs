@@ -43,7 +43,10 @@ pub mod witness;
 #[cfg(feature = "std")]
 pub mod test_utils;
 
-pub use commitment::{Commitment, KnownSignature, SignedCommitment, VersionedFinalityProof};
+pub use commitment::{
+	AggregatedSignedCommitment, Commitment, KnownSignature, SignedCommitment,
+	VersionedFinalityProof,
+};
 pub use payload::{known_payloads, BeefyPayloadId, Payload, PayloadProvider};
 
 use alloc::vec::Vec;
@@ -69,6 +72,15 @@ pub trait BeefyAuthorityId<MsgHash: Hash>: RuntimeAppPublic {
 	///
 	/// Return `true` if signature over `msg` is valid for this id.
 	fn verify(&self, signature: &<Self as RuntimeAppPublic>::Signature, msg: &[u8]) -> bool;
+
+	/// Verify a proof of possession of the secret key of this id.
+	///
+	/// Proofs of possession are needed to verify aggregated signatures, see
+	/// [`AuthorityIdBound::verify_aggregated_signature`]. Returns `false` if the crypto scheme
+	/// doesn't support signature aggregation (the default).
+	fn verify_proof_of_possession(&self, _proof: &[u8]) -> bool {
+		false
+	}
 }
 
 /// Hasher used for BEEFY signatures.
@@ -84,6 +96,28 @@ pub trait AuthorityIdBound:
 {
 	/// Necessary bounds on the Signature associated with the AuthorityId
 	type BoundedSignature: Debug + Eq + PartialEq + Clone + TypeInfo + Codec + Send + Sync;
+
+	/// Aggregate signatures of the same message into a single signature.
+	///
+	/// Returns `None` if the crypto scheme does not support aggregation (the default) or if
+	/// any of the signatures is malformed.
+	fn aggregate_signatures(_signatures: &[&Self::BoundedSignature]) -> Option<Vec<u8>> {
+		None
+	}
+
+	/// Verify a signature created by [`AuthorityIdBound::aggregate_signatures`] over `msg`
+	/// against the authorities whose signatures were aggregated.
+	///
+	/// Each signer comes with the proof of possession of its key. Without them, a rogue key
+	/// could be chosen to forge an aggregated signature of honest authorities. Returns `false`
+	/// if any of the proofs is invalid.
+	fn verify_aggregated_signature(
+		_signers: &[(&Self, &[u8])],
+		_signature: &[u8],
+		_msg: &[u8],
+	) -> bool {
+		false
+	}
 }
 
 /// BEEFY cryptographic types for ECDSA crypto
@@ -184,9 +218,19 @@ pub mod bls_crypto {
 /// functionality.
 #[cfg(feature = "bls-experimental")]
 pub mod ecdsa_bls_crypto {
-	use super::{AuthorityIdBound, BeefyAuthorityId, Hash, RuntimeAppPublic, KEY_TYPE};
+	use super::{
+		AuthorityIdBound, BeefyAuthorityId, BeefySignatureHasher, Hash, RuntimeAppPublic, KEY_TYPE,
+	};
+	use alloc::vec::Vec;
 	use sp_application_crypto::{app_crypto, ecdsa_bls381};
-	use sp_core::{crypto::Wraps, ecdsa_bls381::Pair as EcdsaBlsPair};
+	use sp_core::{
+		bls381, crypto::Wraps, ecdsa, ecdsa_bls381::Pair as EcdsaBlsPair,
+		proof_of_possession::ProofOfPossessionVerifier,
+	};
+	use w3f_bls::{
+		DoublePublicKey, DoubleSignature, Message, PublicKey as BlsPublic, SerializableToBytes,
+		Signature as BlsSignature, TinyBLS381,
+	};
 
 	app_crypto!(ecdsa_bls381, KEY_TYPE);
 
@@ -215,10 +259,68 @@ pub mod ecdsa_bls_crypto {
 				self.as_inner_ref(),
 			)
 		}
+
+		/// Checks the proof of possession of the BLS12-381 component of the key.
+		fn verify_proof_of_possession(&self, proof: &[u8]) -> bool {
+			let bls_part =
+				&AsRef::<[u8]>::as_ref(self.as_inner_ref())[ecdsa::PUBLIC_KEY_SERIALIZED_SIZE..];
+			match (bls381::Public::try_from(bls_part), bls381::Signature::try_from(proof)) {
+				(Ok(public), Ok(proof)) =>
+					bls381::Pair::verify_proof_of_possession(&proof, &public),
+				_ => false,
+			}
+		}
 	}
 
 	impl AuthorityIdBound for AuthorityId {
 		type BoundedSignature = Signature;
+
+		/// Aggregates the BLS12-381 components of the signatures, the ECDSA components are
+		/// dropped.
+		fn aggregate_signatures(signatures: &[&Signature]) -> Option<Vec<u8>> {
+			let mut aggregated: Option<BlsSignature<TinyBLS381>> = None;
+			for signature in signatures {
+				let bls_part = &AsRef::<[u8]>::as_ref(signature.as_inner_ref())
+					[ecdsa::SIGNATURE_SERIALIZED_SIZE..];
+				let bls_signature = DoubleSignature::<TinyBLS381>::from_bytes(bls_part).ok()?;
+				match aggregated {
+					Some(ref mut aggregated) => aggregated.0 += bls_signature.0,
+					None => aggregated = Some(BlsSignature(bls_signature.0)),
+				}
+			}
+			aggregated.map(|signature| signature.to_bytes())
+		}
+
+		/// Checks the aggregated BLS12-381 signature against the sum of the signers' BLS12-381
+		/// public keys, after checking the proofs of possession of these keys.
+		fn verify_aggregated_signature(
+			signers: &[(&Self, &[u8])],
+			signature: &[u8],
+			msg: &[u8],
+		) -> bool {
+			let Ok(signature) = BlsSignature::<TinyBLS381>::from_bytes(signature) else {
+				return false
+			};
+			let mut aggregated: Option<BlsPublic<TinyBLS381>> = None;
+			for (signer, proof_of_possession) in signers {
+				if !<Self as BeefyAuthorityId<BeefySignatureHasher>>::verify_proof_of_possession(
+					signer,
+					proof_of_possession,
+				) {
+					return false
+				}
+				let bls_part = &AsRef::<[u8]>::as_ref(signer.as_inner_ref())
+					[ecdsa::PUBLIC_KEY_SERIALIZED_SIZE..];
+				let Ok(public) = DoublePublicKey::<TinyBLS381>::from_bytes(bls_part) else {
+					return false
+				};
+				match aggregated {
+					Some(ref mut aggregated) => aggregated.0 += public.1,
+					None => aggregated = Some(BlsPublic(public.1)),
+				}
+			}
+			aggregated.is_some_and(|public| signature.verify(&Message::new(b"", msg), &public))
+		}
 	}
 }
 
@@ -560,6 +662,14 @@ sp_api::decl_runtime_apis! {
 			prev_block_number: NumberFor<Block>,
 			best_known_block_number: Option<NumberFor<Block>>,
 		) -> Option<OpaqueValue>;
+
+		/// Return the registered proofs of possession of the given authority keys, in the same
+		/// order, or `None` if any of them is missing.
+		///
+		/// Needed to verify aggregated finality proofs, see
+		/// [`AggregatedSignedCommitment::verify_signature`].
+		#[api_version(6)]
+		fn proofs_of_possession(authorities: Vec<AuthorityId>) -> Option<Vec<Vec<u8>>>;
 	}
 
 }
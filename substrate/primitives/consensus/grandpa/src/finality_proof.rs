@@ -0,0 +1,256 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Self-contained GRANDPA finality proofs.
+//!
+//! A [`FinalityProofBundle`] proves the finality of a block to anyone who trusts some GRANDPA
+//! authority set, e.g. the genesis authorities, without access to a node. It contains the
+//! justified last block of every authority set from the trusted one up to the set that finalized
+//! the target block, followed by a justification finalizing the target block or one of its
+//! descendants.
+
+use crate::{
+	AuthorityId, AuthorityList, ConsensusLog, GrandpaJustification, ScheduledChange, SetId,
+	SignatureResult, GRANDPA_ENGINE_ID,
+};
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	vec::Vec,
+};
+use codec::{Decode, Encode};
+use finality_grandpa::{voter_set::VoterSet, BlockNumberOps, Chain};
+use scale_info::TypeInfo;
+use sp_runtime::{generic::OpaqueDigestItemId, traits::Header as HeaderT, RuntimeDebug};
+
+/// A block header together with the GRANDPA justification of its finality.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct JustifiedHeader<Header: HeaderT> {
+	/// The justified header.
+	pub header: Header,
+	/// The justification of the header.
+	pub justification: GrandpaJustification<Header>,
+}
+
+/// Proof of the finality of a block, starting at a trusted authority set.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FinalityProofBundle<Header: HeaderT> {
+	/// Id of the authority set the proof starts at.
+	pub set_id: SetId,
+	/// The authority set the proof starts at.
+	pub authorities: AuthorityList,
+	/// The last block of every authority set from `set_id` up to the set which finalized
+	/// `target`, in order. Each of them signals the change to the next authority set.
+	pub set_changes: Vec<JustifiedHeader<Header>>,
+	/// The block whose finality is proven.
+	pub target: Header,
+	/// The headers between `target` and the block justified by `justification`, exclusive and in
+	/// ascending order.
+	pub headers: Vec<Header>,
+	/// Justification of `target` or one of its descendants.
+	pub justification: JustifiedHeader<Header>,
+}
+
+/// Errors of finality proof verification.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum Error {
+	/// The proof doesn't start at the trusted authority set.
+	UntrustedAuthoritySet,
+	/// The authority set is empty or contains zero weights.
+	InvalidAuthoritySet,
+	/// The justification doesn't justify the block it is provided for.
+	JustificationTargetMismatch,
+	/// The commit of the justification doesn't have a supermajority of valid precommits.
+	InvalidCommit,
+	/// A precommit signature is invalid for the authority set.
+	InvalidSignature,
+	/// The votes ancestries of a justification are missing or have unused headers.
+	InvalidVotesAncestries,
+	/// The last block of an authority set doesn't signal the change to the next set.
+	MissingAuthoritySetChange,
+	/// The headers don't link the target to the justified block.
+	InvalidAncestry,
+}
+
+impl<Header: HeaderT> FinalityProofBundle<Header>
+where
+	Header::Number: BlockNumberOps,
+{
+	/// Verify the proof, starting at the trusted authority set `set_id` with `authorities`.
+	///
+	/// On success returns the id and the authorities of the set following the justified block,
+	/// which is the set that finalized the target unless the justified block changes the set.
+	pub fn verify(
+		&self,
+		set_id: SetId,
+		authorities: &AuthorityList,
+	) -> Result<(SetId, AuthorityList), Error> {
+		if self.set_id != set_id || &self.authorities != authorities {
+			return Err(Error::UntrustedAuthoritySet)
+		}
+
+		let mut set_id = self.set_id;
+		let mut authorities = self.authorities.clone();
+		for set_change in &self.set_changes {
+			set_change.verify(set_id, &authorities)?;
+			let change = find_scheduled_change(&set_change.header)
+				.ok_or(Error::MissingAuthoritySetChange)?;
+			set_id += 1;
+			authorities = change.next_authorities;
+		}
+
+		self.justification.verify(set_id, &authorities)?;
+		if let Some(change) = find_scheduled_change(&self.justification.header) {
+			set_id += 1;
+			authorities = change.next_authorities;
+		}
+
+		let mut parent_hash = *self.justification.header.parent_hash();
+		if self.justification.header != self.target {
+			for header in self.headers.iter().rev().chain(core::iter::once(&self.target)) {
+				if header.hash() != parent_hash {
+					return Err(Error::InvalidAncestry)
+				}
+				parent_hash = *header.parent_hash();
+			}
+		} else if !self.headers.is_empty() {
+			return Err(Error::InvalidAncestry)
+		}
+
+		Ok((set_id, authorities))
+	}
+}
+
+impl<Header: HeaderT> JustifiedHeader<Header>
+where
+	Header::Number: BlockNumberOps,
+{
+	/// Verify that the justification finalizes the header for the given authority set.
+	pub fn verify(&self, set_id: SetId, authorities: &AuthorityList) -> Result<(), Error> {
+		let commit = &self.justification.commit;
+		if (commit.target_hash, commit.target_number) != (self.header.hash(), *self.header.number())
+		{
+			return Err(Error::JustificationTargetMismatch)
+		}
+		verify_justification(&self.justification, set_id, authorities)
+	}
+}
+
+/// Verify the commit, the precommit signatures and the votes ancestries of a justification.
+pub fn verify_justification<Header: HeaderT>(
+	justification: &GrandpaJustification<Header>,
+	set_id: SetId,
+	authorities: &AuthorityList,
+) -> Result<(), Error>
+where
+	Header::Number: BlockNumberOps,
+{
+	let voters: VoterSet<AuthorityId> =
+		VoterSet::new(authorities.iter().cloned()).ok_or(Error::InvalidAuthoritySet)?;
+	let ancestry_chain = AncestryChain::new(&justification.votes_ancestries);
+
+	match finality_grandpa::validate_commit(&justification.commit, &voters, &ancestry_chain) {
+		Ok(ref result) if result.is_valid() => {},
+		_ => return Err(Error::InvalidCommit),
+	}
+
+	// the precommit for the lowest block is the root of the votes ancestries.
+	let base_hash = justification
+		.commit
+		.precommits
+		.iter()
+		.map(|signed| &signed.precommit)
+		.min_by_key(|precommit| precommit.target_number)
+		.map(|precommit| precommit.target_hash)
+		.ok_or(Error::InvalidCommit)?;
+
+	let mut buf = Vec::new();
+	let mut visited_hashes = BTreeSet::new();
+	for signed in &justification.commit.precommits {
+		let signature_result = crate::check_message_signature_with_buffer(
+			&finality_grandpa::Message::Precommit(signed.precommit.clone()),
+			&signed.id,
+			&signed.signature,
+			justification.round,
+			set_id,
+			&mut buf,
+		);
+		if signature_result != SignatureResult::Valid {
+			return Err(Error::InvalidSignature)
+		}
+
+		if base_hash == signed.precommit.target_hash {
+			continue
+		}
+		let route = ancestry_chain
+			.ancestry(base_hash, signed.precommit.target_hash)
+			.map_err(|_| Error::InvalidVotesAncestries)?;
+		// the route starts at the parent of the precommit target.
+		visited_hashes.insert(signed.precommit.target_hash);
+		visited_hashes.extend(route);
+	}
+
+	if visited_hashes != ancestry_chain.ancestry.keys().cloned().collect() {
+		return Err(Error::InvalidVotesAncestries)
+	}
+	Ok(())
+}
+
+/// Find the standard authority set change signaled by the header.
+fn find_scheduled_change<Header: HeaderT>(
+	header: &Header,
+) -> Option<ScheduledChange<Header::Number>> {
+	let id = OpaqueDigestItemId::Consensus(&GRANDPA_ENGINE_ID);
+	header
+		.digest()
+		.convert_first(|l| l.try_to(id).and_then(ConsensusLog::try_into_change))
+}
+
+/// `finality_grandpa::Chain` over the votes ancestries of a justification.
+struct AncestryChain<Header: HeaderT> {
+	ancestry: BTreeMap<Header::Hash, Header>,
+}
+
+impl<Header: HeaderT> AncestryChain<Header> {
+	fn new(ancestry: &[Header]) -> Self {
+		Self { ancestry: ancestry.iter().map(|h| (h.hash(), h.clone())).collect() }
+	}
+}
+
+impl<Header: HeaderT> Chain<Header::Hash, Header::Number> for AncestryChain<Header>
+where
+	Header::Number: BlockNumberOps,
+{
+	fn ancestry(
+		&self,
+		base: Header::Hash,
+		block: Header::Hash,
+	) -> Result<Vec<Header::Hash>, finality_grandpa::Error> {
+		let mut route = Vec::new();
+		let mut current_hash = block;
+		while current_hash != base {
+			let header =
+				self.ancestry.get(&current_hash).ok_or(finality_grandpa::Error::NotDescendent)?;
+			current_hash = *header.parent_hash();
+			route.push(current_hash);
+		}
+		route.pop(); // remove the base
+
+		Ok(route)
+	}
+}
@@ -34,6 +34,8 @@ use sp_runtime::{
 	ConsensusEngineId, OpaqueValue, RuntimeDebug,
 };
 
+pub mod finality_proof;
+
 /// The log target to be used by client code.
 pub const CLIENT_LOG_TARGET: &str = "grandpa";
 /// The log target to be used by runtime code.
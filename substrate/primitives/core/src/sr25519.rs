@@ -316,7 +316,24 @@ pub mod vrf {
 
 	/// Transcript ready to be used for VRF related operations.
 	#[derive(Clone)]
-	pub struct VrfTranscript(pub merlin::Transcript);
+	pub struct VrfTranscript(pub merlin::Transcript, Option<VrfTranscriptData>);
+
+	/// The data a [`VrfTranscript`] was built from.
+	///
+	/// Unlike the transcript itself it can be encoded, e.g. to sign it with a remote keystore.
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct VrfTranscriptData {
+		/// Label of the transcript.
+		pub label: Vec<u8>,
+		/// `(domain, message)` tuples appended to the transcript.
+		pub items: Vec<(Vec<u8>, Vec<u8>)>,
+	}
+
+	impl From<merlin::Transcript> for VrfTranscript {
+		fn from(transcript: merlin::Transcript) -> Self {
+			VrfTranscript(transcript, None)
+		}
+	}
 
 	impl VrfTranscript {
 		/// Build a new transcript instance.
@@ -325,7 +342,15 @@ pub mod vrf {
 		pub fn new(label: &'static [u8], data: &[(&'static [u8], &[u8])]) -> Self {
 			let mut transcript = merlin::Transcript::new(label);
 			data.iter().for_each(|(l, b)| transcript.append_message(l, b));
-			VrfTranscript(transcript)
+			let items = data.iter().map(|(l, b)| (l.to_vec(), b.to_vec())).collect();
+			VrfTranscript(transcript, Some(VrfTranscriptData { label: label.to_vec(), items }))
+		}
+
+		/// The data the transcript was built from.
+		///
+		/// Only available for transcripts built with [`VrfTranscript::new`].
+		pub fn data(&self) -> Option<&VrfTranscriptData> {
+			self.1.as_ref()
 		}
 
 		/// Map transcript to `VrfSignData`.
@@ -376,6 +401,11 @@ pub mod vrf {
 			self.extra = Some(extra);
 			self
 		}
+
+		/// Get the extra data to be signed, if any.
+		pub fn extra(&self) -> Option<&VrfTranscript> {
+			self.extra.as_ref()
+		}
 	}
 
 	/// VRF signature data
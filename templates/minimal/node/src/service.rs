@@ -189,7 +189,7 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 		task_manager: &mut task_manager,
 		transaction_pool: transaction_pool.clone(),
 		rpc_builder: rpc_extensions_builder,
-		backend,
+		backend: backend.clone(),
 		system_rpc_tx,
 		tx_handler_controller,
 		sync_service,
@@ -277,7 +277,8 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 					Ok(sp_timestamp::InherentDataProvider::from_system_time())
 				},
 			};
-			let authorship_future = sc_consensus_manual_seal::run_manual_seal(params);
+			let authorship_future =
+				sc_consensus_manual_seal::run_manual_seal_with_backend(params, backend);
 
 			task_manager.spawn_essential_handle().spawn_blocking(
 				"manual-seal",
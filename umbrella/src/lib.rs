@@ -612,6 +612,10 @@ pub use pallet_recovery;
 #[cfg(feature = "pallet-referenda")]
 pub use pallet_referenda;
 
+/// Runtime API for the referenda FRAME pallet.
+#[cfg(feature = "pallet-referenda-runtime-api")]
+pub use pallet_referenda_runtime_api;
+
 /// Remark storage pallet.
 #[cfg(feature = "pallet-remark")]
 pub use pallet_remark;